@@ -1,6 +1,6 @@
 use crate::window::create_surface;
 use crate::window::ViziaWindow;
-use baseview::{Window, WindowHandle, WindowScalePolicy};
+use baseview::{DropEffect, EventStatus, Window, WindowHandle, WindowScalePolicy};
 use gl_rs as gl;
 use gl_rs::types::GLint;
 use raw_window_handle::HasRawWindowHandle;
@@ -35,6 +35,7 @@ where
     window_description: WindowDescription,
     window_scale_policy: WindowScalePolicy,
     on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+    on_panic: Option<Box<dyn Fn(&mut EventContext, &ViewPanic) + Send>>,
     ignore_default_theme: bool,
 }
 
@@ -49,6 +50,7 @@ where
             window_description: WindowDescription::new(),
             window_scale_policy: WindowScalePolicy::SystemScaleFactor,
             on_idle: None,
+            on_panic: None,
             ignore_default_theme: false,
         }
     }
@@ -86,6 +88,21 @@ where
         self
     }
 
+    /// Catches panics in views while they handle events or are drawn, calling the handler instead of unwinding out of
+    /// the host.
+    ///
+    /// The view which panicked, or the closest view marked with
+    /// [`panic_boundary`](vizia_core::view::Handle::panic_boundary) containing it, is disabled and its children
+    /// replaced with the panic message.
+    pub fn with_panic_handler(
+        mut self,
+        handler: impl Fn(&mut EventContext, &ViewPanic) + Send + 'static,
+    ) -> Self {
+        self.on_panic = Some(Box::new(handler));
+
+        self
+    }
+
     /// Open a new window that blocks the current thread until the window is destroyed.
     ///
     /// Do **not** use this in the context of audio plugins, unless it is compiled as a
@@ -98,6 +115,7 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.on_panic,
             self.ignore_default_theme,
         );
 
@@ -118,6 +136,7 @@ where
             self.window_scale_policy,
             self.app,
             self.on_idle,
+            self.on_panic,
             self.ignore_default_theme,
         )
     }
@@ -205,6 +224,9 @@ impl ApplicationRunner {
             self.cx.send_event(event);
         }
 
+        // Baseview calls this once per frame, so animation frames are always due.
+        self.cx.process_animation_frames(Duration::ZERO);
+
         // Events
         self.event_manager.flush_events(self.cx.context(), |window_event| match window_event {
             // For some reason calling window.close() crashes baseview on macos
@@ -218,6 +240,11 @@ impl ApplicationRunner {
             _ => {}
         });
 
+        // Baseview calls this once per frame, so idle work runs in every frame without queued events.
+        if !self.cx.has_queued_events() {
+            self.cx.process_idle_tasks();
+        }
+
         // if *cx.window_size() != self.current_window_size
         //     || cx.user_scale_factor() != self.current_user_scale_factor
         // {
@@ -307,7 +334,24 @@ impl ApplicationRunner {
         self.should_redraw = false;
     }
 
-    pub fn handle_event(&mut self, event: baseview::Event, should_quit: &mut bool) {
+    /// Converts a logical position received from baseview to a physical position in the window.
+    fn physical_position(&self, position: baseview::Point) -> (f32, f32) {
+        // NOTE: We multiply by `self.window_scale_factor` and not by
+        //       `self.context.style.dpi_factor`. Since the additional scaling by
+        //       internally do additional scaling by `self.context.user_scale_factor` is
+        //       done internally to be able to separate actual HiDPI scaling from
+        //       arbitrary uniform scaling baseview only knows about its own scale
+        //       factor.
+        let physical_posx = position.x * self.window_scale_factor;
+        let physical_posy = position.y * self.window_scale_factor;
+        (physical_posx as f32, physical_posy as f32)
+    }
+
+    /// Handles an event received from baseview, returning whether a file dragged over the window would be accepted
+    /// if it were dropped.
+    pub fn handle_event(&mut self, event: baseview::Event, should_quit: &mut bool) -> EventStatus {
+        let mut status = EventStatus::Ignored;
+
         if requests_exit(&event) {
             self.cx.send_event(Event::new(WindowEvent::WindowClose));
             *should_quit = true;
@@ -333,16 +377,7 @@ impl ApplicationRunner {
                 baseview::MouseEvent::CursorMoved { position, modifiers } => {
                     update_modifiers(modifiers);
 
-                    // NOTE: We multiply by `self.window_scale_factor` and not by
-                    //       `self.context.style.dpi_factor`. Since the additional scaling by
-                    //       internally do additional scaling by `self.context.user_scale_factor` is
-                    //       done internally to be able to separate actual HiDPI scaling from
-                    //       arbitrary uniform scaling baseview only knows about its own scale
-                    //       factor.
-                    let physical_posx = position.x * self.window_scale_factor;
-                    let physical_posy = position.y * self.window_scale_factor;
-                    let cursor_x = (physical_posx) as f32;
-                    let cursor_y = (physical_posy) as f32;
+                    let (cursor_x, cursor_y) = self.physical_position(position);
                     self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
                 }
                 baseview::MouseEvent::ButtonPressed { button, modifiers } => {
@@ -391,6 +426,52 @@ impl ApplicationRunner {
                     self.cx.emit_origin(WindowEvent::MouseLeave);
                 }
 
+                // Files dragged over the window are translated into the same events as the winit backend sends.
+                baseview::MouseEvent::DragEntered { position, modifiers, data } => {
+                    update_modifiers(modifiers);
+
+                    let (cursor_x, cursor_y) = self.physical_position(position);
+                    self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
+
+                    if let baseview::DropData::Files(paths) = data {
+                        for path in paths {
+                            self.cx.emit_origin(WindowEvent::FileHovered(path));
+                        }
+
+                        status = EventStatus::AcceptDrop(DropEffect::Copy);
+                    }
+                }
+
+                baseview::MouseEvent::DragMoved { position, modifiers, data } => {
+                    update_modifiers(modifiers);
+
+                    let (cursor_x, cursor_y) = self.physical_position(position);
+                    self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
+
+                    if let baseview::DropData::Files(_) = data {
+                        status = EventStatus::AcceptDrop(DropEffect::Copy);
+                    }
+                }
+
+                baseview::MouseEvent::DragLeft => {
+                    self.cx.emit_origin(WindowEvent::FileHoverCancelled);
+                }
+
+                baseview::MouseEvent::DragDropped { position, modifiers, data } => {
+                    update_modifiers(modifiers);
+
+                    let (cursor_x, cursor_y) = self.physical_position(position);
+                    self.cx.emit_origin(WindowEvent::MouseMove(cursor_x, cursor_y));
+
+                    if let baseview::DropData::Files(paths) = data {
+                        for path in paths {
+                            self.cx.emit_origin(WindowEvent::Drop(DropData::File(path)));
+                        }
+
+                        status = EventStatus::AcceptDrop(DropEffect::Copy);
+                    }
+                }
+
                 _ => {}
             },
             baseview::Event::Keyboard(event) => {
@@ -483,16 +564,18 @@ impl ApplicationRunner {
 
                     //self.cx.set_scale_factor(self.window_scale_factor * user_scale_factor);
 
-                    let physical_size =
-                        (window_info.physical_size().width, window_info.physical_size().height);
-
-                    self.cx.set_window_size(
-                        Entity::root(),
-                        physical_size.0 as f32,
-                        physical_size.1 as f32,
+                    let user_scale_factor = self
+                        .cx
+                        .0
+                        .windows
+                        .get(&Entity::root())
+                        .map_or(1.0, |window| window.window_description.user_scale_factor);
+
+                    self.cx.set_size(
+                        window_info.physical_size().width,
+                        window_info.physical_size().height,
+                        self.window_scale_factor * user_scale_factor,
                     );
-
-                    self.cx.needs_refresh(Entity::root());
                 }
                 baseview::WindowEvent::WillClose => {
                     self.cx.send_event(Event::new(WindowEvent::WindowClose));
@@ -500,6 +583,8 @@ impl ApplicationRunner {
                 _ => {}
             },
         }
+
+        status
     }
 
     pub fn handle_idle(&mut self, on_idle: &Option<Box<dyn Fn(&mut Context) + Send>>) {
@@ -126,6 +126,7 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_panic: Option<Box<dyn Fn(&mut EventContext, &ViewPanic) + Send>>,
         ignore_default_theme: bool,
     ) -> WindowHandle
     where
@@ -157,6 +158,9 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
+                if let Some(handler) = on_panic {
+                    cx.set_panic_handler(handler);
+                }
                 ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
             },
         )
@@ -170,6 +174,7 @@ impl ViziaWindow {
         scale_policy: WindowScalePolicy,
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
+        on_panic: Option<Box<dyn Fn(&mut EventContext, &ViewPanic) + Send>>,
         ignore_default_theme: bool,
     ) where
         F: Fn(&mut Context),
@@ -196,6 +201,9 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
+                if let Some(handler) = on_panic {
+                    cx.set_panic_handler(handler);
+                }
                 ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
             },
         )
@@ -218,7 +226,7 @@ impl WindowHandler for ViziaWindow {
     fn on_event(&mut self, _window: &mut Window<'_>, event: Event) -> EventStatus {
         let mut should_quit = false;
 
-        self.application.handle_event(event, &mut should_quit);
+        let status = self.application.handle_event(event, &mut should_quit);
 
         self.application.handle_idle(&self.on_idle);
 
@@ -226,7 +234,7 @@ impl WindowHandler for ViziaWindow {
             // TODO: Request close.
         }
 
-        EventStatus::Ignored
+        status
     }
 }
 
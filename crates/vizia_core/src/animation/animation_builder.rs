@@ -5,6 +5,8 @@ use vizia_style::{BorderWidth, Property};
 /// A builder for constructing animations.
 pub struct AnimationBuilder<'a> {
     pub(crate) keyframes: Vec<KeyframeBuilder<'a>>,
+    pub(crate) from_current_value: bool,
+    pub(crate) max_time_step: Option<Duration>,
 }
 
 impl Default for AnimationBuilder<'_> {
@@ -16,7 +18,47 @@ impl Default for AnimationBuilder<'_> {
 impl AnimationBuilder<'_> {
     /// Creates a new [AnimationBuilder].
     pub fn new() -> Self {
-        Self { keyframes: Vec::new() }
+        Self { keyframes: Vec::new(), from_current_value: false, max_time_step: None }
+    }
+
+    /// Starts each animated property from its current value on the view, instead of from the value in the first
+    /// keyframe, when the animation is played.
+    ///
+    /// The current value includes the output of any animation already playing on the view, so playing an animation
+    /// built this way interrupts the animation and continues smoothly from where it was, without a jump.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// let animation = AnimationBuilder::new()
+    ///     .from_current_value()
+    ///     .keyframe(0.0, |key| key.opacity(0.0))
+    ///     .keyframe(1.0, |key| key.opacity(1.0));
+    /// ```
+    pub fn from_current_value(mut self) -> Self {
+        self.from_current_value = true;
+
+        self
+    }
+
+    /// Limits how far the animation advances each frame, so that a dropped frame slows the animation down instead of
+    /// making it jump forward.
+    ///
+    /// For example, with a maximum time step of one frame at 60Hz, a 200ms stall makes the animation finish about
+    /// 180ms later rather than skipping over most of a short animation.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// let animation = AnimationBuilder::new()
+    ///     .max_time_step(Duration::from_millis(16))
+    ///     .keyframe(0.0, |key| key.opacity(0.0))
+    ///     .keyframe(1.0, |key| key.opacity(1.0));
+    /// ```
+    pub fn max_time_step(mut self, max_time_step: Duration) -> Self {
+        self.max_time_step = Some(max_time_step);
+
+        self
     }
 
     /// Adds a new keyframe to the animation.
@@ -67,6 +109,20 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    /// Set the backdrop-filter value for the keyframe.
+    pub fn backdrop_filter(mut self, val: impl Into<Vec<FilterFunction>>) -> Self {
+        self.properties.push(Property::BackdropFilter(val.into()));
+
+        self
+    }
+
+    /// Set the filter value for the keyframe.
+    pub fn filter(mut self, val: impl Into<Vec<FilterFunction>>) -> Self {
+        self.properties.push(Property::Filter(val.into()));
+
+        self
+    }
+
     // TRANSFORM
 
     /// Set the transform value for the keyframe.
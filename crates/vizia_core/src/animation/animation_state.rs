@@ -41,6 +41,12 @@ pub(crate) struct AnimationState<T: Interpolator> {
     pub from_rule: usize,
     /// For tansitions. The ending rule for this transition.
     pub to_rule: usize,
+    /// For transitions. Whether the values of discrete properties are kept visible for the whole transition.
+    pub allow_discrete: bool,
+    /// The maximum time by which the animation advances each tick, if the time step is capped.
+    pub max_time_step: Option<Duration>,
+    /// The time of the last tick of the animation.
+    pub last_tick: Option<Instant>,
 
     /// List of entities connected to this animation (used when animation is removed from active list)
     pub entities: HashSet<Entity>,
@@ -54,7 +60,7 @@ where
     pub(crate) fn new(id: Animation) -> Self {
         AnimationState {
             id,
-            start_time: Instant::now(),
+            start_time: crate::util::now(),
             duration: Duration::new(0, 0),
             delay: Duration::new(0, 0),
             keyframes: Vec::new(),
@@ -66,6 +72,9 @@ where
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
+            allow_discrete: false,
+            max_time_step: None,
+            last_tick: None,
         }
     }
 
@@ -81,6 +90,12 @@ where
         self
     }
 
+    pub(crate) fn with_allow_discrete(mut self, allow_discrete: bool) -> Self {
+        self.allow_discrete = allow_discrete;
+
+        self
+    }
+
     pub(crate) fn with_keyframe(mut self, key: Keyframe<T>) -> Self {
         self.keyframes.push(key);
 
@@ -94,7 +109,7 @@ where
     pub(crate) fn play(&mut self, entity: Entity) {
         self.active = true;
         self.t = 0.0;
-        self.start_time = Instant::now();
+        self.start_time = crate::util::now();
         self.entities.insert(entity);
     }
 
@@ -110,7 +125,7 @@ where
     fn default() -> Self {
         AnimationState {
             id: Animation::null(),
-            start_time: Instant::now(),
+            start_time: crate::util::now(),
             duration: Duration::new(0, 0),
             delay: Duration::new(0, 0),
             keyframes: Vec::new(),
@@ -122,6 +137,9 @@ where
             entities: HashSet::new(),
             from_rule: usize::MAX,
             to_rule: usize::MAX,
+            allow_discrete: false,
+            max_time_step: None,
+            last_tick: None,
         }
     }
 }
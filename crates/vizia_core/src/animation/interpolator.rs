@@ -1,8 +1,8 @@
 use morphorm::Units;
 use vizia_style::{
-    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, Filter, FontSize, Gradient, Length,
-    LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection, LinearGradient,
-    Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, RGBA,
+    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, FilterFunction, FontSize, Gradient,
+    Length, LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection, LinearGradient,
+    Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, Visibility, RGBA,
 };
 
 use skia_safe::Matrix;
@@ -12,6 +12,15 @@ use crate::style::ImageOrGradient;
 /// A trait which describes how a property is interpolated for animations.
 pub(crate) trait Interpolator {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self;
+
+    /// Interpolates the property for a transition with `allow-discrete` set, which only differs from
+    /// [`interpolate`](Interpolator::interpolate) for discrete properties.
+    fn interpolate_discrete(start: &Self, end: &Self, t: f32) -> Self
+    where
+        Self: Sized,
+    {
+        Self::interpolate(start, end, t)
+    }
 }
 
 // Implementations of `Interpolator` for various properties.
@@ -59,6 +68,35 @@ impl Interpolator for Display {
             *end
         }
     }
+
+    // The view stays displayed for the whole transition when it is shown or hidden, so that other properties
+    // such as opacity can be transitioned at the same time.
+    fn interpolate_discrete(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (Display::None, _) if t > 0.0 => *end,
+            (_, Display::None) if t < 1.0 => *start,
+            _ => Self::interpolate(start, end, t),
+        }
+    }
+}
+
+impl Interpolator for Visibility {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        if t < 0.5 {
+            *start
+        } else {
+            *end
+        }
+    }
+
+    // The view stays visible for the whole transition when it is shown or hidden.
+    fn interpolate_discrete(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (Visibility::Hidden, Visibility::Visible) if t > 0.0 => *end,
+            (Visibility::Visible, Visibility::Hidden) if t < 1.0 => *start,
+            _ => Self::interpolate(start, end, t),
+        }
+    }
 }
 
 impl Interpolator for Opacity {
@@ -87,12 +125,41 @@ impl Interpolator for RGBA {
     }
 }
 
-impl Interpolator for Filter {
+impl Interpolator for FilterFunction {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (start, end) {
-            (Filter::Blur(start), Filter::Blur(end)) => {
-                Filter::Blur(Length::interpolate(start, end, t))
+            (FilterFunction::Blur(start), FilterFunction::Blur(end)) => {
+                FilterFunction::Blur(Length::interpolate(start, end, t))
+            }
+            (FilterFunction::Brightness(start), FilterFunction::Brightness(end)) => {
+                FilterFunction::Brightness(f32::interpolate(start, end, t))
+            }
+            (FilterFunction::Contrast(start), FilterFunction::Contrast(end)) => {
+                FilterFunction::Contrast(f32::interpolate(start, end, t))
+            }
+            (FilterFunction::DropShadow(start), FilterFunction::DropShadow(end)) => {
+                FilterFunction::DropShadow(Shadow::interpolate(start, end, t))
             }
+            (FilterFunction::Grayscale(start), FilterFunction::Grayscale(end)) => {
+                FilterFunction::Grayscale(f32::interpolate(start, end, t))
+            }
+            (FilterFunction::HueRotate(start), FilterFunction::HueRotate(end)) => {
+                FilterFunction::HueRotate(Angle::interpolate(start, end, t))
+            }
+            (FilterFunction::Invert(start), FilterFunction::Invert(end)) => {
+                FilterFunction::Invert(f32::interpolate(start, end, t))
+            }
+            (FilterFunction::Opacity(start), FilterFunction::Opacity(end)) => {
+                FilterFunction::Opacity(f32::interpolate(start, end, t))
+            }
+            (FilterFunction::Saturate(start), FilterFunction::Saturate(end)) => {
+                FilterFunction::Saturate(f32::interpolate(start, end, t))
+            }
+            (FilterFunction::Sepia(start), FilterFunction::Sepia(end)) => {
+                FilterFunction::Sepia(f32::interpolate(start, end, t))
+            }
+            // Different filter functions can't be interpolated, so the end value is used.
+            _ => end.clone(),
         }
     }
 }
@@ -1,9 +1,9 @@
+use vizia_style::StepPosition;
+
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct TimingFunction {
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+pub(crate) enum TimingFunction {
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32 },
+    Steps(u32, StepPosition),
 }
 
 impl Default for TimingFunction {
@@ -32,16 +32,43 @@ impl TimingFunction {
 
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self::CubicBezier { x1, y1, x2, y2 }
+    }
+
+    pub fn steps(count: u32, position: StepPosition) -> Self {
+        Self::Steps(count, position)
     }
 
     pub fn value(&self, x: f32) -> f32 {
-        // Linear
-        if self.x1 == self.y1 && self.x2 == self.y2 {
-            return x;
+        match *self {
+            Self::CubicBezier { x1, y1, x2, y2 } => {
+                // Linear
+                if x1 == y1 && x2 == y2 {
+                    return x;
+                }
+
+                Self::calc_bezier(Self::find_t_for_x(x, x1, x2), y1, y2)
+            }
+
+            Self::Steps(count, position) => Self::calc_steps(x, count, position),
+        }
+    }
+
+    // See https://www.w3.org/TR/css-easing-1/#step-easing-algo
+    fn calc_steps(x: f32, count: u32, position: StepPosition) -> f32 {
+        let count = count.max(1) as f32;
+        let jumps = match position {
+            StepPosition::Start | StepPosition::End => count,
+            StepPosition::Both => count + 1.0,
+            StepPosition::None => (count - 1.0).max(1.0),
+        };
+
+        let mut step = (x * count).floor();
+        if matches!(position, StepPosition::Start | StepPosition::Both) {
+            step += 1.0;
         }
 
-        Self::calc_bezier(self.find_t_for_x(x), self.y1, self.y2)
+        step.clamp(0.0, jumps) / jumps
     }
 
     fn calc_bezier(t: f32, a1: f32, a2: f32) -> f32 {
@@ -60,16 +87,16 @@ impl TimingFunction {
         3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1)
     }
 
-    fn find_t_for_x(&self, x: f32) -> f32 {
+    fn find_t_for_x(x: f32, x1: f32, x2: f32) -> f32 {
         let mut guess = x;
         let mut error = f32::MAX;
         for _ in 0..8 {
-            let pos = Self::calc_bezier(guess, self.x1, self.x2);
+            let pos = Self::calc_bezier(guess, x1, x2);
             error = pos - x;
             if error.abs() <= 0.0000001 {
                 return guess;
             }
-            let slope = Self::calc_bezier_slope(guess, self.x1, self.x2);
+            let slope = Self::calc_bezier_slope(guess, x1, x2);
             guess -= error / slope;
         }
         if error.abs() <= 0.0000001 {
@@ -83,6 +110,7 @@ impl TimingFunction {
 #[cfg(test)]
 mod tests {
     use super::TimingFunction;
+    use vizia_style::StepPosition;
 
     #[test]
     fn linear() {
@@ -95,4 +123,35 @@ mod tests {
         let timing_func = TimingFunction::ease();
         assert_eq!(timing_func.value(0.25), 0.4085106);
     }
+
+    #[test]
+    fn steps_end() {
+        let timing_func = TimingFunction::steps(4, StepPosition::End);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.3), 0.25);
+        assert_eq!(timing_func.value(0.99), 0.75);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn steps_start() {
+        let timing_func = TimingFunction::steps(4, StepPosition::Start);
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(0.8), 1.0);
+    }
+
+    #[test]
+    fn steps_both() {
+        let timing_func = TimingFunction::steps(3, StepPosition::Both);
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
+
+    #[test]
+    fn steps_none() {
+        let timing_func = TimingFunction::steps(3, StepPosition::None);
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.5), 0.5);
+        assert_eq!(timing_func.value(1.0), 1.0);
+    }
 }
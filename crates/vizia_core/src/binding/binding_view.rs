@@ -1,8 +1,9 @@
 use hashbrown::{HashMap, HashSet};
 use std::any::TypeId;
+use vizia_storage::ChildIterator;
 
-use crate::binding::{get_storeid, BasicStore, Store, StoreId};
-use crate::context::{CURRENT, MAPS, MAP_MANAGER};
+use crate::binding::{get_storeid, BasicStore, MapId, Store, StoreId};
+use crate::context::{InternalEvent, CURRENT, MAPS, MAP_MANAGER};
 use crate::model::ModelOrView;
 use crate::prelude::*;
 
@@ -18,7 +19,36 @@ where
     entity: Entity,
     lens: L,
     #[allow(clippy::type_complexity)]
-    content: Option<Box<dyn Fn(&mut Context, L)>>,
+    content: Option<Box<dyn Fn(&mut Context, L, Option<TransitionState<L::Target>>)>>,
+    transition: Option<BindingTransition<L::Target>>,
+}
+
+/// The change of data which caused the contents of an animated [`Binding`] to be rebuilt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionState<T> {
+    /// The value of the data before it changed.
+    pub from: T,
+    /// The value of the data after it changed.
+    pub to: T,
+    /// The duration of the transition, which can be used to play animations on the new contents which finish with
+    /// the transition.
+    pub duration: Duration,
+}
+
+// The state of the crossfade between the previous and current contents of an animated binding.
+struct BindingTransition<T> {
+    duration: Duration,
+    timer: Timer,
+    start: Instant,
+    // The value the current contents were built with.
+    value: Option<T>,
+    // The views and map lenses of the previous contents, which are removed when the transition finishes. Each view is
+    // paired with the opacity it fades out from.
+    outgoing: Vec<(Entity, Option<Opacity>)>,
+    outgoing_maps: Vec<MapId>,
+    // The views of the new contents, paired with any inline opacity they were built with, which is restored when the
+    // transition finishes.
+    incoming: Vec<(Entity, Option<Opacity>)>,
 }
 
 impl<L> Binding<L>
@@ -42,6 +72,42 @@ where
     pub fn new<F>(cx: &mut Context, lens: L, builder: F)
     where
         F: 'static + Fn(&mut Context, L),
+    {
+        Self::build(cx, lens, None, move |cx, lens, _| (builder)(cx, lens));
+    }
+
+    /// Creates a new binding view which crossfades between its previous and new contents when the data changes.
+    ///
+    /// When the observed data changes the contents are rebuilt with the previous and new values of the data, and for
+    /// the given `duration` the previous contents are kept alive while they fade out and the new contents fade in.
+    /// The previous contents are removed once the transition has finished, or when the data changes again. The
+    /// contents are built with no transition state when the binding is first built.
+    ///
+    /// As during the transition both the previous and new contents are laid out within the parent of the binding, the
+    /// contents are typically positioned absolutely or placed in a [`ZStack`] so that they overlap.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ZStack::new(cx, |cx| {
+    ///     Binding::new_animated(cx, AppData::page, Duration::from_millis(200), |cx, page, transition| {
+    ///         if let Some(TransitionState { from, to, .. }) = transition {
+    ///             debug!("Navigating from {:?} to {:?}", from, to);
+    ///         }
+    ///
+    ///         Label::new(cx, page.get(cx).title());
+    ///     });
+    /// });
+    /// ```
+    pub fn new_animated<F>(cx: &mut Context, lens: L, duration: Duration, builder: F)
+    where
+        F: 'static + Fn(&mut Context, L, Option<TransitionState<L::Target>>),
+    {
+        Self::build(cx, lens, Some(duration), builder);
+    }
+
+    fn build<F>(cx: &mut Context, lens: L, duration: Option<Duration>, builder: F)
+    where
+        F: 'static + Fn(&mut Context, L, Option<TransitionState<L::Target>>),
     {
         let id = cx.entity_manager.create();
         let current = cx.current();
@@ -50,7 +116,35 @@ where
         cx.style.add(id);
         cx.tree.set_ignored(id, true);
 
-        let binding = Self { entity: id, lens, content: Some(Box::new(builder)) };
+        let transition = duration.map(|duration| {
+            // The timer is added by the binding so that it is removed along with it.
+            let timer = cx.with_current(id, |cx| {
+                cx.add_timer(Duration::from_millis(16), Some(duration), |cx, action| {
+                    let binding = cx.current();
+                    match action {
+                        TimerAction::Tick(_) => {
+                            cx.emit(InternalEvent::TransitionBinding { binding, finished: false })
+                        }
+                        TimerAction::Stop => {
+                            cx.emit(InternalEvent::TransitionBinding { binding, finished: true })
+                        }
+                        _ => {}
+                    }
+                })
+            });
+
+            BindingTransition {
+                duration,
+                timer,
+                start: crate::util::now(),
+                value: None,
+                outgoing: Vec::new(),
+                outgoing_maps: Vec::new(),
+                incoming: Vec::new(),
+            }
+        });
+
+        let binding = Self { entity: id, lens, content: Some(Box::new(builder)), transition };
 
         CURRENT.with_borrow_mut(|f| *f = id);
 
@@ -146,32 +240,152 @@ where
     }
 }
 
+impl<L: Lens> Binding<L> {
+    // Returns the ids of the map lenses created by the contents of the binding.
+    fn maps(&self) -> Vec<MapId> {
+        MAPS.with_borrow(|maps| {
+            maps.iter().filter(|(_, (e, _))| *e == self.entity).map(|(id, _)| *id).collect()
+        })
+    }
+}
+
+fn destroy_maps(ids: &[MapId]) {
+    MAP_MANAGER.with_borrow_mut(|manager| {
+        MAPS.with_borrow_mut(|maps| {
+            for id in ids {
+                if maps.remove(id).is_some() {
+                    manager.destroy(*id);
+                }
+            }
+        });
+    });
+}
+
+// Returns the inline opacity of a view, if it has one.
+fn inline_opacity(cx: &mut Context, entity: Entity) -> Option<Opacity> {
+    cx.style.opacity.get_inline_mut(entity).copied()
+}
+
+// Sets the opacity of each view to a fraction of the opacity paired with it, which defaults to fully opaque.
+fn set_opacity(cx: &mut Context, entities: &[(Entity, Option<Opacity>)], fraction: f32) {
+    for (entity, opacity) in entities {
+        let opacity = opacity.map_or(1.0, |opacity| opacity.0);
+        cx.style.opacity.insert(*entity, Opacity(opacity * fraction));
+        cx.needs_redraw(*entity);
+    }
+}
+
 pub(crate) trait BindingHandler {
     fn update(&mut self, cx: &mut Context);
     fn remove(&self, cx: &mut Context);
     fn debug(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result;
+
+    /// Advances the transition of an animated binding.
+    fn transition(&mut self, _cx: &mut Context, _finished: bool) {}
 }
 
-impl<L: 'static + Lens> BindingHandler for Binding<L> {
+impl<L: 'static + Lens<Target: Data>> BindingHandler for Binding<L> {
     fn update(&mut self, cx: &mut Context) {
-        cx.remove_children(cx.current());
-
-        MAP_MANAGER.with_borrow_mut(|manager| {
-            MAPS.with_borrow_mut(|maps| {
-                maps.retain(|id, (e, _)| {
-                    if *e == self.entity {
-                        manager.destroy(*id);
-                        false
-                    } else {
-                        true
-                    }
-                });
-            });
-        });
+        let Some(mut transition) = self.transition.take() else {
+            cx.remove_children(cx.current());
+            destroy_maps(&self.maps());
 
+            if let Some(builder) = &self.content {
+                CURRENT.with_borrow_mut(|f| *f = self.entity);
+                (builder)(cx, self.lens, None);
+            }
+
+            return;
+        };
+
+        // Contents which are still fading out from an interrupted transition are removed straight away.
+        for (entity, _) in transition.outgoing.drain(..) {
+            cx.remove(entity);
+        }
+        destroy_maps(&std::mem::take(&mut transition.outgoing_maps));
+
+        let value = self.lens.get_option(cx);
+        let state = match (transition.value.take(), value.clone()) {
+            (Some(from), Some(to)) => {
+                Some(TransitionState { from, to, duration: transition.duration })
+            }
+            _ => None,
+        };
+        transition.value = value;
+
+        // Contents which are still fading in from an interrupted transition fade out from their current opacity.
+        let outgoing = ChildIterator::new(&cx.tree, self.entity)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|entity| (entity, inline_opacity(cx, entity)))
+            .collect::<Vec<_>>();
+        let outgoing_maps = self.maps();
+        transition.incoming.clear();
+
+        if state.is_none() {
+            for (entity, _) in outgoing.iter() {
+                cx.remove(*entity);
+            }
+            destroy_maps(&outgoing_maps);
+        }
+
+        let is_transition = state.is_some();
         if let Some(builder) = &self.content {
             CURRENT.with_borrow_mut(|f| *f = self.entity);
-            (builder)(cx, self.lens);
+            (builder)(cx, self.lens, state);
+        }
+
+        if is_transition {
+            transition.incoming = ChildIterator::new(&cx.tree, self.entity)
+                .filter(|entity| outgoing.iter().all(|(outgoing, _)| outgoing != entity))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|entity| (entity, inline_opacity(cx, entity)))
+                .collect();
+            set_opacity(cx, &transition.incoming, 0.0);
+
+            transition.outgoing = outgoing;
+            transition.outgoing_maps = outgoing_maps;
+            transition.start = crate::util::now();
+            cx.start_timer(transition.timer);
+        }
+
+        self.transition = Some(transition);
+    }
+
+    fn transition(&mut self, cx: &mut Context, finished: bool) {
+        let Some(transition) = &mut self.transition else { return };
+
+        if transition.outgoing.is_empty() && transition.incoming.is_empty() {
+            return;
+        }
+
+        let elapsed = crate::util::now().saturating_duration_since(transition.start);
+        let progress = if finished || transition.duration.is_zero() {
+            1.0
+        } else {
+            (elapsed.as_secs_f32() / transition.duration.as_secs_f32()).min(1.0)
+        };
+
+        set_opacity(cx, &transition.incoming, progress);
+        set_opacity(cx, &transition.outgoing, 1.0 - progress);
+
+        if progress < 1.0 {
+            return;
+        }
+
+        for (entity, _) in transition.outgoing.drain(..) {
+            cx.remove(entity);
+        }
+        destroy_maps(&std::mem::take(&mut transition.outgoing_maps));
+
+        for (entity, opacity) in transition.incoming.drain(..) {
+            if let Some(opacity) = opacity {
+                cx.style.opacity.insert(entity, opacity);
+            } else {
+                cx.style.opacity.remove(entity);
+            }
+            cx.needs_restyle(entity);
         }
     }
 
@@ -212,3 +426,53 @@ impl std::fmt::Debug for dyn BindingHandler {
         self.debug(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Lens)]
+    struct AppData {
+        page: u32,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|page, _| self.page = *page);
+        }
+    }
+
+    #[test]
+    fn animated_binding_keeps_previous_contents_during_transition() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { page: 0 }.build(cx);
+            Binding::new_animated(cx, AppData::page, Duration::from_millis(200), |cx, page, _| {
+                Label::new(cx, page.get(cx).to_string()).class("page");
+            });
+        });
+
+        harness.emit(1u32);
+        assert_eq!(harness.query_all(".page").len(), 2);
+
+        harness.advance_time(Duration::from_millis(300));
+        let pages = harness.query_all(".page");
+        assert_eq!(pages.len(), 1);
+        assert_eq!(harness.text(pages[0]), Some("1"));
+    }
+
+    #[test]
+    fn animated_binding_restores_inline_opacity() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { page: 0 }.build(cx);
+            Binding::new_animated(cx, AppData::page, Duration::from_millis(200), |cx, page, _| {
+                Label::new(cx, page.get(cx).to_string()).class("page").opacity(0.5);
+            });
+        });
+
+        harness.emit(1u32);
+        harness.advance_time(Duration::from_millis(300));
+        let page = harness.query(".page").unwrap();
+        assert_eq!(harness.context().style.opacity.get(page), Some(&Opacity(0.5)));
+    }
+}
@@ -75,10 +75,13 @@ impl_data_simple!(KeyChord);
 impl_data_simple!(FamilyOwned);
 impl_data_simple!(FontWeight);
 impl_data_simple!(TextAlign);
+impl_data_simple!(Direction);
 impl_data_simple!(LengthOrPercentage);
 impl_data_simple!(CornerShape);
 impl_data_simple!(Shadow);
 impl_data_simple!(TextDecorationLine);
+impl_data_simple!(WindowPosition);
+impl_data_simple!(WindowSize);
 
 impl Data for &'static str {
     fn same(&self, other: &Self) -> bool {
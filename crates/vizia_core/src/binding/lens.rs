@@ -1,12 +1,14 @@
 use std::any::TypeId;
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::{BitAnd, BitOr, Deref};
 use std::rc::Rc;
+use std::sync::{Arc, LazyLock, Mutex};
 
-use crate::context::{CURRENT, MAPS, MAP_MANAGER};
+use crate::context::{DataContext, CURRENT, MAPS, MAP_MANAGER};
 
 use super::MapId;
 
@@ -112,6 +114,15 @@ pub trait LensExt: Lens {
         Index::new(self, index)
     }
 
+    /// Used to construct a lens to a value computed from the lensed data, such as the length of a string.
+    ///
+    /// The closure returns an owned value, so prefer [`map_ref`](LensExt::map_ref) when selecting a part of the
+    /// lensed data, which avoids cloning it.
+    ///
+    /// # Example
+    /// ```compile_fail
+    /// Label::new(cx, AppData::name.map(|name| name.len()));
+    /// ```
     fn map<O: 'static, F: 'static + Fn(&Self::Target) -> O>(self, map: F) -> Map<Self, O> {
         let id = MAP_MANAGER.with_borrow_mut(|f| f.create());
         let entity = CURRENT.with_borrow(|f| *f);
@@ -121,6 +132,17 @@ pub trait LensExt: Lens {
         Map { id, lens: self, o: PhantomData }
     }
 
+    /// Used to construct a lens to a part of the lensed data, such as a field of a struct which doesn't derive
+    /// `Lens`, without cloning it.
+    ///
+    /// The value is only cloned when the lensed data is itself computed, such as by a [`map`](LensExt::map), and
+    /// when it changes, as bindings keep a copy of the previous value to detect changes with its [`Data`](super::Data)
+    /// implementation.
+    ///
+    /// # Example
+    /// ```compile_fail
+    /// Label::new(cx, AppData::document.map_ref(|document| &document.title));
+    /// ```
     fn map_ref<O: 'static, F: 'static + Fn(&Self::Target) -> &O>(self, map: F) -> MapRef<Self, O> {
         let id = MAP_MANAGER.with_borrow_mut(|f| f.create());
         let entity = CURRENT.with_borrow(|f| *f);
@@ -143,6 +165,34 @@ pub trait LensExt: Lens {
     {
         self.then(IntoLens::new())
     }
+
+    /// Returns the value of the lens, or `None` if the model or view data the lens reads from can't be found from
+    /// the context or the lens has no value.
+    ///
+    /// Unlike [`get`](super::ResGet::get), this doesn't panic, so it can be used in places which may outlive the data,
+    /// such as the body of a [`Binding`](crate::prelude::Binding) in a subtree which is removed and rebuilt.
+    ///
+    /// # Example
+    /// ```compile_fail
+    /// if let Some(name) = AppData::name.get_option(cx) {
+    ///     Label::new(cx, name);
+    /// }
+    /// ```
+    fn get_option(&self, cx: &impl DataContext) -> Option<Self::Target>
+    where
+        Self::Target: Clone,
+    {
+        cx.data::<Self::Source>().and_then(|source| self.view(source)).map(LensValue::into_owned)
+    }
+
+    /// Returns the value of the lens, or the default value of the target type if the model or view data the lens
+    /// reads from can't be found from the context or the lens has no value.
+    fn get_or_default(&self, cx: &impl DataContext) -> Self::Target
+    where
+        Self::Target: Clone + Default,
+    {
+        self.get_option(cx).unwrap_or_default()
+    }
 }
 
 // Implement LensExt for all types which implement Lens.
@@ -232,7 +282,7 @@ impl<L: Lens, O: 'static + Clone> Lens for MapRef<L, O> {
 
 impl<L: Lens, O: 'static> Debug for MapRef<L, O> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{:?}.map(?)", self.lens))
+        f.write_fmt(format_args!("{:?}.map_ref(?)", self.lens))
     }
 }
 
@@ -351,6 +401,15 @@ where
     }
 }
 
+/// A lens to a value which is never changed, such as a constant.
+///
+/// # Example
+/// ```compile_fail
+/// ProgressBar::horizontal(cx, StaticLens::new(&None::<f32>));
+/// List::new(cx, StaticLens::new_slice(&["One", "Two", "Three"]), |cx, _, item| {
+///     Label::new(cx, item);
+/// });
+/// ```
 pub struct StaticLens<T: 'static> {
     data: &'static T,
 }
@@ -388,9 +447,51 @@ impl<T> Lens for StaticLens<T> {
 }
 
 impl<T> StaticLens<T> {
+    /// Creates a lens to a value in static storage.
     pub fn new(data: &'static T) -> Self {
         StaticLens { data }
     }
+
+    /// Creates a lens to a value which isn't in static storage by leaking it, so the value is never dropped.
+    ///
+    /// This should only be used for values which live for the rest of the program, for example data which is
+    /// computed when the application starts.
+    pub fn new_leaked(data: T) -> Self {
+        StaticLens { data: Box::leak(Box::new(data)) }
+    }
+
+    /// Creates a lens to a shared value, which keeps the value alive for the rest of the program.
+    ///
+    /// This should only be used for values which live for the rest of the program. Creating more lenses from the same
+    /// shared value doesn't keep any more memory alive.
+    pub fn new_arc(data: Arc<T>) -> Self {
+        let key = (TypeId::of::<T>(), Arc::as_ptr(&data) as usize, 0);
+        let address = intern_static(key, || Arc::into_raw(data) as usize);
+        // Safety: the interned reference to the value is never released, so the value is never dropped.
+        StaticLens { data: unsafe { &*(address as *const T) } }
+    }
+}
+
+impl<T: 'static> StaticLens<&'static [T]> {
+    /// Creates a lens to a slice in static storage, for example a constant array, which can be used to build a
+    /// [`List`](crate::prelude::List).
+    pub fn new_slice(data: &'static [T]) -> Self {
+        let key = (TypeId::of::<T>(), data.as_ptr() as usize, data.len());
+        let address = intern_static(key, || Box::leak(Box::new(data)) as *const &[T] as usize);
+        // Safety: the interned reference to the slice is leaked, so it is never dropped.
+        StaticLens { data: unsafe { &*(address as *const &'static [T]) } }
+    }
+}
+
+// The addresses of the values kept alive for lenses created with `StaticLens::new_arc` and `StaticLens::new_slice`,
+// keyed by the type, address, and length of the value each lens was created from. Creating lenses for the same value
+// again reuses the address, so the memory kept alive is bounded by the number of distinct values.
+static INTERNED_STATICS: LazyLock<Mutex<HashMap<(TypeId, usize, usize), usize>>> =
+    LazyLock::new(Mutex::default);
+
+// Returns the interned address for a key, keeping a new value alive if there isn't one yet.
+fn intern_static(key: (TypeId, usize, usize), keep: impl FnOnce() -> usize) -> usize {
+    *INTERNED_STATICS.lock().unwrap().entry(key).or_insert_with(keep)
 }
 
 #[derive(Default)]
@@ -694,3 +795,41 @@ where
         Some(LensValue::Borrowed(*self))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view<T: Clone>(lens: StaticLens<T>) -> T {
+        match lens.view(&()).unwrap() {
+            LensValue::Borrowed(value) => value.clone(),
+            LensValue::Owned(value) => value,
+        }
+    }
+
+    #[test]
+    fn static_lens_from_slice() {
+        static ITEMS: [u32; 3] = [1, 2, 3];
+
+        let first = StaticLens::new_slice(&ITEMS);
+        let second = StaticLens::new_slice(&ITEMS);
+        assert_eq!(view(first), [1, 2, 3]);
+
+        // Lenses to the same slice share the same reference instead of each keeping one alive.
+        assert!(std::ptr::eq(first.data, second.data));
+        assert!(!std::ptr::eq(first.data, StaticLens::new_slice(&ITEMS[1..]).data));
+    }
+
+    #[test]
+    fn static_lens_from_arc() {
+        let data = Arc::new(String::from("shared"));
+
+        let first = StaticLens::new_arc(data.clone());
+        let second = StaticLens::new_arc(data.clone());
+        assert_eq!(view(first), "shared");
+
+        // Only the first lens keeps a reference to the value alive.
+        assert!(std::ptr::eq(first.data, second.data));
+        assert_eq!(Arc::strong_count(&data), 2);
+    }
+}
@@ -121,6 +121,7 @@ impl_res_simple!(FontSlant);
 impl_res_simple!(CornerShape);
 impl_res_simple!(Angle);
 impl_res_simple!(TextAlign);
+impl_res_simple!(Direction);
 impl_res_simple!(TextOverflow);
 impl_res_simple!(LineClamp);
 impl_res_clone!(Shadow);
@@ -129,6 +130,7 @@ impl_res_clone!(ShadowBuilder);
 impl_res_simple!(FontVariation);
 impl_res_clone!(Filter);
 impl_res_simple!(Opacity);
+impl_res_simple!(BlendMode);
 impl_res_simple!(FontWidth);
 impl_res_clone!(Translate);
 impl_res_clone!(Scale);
@@ -2,7 +2,7 @@
 //! results. The main type here is CachedData, usually accessed via `cx.cache`.
 
 use crate::prelude::*;
-use skia_safe::{Matrix, Path};
+use skia_safe::{IRect, IVector, Image, Matrix, Path};
 use vizia_storage::SparseSet;
 
 /// Stores data which can be cached between system runs.
@@ -18,6 +18,26 @@ pub struct CachedData {
     pub(crate) transform: SparseSet<Matrix>,
     pub(crate) clip_path: SparseSet<BoundingBox>,
     pub(crate) path: SparseSet<Path>,
+    pub(crate) layers: SparseSet<CachedLayer>,
+}
+
+/// An image of a view and its descendants, drawn by views with the `cache_layer` modifier.
+#[derive(Clone)]
+pub(crate) struct CachedLayer {
+    pub entity: Entity,
+    /// The drawn subtree, covering `rect` in window coordinates.
+    pub image: Image,
+    pub rect: IRect,
+    /// The bounds of the whole subtree when the image was drawn, including any part outside of the window.
+    pub unclipped_rect: IRect,
+    /// The transform of the view when the image was drawn.
+    pub transform: Matrix,
+    /// The translation of the view since the image was drawn, in whole pixels.
+    pub offset: IVector,
+    /// Whether the image is still up to date with the views in the subtree.
+    pub valid: bool,
+    /// Whether the layer was drawn in the current frame. Layers which were not are dropped.
+    pub used: bool,
 }
 
 impl CachedData {
@@ -36,6 +56,7 @@ impl CachedData {
         self.transform.remove(entity);
         self.clip_path.remove(entity);
         self.path.remove(entity);
+        self.layers.remove(entity);
     }
 
     /// Returns the bounding box of the entity, determined by the layout system.
@@ -43,6 +64,17 @@ impl CachedData {
         self.bounds.get(entity).cloned().unwrap()
     }
 
+    /// Returns the clip region of the entity in window coordinates, which is the intersection of the clip bounds of
+    /// the entity and those of its ancestors. Views which are not clipped return an unbounded region.
+    pub fn get_clip_region(&self, entity: Entity) -> BoundingBox {
+        self.clip_path.get(entity).copied().unwrap_or(BoundingBox::from_min_max(
+            -f32::MAX,
+            -f32::MAX,
+            f32::MAX,
+            f32::MAX,
+        ))
+    }
+
     /// Returns the x position of the entity.
     pub fn get_posx(&self, entity: Entity) -> f32 {
         self.bounds.get(entity).map_or(0.0, |b| b.x)
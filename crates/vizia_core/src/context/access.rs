@@ -121,4 +121,9 @@ impl AccessNode {
     pub fn set_max_numeric_value(&mut self, value: f64) {
         self.node_builder.set_max_numeric_value(value);
     }
+
+    /// Marks the node as disabled.
+    pub fn set_disabled(&mut self) {
+        self.node_builder.set_disabled();
+    }
 }
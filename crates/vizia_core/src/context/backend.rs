@@ -1,11 +1,17 @@
-use std::any::Any;
+use std::any::{Any, TypeId};
 
-use skia_safe::Surface;
+use skia_safe::{EncodedImageFormat, Surface};
 use vizia_storage::LayoutTreeIterator;
-use vizia_window::{WindowDescription, WindowPosition};
+use vizia_window::{Monitor, WindowDescription, WindowPosition};
 
-use super::EventProxy;
-use crate::{cache::CachedData, prelude::*, systems::*};
+use super::{EventProxy, InternalEvent};
+use crate::{
+    cache::CachedData,
+    events::EventManager,
+    prelude::*,
+    profiling::{end_frame, time_pass, FramePass},
+    systems::*,
+};
 
 #[cfg(feature = "clipboard")]
 use copypasta::ClipboardProvider;
@@ -13,6 +19,15 @@ use copypasta::ClipboardProvider;
 /// Context used to integrate vizia with windowing backends such as winit and baseview.
 pub struct BackendContext(pub Context);
 
+// The view of a window which is rendered without a native window.
+struct HeadlessWindow;
+
+impl View for HeadlessWindow {
+    fn element(&self) -> Option<&'static str> {
+        Some("window")
+    }
+}
+
 impl BackendContext {
     /// Creates a new instance of a backend context.
     pub fn new(cx: Context) -> Self {
@@ -119,7 +134,136 @@ impl BackendContext {
         surface: &mut Surface,
         dirty_surface: &mut Surface,
     ) -> bool {
-        draw_system(&mut self.0, window_entity, surface, dirty_surface)
+        let redrawn = {
+            let _timer = time_pass(FramePass::Draw);
+            draw_system(&mut self.0, window_entity, surface, dirty_surface)
+        };
+
+        if redrawn {
+            end_frame();
+
+            let now = crate::util::now();
+            self.0.redraw_times.push_back(now);
+            while self
+                .0
+                .redraw_times
+                .front()
+                .is_some_and(|time| now.duration_since(*time) >= Duration::from_secs(1))
+            {
+                self.0.redraw_times.pop_front();
+            }
+        }
+
+        redrawn
+    }
+
+    /// Returns the region of a window which was redrawn by the last call to [`draw`](Self::draw), in physical window
+    /// coordinates. Backends can use this to present only the damaged region of the window.
+    pub fn damage(&self, window_entity: Entity) -> Option<BoundingBox> {
+        self.0.windows.get(&window_entity).and_then(|window_state| window_state.damage)
+    }
+
+    /// Sets up the root window for rendering without a native window, using the given logical size and scale
+    /// factor, and loads the default themes.
+    ///
+    /// The content of the window can then be rendered with [`render_to_image`](Self::render_to_image) or
+    /// [`snapshot`](Self::snapshot), which require neither a GPU nor a display server.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::backend::BackendContext;
+    /// let mut cx = BackendContext::new(Context::new());
+    /// cx.add_headless_window(WindowSize::new(400, 300), 1.0);
+    /// cx.context().disable_system_fonts();
+    /// cx.context().add_font_mem(std::fs::read("Roboto-Regular.ttf").unwrap());
+    /// cx.context().set_default_font(&["Roboto"]);
+    ///
+    /// Label::new(cx.context(), "Hello World");
+    ///
+    /// let png = cx.render_to_image().unwrap();
+    /// std::fs::write("screenshot.png", png).unwrap();
+    /// ```
+    pub fn add_headless_window(&mut self, size: WindowSize, scale_factor: f32) {
+        let window_description = WindowDescription { inner_size: size, ..Default::default() };
+
+        self.0.remove_user_themes();
+        self.add_main_window(Entity::root(), &window_description, scale_factor);
+        self.add_window(HeadlessWindow);
+        self.0
+            .windows
+            .insert(Entity::root(), WindowState { window_description, ..Default::default() });
+
+        self.set_window_size(
+            Entity::root(),
+            size.width as f32 * scale_factor,
+            size.height as f32 * scale_factor,
+        );
+        self.needs_refresh(Entity::root());
+    }
+
+    /// Styles, lays out, and draws the whole of the root window into an off-screen raster surface, returning the
+    /// result as PNG encoded data. Any queued events are handled first.
+    pub fn render_to_image(&mut self) -> Option<Vec<u8>> {
+        self.process_headless_updates();
+
+        let bounds = self.0.cache.get_bounds(Entity::root());
+        let dimensions = (bounds.w.ceil() as i32, bounds.h.ceil() as i32);
+        let mut surface = skia_safe::surfaces::raster_n32_premul(dimensions)?;
+        let mut dirty_surface = surface.new_surface_with_dimensions(dimensions)?;
+
+        // A new surface is blank, so every view has to be drawn.
+        self.0.windows.get_mut(&Entity::root())?.dirty_rect = Some(bounds);
+
+        if !self.draw(Entity::root(), &mut surface, &mut dirty_surface) {
+            return None;
+        }
+
+        let image = surface.image_snapshot();
+        let data = image.encode(None, EncodedImageFormat::PNG, None)?;
+
+        Some(data.as_bytes().to_vec())
+    }
+
+    /// Styles, lays out, and draws a view and its descendants into an off-screen raster surface, returning the
+    /// result as PNG encoded data the size of the view. Any queued events are handled first.
+    pub fn snapshot(&mut self, entity: Entity) -> Option<Vec<u8>> {
+        self.process_headless_updates();
+        transform_system(&mut self.0);
+
+        let cx = &mut self.0;
+        let mut draw_cx = DrawContext {
+            current: entity,
+            style: &cx.style,
+            cache: &mut cx.cache,
+            tree: &cx.tree,
+            models: &cx.models,
+            views: &mut cx.views,
+            resource_manager: &cx.resource_manager,
+            text_context: &mut cx.text_context,
+            modifiers: &cx.modifiers,
+            mouse: &cx.mouse,
+            windows: &mut cx.windows,
+            dirty_rect: BoundingBox::default(),
+            panic_handling: &cx.panic_handling,
+            panics: Vec::new(),
+        };
+        let image = draw_to_png(&mut draw_cx, entity);
+
+        for panic in std::mem::take(&mut draw_cx.panics) {
+            let entity = panic.entity;
+            cx.event_queue.push_back(Event::new(InternalEvent::ViewPanicked(panic)).direct(entity));
+        }
+
+        image
+    }
+
+    // Handles any queued events and runs the style, animation, and layout systems.
+    fn process_headless_updates(&mut self) {
+        EventManager::new().flush_events(&mut self.0, |_| {});
+        self.process_style_updates();
+        self.process_animations();
+        self.process_visual_updates();
     }
 
     /// Set the current entity. This is useful in user code when you're performing black magic and
@@ -150,6 +294,24 @@ impl BackendContext {
         let logical_height = self.0.style.physical_to_logical(physical_height);
         self.0.style.width.insert(window_entity, Units::Pixels(logical_width));
         self.0.style.height.insert(window_entity, Units::Pixels(logical_height));
+
+        self.update_window_data(window_entity);
+    }
+
+    /// Sets the physical size and scale factor of the main window, for example when the window is resized by the
+    /// application it is embedded in, and updates the layout and redraws the window.
+    pub fn set_size(&mut self, width: u32, height: u32, scale: f64) {
+        self.set_scale_factor(scale);
+        self.set_window_size(Entity::root(), width as f32, height as f32);
+
+        if let Some(window_state) = self.0.windows.get_mut(&Entity::root()) {
+            window_state.window_description.inner_size = WindowSize::new(
+                (width as f64 / scale).round() as u32,
+                (height as f64 / scale).round() as u32,
+            );
+        }
+
+        self.needs_refresh(Entity::root());
     }
 
     pub fn set_window_position(&mut self, window_entity: Entity, physical_x: f32, physical_y: f32) {
@@ -159,7 +321,147 @@ impl BackendContext {
         if let Some(state) = self.0.windows.get_mut(&window_entity) {
             state.position =
                 WindowPosition::new(logical_x.round() as u32, logical_y.round() as u32);
+            state.physical_position = (physical_x.round() as i32, physical_y.round() as i32);
+        }
+
+        self.update_window_data(window_entity);
+    }
+
+    // Updates the `WindowData` model of a window, adding it if the window doesn't have one yet, and sends a
+    // `WindowEvent::Move` or `WindowEvent::Resize` event if the window has moved or been resized.
+    fn update_window_data(&mut self, window_entity: Entity) {
+        let bounds = self.0.cache.get_bounds(window_entity);
+        let size = WindowSize::new(
+            self.0.style.physical_to_logical(bounds.w).round() as u32,
+            self.0.style.physical_to_logical(bounds.h).round() as u32,
+        );
+        let position =
+            self.0.windows.get(&window_entity).map(|state| state.position).unwrap_or_default();
+
+        let window_data = self
+            .0
+            .models
+            .get(&window_entity)
+            .and_then(|models| models.get(&TypeId::of::<WindowData>()))
+            .and_then(|model| model.downcast_ref::<WindowData>())
+            .copied();
+
+        let Some(window_data) = window_data else {
+            self.0.with_current(window_entity, |cx| WindowData { position, size }.build(cx));
+            return;
+        };
+
+        let mut emit = |message: WindowEvent| {
+            self.0.event_queue.push_back(
+                Event::new(message)
+                    .target(window_entity)
+                    .origin(window_entity)
+                    .propagate(Propagation::Up),
+            );
+        };
+
+        if window_data.position != position {
+            emit(WindowEvent::Move(position.x, position.y));
+        }
+
+        if window_data.size != size {
+            emit(WindowEvent::Resize(size.width, size.height));
+        }
+    }
+
+    /// Sets the name of the monitor a window is on, returning whether the window has moved to a different monitor.
+    pub fn set_window_monitor(&mut self, window_entity: Entity, monitor: Option<String>) -> bool {
+        match self.0.windows.get_mut(&window_entity) {
+            Some(state) if state.monitor != monitor => {
+                state.monitor = monitor;
+                true
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Sets the monitors connected to the system.
+    ///
+    /// If the monitor configuration has changed, a [`WindowEvent::MonitorsChanged`] event is sent to every window.
+    pub fn set_monitors(&mut self, monitors: Vec<Monitor>) {
+        if self.0.monitors == monitors {
+            return;
+        }
+
+        self.0.monitors = monitors;
+
+        for window_entity in self.0.windows.keys() {
+            self.0.event_queue.push_back(
+                Event::new(WindowEvent::MonitorsChanged)
+                    .target(*window_entity)
+                    .origin(*window_entity)
+                    .propagate(Propagation::Subtree),
+            );
+        }
+    }
+
+    /// Sends a [`WindowEvent::CloseRequested`] event to a window and all of its views.
+    ///
+    /// After the event has been processed, [`close_prevented`](Self::close_prevented) returns whether
+    /// any of the views or models of the window prevented the close.
+    pub fn emit_close_requested(&mut self, window_entity: Entity) {
+        if let Some(state) = self.0.windows.get_mut(&window_entity) {
+            state.close_prevented = false;
+        }
+
+        self.0.event_queue.push_back(
+            Event::new(WindowEvent::CloseRequested)
+                .target(window_entity)
+                .origin(window_entity)
+                .propagate(Propagation::Subtree),
+        );
+    }
+
+    /// Returns true if the close of a window was prevented while handling a [`WindowEvent::CloseRequested`] event.
+    pub fn close_prevented(&self, window_entity: Entity) -> bool {
+        self.0.windows.get(&window_entity).is_some_and(|state| state.close_prevented)
+    }
+
+    /// Updates the minimized, maximized, and fullscreen state of a window, sending a
+    /// [`WindowEvent::Maximized`], [`WindowEvent::Minimized`], or [`WindowEvent::Restored`] event
+    /// to the views of the window when the state has changed.
+    pub fn set_window_state(
+        &mut self,
+        window_entity: Entity,
+        minimized: bool,
+        maximized: bool,
+        fullscreen: bool,
+    ) {
+        let Some(state) = self.0.windows.get_mut(&window_entity) else {
+            return;
+        };
+
+        if state.is_minimized == minimized
+            && state.is_maximized == maximized
+            && state.is_fullscreen == fullscreen
+        {
+            return;
         }
+
+        state.is_minimized = minimized;
+        state.is_maximized = maximized;
+        state.is_fullscreen = fullscreen;
+
+        let event = if minimized {
+            WindowEvent::Minimized
+        } else if maximized {
+            WindowEvent::Maximized
+        } else {
+            WindowEvent::Restored
+        };
+
+        self.0.event_queue.push_back(
+            Event::new(event)
+                .target(window_entity)
+                .origin(window_entity)
+                .propagate(Propagation::Subtree),
+        );
     }
 
     /// Temporarily sets the current entity, calls the provided closure, and then resets the current entity back to previous.
@@ -185,6 +487,24 @@ impl BackendContext {
         self.0.event_proxy = Some(proxy);
     }
 
+    /// Sets a handler which is called with panics caught while views handle events or are drawn.
+    ///
+    /// Once a handler is set, a panic in a view is caught rather than unwinding out of the event loop. The view, or the
+    /// closest [panic boundary](Handle::panic_boundary) containing it, is disabled and its children replaced with the
+    /// panic message before the handler is called.
+    pub fn set_panic_handler(&mut self, handler: impl Fn(&mut EventContext, &ViewPanic) + 'static) {
+        self.0.panic_handling.handler = Some(Box::new(handler));
+    }
+
+    /// Sets a function which shows an [`Inspector`] of a window in a separate window, for backends which support
+    /// multiple windows. Without it the inspector is shown as an overlay of the inspected window.
+    ///
+    /// The function is called with the inspected window as the current entity.
+    #[cfg(feature = "inspector")]
+    pub fn set_inspector_window(&mut self, builder: impl Fn(&mut Context, Entity) + 'static) {
+        self.0.inspector_window = Some(Box::new(builder));
+    }
+
     /// You should not call this method unless you are writing a windowing backend, in which case
     /// you should consult the existing windowing backends for usage information.
     #[cfg(feature = "clipboard")]
@@ -197,6 +517,17 @@ impl BackendContext {
         self.0.event_queue.push_back(event);
     }
 
+    /// Returns true if data is being dragged within the application, set with
+    /// [`set_drop_data`](crate::context::EventContext::set_drop_data).
+    pub fn is_dragging(&self) -> bool {
+        self.0.drop_data.is_some()
+    }
+
+    /// Returns the position of the mouse cursor in physical pixels, relative to the window it was last moved over.
+    pub fn cursor_position(&self) -> (f32, f32) {
+        (self.0.mouse.cursor_x, self.0.mouse.cursor_y)
+    }
+
     /// Check whether there are any events in the queue waiting for the next event dispatch cycle.
     pub fn has_queued_events(&self) -> bool {
         !self.0.event_queue.is_empty()
@@ -208,11 +539,13 @@ impl BackendContext {
 
     /// Calls the accessibility system and updates the accesskit node tree.
     pub fn process_tree_updates(&mut self) {
+        let _timer = time_pass(FramePass::Accessibility);
         accessibility_system(&mut self.0);
     }
 
     /// Calls the style system to match entities with shared styles.
     pub fn process_style_updates(&mut self) {
+        let _timer = time_pass(FramePass::Style);
         style_system(&mut self.0);
 
         // Load any unloaded images and remove unused images.
@@ -221,6 +554,7 @@ impl BackendContext {
 
     // Returns true if animations are playing
     pub fn process_animations(&mut self) -> bool {
+        let _timer = time_pass(FramePass::Animations);
         animation_system(&mut self.0)
     }
 
@@ -262,6 +596,17 @@ impl BackendContext {
         }
     }
 
+    /// Runs work scheduled with [`Context::schedule_idle`] or [`Context::build_incrementally`] until it is finished or
+    /// the idle budget of the frame is used up. This should be called when there are no queued events.
+    pub fn process_idle_tasks(&mut self) {
+        self.0.process_idle_tasks();
+    }
+
+    /// Returns true if there is scheduled idle work left to run.
+    pub fn has_idle_tasks(&self) -> bool {
+        !self.0.idle_tasks.is_empty()
+    }
+
     pub fn process_timers(&mut self) {
         self.0.tick_timers();
     }
@@ -278,8 +623,47 @@ impl BackendContext {
         }
     }
 
+    /// Sends a [`WindowEvent::AnimationFrame`] event to the shown views which have requested an animation frame, if
+    /// at least `frame_interval` has passed since the last frame. Requests from views which are not shown are kept
+    /// until the views are shown.
+    pub fn process_animation_frames(&mut self, frame_interval: Duration) {
+        let now = crate::util::now();
+        if now.duration_since(self.0.last_animation_frame) < frame_interval {
+            return;
+        }
+
+        let requests = std::mem::take(&mut self.0.animation_frame_requests);
+        let mut emitted = false;
+        for entity in requests {
+            if self.0.is_shown(entity) {
+                self.0.event_queue.push_back(
+                    Event::new(WindowEvent::AnimationFrame(now))
+                        .target(entity)
+                        .origin(entity)
+                        .propagate(Propagation::Direct),
+                );
+                emitted = true;
+            } else if self.0.entity_manager.is_alive(entity) {
+                self.0.animation_frame_requests.insert(entity);
+            }
+        }
+
+        if emitted {
+            self.0.last_animation_frame = now;
+        }
+    }
+
+    /// Returns the time of the next animation frame if any shown views have requested one.
+    pub fn next_animation_frame_time(&self, frame_interval: Duration) -> Option<Instant> {
+        self.0
+            .animation_frame_requests
+            .iter()
+            .any(|entity| self.0.is_shown(*entity))
+            .then(|| self.0.last_animation_frame + frame_interval)
+    }
+
     pub fn emit_scheduled_events(&mut self) {
-        let now = Instant::now();
+        let now = crate::util::now();
         while let Some(timed_event) = self.0.event_schedule.peek() {
             if timed_event.time <= now {
                 self.0.event_queue.push_back(self.0.event_schedule.pop().unwrap().event);
@@ -289,3 +673,76 @@ impl BackendContext {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skia_safe::image::CachingHint;
+    use skia_safe::{AlphaType, ColorType, Data, Image, ImageInfo};
+
+    // Decodes PNG data and returns its size and the color of its center pixel as RGBA.
+    fn decode(png: &[u8]) -> ((i32, i32), [u8; 4]) {
+        let image = Image::from_encoded(Data::new_copy(png)).expect("Failed to decode PNG");
+        let info = ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let mut pixel = [0u8; 4];
+        let center = (image.width() / 2, image.height() / 2);
+        assert!(image.read_pixels(&info, &mut pixel, 4, center, CachingHint::Allow));
+
+        ((image.width(), image.height()), pixel)
+    }
+
+    fn headless_context() -> (BackendContext, Entity) {
+        let mut cx = BackendContext::new(Context::new());
+        cx.add_headless_window(WindowSize::new(100, 50), 2.0);
+
+        let element = Element::new(cx.context())
+            .size(Pixels(20.0))
+            .background_color(Color::rgb(255, 0, 0))
+            .entity();
+
+        (cx, element)
+    }
+
+    #[test]
+    fn renders_window_to_image() {
+        let (mut cx, _) = headless_context();
+
+        let (size, _) = decode(&cx.render_to_image().unwrap());
+        assert_eq!(size, (200, 100));
+    }
+
+    #[test]
+    fn snapshots_view() {
+        let (mut cx, element) = headless_context();
+
+        let (size, center) = decode(&cx.snapshot(element).unwrap());
+        assert_eq!(size, (40, 40));
+        assert_eq!(center, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn finds_monitor_left_of_primary() {
+        let (mut cx, _) = headless_context();
+
+        let monitor = |name: &str, x: i32, is_primary: bool| Monitor {
+            name: Some(name.to_string()),
+            position: (x, 0),
+            size: WindowSize::new(1920, 1080),
+            scale_factor: 1.0,
+            is_primary,
+        };
+        cx.set_monitors(vec![monitor("left", -1920, false), monitor("primary", 0, true)]);
+
+        cx.set_window_position(Entity::root(), -1000.0, 100.0);
+        cx.0.mouse.cursor_x = 50.0;
+        cx.0.mouse.cursor_y = 50.0;
+        let monitor = EventContext::new(cx.context()).monitor_at_cursor().cloned();
+        assert_eq!(monitor.and_then(|monitor| monitor.name).as_deref(), Some("left"));
+
+        // The cursor is relative to the window, so moving it past the right edge of the left monitor crosses onto
+        // the primary monitor.
+        cx.0.mouse.cursor_x = 1050.0;
+        let monitor = EventContext::new(cx.context()).monitor_at_cursor().cloned();
+        assert_eq!(monitor.and_then(|monitor| monitor.name).as_deref(), Some("primary"));
+    }
+}
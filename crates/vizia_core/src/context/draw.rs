@@ -2,10 +2,11 @@ use skia_safe::canvas::SaveLayerRec;
 use skia_safe::gradient_shader::GradientShaderColors;
 use skia_safe::path::ArcSize;
 use skia_safe::rrect::Corner;
+use skia_safe::textlayout::{Paragraph, ParagraphBuilder, ParagraphStyle};
 use skia_safe::wrapper::PointerWrapper;
 use skia_safe::{
-    BlurStyle, ClipOp, MaskFilter, Matrix, Paint, PaintStyle, Path, PathDirection, PathEffect,
-    Point, RRect, Rect, SamplingOptions, Shader, TileMode,
+    image_filters, BlurStyle, ClipOp, Image, MaskFilter, Matrix, Paint, PaintStyle, Path,
+    PathDirection, PathEffect, Point, RRect, Rect, SamplingOptions, Shader, TileMode,
 };
 use std::any::{Any, TypeId};
 use std::f32::consts::SQRT_2;
@@ -18,10 +19,11 @@ use crate::cache::CachedData;
 use crate::events::ViewHandler;
 use crate::prelude::*;
 use crate::resource::{ImageOrSvg, ResourceManager};
-use crate::text::TextContext;
+use crate::systems::{text_align, text_style};
+use crate::text::{StringKey, TextContext};
 use vizia_input::MouseState;
 
-use super::ModelData;
+use super::{ModelData, PanicHandling};
 
 /// A context used when drawing a view.
 ///
@@ -67,6 +69,84 @@ pub struct DrawContext<'a> {
     pub(crate) modifiers: &'a Modifiers,
     pub(crate) mouse: &'a MouseState<Entity>,
     pub(crate) windows: &'a mut HashMap<Entity, WindowState>,
+    pub(crate) dirty_rect: BoundingBox,
+    pub(crate) panic_handling: &'a PanicHandling,
+    pub(crate) panics: Vec<ViewPanic>,
+}
+
+/// Overrides for the computed text style of a view, used when drawing or measuring a string with
+/// [`DrawContext::draw_string`] and [`DrawContext::measure_text`].
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let overrides = TextOverrides::new().font_size(12.0).color(Color::gray()).wrap(true);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextOverrides {
+    /// The font size in logical pixels.
+    pub font_size: Option<f32>,
+    /// The font weight.
+    pub font_weight: Option<FontWeight>,
+    /// The font slant.
+    pub font_slant: Option<FontSlant>,
+    /// The color of the text.
+    pub color: Option<Color>,
+    /// The letter spacing in logical pixels.
+    pub letter_spacing: Option<f32>,
+    /// The horizontal alignment of the text.
+    pub text_align: Option<TextAlign>,
+    /// Whether the text wraps onto multiple lines. Text which does not wrap is ellipsized to the bounds.
+    pub wrap: bool,
+}
+
+impl TextOverrides {
+    /// Creates a new set of overrides which uses the computed text style of the view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the font size in logical pixels.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Sets the font weight.
+    pub fn font_weight(mut self, font_weight: impl Into<FontWeight>) -> Self {
+        self.font_weight = Some(font_weight.into());
+        self
+    }
+
+    /// Sets the font slant.
+    pub fn font_slant(mut self, font_slant: FontSlant) -> Self {
+        self.font_slant = Some(font_slant);
+        self
+    }
+
+    /// Sets the color of the text.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the letter spacing in logical pixels.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = Some(letter_spacing);
+        self
+    }
+
+    /// Sets the horizontal alignment of the text.
+    pub fn text_align(mut self, text_align: TextAlign) -> Self {
+        self.text_align = Some(text_align);
+        self
+    }
+
+    /// Sets whether the text wraps onto multiple lines.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
 }
 
 macro_rules! get_units_property {
@@ -127,6 +207,21 @@ impl DrawContext<'_> {
         self.cache.get_bounds(self.current)
     }
 
+    /// Returns the bounds of another view, such as a sibling or ancestor of the current view, or `None` if the view
+    /// does not exist.
+    ///
+    /// This can be used to draw relative to other views, for example to draw a line connecting two nodes of a graph.
+    pub fn bounds_for(&self, entity: Entity) -> Option<BoundingBox> {
+        self.cache.bounds.get(entity).copied()
+    }
+
+    /// Returns the region of the window which is being redrawn, in physical window coordinates.
+    ///
+    /// Drawing is clipped to this region, so views which are expensive to draw can skip anything outside of it.
+    pub fn dirty_rect(&self) -> BoundingBox {
+        self.dirty_rect
+    }
+
     /// Marks the current view as needing to be redrawn.
     pub fn needs_redraw(&mut self) {
         let parent_window = self.tree.get_parent_window(self.current).unwrap_or(Entity::root());
@@ -155,6 +250,76 @@ impl DrawContext<'_> {
         self.mouse
     }
 
+    /// Returns the clip region of the current view in window coordinates, which is the intersection of the clip
+    /// bounds of the view and those of its ancestors.
+    pub fn clip_region(&self) -> BoundingBox {
+        self.cache.get_clip_region(self.current)
+    }
+
+    /// Clips any further drawing of the current view and its children to the given path, such as a circle or
+    /// polygon. The path is intersected with the existing clip region so that nothing is drawn outside the bounds
+    /// of the parent views. The clip is removed once the current view has been drawn.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::vg;
+    /// pub struct CircleView {}
+    ///
+    /// impl View for CircleView {
+    ///     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+    ///         let bounds = cx.bounds();
+    ///         let radius = bounds.w.min(bounds.h) / 2.0;
+    ///         cx.set_clip_path(canvas, vg::Path::circle(bounds.center(), radius, None));
+    ///         cx.draw_background(canvas);
+    ///     }
+    /// }
+    /// ```
+    pub fn set_clip_path(&self, canvas: &Canvas, path: Path) {
+        canvas.clip_path(&path, ClipOp::Intersect, true);
+    }
+
+    /// Captures what has already been drawn to the canvas within the given bounds and returns it blurred with a
+    /// gaussian blur of the given sigma, for drawing frosted glass and other blur-behind effects.
+    ///
+    /// The bounds are in the same coordinate space as [`bounds`](Self::bounds) and are transformed by the current
+    /// transform of the canvas, so the returned image should be drawn back into the same bounds. Returns `None` if
+    /// the canvas is not backed by a surface or the bounds are empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::vg;
+    /// pub struct FrostedCard {}
+    ///
+    /// impl View for FrostedCard {
+    ///     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+    ///         let bounds = cx.bounds();
+    ///         if let Some(image) = cx.blur_region(canvas, bounds, 10.0) {
+    ///             let rect: vg::Rect = bounds.into();
+    ///             canvas.draw_image_rect(&image, None, rect, &vg::Paint::default());
+    ///         }
+    ///         cx.draw_background(canvas);
+    ///     }
+    /// }
+    /// ```
+    pub fn blur_region(&self, canvas: &Canvas, bounds: BoundingBox, sigma: f32) -> Option<Image> {
+        let rect: Rect = bounds.into();
+        let (device_rect, _) = canvas.local_to_device_as_3x3().map_rect(rect);
+        let device_rect = device_rect.round_out();
+
+        // Safety: the surface is only used while the canvas, which it owns, is borrowed.
+        let mut surface = unsafe { canvas.surface() }?;
+        let snapshot = surface.image_snapshot_with_bounds(device_rect)?;
+
+        let mut blurred = surface.new_surface_with_dimensions(snapshot.dimensions())?;
+        let mut paint = Paint::default();
+        paint.set_image_filter(image_filters::blur((sigma, sigma), TileMode::Clamp, None, None));
+        blurred.canvas().draw_image(&snapshot, (0, 0), Some(&paint));
+
+        Some(blurred.image_snapshot())
+    }
+
     /// Returns the clip path of the current view.
     pub fn clip_path(&self) -> Option<skia_safe::Path> {
         let bounds = self.bounds();
@@ -484,11 +649,16 @@ impl DrawContext<'_> {
         self.style.shadow.get(self.current)
     }
 
-    /// Return to reference to any filter applied to the current view.
-    pub fn backdrop_filter(&self) -> Option<&Filter> {
+    /// Returns a reference to any filter functions applied to the content behind the current view.
+    pub fn backdrop_filter(&self) -> Option<&Vec<FilterFunction>> {
         self.style.backdrop_filter.get(self.current)
     }
 
+    /// Returns a reference to any filter functions applied to the current view.
+    pub fn filter(&self) -> Option<&Vec<FilterFunction>> {
+        self.style.filter.get(self.current)
+    }
+
     /// Returns a reference to any images of the current view.
     pub fn background_images(&self) -> Option<&Vec<ImageOrGradient>> {
         self.style.background_image.get(self.current)
@@ -1193,24 +1363,123 @@ impl DrawContext<'_> {
         }
     }
 
+    /// Draws a string within the given bounds, in physical pixels, using the computed text style of the current view
+    /// with any overrides applied.
+    ///
+    /// The text is aligned within the bounds by the alignment of the view, and is ellipsized if it does not fit.
+    /// Paragraphs are cached for each view, so a string is only shaped again when it or its style changes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # struct Meter;
+    /// impl View for Meter {
+    ///     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+    ///         let bounds = cx.bounds();
+    ///         cx.draw_string(canvas, "-12 dB", bounds, &TextOverrides::new().font_size(10.0));
+    ///     }
+    /// }
+    /// ```
+    pub fn draw_string(
+        &mut self,
+        canvas: &Canvas,
+        text: &str,
+        bounds: BoundingBox,
+        overrides: &TextOverrides,
+    ) {
+        let (top, _) = alignment_factors(self.alignment());
+        let paragraph = self.string_paragraph(text, bounds.width(), overrides);
+        let top = top * (bounds.height() - paragraph.height()).max(0.0);
+        paragraph.paint(canvas, (bounds.x.round(), (bounds.y + top).round()));
+    }
+
+    /// Returns the width and height, in physical pixels, of a string drawn with [`draw_string`](Self::draw_string).
+    ///
+    /// If a `max_width` is given the text is laid out within it, otherwise the width of the text on a single line is
+    /// returned.
+    pub fn measure_text(
+        &mut self,
+        text: &str,
+        max_width: Option<f32>,
+        overrides: &TextOverrides,
+    ) -> (f32, f32) {
+        let paragraph = self.string_paragraph(text, max_width.unwrap_or(f32::MAX), overrides);
+        let width = if max_width.is_some() {
+            paragraph.longest_line()
+        } else {
+            paragraph.max_intrinsic_width()
+        };
+        (width.ceil(), paragraph.height().ceil())
+    }
+
+    fn string_paragraph(
+        &mut self,
+        text: &str,
+        width: f32,
+        overrides: &TextOverrides,
+    ) -> &Paragraph {
+        let scale_factor = self.scale_factor();
+        let mut text_style = text_style(self.style, self.current);
+
+        if let Some(font_size) = overrides.font_size {
+            text_style.set_font_size(font_size * scale_factor);
+        }
+
+        if let Some(letter_spacing) = overrides.letter_spacing {
+            text_style.set_letter_spacing(letter_spacing * scale_factor);
+        }
+
+        if overrides.font_weight.is_some() || overrides.font_slant.is_some() {
+            let font_style = text_style.font_style();
+            text_style.set_font_style(skia_safe::FontStyle::new(
+                overrides.font_weight.map_or(font_style.weight(), Into::into),
+                font_style.width(),
+                overrides.font_slant.map_or(font_style.slant(), Into::into),
+            ));
+        }
+
+        if let Some(color) = overrides.color {
+            let mut paint = text_style.foreground();
+            paint.set_color(color);
+            text_style.set_foreground_paint(&paint);
+        }
+
+        let key = StringKey {
+            text: text.to_owned(),
+            text_style,
+            text_align: overrides
+                .text_align
+                .unwrap_or_else(|| text_align(self.style, self.current)),
+            max_lines: if overrides.wrap {
+                self.style.line_clamp.get(self.current).map(|line_clamp| line_clamp.0 as usize)
+            } else {
+                Some(1)
+            },
+            width,
+        };
+
+        self.text_context.string_paragraph(self.current, key.clone(), |font_collection| {
+            let mut paragraph_style = ParagraphStyle::default();
+            paragraph_style.set_ellipsis("…");
+            paragraph_style.set_max_lines(key.max_lines);
+            paragraph_style.set_text_align(key.text_align.into());
+
+            let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+            paragraph_builder.push_style(&key.text_style);
+            paragraph_builder.add_text(&key.text);
+
+            let mut paragraph = paragraph_builder.build();
+            paragraph.layout(key.width);
+            paragraph
+        })
+    }
+
     /// Draw any text for the current view.
     pub fn draw_text(&mut self, canvas: &Canvas) {
         if let Some(paragraph) = self.text_context.text_paragraphs.get(self.current) {
             let bounds = self.bounds();
 
-            let alignment = self.alignment();
-
-            let (mut top, _) = match alignment {
-                Alignment::TopLeft => (0.0, 0.0),
-                Alignment::TopCenter => (0.0, 0.5),
-                Alignment::TopRight => (0.0, 1.0),
-                Alignment::Left => (0.5, 0.0),
-                Alignment::Center => (0.5, 0.5),
-                Alignment::Right => (0.5, 1.0),
-                Alignment::BottomLeft => (1.0, 0.0),
-                Alignment::BottomCenter => (1.0, 0.5),
-                Alignment::BottomRight => (1.0, 1.0),
-            };
+            let (mut top, _) = alignment_factors(self.alignment());
 
             let padding_top = match self.padding_top() {
                 Units::Pixels(val) => val,
@@ -1237,6 +1506,22 @@ impl DrawContext<'_> {
     }
 }
 
+// Returns the vertical and horizontal position of content within its parent for an alignment, from 0.0 at the top or
+// left to 1.0 at the bottom or right.
+fn alignment_factors(alignment: Alignment) -> (f32, f32) {
+    match alignment {
+        Alignment::TopLeft => (0.0, 0.0),
+        Alignment::TopCenter => (0.0, 0.5),
+        Alignment::TopRight => (0.0, 1.0),
+        Alignment::Left => (0.5, 0.0),
+        Alignment::Center => (0.5, 0.5),
+        Alignment::Right => (0.5, 1.0),
+        Alignment::BottomLeft => (1.0, 0.0),
+        Alignment::BottomCenter => (1.0, 0.5),
+        Alignment::BottomRight => (1.0, 1.0),
+    }
+}
+
 impl DataContext for DrawContext<'_> {
     fn data<T: 'static>(&self) -> Option<&T> {
         // Return data for the static model.
@@ -2,17 +2,22 @@ use std::any::{Any, TypeId};
 use std::collections::{BinaryHeap, VecDeque};
 #[cfg(feature = "clipboard")]
 use std::error::Error;
+#[cfg(feature = "dialogs")]
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use hashbrown::{HashMap, HashSet};
+use vizia_id::IdManager;
 use vizia_storage::{LayoutTreeIterator, TreeIterator};
-use vizia_window::WindowPosition;
+use vizia_style::Atom;
+use vizia_window::{Monitor, WindowPosition};
 
 use crate::animation::{AnimId, Interpolator};
 use crate::cache::CachedData;
-use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
+use crate::events::{IdleTask, TimedEvent, TimedEventHandle, TimerState, ViewHandler};
 use crate::prelude::*;
 use crate::resource::ResourceManager;
+use crate::systems::draw_to_png;
 use crate::tree::{focus_backward, focus_forward, is_navigatable};
 use vizia_input::MouseState;
 
@@ -22,7 +27,8 @@ use crate::text::TextContext;
 #[cfg(feature = "clipboard")]
 use copypasta::ClipboardProvider;
 
-use super::{LocalizationContext, ModelData, DARK_THEME, LIGHT_THEME};
+use super::{built_in_theme, LocalizationContext, ModelData, PanicHandling};
+use crate::style::RuleScope;
 
 type Views = HashMap<Entity, Box<dyn ViewHandler>>;
 type Models = HashMap<Entity, HashMap<TypeId, Box<dyn ModelData>>>;
@@ -79,8 +85,8 @@ pub struct EventContext<'a> {
     pub(crate) mouse: &'a MouseState<Entity>,
     pub(crate) event_queue: &'a mut VecDeque<Event>,
     pub(crate) event_schedule: &'a mut BinaryHeap<TimedEvent>,
-    pub(crate) next_event_id: &'a mut usize,
-    pub(crate) timers: &'a mut Vec<TimerState>,
+    pub(crate) timer_manager: &'a mut IdManager<Timer>,
+    pub(crate) timers: &'a mut Vec<Option<TimerState>>,
     pub(crate) running_timers: &'a mut BinaryHeap<TimerState>,
     cursor_icon_locked: &'a mut bool,
     #[cfg(feature = "clipboard")]
@@ -89,6 +95,13 @@ pub struct EventContext<'a> {
     pub(crate) ignore_default_theme: &'a bool,
     pub(crate) drop_data: &'a mut Option<DropData>,
     pub windows: &'a mut HashMap<Entity, WindowState>,
+    pub(crate) monitors: &'a [Monitor],
+    pub(crate) animation_frame_requests: &'a mut HashSet<Entity>,
+    pub(crate) redraw_times: &'a VecDeque<Instant>,
+    pub(crate) events_per_frame: usize,
+    pub(crate) idle_tasks: &'a mut VecDeque<IdleTask>,
+    pub(crate) panic_handling: &'a PanicHandling,
+    pub(crate) capturing_views: &'a mut HashSet<Entity>,
 }
 
 macro_rules! get_length_property {
@@ -132,7 +145,7 @@ impl<'a> EventContext<'a> {
             mouse: &cx.mouse,
             event_queue: &mut cx.event_queue,
             event_schedule: &mut cx.event_schedule,
-            next_event_id: &mut cx.next_event_id,
+            timer_manager: &mut cx.timer_manager,
             timers: &mut cx.timers,
             running_timers: &mut cx.running_timers,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
@@ -142,6 +155,13 @@ impl<'a> EventContext<'a> {
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
             windows: &mut cx.windows,
+            monitors: &cx.monitors,
+            animation_frame_requests: &mut cx.animation_frame_requests,
+            redraw_times: &cx.redraw_times,
+            events_per_frame: cx.events_per_frame,
+            idle_tasks: &mut cx.idle_tasks,
+            panic_handling: &cx.panic_handling,
+            capturing_views: &mut cx.capturing_views,
         }
     }
 
@@ -166,7 +186,7 @@ impl<'a> EventContext<'a> {
             mouse: &cx.mouse,
             event_queue: &mut cx.event_queue,
             event_schedule: &mut cx.event_schedule,
-            next_event_id: &mut cx.next_event_id,
+            timer_manager: &mut cx.timer_manager,
             timers: &mut cx.timers,
             running_timers: &mut cx.running_timers,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
@@ -176,6 +196,13 @@ impl<'a> EventContext<'a> {
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
             windows: &mut cx.windows,
+            monitors: &cx.monitors,
+            animation_frame_requests: &mut cx.animation_frame_requests,
+            redraw_times: &cx.redraw_times,
+            events_per_frame: cx.events_per_frame,
+            idle_tasks: &mut cx.idle_tasks,
+            panic_handling: &cx.panic_handling,
+            capturing_views: &mut cx.capturing_views,
         }
     }
 
@@ -195,6 +222,39 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Prevents the parent window of the current view from closing in response to a
+    /// [`WindowEvent::CloseRequested`] event.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// #[derive(Lens)]
+    /// pub struct AppData {
+    ///     unsaved_changes: bool,
+    /// }
+    ///
+    /// impl Model for AppData {
+    ///     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+    ///         event.map(|window_event, _| match window_event {
+    ///             WindowEvent::CloseRequested => {
+    ///                 if self.unsaved_changes {
+    ///                     cx.prevent_close();
+    ///                     // Show a dialog asking the user whether to save the changes.
+    ///                 }
+    ///             }
+    ///
+    ///             _ => {}
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub fn prevent_close(&mut self) {
+        if let Some(state) = self.windows.get_mut(&self.parent_window()) {
+            state.close_prevented = true;
+        }
+    }
+
     pub fn window_position(&self) -> WindowPosition {
         let parent_window = self.parent_window();
         if let Some(state) = self.windows.get(&parent_window) {
@@ -210,11 +270,134 @@ impl<'a> EventContext<'a> {
         WindowSize::new(bounds.width() as u32, bounds.height() as u32)
     }
 
+    /// Returns true if the parent window of the current view is minimized.
+    pub fn is_window_minimized(&self) -> bool {
+        self.windows.get(&self.parent_window()).is_some_and(|state| state.is_minimized)
+    }
+
+    /// Returns true if the parent window of the current view is maximized.
+    pub fn is_window_maximized(&self) -> bool {
+        self.windows.get(&self.parent_window()).is_some_and(|state| state.is_maximized)
+    }
+
+    /// Returns true if the parent window of the current view is fullscreen.
+    pub fn is_window_fullscreen(&self) -> bool {
+        self.windows.get(&self.parent_window()).is_some_and(|state| state.is_fullscreen)
+    }
+
+    /// Returns the name of the monitor the parent window of the current view is on.
+    pub fn window_monitor(&self) -> Option<String> {
+        self.windows.get(&self.parent_window()).and_then(|state| state.monitor.clone())
+    }
+
+    /// Returns the geometry of the parent window of the current view, which can be saved and later restored
+    /// with the `geometry` window modifier.
+    pub fn window_geometry(&self) -> WindowGeometry {
+        let parent_window = self.parent_window();
+        let bounds = self.cache.get_bounds(parent_window);
+        let width = self.style.physical_to_logical(bounds.width()).round() as u32;
+        let height = self.style.physical_to_logical(bounds.height()).round() as u32;
+
+        WindowGeometry {
+            position: self.window_position(),
+            size: WindowSize::new(width, height),
+            maximized: self.is_window_maximized(),
+            monitor: self.window_monitor(),
+        }
+    }
+
+    /// Returns the monitors connected to the system.
+    ///
+    /// A [`WindowEvent::MonitorsChanged`] event is sent to every window when the monitor configuration changes.
+    pub fn monitors(&self) -> &[Monitor] {
+        self.monitors
+    }
+
+    /// Returns the primary monitor, or the first available monitor if there is no primary monitor.
+    pub fn primary_monitor(&self) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.is_primary).or_else(|| self.monitors.first())
+    }
+
+    /// Returns the monitor containing the mouse cursor.
+    pub fn monitor_at_cursor(&self) -> Option<&Monitor> {
+        let (window_x, window_y) = self
+            .windows
+            .get(&self.parent_window())
+            .map(|state| state.physical_position)
+            .unwrap_or_default();
+        let x = window_x + self.mouse.cursor_x.round() as i32;
+        let y = window_y + self.mouse.cursor_y.round() as i32;
+        self.monitors.iter().find(|monitor| monitor.contains(x, y))
+    }
+
+    /// Returns the window buttons which are enabled for the parent window of the current view.
+    pub fn window_buttons(&self) -> WindowButtons {
+        self.windows
+            .get(&self.parent_window())
+            .map(|state| state.window_description.enabled_window_buttons)
+            .unwrap_or(WindowButtons::all())
+    }
+
+    /// Minimizes the parent window of the current view, if the window has an enabled minimize button.
+    pub fn minimize_window(&mut self) {
+        if self.window_buttons().contains(WindowButtons::MINIMIZE) {
+            let parent_window = self.parent_window();
+            self.emit_to(parent_window, WindowEvent::SetMinimized(true));
+        }
+    }
+
+    /// Toggles whether the parent window of the current view is maximized, if the window has an
+    /// enabled maximize button.
+    pub fn toggle_maximize_window(&mut self) {
+        if self.window_buttons().contains(WindowButtons::MAXIMIZE) {
+            let parent_window = self.parent_window();
+            let maximized = self.is_window_maximized();
+            self.emit_to(parent_window, WindowEvent::SetMaximized(!maximized));
+        }
+    }
+
+    /// Requests that the parent window of the current view be closed, if the window has an enabled
+    /// close button.
+    pub fn request_window_close(&mut self) {
+        if self.window_buttons().contains(WindowButtons::CLOSE) {
+            let parent_window = self.parent_window();
+            self.emit_to(parent_window, WindowEvent::WindowClose);
+        }
+    }
+
     /// Returns the [Entity] id associated with the given identifier.
     pub fn resolve_entity_identifier(&self, id: &str) -> Option<Entity> {
         self.entity_identifiers.get(id).cloned()
     }
 
+    /// Returns the view which was given the ID with the `id` modifier, or `None` if there is no such view.
+    ///
+    /// This can be used to target a view built elsewhere in the tree, for example to focus a search box from the
+    /// event handler of a model.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// if let Some(search) = cx.entity_by_id("search") {
+    ///     cx.with_current(search, |cx| cx.focus());
+    /// }
+    /// ```
+    pub fn entity_by_id(&self, id: &str) -> Option<Entity> {
+        self.resolve_entity_identifier(id)
+    }
+
+    /// Returns all of the views, in tree order, which have the given class name.
+    pub fn entities_by_class(&self, class: &str) -> Vec<Entity> {
+        let class = Atom::new(class);
+        TreeIterator::full(self.tree)
+            .filter(|entity| {
+                self.style.classes.get(*entity).is_some_and(|classes| classes.contains(&class))
+            })
+            .collect()
+    }
+
     /// Returns the [Entity] id of the current view.
     pub fn current(&self) -> Entity {
         self.current
@@ -416,6 +599,33 @@ impl<'a> EventContext<'a> {
         false
     }
 
+    /// Stops all animations playing on the given entity, returning its animated properties to their style values.
+    ///
+    /// Animations which have been played but have not yet started are also stopped. Transitions are not affected,
+    /// see [`cancel_transitions`](Self::cancel_transitions).
+    pub fn stop_all_animations(&mut self, entity: Entity) {
+        self.style.stop_animations(entity, false);
+        self.needs_animation_update(entity);
+    }
+
+    /// Cancels the transitions playing on the given entity, so that its transitioning properties immediately take
+    /// their final values.
+    pub fn cancel_transitions(&mut self, entity: Entity) {
+        self.style.stop_animations(entity, true);
+        self.needs_animation_update(entity);
+    }
+
+    // Marks an entity as needing to be redrawn and laid out after its animated properties have changed.
+    fn needs_animation_update(&mut self, entity: Entity) {
+        self.style.needs_relayout();
+        self.style.needs_text_layout(entity);
+
+        let parent_window = self.tree.get_parent_window(entity).unwrap_or(Entity::root());
+        if let Some(window_state) = self.windows.get_mut(&parent_window) {
+            window_state.redraw_list.insert(entity);
+        }
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
@@ -508,6 +718,24 @@ impl<'a> EventContext<'a> {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Sets the views which are focused before and after a view during keyboard navigation, instead of the views
+    /// before and after it in the tree.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut event_context = EventContext::new(cx);
+    /// # let cx = &mut event_context;
+    /// # let (first, second, third) = (Entity::root(), Entity::root(), Entity::root());
+    /// // Tab from the first view to the third, skipping the second.
+    /// cx.set_focus_order(first, FocusOrder { prev: Entity::null(), next: third });
+    /// cx.set_focus_order(third, FocusOrder { prev: first, next: second });
+    /// ```
+    pub fn set_focus_order(&mut self, entity: Entity, focus_order: FocusOrder) {
+        self.style.focus_order.insert(entity, focus_order);
+    }
+
     /// Moves the keyboard focus to the next navigable view.
     pub fn focus_next(&mut self) {
         let lock_focus_to = self.tree.lock_focus_within(*self.focused);
@@ -659,11 +887,142 @@ impl<'a> EventContext<'a> {
         *self.cursor_icon_locked
     }
 
+    /// Moves the cursor to a position, in logical pixels relative to the top-left corner of the parent window of
+    /// the current view.
+    ///
+    /// Together with [`lock_cursor_icon`](Self::lock_cursor_icon) this can be used to keep dragging past the edges
+    /// of the screen, by moving the cursor back to the center of the window after each mouse move and accumulating
+    /// the distance moved. This has no effect with the baseview backend, which does not support moving the cursor.
+    pub fn set_cursor_position(&mut self, x: f32, y: f32) {
+        let x = self.logical_to_physical(x).round().max(0.0) as u32;
+        let y = self.logical_to_physical(y).round().max(0.0) as u32;
+        let window = self.parent_window();
+        self.emit_to(window, WindowEvent::SetCursorPosition(x, y));
+    }
+
     /// Sets the drop data of the current view.
     pub fn set_drop_data(&mut self, data: impl Into<DropData>) {
         *self.drop_data = Some(data.into())
     }
 
+    /// Starts a native drag and drop operation with the given data, using an image of the current view
+    /// as the drag image.
+    ///
+    /// When the operation finishes the current view receives a [`WindowEvent::DragEnded`] event. See
+    /// [`on_drag_start`](crate::modifiers::ActionModifiers::on_drag_start) for which payloads are supported
+    /// on each platform.
+    pub fn start_drag(&mut self, data: impl Into<DragData>) {
+        let mut draw_cx = DrawContext {
+            current: self.current,
+            style: self.style,
+            cache: self.cache,
+            tree: self.tree,
+            models: self.models,
+            views: self.views,
+            resource_manager: self.resource_manager,
+            text_context: self.text_context,
+            modifiers: self.modifiers,
+            mouse: self.mouse,
+            windows: self.windows,
+            dirty_rect: BoundingBox::default(),
+            panic_handling: self.panic_handling,
+            panics: Vec::new(),
+        };
+        let image = draw_to_png(&mut draw_cx, self.current);
+
+        for panic in std::mem::take(&mut draw_cx.panics) {
+            let entity = panic.entity;
+            self.emit_custom(Event::new(super::InternalEvent::ViewPanicked(panic)).direct(entity));
+        }
+
+        self.emit(WindowEvent::StartDrag { data: data.into(), image });
+    }
+
+    /// Shows a native dialog for choosing files to open, parented to the window of the current view.
+    ///
+    /// The dialog does not block the UI. Once the dialog is closed the callback is called with the chosen paths, or
+    /// `None` if the dialog was cancelled. Only one file can be chosen unless
+    /// [`multiple`](FileDialogOptions::multiple) is set. File dialogs are only supported by the winit backend.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// cx.open_file_dialog(
+    ///     FileDialogOptions::new().filter("Text", &["txt", "md"]).multiple(true),
+    ///     |_, paths| {
+    ///         if let Some(paths) = paths {
+    ///             println!("Opening {:?}", paths);
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    #[cfg(feature = "dialogs")]
+    pub fn open_file_dialog<F>(&mut self, options: FileDialogOptions, callback: F)
+    where
+        F: 'static + Send + FnOnce(&mut EventContext, Option<Vec<PathBuf>>),
+    {
+        let dialog =
+            FileDialog::new(FileDialogKind::Open, options, self.current, Box::new(callback));
+        self.emit(WindowEvent::FileDialog(dialog));
+    }
+
+    /// Shows a native dialog for choosing a location to save a file to, parented to the window of the current view.
+    ///
+    /// The dialog does not block the UI. Once the dialog is closed the callback is called with the chosen path, or
+    /// `None` if the dialog was cancelled. File dialogs are only supported by the winit backend.
+    #[cfg(feature = "dialogs")]
+    pub fn save_file_dialog<F>(&mut self, options: FileDialogOptions, callback: F)
+    where
+        F: 'static + Send + FnOnce(&mut EventContext, Option<PathBuf>),
+    {
+        let dialog = FileDialog::new(
+            FileDialogKind::Save,
+            options,
+            self.current,
+            Box::new(move |cx, paths| {
+                (callback)(cx, paths.and_then(|paths| paths.into_iter().next()))
+            }),
+        );
+        self.emit(WindowEvent::FileDialog(dialog));
+    }
+
+    /// Registers a key chord as a global shortcut, which is pressed even while the application doesn't have focus,
+    /// for example to show a window for quickly adding a note.
+    ///
+    /// When the key chord is pressed, a [`WindowEvent::GlobalShortcut`] event with the given id is sent to the
+    /// current view. If the shortcut can't be registered, a [`WindowEvent::GlobalShortcutFailed`] event is sent
+    /// instead. Registering another shortcut with the same id replaces the previous one. The shortcuts of a view are
+    /// unregistered when it is removed, and all global shortcuts are unregistered when the application exits.
+    ///
+    /// Global shortcuts are only supported by the winit backend. On Linux they require an X11 session, as Wayland
+    /// doesn't allow applications to listen for key presses outside of their own windows, and on macOS some key
+    /// chords are reserved by the system.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let mut cx = EventContext::new(cx);
+    /// const QUICK_ENTRY: u32 = 0;
+    ///
+    /// cx.register_global_shortcut(
+    ///     KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::Space),
+    ///     QUICK_ENTRY,
+    /// );
+    /// ```
+    #[cfg(feature = "global-shortcuts")]
+    pub fn register_global_shortcut(&mut self, chord: KeyChord, id: u32) {
+        self.emit(WindowEvent::RegisterGlobalShortcut(chord, id));
+    }
+
+    /// Unregisters the global shortcut with the given id.
+    #[cfg(feature = "global-shortcuts")]
+    pub fn unregister_global_shortcut(&mut self, id: u32) {
+        self.emit(WindowEvent::UnregisterGlobalShortcut(id));
+    }
+
     /// Get the contents of the system clipboard.
     ///
     /// This may fail for a variety of backend-specific reasons.
@@ -694,15 +1053,16 @@ impl<'a> EventContext<'a> {
     /// ```
     pub fn toggle_class(&mut self, class_name: &str, applied: bool) {
         let current = self.current();
+        let class_name = Atom::new(class_name);
         if let Some(class_list) = self.style.classes.get_mut(current) {
             if applied {
-                class_list.insert(class_name.to_string());
+                class_list.insert(class_name);
             } else {
-                class_list.remove(class_name);
+                class_list.remove(&class_name);
             }
         } else if applied {
             let mut class_list = HashSet::new();
-            class_list.insert(class_name.to_string());
+            class_list.insert(class_name);
             self.style.classes.insert(current, class_list);
         }
 
@@ -717,18 +1077,118 @@ impl<'a> EventContext<'a> {
     /// Sets the current [theme mode](ThemeMode).
     pub fn set_theme_mode(&mut self, theme_mode: ThemeMode) {
         if !self.ignore_default_theme {
-            match theme_mode {
-                ThemeMode::LightMode => {
-                    self.resource_manager.themes[2] = String::from(LIGHT_THEME);
-                }
+            self.resource_manager.themes[2] = String::from(built_in_theme(theme_mode));
+        }
+    }
 
-                ThemeMode::DarkMode => {
-                    self.resource_manager.themes[2] = String::from(DARK_THEME);
-                }
+    /// Sets the theme mode of the window containing the current view, overriding the theme mode of the application
+    /// for the views within that window. A theme mode of `None` makes the window follow the application theme mode.
+    ///
+    /// The stylesheets need to be reloaded for the change to take effect.
+    pub fn set_window_theme_mode(&mut self, theme_mode: Option<ThemeMode>) {
+        let window = self.parent_window();
+        match theme_mode {
+            Some(theme_mode) => self.resource_manager.window_themes.insert(window, theme_mode),
+            None => self.resource_manager.window_themes.remove(&window),
+        };
+    }
+
+    /// Requests that a [`WindowEvent::AnimationFrame`] event is sent to the current view on the next frame.
+    ///
+    /// A request only lasts for a single frame, so views which update continuously, such as spinners or audio
+    /// meters, should request another frame each time they handle the event. Requests from views which are not
+    /// shown are held until the view is shown again, so hidden views do not keep the application redrawing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// pub struct Spinner {
+    ///     angle: f32,
+    /// }
+    ///
+    /// impl View for Spinner {
+    ///     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+    ///         event.map(|window_event, _| {
+    ///             if let WindowEvent::AnimationFrame(_) = window_event {
+    ///                 self.angle += 5.0;
+    ///                 cx.needs_redraw();
+    ///                 cx.request_animation_frame();
+    ///             }
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub fn request_animation_frame(&mut self) {
+        self.animation_frame_requests.insert(self.current);
+    }
+
+    /// Returns the number of times a window of the application was redrawn within the last second.
+    ///
+    /// This is intended for debugging, for example to check that an idle application does not redraw.
+    pub fn redraws_per_second(&self) -> usize {
+        let now = crate::util::now();
+        self.redraw_times
+            .iter()
+            .filter(|time| now.duration_since(**time) < Duration::from_secs(1))
+            .count()
+    }
+
+    /// Schedules some work to run when the event loop is otherwise idle, with the current view as the current entity.
+    ///
+    /// The work is cancelled if the current view is removed before it runs.
+    pub fn schedule_idle(&mut self, work: impl FnOnce(&mut Context) + 'static) {
+        self.idle_tasks.push_back(IdleTask::once(self.current, work));
+    }
+
+    /// Returns the number of events which were handled the last time the event queue was flushed, which happens once
+    /// per frame.
+    ///
+    /// This is intended for debugging. Successive cursor moves, repeated redraw requests, and repeated geometry
+    /// changes of the same view are merged before the events are handled and are only counted once.
+    pub fn events_per_frame(&self) -> usize {
+        self.events_per_frame
+    }
+
+    /// Sets whether the region of the parent window which is redrawn in each frame is highlighted.
+    ///
+    /// This is intended for debugging, for example to check that changing a view doesn't redraw the whole window.
+    pub fn show_damage(&mut self, flag: bool) {
+        let parent_window = self.parent_window();
+        if let Some(window_state) = self.windows.get_mut(&parent_window) {
+            window_state.show_damage = flag;
+            window_state.redraw_list.insert(parent_window);
+        }
+    }
+
+    /// Highlights the bounds of a view in its window, or removes the highlight if `None`.
+    ///
+    /// This is intended for debugging tools, which can use it to show which view is being inspected.
+    pub fn highlight_view(&mut self, entity: Option<Entity>) {
+        let window =
+            entity.map(|entity| self.tree.get_parent_window(entity).unwrap_or(Entity::root()));
+        for (window_entity, window_state) in self.windows.iter_mut() {
+            let highlight = entity.filter(|_| window == Some(*window_entity));
+            if window_state.highlight != highlight {
+                window_state.highlight = highlight;
+                window_state.redraw_list.insert(*window_entity);
             }
         }
     }
 
+    /// Shows an [`Inspector`] of the parent window of the current view, unless it is already shown.
+    #[cfg(feature = "inspector")]
+    pub fn open_inspector(&mut self) {
+        let window = self.parent_window();
+        self.emit(super::InternalEvent::OpenInspector(window));
+    }
+
+    /// Removes the inspector of the parent window of the current view, or the inspector shown in it, if there is one.
+    #[cfg(feature = "inspector")]
+    pub fn close_inspector(&mut self) {
+        let window = self.parent_window();
+        self.emit(super::InternalEvent::CloseInspector(window));
+    }
+
     /// Marks the current view as needing to be redrawn.
     pub fn needs_redraw(&mut self) {
         let parent_window = self.tree.get_parent_window(self.current).unwrap_or(Entity::root());
@@ -759,28 +1219,74 @@ impl<'a> EventContext<'a> {
     }
 
     /// Reloads the stylesheets linked to the application.
-    pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
+    ///
+    /// Every stylesheet which can be read is applied, even if others fail. Returns [`StylesheetError::IoError`] if a
+    /// stylesheet file can no longer be read, in which case that stylesheet is skipped, and otherwise
+    /// [`StylesheetError::ParseWarning`] with the invalid rules and declarations of all stylesheets which were skipped.
+    pub fn reload_styles(&mut self) -> Result<(), StylesheetError> {
+        let mut warnings = Vec::new();
+        for (_, result) in self.reload_stylesheets() {
+            warnings.extend(result?);
+        }
+
+        if !warnings.is_empty() {
+            return Err(StylesheetError::ParseWarning(warnings));
+        }
+
+        Ok(())
+    }
+
+    /// Reloads the stylesheets linked to the application and returns, for each user stylesheet in the order they were
+    /// parsed, the window it is scoped to and either the rules and declarations which were skipped or the error
+    /// reading it.
+    pub(crate) fn reload_stylesheets(
+        &mut self,
+    ) -> Vec<(Option<Entity>, Result<Vec<String>, std::io::Error>)> {
+        let mut results = Vec::new();
+
         if self.resource_manager.themes.is_empty() && self.resource_manager.styles.is_empty() {
-            return Ok(());
+            return results;
         }
 
         self.style.remove_rules();
 
         self.style.clear_style_rules();
 
-        let mut overall_theme = String::new();
+        // Windows which override the theme mode replace the built-in theme of the application with their own.
+        let overridden_windows =
+            self.resource_manager.window_themes.keys().copied().collect::<Vec<_>>();
 
         // Reload built-in themes
-        for theme in self.resource_manager.themes.iter() {
-            overall_theme += theme;
+        for (index, theme) in self.resource_manager.themes.iter().enumerate() {
+            if index == 2 && !*self.ignore_default_theme {
+                self.style.parse_theme(theme, RuleScope::AllExcept(overridden_windows.clone()));
+
+                for (window, theme_mode) in self.resource_manager.window_themes.iter() {
+                    self.style.parse_theme(built_in_theme(*theme_mode), RuleScope::Window(*window));
+                }
+            } else {
+                self.style.parse_theme(theme, RuleScope::All);
+            }
         }
 
-        for style_string in self.resource_manager.styles.iter().flat_map(|style| style.get_style())
-        {
-            overall_theme += &style_string;
+        // Invalid rules in user stylesheets are skipped and returned, so that changes to a hot-reloaded stylesheet
+        // are reported as well.
+        for style in self.resource_manager.styles.iter() {
+            let result = style
+                .get_style()
+                .map(|style_string| self.style.parse_theme(&style_string, RuleScope::All));
+            results.push((None, result));
         }
 
-        self.style.parse_theme(&overall_theme);
+        // Window stylesheets are parsed last so that they take precedence over the application stylesheets.
+        for (window, styles) in self.resource_manager.window_styles.iter() {
+            for style in styles.iter() {
+                let result = style.get_style().map(|style_string| {
+                    self.style.parse_theme(&style_string, RuleScope::Window(*window))
+                });
+                results.push((Some(*window), result));
+            }
+        }
 
         for entity in self.tree.into_iter() {
             self.style.needs_restyle(entity);
@@ -789,7 +1295,7 @@ impl<'a> EventContext<'a> {
             self.style.needs_text_update(entity);
         }
 
-        Ok(())
+        results
     }
 
     /// Spawns a thread and provides a [ContextProxy] for sending events back to the main UI thread.
@@ -837,7 +1343,7 @@ impl<'a> EventContext<'a> {
     // Setters
 
     pub fn set_id(&mut self, id: &str) {
-        self.style.ids.insert(self.current, id.to_string())
+        self.style.ids.insert(self.current, Atom::new(id))
     }
 
     // Pseudoclass Setters
@@ -1072,8 +1578,13 @@ impl<'a> EventContext<'a> {
     // FILTER
 
     /// Sets the backdrop filter of the current view.
-    pub fn set_backdrop_filter(&mut self, filter: Filter) {
-        self.style.backdrop_filter.insert(self.current, filter);
+    pub fn set_backdrop_filter(&mut self, filter: impl Into<Vec<FilterFunction>>) {
+        self.style.backdrop_filter.insert(self.current, filter.into());
+    }
+
+    /// Sets the filter of the current view.
+    pub fn set_filter(&mut self, filter: impl Into<Vec<FilterFunction>>) {
+        self.style.filter.insert(self.current, filter.into());
     }
 
     // BOX SHADOW
@@ -1222,14 +1733,20 @@ impl<'a> EventContext<'a> {
         duration: Option<Duration>,
         callback: impl Fn(&mut EventContext, TimerAction) + 'static,
     ) -> Timer {
-        let id = Timer(self.timers.len());
-        self.timers.push(TimerState {
+        let id = self.timer_manager.create();
+        let index = id.index();
+        if index >= self.timers.len() {
+            self.timers.resize_with(index + 1, || None);
+        }
+
+        self.timers[index] = Some(TimerState {
             entity: Entity::root(),
+            owner: self.current,
             id,
-            time: Instant::now(),
+            time: crate::util::now(),
             interval,
             duration,
-            start_time: Instant::now(),
+            start_time: crate::util::now(),
             callback: Rc::new(callback),
             ticking: false,
             stopping: false,
@@ -1244,13 +1761,18 @@ impl<'a> EventContext<'a> {
     pub fn start_timer(&mut self, timer: Timer) {
         let current = self.current;
         if !self.timer_is_running(timer) {
-            let timer_state = self.timers[timer.0].clone();
+            let Some(Some(timer_state)) = self.timers.get(timer.index()) else {
+                return;
+            };
+            if timer_state.id != timer {
+                return;
+            }
             // Copy timer state from pending to playing
-            self.running_timers.push(timer_state);
+            self.running_timers.push(timer_state.clone());
         }
 
         self.modify_timer(timer, |timer_state| {
-            let now = Instant::now();
+            let now = crate::util::now();
             timer_state.start_time = now;
             timer_state.time = now;
             timer_state.entity = current;
@@ -1273,7 +1795,7 @@ impl<'a> EventContext<'a> {
             }
         }
 
-        for pending_timer in self.timers.iter_mut() {
+        for pending_timer in self.timers.iter_mut().flatten() {
             if pending_timer.id == timer {
                 (timer_function)(pending_timer);
             }
@@ -1297,7 +1819,7 @@ impl<'a> EventContext<'a> {
             }
         }
 
-        for pending_timer in self.timers.iter() {
+        for pending_timer in self.timers.iter().flatten() {
             if pending_timer.id == timer {
                 return Some(timer_function(pending_timer));
             }
@@ -1408,9 +1930,8 @@ impl EmitContext for EventContext<'_> {
         )
     }
     fn schedule_emit_custom(&mut self, event: Event, at: Instant) -> TimedEventHandle {
-        let handle = TimedEventHandle(*self.next_event_id);
+        let handle = TimedEventHandle::next();
         self.event_schedule.push(TimedEvent { event, time: at, ident: handle });
-        *self.next_event_id += 1;
         handle
     }
     fn cancel_scheduled(&mut self, handle: TimedEventHandle) {
@@ -5,15 +5,12 @@ mod access;
 pub mod backend;
 mod draw;
 mod event;
+mod panic;
 mod proxy;
 mod resource;
 
 use log::debug;
-use skia_safe::{
-    svg,
-    textlayout::{FontCollection, TypefaceFontProvider},
-    FontMgr,
-};
+use skia_safe::{svg, FontMgr};
 use std::cell::RefCell;
 use std::collections::{BinaryHeap, VecDeque};
 use std::rc::Rc;
@@ -23,9 +20,13 @@ use std::{
     sync::Arc,
 };
 use vizia_id::IdManager;
-use vizia_window::{WindowDescription, WindowPosition};
+use vizia_style::Atom;
+use vizia_window::{Monitor, WindowDescription, WindowPosition};
 
-#[cfg(all(feature = "clipboard", feature = "x11"))]
+#[cfg(all(
+    feature = "clipboard",
+    any(feature = "x11", target_os = "windows", target_os = "macos")
+))]
 use copypasta::ClipboardContext;
 #[cfg(feature = "clipboard")]
 use copypasta::{nop_clipboard::NopClipboardContext, ClipboardProvider};
@@ -34,12 +35,14 @@ use hashbrown::{hash_map::Entry, HashMap, HashSet};
 pub use access::*;
 pub use draw::*;
 pub use event::*;
+pub use panic::ViewPanic;
+pub(crate) use panic::*;
 pub use proxy::*;
 pub use resource::*;
 
 use crate::{
     binding::{Store, StoreId},
-    events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler},
+    events::{visit_entity, IdleTask, TimedEvent, TimedEventHandle, TimerState, ViewHandler},
     model::ModelData,
 };
 
@@ -51,15 +54,34 @@ use crate::{cache::CachedData, resource::ImageOrSvg};
 
 use crate::prelude::*;
 use crate::resource::ResourceManager;
+use crate::testing::EntityTree;
 use crate::text::TextContext;
 use vizia_input::MouseState;
-use vizia_storage::{ChildIterator, LayoutTreeIterator};
+use vizia_storage::{ChildIterator, LayoutTreeIterator, TreeIterator};
 
 static DEFAULT_LAYOUT: &str = include_str!("../../resources/themes/default_layout.css");
 static DARK_THEME: &str = include_str!("../../resources/themes/dark_theme.css");
 static LIGHT_THEME: &str = include_str!("../../resources/themes/light_theme.css");
 static MARKDOWN: &str = include_str!("../../resources/themes/markdown.css");
 
+// Returns the stylesheet of the built-in theme for the given theme mode.
+pub(crate) fn built_in_theme(theme_mode: ThemeMode) -> &'static str {
+    match theme_mode {
+        ThemeMode::LightMode => LIGHT_THEME,
+        ThemeMode::DarkMode => DARK_THEME,
+    }
+}
+
+// Converts the result of reloading a single stylesheet into the result of adding it.
+fn stylesheet_result(result: Result<Vec<String>, std::io::Error>) -> Result<(), StylesheetError> {
+    let warnings = result?;
+    if warnings.is_empty() {
+        Ok(())
+    } else {
+        Err(StylesheetError::ParseWarning(warnings))
+    }
+}
+
 type Views = HashMap<Entity, Box<dyn ViewHandler>>;
 type Models = HashMap<Entity, HashMap<TypeId, Box<dyn ModelData>>>;
 type Stores = HashMap<Entity, HashMap<StoreId, Box<dyn Store>>>;
@@ -82,17 +104,35 @@ pub struct WindowState {
     pub needs_redraw: bool,
     pub redraw_list: HashSet<Entity>,
     pub dirty_rect: Option<BoundingBox>,
+    /// The region of the window which was redrawn in the last frame, in physical window coordinates.
+    pub damage: Option<BoundingBox>,
+    /// Whether the region redrawn in each frame is highlighted, for debugging.
+    pub show_damage: bool,
+    /// A view in the window whose bounds are highlighted, for debugging.
+    pub highlight: Option<Entity>,
     pub owner: Option<Entity>,
     pub is_modal: bool,
     pub should_close: bool,
+    pub close_prevented: bool,
     pub position: WindowPosition,
+    /// The physical position of the top-left corner of the client area of the window in screen coordinates, which
+    /// can be negative on monitors placed to the left of or above the primary monitor.
+    pub physical_position: (i32, i32),
+    pub is_minimized: bool,
+    pub is_maximized: bool,
+    pub is_fullscreen: bool,
+    pub monitor: Option<String>,
     pub content: Option<Arc<dyn Fn(&mut Context)>>,
 }
 
 /// The main storage and control object for a Vizia application.
 pub struct Context {
     pub(crate) entity_manager: IdManager<Entity>,
+    // The key of the stable entity to give to the next view which is built.
+    pub(crate) stable_id: Option<u64>,
     pub(crate) entity_identifiers: HashMap<String, Entity>,
+    // The element name and type name of each view type which has been built with an element name.
+    pub(crate) element_names: HashMap<TypeId, (&'static str, &'static str)>,
     pub tree: Tree<Entity>,
     pub(crate) current: Entity,
     pub(crate) views: Views,
@@ -101,8 +141,9 @@ pub struct Context {
     pub(crate) bindings: Bindings,
     pub(crate) event_queue: VecDeque<Event>,
     pub(crate) event_schedule: BinaryHeap<TimedEvent>,
-    pub(crate) next_event_id: usize,
-    pub(crate) timers: Vec<TimerState>,
+    pub(crate) timer_manager: IdManager<Timer>,
+    // The timers which have been added, indexed by the index of their handle.
+    pub(crate) timers: Vec<Option<TimerState>>,
     pub(crate) running_timers: BinaryHeap<TimerState>,
     pub tree_updates: Vec<Option<accesskit::TreeUpdate>>,
     pub(crate) listeners:
@@ -111,6 +152,7 @@ pub struct Context {
     pub(crate) style: Style,
     pub(crate) cache: CachedData,
     pub windows: HashMap<Entity, WindowState>,
+    pub(crate) monitors: Vec<Monitor>,
 
     pub mouse: MouseState<Entity>,
     pub(crate) modifiers: Modifiers,
@@ -140,6 +182,28 @@ pub struct Context {
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+    pub(crate) drag_target: Entity,
+    pub(crate) touches: Vec<(u64, (f32, f32))>,
+
+    pub(crate) animation_frame_requests: HashSet<Entity>,
+    pub(crate) last_animation_frame: Instant,
+    pub(crate) redraw_times: VecDeque<Instant>,
+    pub(crate) events_per_frame: usize,
+    pub(crate) idle_tasks: VecDeque<IdleTask>,
+    pub(crate) idle_budget: Duration,
+    pub(crate) panic_handling: PanicHandling,
+    // Views which may handle events in the capture phase. Views are added when they are built and remove themselves
+    // the first time the default, empty, capture handler is called, so that events usually skip the capture phase.
+    pub(crate) capturing_views: HashSet<Entity>,
+    // Builds an inspector of a window in a separate window, for backends which support multiple windows.
+    #[cfg(feature = "inspector")]
+    pub(crate) inspector_window: Option<Box<dyn Fn(&mut Context, Entity)>>,
+    // The timer which refreshes every inspector, added when the first inspector is shown.
+    #[cfg(feature = "inspector")]
+    pub(crate) inspector_timer: Option<Timer>,
+    // The views which have registered global shortcuts, whose shortcuts are unregistered when they are removed.
+    #[cfg(feature = "global-shortcuts")]
+    pub(crate) global_shortcut_sources: HashSet<Entity>,
 }
 
 impl Default for Context {
@@ -156,7 +220,9 @@ impl Context {
 
         let mut result = Self {
             entity_manager: IdManager::new(),
+            stable_id: None,
             entity_identifiers: HashMap::new(),
+            element_names: HashMap::new(),
             tree: Tree::new(),
             current: Entity::root(),
             views: HashMap::default(),
@@ -166,9 +232,10 @@ impl Context {
             style: Style::default(),
             cache,
             windows: HashMap::new(),
+            monitors: Vec::new(),
             event_queue: VecDeque::new(),
             event_schedule: BinaryHeap::new(),
-            next_event_id: 0,
+            timer_manager: IdManager::new(),
             timers: Vec::new(),
             running_timers: BinaryHeap::new(),
             tree_updates: Vec::new(),
@@ -183,37 +250,21 @@ impl Context {
             focus_stack: Vec::new(),
             cursor_icon_locked: false,
             resource_manager: ResourceManager::new(),
-            text_context: {
-                let mut font_collection = FontCollection::new();
-
-                let default_font_manager = FontMgr::default();
-
-                let asset_provider = TypefaceFontProvider::new();
-
-                font_collection.set_default_font_manager(default_font_manager.clone(), None);
-                let asset_font_manager: FontMgr = asset_provider.clone().into();
-                font_collection.set_asset_font_manager(asset_font_manager);
-
-                TextContext {
-                    font_collection,
-                    default_font_manager,
-                    asset_provider,
-                    text_bounds: Default::default(),
-                    text_paragraphs: Default::default(),
-                }
-            },
+            text_context: TextContext::new(),
 
             event_proxy: None,
 
             #[cfg(feature = "clipboard")]
             clipboard: {
-                #[cfg(feature = "x11")]
+                // Only linux and the BSDs need the x11 feature for the system clipboard, which is often disabled
+                // when using the baseview backend.
+                #[cfg(any(feature = "x11", target_os = "windows", target_os = "macos"))]
                 if let Ok(context) = ClipboardContext::new() {
                     Box::new(context)
                 } else {
                     Box::new(NopClipboardContext::new().unwrap())
                 }
-                #[cfg(not(feature = "x11"))]
+                #[cfg(not(any(feature = "x11", target_os = "windows", target_os = "macos")))]
                 Box::new(NopClipboardContext::new().unwrap())
             },
             click_time: Instant::now(),
@@ -225,6 +276,23 @@ impl Context {
             window_has_focus: true,
 
             drop_data: None,
+            drag_target: Entity::null(),
+            touches: Vec::new(),
+
+            animation_frame_requests: HashSet::new(),
+            last_animation_frame: Instant::now(),
+            redraw_times: VecDeque::new(),
+            events_per_frame: 0,
+            idle_tasks: VecDeque::new(),
+            idle_budget: Duration::from_millis(8),
+            panic_handling: PanicHandling::default(),
+            capturing_views: HashSet::new(),
+            #[cfg(feature = "inspector")]
+            inspector_window: None,
+            #[cfg(feature = "inspector")]
+            inspector_timer: None,
+            #[cfg(feature = "global-shortcuts")]
+            global_shortcut_sources: HashSet::new(),
         };
 
         result.tree.set_window(Entity::root(), true);
@@ -260,6 +328,49 @@ impl Context {
         ret
     }
 
+    /// Calls a closure which builds views, giving the first view it builds the stable entity for a key rather than a
+    /// new entity.
+    ///
+    /// A stable entity is the same in every run, so it can be saved and restored, for example with the `serde` feature,
+    /// to refer to the view when restoring layout state. Keys range from 0 to one less than the number of stable ids
+    /// reserved with [`reserve_stable_ids`](Self::reserve_stable_ids). If the key is out of range or its entity is
+    /// already in use, the view gets a new entity and a warning is logged.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.reserve_stable_ids(1);
+    /// let sidebar = cx.with_stable_id(0, |cx| VStack::new(cx, |_| {}).entity());
+    /// assert_eq!(cx.stable_entity(0), Some(sidebar));
+    /// ```
+    pub fn with_stable_id<T>(&mut self, key: u64, f: impl FnOnce(&mut Context) -> T) -> T {
+        let previous = self.stable_id.replace(key);
+        let ret = f(self);
+        self.stable_id = previous;
+        ret
+    }
+
+    /// Reserves entities for `count` stable ids, with keys from `0` to `count - 1`, which can then be given to views with
+    /// [`with_stable_id`](Self::with_stable_id). No stable ids are reserved by default.
+    ///
+    /// The reserved entities follow the entities which already exist, so for them to be the same in every run this
+    /// should be called before any views are built, for example at the start of the content closure of the
+    /// application. Every reserved entity takes up space in the storage of styles and layout whether or not a view
+    /// uses it, so only as many stable ids as are needed should be reserved.
+    ///
+    /// # Panics
+    /// Panics if stable ids have already been reserved.
+    pub fn reserve_stable_ids(&mut self, count: u64) {
+        self.entity_manager.reserve_stable_ids(count);
+    }
+
+    /// Returns the stable entity for a key if a view was built with it using
+    /// [`with_stable_id`](Self::with_stable_id) and has not been removed.
+    pub fn stable_entity(&self, key: u64) -> Option<Entity> {
+        self.entity_manager.get_stable(key)
+    }
+
     /// Returns a reference to the [Environment] model.
     pub fn environment(&self) -> &Environment {
         self.data::<Environment>().unwrap()
@@ -270,6 +381,11 @@ impl Context {
         self.tree.get_parent_window(self.current).unwrap_or(Entity::root())
     }
 
+    /// Returns the monitors connected to the system.
+    pub fn monitors(&self) -> &[Monitor] {
+        &self.monitors
+    }
+
     /// Returns the scale factor of the display.
     pub fn scale_factor(&self) -> f32 {
         self.style.dpi_factor as f32
@@ -285,6 +401,44 @@ impl Context {
         }
     }
 
+    // Returns true if the entity is displayed, not hidden, within its clip region, and in a window which is not
+    // minimized.
+    pub(crate) fn is_shown(&self, entity: Entity) -> bool {
+        if !self.entity_manager.is_alive(entity) {
+            return false;
+        }
+
+        let parent_window = self.tree.get_parent_window(entity).unwrap_or(Entity::root());
+        if self.windows.get(&parent_window).map_or(true, |window_state| window_state.is_minimized) {
+            return false;
+        }
+
+        let mut visibility = None;
+        for ancestor in entity.parent_iter(&self.tree) {
+            if self.style.display.get(ancestor) == Some(&Display::None) {
+                return false;
+            }
+
+            if visibility.is_none() {
+                visibility = self.style.visibility.get(ancestor).copied();
+            }
+
+            if self.tree.is_window(ancestor) {
+                break;
+            }
+        }
+
+        if visibility == Some(Visibility::Hidden) {
+            return false;
+        }
+
+        self.cache.bounds.get(entity).is_some_and(|bounds| {
+            bounds.w > 0.0
+                && bounds.h > 0.0
+                && self.cache.get_clip_region(entity).intersects(bounds)
+        })
+    }
+
     /// Mark the application as needing to recompute view styles
     pub fn needs_restyle(&mut self, entity: Entity) {
         self.style.restyle.insert(entity).unwrap();
@@ -411,6 +565,20 @@ impl Context {
             self.needs_redraw(self.current);
         }
 
+        // Let the hovered view and its ancestors know that the cursor has left the hovered view. Ancestors which are not
+        // removed no longer contain a hovered view until the hovered view is updated.
+        if delete_list.contains(&self.hovered) {
+            let hovered = self.hovered;
+            let left = hovered.parent_iter(&self.tree).collect::<Vec<_>>();
+            self.hovered = Entity::null();
+
+            let cx = &mut EventContext::new(self);
+            visit_entity(cx, hovered, &mut Event::new(WindowEvent::MouseLeave).direct(hovered));
+            for entity in left {
+                visit_entity(cx, entity, &mut Event::new(WindowEvent::MouseOut).target(hovered));
+            }
+        }
+
         for entity in delete_list.iter().rev() {
             if let Some(mut view) = self.views.remove(entity) {
                 view.event(
@@ -433,13 +601,25 @@ impl Context {
             }
 
             if let Some(identifier) = self.style.ids.get(*entity) {
-                self.entity_identifiers.remove(identifier);
+                self.entity_identifiers.remove(identifier.as_str());
             }
 
             if let Some(index) = self.focus_stack.iter().position(|r| r == entity) {
                 self.focus_stack.remove(index);
             }
 
+            self.panic_handling.boundaries.remove(entity);
+            self.panic_handling.panicked.remove(entity);
+
+            #[cfg(feature = "global-shortcuts")]
+            if self.global_shortcut_sources.remove(entity) {
+                self.event_queue.push_back(
+                    Event::new(WindowEvent::UnregisterGlobalShortcuts(*entity))
+                        .target(Entity::root())
+                        .origin(Entity::root()),
+                );
+            }
+
             if self.focused == *entity {
                 if let Some(new_focus) = self.focus_stack.pop() {
                     self.with_current(new_focus, |cx| cx.focus());
@@ -483,6 +663,27 @@ impl Context {
                 self.stop_timer(timer);
             }
 
+            // Remove any timers added by the entity.
+            for timer in self.timers.iter_mut() {
+                if let Some(timer_state) = timer.take_if(|timer| timer.owner == *entity) {
+                    self.timer_manager.destroy(timer_state.id);
+                }
+            }
+            self.running_timers.retain(|timer| timer.owner != *entity);
+
+            self.animation_frame_requests.remove(entity);
+
+            for window_state in self.windows.values_mut() {
+                if window_state.highlight == Some(*entity) {
+                    window_state.highlight = None;
+                }
+            }
+
+            // Drop any events which were scheduled by or for the entity.
+            self.event_schedule.retain(|timed_event| {
+                timed_event.event.meta.origin != *entity && timed_event.event.meta.target != *entity
+            });
+
             let window_entity = self.tree.get_parent_window(*entity).unwrap_or(Entity::root());
 
             if !self.tree.is_window(*entity) {
@@ -502,6 +703,9 @@ impl Context {
 
             if self.windows.contains_key(entity) {
                 self.windows.remove(entity);
+                // The rules of the window stylesheets no longer match any views and are dropped on the next reload.
+                self.resource_manager.window_themes.remove(entity);
+                self.resource_manager.window_styles.remove(entity);
             }
 
             self.tree.remove(*entity).expect("");
@@ -510,8 +714,10 @@ impl Context {
             self.models.remove(entity);
             self.stores.remove(entity);
             self.views.remove(entity);
+            self.capturing_views.remove(entity);
             self.text_context.text_bounds.remove(*entity);
             self.text_context.text_paragraphs.remove(*entity);
+            self.text_context.string_paragraphs.remove(*entity);
             self.entity_manager.destroy(*entity);
         }
     }
@@ -566,6 +772,18 @@ impl Context {
             self.text_context.default_font_manager.new_from_data(data.as_ref(), None).unwrap(),
             None,
         );
+        self.text_context.fonts_changed();
+    }
+
+    /// Prevents fonts installed on the system from being used, so that text is only rendered with fonts added with
+    /// [`add_font_mem`](Self::add_font_mem).
+    ///
+    /// This makes text rendering reproducible across machines, for example when comparing screenshots in tests.
+    pub fn disable_system_fonts(&mut self) {
+        self.text_context.font_collection.set_default_font_manager(None::<FontMgr>, None::<&str>);
+        self.text_context.font_collection.disable_font_fallback();
+        self.text_context.system_fonts = false;
+        self.text_context.fonts_changed();
     }
 
     /// Sets the global default font for the application.
@@ -581,15 +799,33 @@ impl Context {
     pub(crate) fn add_theme(&mut self, theme: &str) {
         self.resource_manager.themes.push(theme.to_owned());
 
-        EventContext::new(self).reload_styles().expect("Failed to reload styles");
+        self.reload_and_log_styles();
     }
 
-    pub fn add_stylesheet(&mut self, style: impl IntoCssStr) -> Result<(), std::io::Error> {
-        self.resource_manager.styles.push(Box::new(style));
+    // Reloads the stylesheets after a change which is not caused by a user stylesheet, logging any problems with the
+    // user stylesheets instead of returning them.
+    fn reload_and_log_styles(&mut self) {
+        if let Err(error) = EventContext::new(self).reload_styles() {
+            log::warn!("{}", error);
+        }
+    }
 
-        EventContext::new(self).reload_styles().expect("Failed to reload styles");
+    /// Adds a stylesheet to the application, given either as a string of CSS or as a [`StylesheetPath`] to a file.
+    ///
+    /// Returns [`StylesheetError::IoError`] if the file cannot be read, in which case the stylesheet is not added.
+    /// Invalid rules and declarations are skipped and returned as a [`StylesheetError::ParseWarning`], but the rest of
+    /// the stylesheet is still added.
+    pub fn add_stylesheet(&mut self, style: impl IntoCssStr) -> Result<(), StylesheetError> {
+        style.get_style()?;
+        self.resource_manager.styles.push(Box::new(style));
 
-        Ok(())
+        // The application stylesheets are parsed in the order they were added, so the new stylesheet is the last one
+        // which is not scoped to a window.
+        let results = EventContext::new(self).reload_stylesheets();
+        match results.into_iter().rev().find(|(window, _)| window.is_none()) {
+            Some((_, result)) => stylesheet_result(result),
+            None => Ok(()),
+        }
     }
 
     /// Remove all user themes from the application.
@@ -600,13 +836,40 @@ impl Context {
         self.add_theme(MARKDOWN);
         if !self.ignore_default_theme {
             let environment = self.data::<Environment>().expect("Failed to get environment");
-            match environment.theme.get_current_theme() {
-                ThemeMode::LightMode => self.add_theme(LIGHT_THEME),
-                ThemeMode::DarkMode => self.add_theme(DARK_THEME),
-            }
+            self.add_theme(built_in_theme(environment.theme.get_current_theme()));
+        }
+    }
+
+    /// Adds a stylesheet which only applies to the views within the given window.
+    ///
+    /// Window stylesheets take precedence over the stylesheets of the application and are reloaded along with them.
+    /// Errors are returned in the same way as for [`add_stylesheet`](Self::add_stylesheet).
+    pub fn add_window_stylesheet(
+        &mut self,
+        window: Entity,
+        style: impl IntoCssStr,
+    ) -> Result<(), StylesheetError> {
+        style.get_style()?;
+        self.resource_manager.window_styles.entry(window).or_default().push(Box::new(style));
+
+        let results = EventContext::new(self).reload_stylesheets();
+        match results.into_iter().rev().find(|(scope, _)| *scope == Some(window)) {
+            Some((_, result)) => stylesheet_result(result),
+            None => Ok(()),
         }
     }
 
+    /// Sets the theme mode of the given window, overriding the theme mode of the application for the views within
+    /// that window. A theme mode of `None` makes the window follow the application theme mode.
+    pub fn set_window_theme_mode(&mut self, window: Entity, theme_mode: Option<ThemeMode>) {
+        match theme_mode {
+            Some(theme_mode) => self.resource_manager.window_themes.insert(window, theme_mode),
+            None => self.resource_manager.window_themes.remove(&window),
+        };
+
+        self.reload_and_log_styles();
+    }
+
     pub fn add_animation(&mut self, animation: AnimationBuilder) -> Animation {
         self.style.add_animation(animation)
     }
@@ -619,6 +882,98 @@ impl Context {
         self.resource_manager.add_translation(lang, ftl.to_string());
     }
 
+    /// Sets the locales, in order of preference, whose translations are used for messages which are missing from the
+    /// translations of the current locale.
+    ///
+    /// A missing message is logged once for each locale it is missing from.
+    pub fn set_fallback_locales(&mut self, locales: impl IntoIterator<Item = LanguageIdentifier>) {
+        self.resource_manager.fallback_locales = locales.into_iter().collect();
+    }
+
+    /// Schedules some work to run when the event loop is otherwise idle, with the current view as the current entity.
+    ///
+    /// The work is cancelled if the current view is removed before it runs.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.schedule_idle(|cx| {
+    ///     Label::new(cx, "Built when idle");
+    /// });
+    /// ```
+    pub fn schedule_idle(&mut self, work: impl FnOnce(&mut Context) + 'static) {
+        self.idle_tasks.push_back(IdleTask::once(self.current, work));
+    }
+
+    /// Builds a view for each item, `chunk_size` items at a time, when the event loop is otherwise idle.
+    ///
+    /// Chunks are built until the idle budget of the frame, set with [`set_idle_budget`](Self::set_idle_budget), is
+    /// used up, and the rest are built in later frames, so that the application stays responsive while a large number
+    /// of views is built. The views are built as children of the current view, and the remaining items are dropped if
+    /// the current view is removed before they are built.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// VStack::new(cx, |cx| {
+    ///     cx.build_incrementally(0..10000, 100, |cx, index| {
+    ///         Label::new(cx, format!("Row {}", index));
+    ///     });
+    /// });
+    /// ```
+    pub fn build_incrementally<I>(
+        &mut self,
+        items: I,
+        chunk_size: usize,
+        build: impl Fn(&mut Context, I::Item) + 'static,
+    ) where
+        I: IntoIterator,
+        I::IntoIter: 'static,
+    {
+        self.idle_tasks.push_back(IdleTask::chunked(
+            self.current,
+            items.into_iter(),
+            chunk_size,
+            build,
+        ));
+    }
+
+    /// Sets the time which may be spent on idle work in each frame, which defaults to 8 milliseconds.
+    ///
+    /// Work which is scheduled with [`schedule_idle`](Self::schedule_idle) or
+    /// [`build_incrementally`](Self::build_incrementally) is split into pieces, which run until the budget is used up.
+    pub fn set_idle_budget(&mut self, budget: Duration) {
+        self.idle_budget = budget;
+    }
+
+    // Runs scheduled idle work, in the order it was scheduled, until it is finished or the idle budget is used up.
+    // The budget is measured in real time, even when time is virtual, as it limits the time taken by the work.
+    pub(crate) fn process_idle_tasks(&mut self) {
+        let start = Instant::now();
+        let mut tasks = std::mem::take(&mut self.idle_tasks);
+
+        while let Some(mut task) = tasks.pop_front() {
+            if !self.entity_manager.is_alive(task.entity) {
+                continue;
+            }
+
+            let finished = self.with_current(task.entity, |cx| (task.work)(cx));
+            if !finished {
+                tasks.push_front(task);
+            }
+
+            if start.elapsed() >= self.idle_budget {
+                break;
+            }
+        }
+
+        // Work scheduled by the tasks runs after the work scheduled before it.
+        tasks.append(&mut self.idle_tasks);
+        self.idle_tasks = tasks;
+    }
+
     /// Adds a timer to the application.
     ///
     /// `interval` - The time between ticks of the timer.
@@ -655,14 +1010,20 @@ impl Context {
         duration: Option<Duration>,
         callback: impl Fn(&mut EventContext, TimerAction) + 'static,
     ) -> Timer {
-        let id = Timer(self.timers.len());
-        self.timers.push(TimerState {
+        let id = self.timer_manager.create();
+        let index = id.index();
+        if index >= self.timers.len() {
+            self.timers.resize_with(index + 1, || None);
+        }
+
+        self.timers[index] = Some(TimerState {
             entity: Entity::root(),
+            owner: self.current,
             id,
-            time: Instant::now(),
+            time: crate::util::now(),
             interval,
             duration,
-            start_time: Instant::now(),
+            start_time: crate::util::now(),
             callback: Rc::new(callback),
             ticking: false,
             stopping: false,
@@ -677,13 +1038,18 @@ impl Context {
     pub fn start_timer(&mut self, timer: Timer) {
         let current = self.current;
         if !self.timer_is_running(timer) {
-            let timer_state = self.timers[timer.0].clone();
+            let Some(Some(timer_state)) = self.timers.get(timer.index()) else {
+                return;
+            };
+            if timer_state.id != timer {
+                return;
+            }
             // Copy timer state from pending to playing
-            self.running_timers.push(timer_state);
+            self.running_timers.push(timer_state.clone());
         }
 
         self.modify_timer(timer, |timer_state| {
-            let now = Instant::now();
+            let now = crate::util::now();
             timer_state.start_time = now;
             timer_state.time = now;
             timer_state.entity = current;
@@ -706,7 +1072,7 @@ impl Context {
             }
         }
 
-        for pending_timer in self.timers.iter_mut() {
+        for pending_timer in self.timers.iter_mut().flatten() {
             if pending_timer.id == timer {
                 (timer_function)(pending_timer);
             }
@@ -745,7 +1111,7 @@ impl Context {
 
     // Tick all timers.
     pub(crate) fn tick_timers(&mut self) {
-        let now = Instant::now();
+        let now = crate::util::now();
         while let Some(next_timer_state) = self.running_timers.peek() {
             if next_timer_state.time <= now {
                 let mut timer_state = self.running_timers.pop().unwrap();
@@ -866,6 +1232,41 @@ impl Context {
         self.entity_identifiers.get(identity).cloned()
     }
 
+    /// Returns the view which was given the ID with the `id` modifier, or `None` if there is no such view.
+    pub fn entity_by_id(&self, id: &str) -> Option<Entity> {
+        self.resolve_entity_identifier(id)
+    }
+
+    /// Returns all of the views, in tree order, which have the given class name.
+    pub fn entities_by_class(&self, class: &str) -> Vec<Entity> {
+        let class = Atom::new(class);
+        TreeIterator::full(&self.tree)
+            .filter(|entity| {
+                self.style.classes.get(*entity).is_some_and(|classes| classes.contains(&class))
+            })
+            .collect()
+    }
+
+    /// Returns a snapshot of the view tree, starting from the root window, with the element name, id, classes,
+    /// bounds, and key style properties of each view, for making assertions about the state of the UI in tests.
+    ///
+    /// With the `serde` feature enabled the snapshot can be serialized, for example to JSON with
+    /// [`EntityTree::to_json`].
+    pub fn dump_tree(&self) -> EntityTree {
+        EntityTree::new(self, Entity::root())
+    }
+
+    /// Shows an [`Inspector`] of the window of the current view, unless it is already shown. The inspector can also be
+    /// toggled by pressing `Ctrl+Shift+I`.
+    ///
+    /// The inspector is shown in a separate window with the winit backend, and as an overlay on the right side of the
+    /// window with the baseview backend.
+    #[cfg(feature = "inspector")]
+    pub fn open_inspector(&mut self) {
+        let window = self.tree.get_parent_window(self.current).unwrap_or(Entity::root());
+        crate::views::open_inspector(self, window);
+    }
+
     /// Toggles the addition/removal of a class name for the current view.
     ///
     /// # Example
@@ -877,15 +1278,16 @@ impl Context {
     /// ```
     pub fn toggle_class(&mut self, class_name: &str, applied: bool) {
         let current = self.current();
+        let class_name = Atom::new(class_name);
         if let Some(class_list) = self.style.classes.get_mut(current) {
             if applied {
-                class_list.insert(class_name.to_string());
+                class_list.insert(class_name);
             } else {
-                class_list.remove(class_name);
+                class_list.remove(&class_name);
             }
         } else if applied {
             let mut class_list = HashSet::new();
-            class_list.insert(class_name.to_string());
+            class_list.insert(class_name);
             self.style.classes.insert(current, class_list);
         }
 
@@ -895,7 +1297,32 @@ impl Context {
 
 pub(crate) enum InternalEvent {
     Redraw,
-    LoadImage { path: String, image: Mutex<Option<skia_safe::Image>>, policy: ImageRetentionPolicy },
+    LoadImage {
+        path: String,
+        image: Mutex<Option<skia_safe::Image>>,
+        policy: ImageRetentionPolicy,
+    },
+    #[cfg(feature = "dialogs")]
+    FileDialogClosed {
+        source: Entity,
+        callback: crate::window::FileDialogCallback,
+        paths: Option<Vec<std::path::PathBuf>>,
+    },
+    TransitionBinding {
+        binding: Entity,
+        finished: bool,
+    },
+    ScheduleEvent {
+        event: Event,
+        at: Instant,
+        handle: TimedEventHandle,
+    },
+    CancelScheduled(TimedEventHandle),
+    ViewPanicked(ViewPanic),
+    #[cfg(feature = "inspector")]
+    OpenInspector(Entity),
+    #[cfg(feature = "inspector")]
+    CloseInspector(Entity),
 }
 
 pub struct LocalizationContext<'a> {
@@ -1056,6 +1483,30 @@ pub trait EmitContext {
     /// cx.cancel_scheduled(timed_event);
     /// ```
     fn cancel_scheduled(&mut self, handle: TimedEventHandle);
+
+    /// Send an event containing the provided message up the tree after a delay.
+    ///
+    /// Returns a `TimedEventHandle` which can be used to cancel the event from any entity with
+    /// [`cancel_scheduled`](Self::cancel_scheduled). The event is dropped if the current entity is removed before the
+    /// event is sent.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # enum AppEvent {HideToast}
+    /// cx.emit_after(AppEvent::HideToast, Duration::from_secs(3));
+    /// ```
+    fn emit_after<M: Any + Send>(&mut self, message: M, delay: Duration) -> TimedEventHandle {
+        self.schedule_emit(message, crate::util::now() + delay)
+    }
+
+    /// Send an event containing the provided message up the tree at a particular time instant.
+    ///
+    /// This is the same as [`schedule_emit`](Self::schedule_emit).
+    fn emit_at<M: Any + Send>(&mut self, message: M, at: Instant) -> TimedEventHandle {
+        self.schedule_emit(message, at)
+    }
 }
 
 impl DataContext for Context {
@@ -1159,9 +1610,8 @@ impl EmitContext for Context {
     }
 
     fn schedule_emit_custom(&mut self, event: Event, at: Instant) -> TimedEventHandle {
-        let handle = TimedEventHandle(self.next_event_id);
+        let handle = TimedEventHandle::next();
         self.event_schedule.push(TimedEvent { event, time: at, ident: handle });
-        self.next_event_id += 1;
         handle
     }
 
@@ -0,0 +1,172 @@
+use std::any::Any;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+use hashbrown::HashSet;
+use vizia_style::Atom;
+
+use crate::prelude::*;
+
+/// A panic which was caught while a view was handling an event or being drawn.
+///
+/// Panics are only caught when a panic handler has been set on the application, or within a view marked with
+/// [`panic_boundary`](Handle::panic_boundary). The view which contains the panic is disabled and its
+/// children are replaced with the panic message.
+#[derive(Debug, Clone)]
+pub struct ViewPanic {
+    /// The view which panicked.
+    pub entity: Entity,
+    /// The view whose content was replaced, which is the closest panic boundary containing the view which panicked,
+    /// or the view itself if it is not within a panic boundary.
+    pub boundary: Entity,
+    /// The message of the panic.
+    pub message: String,
+}
+
+type PanicHandler = Box<dyn Fn(&mut EventContext, &ViewPanic)>;
+
+#[derive(Default)]
+pub(crate) struct PanicHandling {
+    pub(crate) handler: Option<PanicHandler>,
+    pub(crate) boundaries: HashSet<Entity>,
+    // Views whose children were replaced after a panic. They keep their element name and styling, but are no longer
+    // sent events and are drawn as a plain view.
+    pub(crate) panicked: HashSet<Entity>,
+}
+
+impl PanicHandling {
+    // Returns the view which should contain a panic of the given view, or `None` if the panic should not be caught.
+    fn boundary(&self, tree: &Tree<Entity>, entity: Entity) -> Option<Entity> {
+        entity
+            .parent_iter(tree)
+            .find(|ancestor| self.boundaries.contains(ancestor))
+            .or(self.handler.as_ref().map(|_| entity))
+    }
+
+    /// Calls a function on behalf of a view, catching a panic if the view is within a panic boundary or a panic
+    /// handler has been set.
+    pub(crate) fn catch<R>(
+        &self,
+        tree: &Tree<Entity>,
+        entity: Entity,
+        f: impl FnOnce() -> R,
+    ) -> Result<R, ViewPanic> {
+        if self.handler.is_none() && self.boundaries.is_empty() {
+            return Ok(f());
+        }
+
+        catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+            let Some(boundary) = self.boundary(tree, entity) else {
+                resume_unwind(payload);
+            };
+
+            ViewPanic { entity, boundary, message: panic_message(payload.as_ref()) }
+        })
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+// Draws a view whose children were replaced after a panic, without calling its own drawing code.
+pub(crate) struct PanickedView;
+
+impl View for PanickedView {}
+
+/// Disables the boundary of a panic, replaces its children with the panic message, and calls the panic handler.
+pub(crate) fn handle_panic(cx: &mut Context, panic: ViewPanic) {
+    let boundary = panic.boundary;
+    if !cx.entity_manager.is_alive(boundary) || !cx.panic_handling.panicked.insert(boundary) {
+        return;
+    }
+
+    cx.remove_children(boundary);
+    cx.style.disabled.insert(boundary, true);
+    if let Some(classes) = cx.style.classes.get_mut(boundary) {
+        classes.insert(Atom::new("panicked"));
+    }
+    cx.with_current(boundary, |cx| {
+        Label::new(cx, panic.message.clone()).class("panic-message");
+    });
+    cx.needs_restyle(boundary);
+    cx.needs_relayout();
+    cx.needs_redraw(boundary);
+
+    if let Some(handler) = cx.panic_handling.handler.take() {
+        cx.with_current(boundary, |cx| (handler)(&mut EventContext::new(cx), &panic));
+        cx.panic_handling.handler = Some(handler);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    struct Break;
+
+    struct Fragile;
+
+    impl View for Fragile {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|_: &Break, _| panic!("broken view"));
+        }
+    }
+
+    struct FragileModel;
+
+    impl Model for FragileModel {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|_: &Break, _| panic!("broken model"));
+        }
+    }
+
+    #[test]
+    fn panic_boundary_replaces_content() {
+        let mut harness = TestHarness::new(|cx| {
+            VStack::new(cx, |cx| {
+                Fragile.build(cx, |_| {}).id("fragile");
+            })
+            .id("boundary")
+            .panic_boundary(true);
+            Label::new(cx, "Still running").id("other");
+        });
+
+        let fragile = harness.query("#fragile").unwrap();
+        harness.context().emit_to(fragile, Break);
+        harness.update();
+
+        let boundary = harness.query("#boundary").unwrap();
+        assert!(harness.query("#fragile").is_none());
+        assert!(harness.matches(boundary, "vstack.panicked"));
+        assert_eq!(harness.context().style.disabled.get(boundary), Some(&true));
+        assert_eq!(harness.text("#boundary .panic-message"), Some("broken view"));
+        assert_eq!(harness.text("#other"), Some("Still running"));
+    }
+
+    #[test]
+    fn panic_boundary_catches_model_panics() {
+        let mut harness = TestHarness::new(|cx| {
+            VStack::new(cx, |cx| {
+                HStack::new(cx, |cx| {
+                    FragileModel.build(cx);
+                })
+                .id("fragile");
+            })
+            .id("boundary")
+            .panic_boundary(true);
+        });
+
+        let fragile = harness.query("#fragile").unwrap();
+        harness.context().emit_to(fragile, Break);
+        harness.update();
+
+        assert!(harness.query("#fragile").is_none());
+        assert_eq!(harness.text("#boundary .panic-message"), Some("broken model"));
+    }
+}
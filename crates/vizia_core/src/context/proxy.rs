@@ -72,6 +72,40 @@ impl ContextProxy {
         }
     }
 
+    /// Sends an event containing the provided message up the tree at a particular time instant.
+    ///
+    /// Returns a `TimedEventHandle` which can be used to cancel the event with
+    /// [`cancel_scheduled`](Self::cancel_scheduled), or from any entity on the main thread.
+    pub fn emit_at<M: Any + Send>(
+        &mut self,
+        message: M,
+        at: Instant,
+    ) -> Result<TimedEventHandle, ProxyEmitError> {
+        let handle = TimedEventHandle::next();
+        let event = Event::new(message)
+            .target(self.current)
+            .origin(self.current)
+            .propagate(Propagation::Up);
+
+        self.emit(InternalEvent::ScheduleEvent { event, at, handle })?;
+
+        Ok(handle)
+    }
+
+    /// Sends an event containing the provided message up the tree after a delay.
+    pub fn emit_after<M: Any + Send>(
+        &mut self,
+        message: M,
+        delay: Duration,
+    ) -> Result<TimedEventHandle, ProxyEmitError> {
+        self.emit_at(message, crate::util::now() + delay)
+    }
+
+    /// Cancels a scheduled event before it is sent.
+    pub fn cancel_scheduled(&mut self, handle: TimedEventHandle) -> Result<(), ProxyEmitError> {
+        self.emit(InternalEvent::CancelScheduled(handle))
+    }
+
     pub fn redraw(&mut self) -> Result<(), ProxyEmitError> {
         self.emit(InternalEvent::Redraw)
     }
@@ -17,3 +17,21 @@ use vizia_id::{
 pub struct Entity(u64);
 
 impl_generational_id!(Entity);
+
+// Entities are serialized as the `u64` of `GenerationalId::to_u64`, so entities created with stable ids can be saved
+// and restored across runs.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Entity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.to_u64())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Entity {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        Entity::from_u64(value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid entity id {}", value)))
+    }
+}
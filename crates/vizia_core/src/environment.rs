@@ -1,6 +1,8 @@
 //! A model for system specific state which can be accessed by any model or view.
+use crate::localization::is_right_to_left;
 use crate::prelude::*;
 
+use std::collections::HashSet;
 use unic_langid::LanguageIdentifier;
 use vizia_derive::Lens;
 use web_time::Duration;
@@ -60,6 +62,8 @@ pub struct Environment {
     pub locale: LanguageIdentifier,
     /// Current application and system theme.
     pub theme: Theme,
+    /// The enabled keymap modes, which activate any keymaps with a matching [`mode`](Keymap::mode).
+    pub keymap_modes: HashSet<String>,
     /// The timer used to blink the caret of a textbox.
     pub(crate) caret_timer: Timer,
 }
@@ -72,7 +76,74 @@ impl Environment {
                 cx.emit(TextEvent::ToggleCaret);
             }
         });
-        Self { locale, theme: Theme::default(), caret_timer }
+        cx.style.locale = locale.clone();
+        set_locale_direction(&mut cx.style, &locale);
+        Self { locale, theme: Theme::default(), keymap_modes: HashSet::new(), caret_timer }
+    }
+
+    /// Returns a lens to the logical position of the window containing the view which uses the lens.
+    ///
+    /// The position is updated when the window is moved, at the same time as a [`WindowEvent::Move`] event is sent.
+    pub fn window_position() -> impl Lens<Target = WindowPosition> {
+        WindowData::position
+    }
+
+    /// Returns a lens to the logical inner size of the window containing the view which uses the lens.
+    ///
+    /// The size is updated when the window is resized, at the same time as a [`WindowEvent::Resize`] event is sent.
+    pub fn window_size() -> impl Lens<Target = WindowSize> {
+        WindowData::size
+    }
+
+    /// Returns the theme mode preferred by the operating system, or `None` if it has not been reported.
+    pub fn system_theme(&self) -> Option<ThemeMode> {
+        self.theme.sys_theme
+    }
+
+    // Sets the locale, updating the text, style, and layout of every view since translated text can change the size of
+    // views, the text direction can change, and stylesheets can select views by language. Views which display localized text are updated by their
+    // bindings to the locale.
+    fn set_locale(&mut self, cx: &mut EventContext, locale: LanguageIdentifier) {
+        if self.locale == locale {
+            return;
+        }
+
+        self.locale = locale.clone();
+        set_locale_direction(cx.style, &locale);
+        cx.style.locale = locale;
+
+        for entity in cx.tree.into_iter() {
+            cx.style.needs_restyle(entity);
+            cx.style.needs_text_update(entity);
+        }
+        cx.style.needs_relayout();
+    }
+
+    // Records the theme mode preferred by the operating system and applies it if the application follows it.
+    fn set_system_theme(&mut self, cx: &mut EventContext, theme_mode: ThemeMode) {
+        self.theme.sys_theme = Some(theme_mode);
+        if self.theme.app_theme == AppTheme::System {
+            cx.set_theme_mode(theme_mode);
+            reload_styles(cx);
+        }
+    }
+}
+
+// Reloads the stylesheets after the theme mode changes. Problems with user stylesheets are logged, since they are not
+// caused by the change.
+fn reload_styles(cx: &mut EventContext) {
+    if let Err(error) = cx.reload_styles() {
+        log::warn!("{}", error);
+    }
+}
+
+// Sets the text direction of the root, which is inherited by every view, to right-to-left for locales written from
+// right to left. Otherwise the direction of text is detected from its content.
+fn set_locale_direction(style: &mut Style, locale: &LanguageIdentifier) {
+    if is_right_to_left(locale) {
+        style.direction.insert(Entity::root(), Direction::Rtl);
+    } else {
+        style.direction.remove(Entity::root());
     }
 }
 
@@ -81,31 +152,51 @@ pub enum EnvironmentEvent {
     /// Set the locale used for the whole application.
     SetLocale(LanguageIdentifier),
     /// Set the default theme mode.
-    // TODO: add SetSysTheme event when the winit `set_theme` fixed.
     SetThemeMode(AppTheme),
+    /// Report the theme mode preferred by the operating system, which is used when the application theme is
+    /// [`AppTheme::System`].
+    ///
+    /// The winit backend reports changes to the system theme with [`WindowEvent::ThemeChanged`], which is handled in
+    /// the same way. Backends which are not notified of changes by the platform, such as baseview, rely on the host
+    /// emitting this event.
+    SystemThemeChanged(ThemeMode),
+    /// Set the theme mode of the window containing the view which emitted the event, overriding the application
+    /// theme mode for that window. A theme mode of `None` makes the window follow the application theme mode.
+    SetWindowThemeMode(Option<ThemeMode>),
     /// Reset the locale to use the system provided locale.
     UseSystemLocale,
     /// Alternate between dark and light theme modes.
     ToggleThemeMode,
+    /// Enable or disable a keymap mode, which activates or deactivates any keymaps with a matching
+    /// [`mode`](Keymap::mode).
+    SetKeymapMode(String, bool),
 }
 
 impl Model for Environment {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.take(|event, _| match event {
-            EnvironmentEvent::SetLocale(locale) => {
-                self.locale = locale;
-            }
+        event.take(|event, meta| match event {
+            EnvironmentEvent::SetLocale(locale) => self.set_locale(cx, locale),
 
             EnvironmentEvent::SetThemeMode(theme) => {
                 theme.clone_into(&mut self.theme.app_theme);
 
                 cx.set_theme_mode(self.theme.get_current_theme());
-                cx.reload_styles().unwrap();
+                reload_styles(cx);
+            }
+
+            EnvironmentEvent::SystemThemeChanged(theme_mode) => {
+                self.set_system_theme(cx, theme_mode);
+            }
+
+            EnvironmentEvent::SetWindowThemeMode(theme_mode) => {
+                cx.with_current(meta.origin, |cx| cx.set_window_theme_mode(theme_mode));
+                reload_styles(cx);
             }
 
             EnvironmentEvent::UseSystemLocale => {
-                self.locale =
+                let locale =
                     sys_locale::get_locale().map(|l| l.parse().unwrap()).unwrap_or_default();
+                self.set_locale(cx, locale);
             }
 
             EnvironmentEvent::ToggleThemeMode => {
@@ -117,19 +208,109 @@ impl Model for Environment {
                 self.theme.app_theme = AppTheme::BuiltIn(theme_mode);
 
                 cx.set_theme_mode(theme_mode);
-                cx.reload_styles().unwrap();
+                reload_styles(cx);
             }
-        });
 
-        event.map(|event, _| match event {
-            WindowEvent::ThemeChanged(theme) => {
-                self.theme.sys_theme = Some(*theme);
-                if self.theme.app_theme == AppTheme::System {
-                    cx.set_theme_mode(*theme);
-                    cx.reload_styles().unwrap();
+            EnvironmentEvent::SetKeymapMode(mode, enabled) => {
+                if enabled {
+                    self.keymap_modes.insert(mode);
+                } else {
+                    self.keymap_modes.remove(&mode);
                 }
             }
+        });
+
+        event.map(|event, _| match event {
+            WindowEvent::ThemeChanged(theme) => self.set_system_theme(cx, *theme),
             _ => (),
         })
     }
 }
+
+/// The logical position and inner size of a window, which is stored as a model on each window.
+///
+/// Lenses to the fields of the model, which are also returned by [`Environment::window_position`] and
+/// [`Environment::window_size`], resolve to the window containing the view which uses them.
+#[derive(Debug, Clone, Copy, PartialEq, Lens)]
+pub struct WindowData {
+    /// The logical position of the window.
+    pub position: WindowPosition,
+    /// The logical inner size of the window.
+    pub size: WindowSize,
+}
+
+impl Model for WindowData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            // Moving or resizing a child window sends an event which propagates through this window too.
+            if meta.origin != cx.current() {
+                return;
+            }
+
+            match window_event {
+                WindowEvent::Move(x, y) => self.position = WindowPosition::new(*x, *y),
+                WindowEvent::Resize(width, height) => self.size = WindowSize::new(*width, *height),
+                _ => {}
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::built_in_theme;
+    use crate::testing::TestHarness;
+
+    #[test]
+    fn system_theme_change_loads_theme() {
+        let mut harness = TestHarness::new(|cx| {
+            Label::new(cx, "Hello");
+        });
+
+        harness.emit(EnvironmentEvent::SystemThemeChanged(ThemeMode::DarkMode));
+        // The application does not follow the system theme yet.
+        assert_eq!(
+            harness.context().resource_manager.themes[2],
+            built_in_theme(ThemeMode::LightMode)
+        );
+
+        harness.emit(EnvironmentEvent::SetThemeMode(AppTheme::System));
+        assert_eq!(harness.context().environment().system_theme(), Some(ThemeMode::DarkMode));
+        assert_eq!(
+            harness.context().resource_manager.themes[2],
+            built_in_theme(ThemeMode::DarkMode)
+        );
+
+        harness.emit(EnvironmentEvent::SystemThemeChanged(ThemeMode::LightMode));
+        assert_eq!(
+            harness.context().resource_manager.themes[2],
+            built_in_theme(ThemeMode::LightMode)
+        );
+    }
+
+    #[test]
+    fn window_size_follows_resize() {
+        let mut harness = TestHarness::new(|cx| {
+            let size = Environment::window_size();
+            Label::new(cx, size.map(|size| format!("{}x{}", size.width, size.height))).id("size");
+        });
+        assert_eq!(harness.text("#size"), Some("800x600"));
+
+        harness.resize(WindowSize::new(640, 480));
+        assert_eq!(harness.text("#size"), Some("640x480"));
+    }
+
+    #[test]
+    fn right_to_left_locale_sets_direction() {
+        let mut harness = TestHarness::new(|cx| {
+            Label::new(cx, "Hello");
+        });
+
+        harness.emit(EnvironmentEvent::SetLocale("ar-EG".parse().unwrap()));
+        assert_eq!(harness.context().style.direction.get(Entity::root()), Some(&Direction::Rtl));
+
+        harness.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+        assert_eq!(harness.context().style.direction.get(Entity::root()), None);
+    }
+}
@@ -0,0 +1,298 @@
+use web_time::{Duration, Instant};
+
+use crate::prelude::*;
+
+/// The time after the last movement of the cursor after which a released drag gesture has no velocity.
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The phase of a [`DragGesture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragPhase {
+    /// The cursor has moved further than the threshold since the mouse button was pressed.
+    Started,
+    /// The cursor has moved during the gesture.
+    Moved,
+    /// The mouse button has been released.
+    Ended,
+}
+
+/// A drag gesture with the left mouse button, reported by the [`on_drag_gesture`] modifier.
+///
+/// Positions are in logical pixels relative to the top-left corner of the window.
+///
+/// [`on_drag_gesture`]: crate::modifiers::ActionModifiers::on_drag_gesture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragGesture {
+    /// The phase of the gesture.
+    pub phase: DragPhase,
+    /// The position of the cursor when the mouse button was pressed.
+    pub start: (f32, f32),
+    /// The current position of the cursor.
+    pub current: (f32, f32),
+    /// The movement of the cursor since the previous event of the gesture, in logical pixels.
+    pub delta: (f32, f32),
+    /// The movement of the cursor since the previous event of the gesture, in physical pixels.
+    pub physical_delta: (f32, f32),
+    /// The velocity of the cursor in logical pixels per second. When the gesture has ended this is the velocity
+    /// the mouse button was released with, which can be used to continue the movement with momentum.
+    pub velocity: (f32, f32),
+}
+
+impl DragGesture {
+    /// Returns the total movement of the cursor since the mouse button was pressed, in logical pixels.
+    pub fn offset(&self) -> (f32, f32) {
+        (self.current.0 - self.start.0, self.current.1 - self.start.1)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DragState {
+    // Positions in physical pixels.
+    start: (f32, f32),
+    previous: (f32, f32),
+    started: bool,
+    time: Instant,
+    velocity: (f32, f32),
+}
+
+/// Tracks drag gestures from the window events received by a view.
+///
+/// The gesture only starts once the cursor has moved further than a threshold, so pressing and releasing the mouse
+/// button without moving it is still a click. The view captures the mouse when the gesture starts, so that it
+/// continues to receive events if the cursor leaves it.
+///
+/// This is used by the [`on_drag_gesture`](crate::modifiers::ActionModifiers::on_drag_gesture) modifier, and can
+/// be used directly by the event handler of a custom view.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// pub struct Pan {
+///     tracker: DragTracker,
+///     offset: (f32, f32),
+/// }
+///
+/// impl View for Pan {
+///     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+///         event.map(|window_event, _| {
+///             if let Some(gesture) = self.tracker.handle(cx, window_event) {
+///                 self.offset.0 += gesture.delta.0;
+///                 self.offset.1 += gesture.delta.1;
+///             }
+///         });
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DragTracker {
+    threshold: f32,
+    state: Option<DragState>,
+}
+
+impl Default for DragTracker {
+    fn default() -> Self {
+        Self::new(4.0)
+    }
+}
+
+impl DragTracker {
+    /// Creates a new tracker with the given threshold, in logical pixels, which the cursor must move before the
+    /// gesture starts.
+    pub fn new(threshold: f32) -> Self {
+        Self { threshold, state: None }
+    }
+
+    /// Returns true if a drag gesture is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.state.is_some_and(|state| state.started)
+    }
+
+    /// Updates the tracker with a window event received by the view, returning the gesture if the event started,
+    /// moved, or ended one.
+    pub fn handle(
+        &mut self,
+        cx: &mut EventContext,
+        window_event: &WindowEvent,
+    ) -> Option<DragGesture> {
+        match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) if !cx.is_disabled() => {
+                let position = (cx.mouse.cursor_x, cx.mouse.cursor_y);
+                self.state = Some(DragState {
+                    start: position,
+                    previous: position,
+                    started: false,
+                    time: crate::util::now(),
+                    velocity: (0.0, 0.0),
+                });
+
+                None
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                let state = self.state.as_mut()?;
+                if cx.mouse.left.state != MouseButtonState::Pressed {
+                    self.state = None;
+                    return None;
+                }
+
+                let phase = if state.started {
+                    DragPhase::Moved
+                } else {
+                    let threshold = cx.logical_to_physical(self.threshold);
+                    if (x - state.start.0).abs() <= threshold
+                        && (y - state.start.1).abs() <= threshold
+                    {
+                        return None;
+                    }
+
+                    state.started = true;
+                    cx.capture();
+                    DragPhase::Started
+                };
+
+                let now = crate::util::now();
+                let physical_delta = (x - state.previous.0, y - state.previous.1);
+                let delta = (
+                    cx.physical_to_logical(physical_delta.0),
+                    cx.physical_to_logical(physical_delta.1),
+                );
+
+                let elapsed = now.duration_since(state.time).as_secs_f32();
+                if elapsed > 0.0 {
+                    // Smooth the velocity over recent movements.
+                    let velocity = (delta.0 / elapsed, delta.1 / elapsed);
+                    state.velocity = (
+                        (state.velocity.0 + velocity.0) / 2.0,
+                        (state.velocity.1 + velocity.1) / 2.0,
+                    );
+                }
+
+                state.previous = (*x, *y);
+                state.time = now;
+
+                Some(self.gesture(cx, phase, delta, physical_delta))
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                let state = self.state.as_mut()?;
+                if !state.started {
+                    self.state = None;
+                    return None;
+                }
+
+                if crate::util::now().duration_since(state.time) > VELOCITY_TIMEOUT {
+                    state.velocity = (0.0, 0.0);
+                }
+
+                cx.release();
+                let gesture = self.gesture(cx, DragPhase::Ended, (0.0, 0.0), (0.0, 0.0));
+                self.state = None;
+                Some(gesture)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn gesture(
+        &self,
+        cx: &EventContext,
+        phase: DragPhase,
+        delta: (f32, f32),
+        physical_delta: (f32, f32),
+    ) -> DragGesture {
+        let state = self.state.unwrap();
+        let logical = |(x, y): (f32, f32)| (cx.physical_to_logical(x), cx.physical_to_logical(y));
+        DragGesture {
+            phase,
+            start: logical(state.start),
+            current: logical(state.previous),
+            delta,
+            physical_delta,
+            velocity: state.velocity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use std::sync::{Arc, Mutex};
+
+    // Builds a view which records the drag gestures it receives, and presses the mouse button at its center.
+    fn press_drag_area() -> (TestHarness, Arc<Mutex<Vec<DragGesture>>>, (f32, f32)) {
+        let gestures = Arc::new(Mutex::new(Vec::new()));
+        let recorded = gestures.clone();
+        let mut harness = TestHarness::new(|cx| {
+            Element::new(cx)
+                .size(Pixels(100.0))
+                .on_drag_gesture(4.0, move |_, gesture| recorded.lock().unwrap().push(gesture))
+                .id("area");
+        });
+
+        let center = harness.bounds("#area").center();
+        harness.hover(center.0, center.1);
+        harness.emit(WindowEvent::MouseDown(MouseButton::Left));
+
+        (harness, gestures, center)
+    }
+
+    #[test]
+    fn click_is_not_a_drag() {
+        let (mut harness, gestures, (x, y)) = press_drag_area();
+
+        harness.hover(x + 2.0, y);
+        harness.emit(WindowEvent::MouseUp(MouseButton::Left));
+
+        assert!(gestures.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn drag_starts_past_threshold() {
+        let (mut harness, gestures, (x, y)) = press_drag_area();
+
+        harness.hover(x + 2.0, y);
+        harness.hover(x + 10.0, y);
+        // The view keeps receiving the gesture after the cursor leaves it.
+        harness.hover(x + 100.0, y + 5.0);
+        harness.emit(WindowEvent::MouseUp(MouseButton::Left));
+
+        let gestures = gestures.lock().unwrap();
+        let phases = gestures.iter().map(|gesture| gesture.phase).collect::<Vec<_>>();
+        assert_eq!(phases, [DragPhase::Started, DragPhase::Moved, DragPhase::Ended]);
+        assert_eq!(gestures[0].delta, (10.0, 0.0));
+        assert_eq!(gestures[1].delta, (90.0, 5.0));
+        assert_eq!(gestures[2].offset(), (100.0, 5.0));
+    }
+
+    #[test]
+    fn release_velocity() {
+        let (mut harness, gestures, (x, y)) = press_drag_area();
+
+        harness.advance_time(Duration::from_millis(50));
+        harness.hover(x + 10.0, y);
+        harness.advance_time(Duration::from_millis(50));
+        harness.hover(x + 20.0, y);
+        harness.emit(WindowEvent::MouseUp(MouseButton::Left));
+
+        // The velocity is smoothed over the movements, starting from rest.
+        let velocity = gestures.lock().unwrap().last().unwrap().velocity;
+        assert!((velocity.0 - 150.0).abs() < 0.1);
+        assert_eq!(velocity.1, 0.0);
+    }
+
+    #[test]
+    fn release_after_pause_has_no_velocity() {
+        let (mut harness, gestures, (x, y)) = press_drag_area();
+
+        harness.advance_time(Duration::from_millis(50));
+        harness.hover(x + 10.0, y);
+        harness.advance_time(VELOCITY_TIMEOUT * 2);
+        harness.emit(WindowEvent::MouseUp(MouseButton::Left));
+
+        let gestures = gestures.lock().unwrap();
+        assert_eq!(gestures.last().unwrap().phase, DragPhase::Ended);
+        assert_eq!(gestures.last().unwrap().velocity, (0.0, 0.0));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::entity::Entity;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 use std::{any::Any, cmp::Ordering, fmt::Debug};
 use vizia_id::GenerationalId;
 use web_time::Instant;
 
 /// Determines how an event propagates through the tree.
+///
+/// Whatever the propagation, an event is first passed to the [`View::event_capture`] method of each ancestor of its
+/// target, from the root down, before it is sent to the target.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Propagation {
     // /// Events propagate down the tree to the target entity, e.g. from grand-parent to parent to child (target)
@@ -193,6 +197,14 @@ impl Default for EventMeta {
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub struct TimedEventHandle(pub usize);
 
+impl TimedEventHandle {
+    // Returns a new handle. Handles are unique across threads so that events can be scheduled from a `ContextProxy`.
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        TimedEventHandle(NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed))
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct TimedEvent {
     pub ident: TimedEventHandle,
@@ -1,6 +1,7 @@
-use crate::context::{InternalEvent, ResourceContext};
-use crate::events::EventMeta;
+use crate::context::{handle_panic, InternalEvent, ResourceContext};
+use crate::events::{EventMeta, TimedEvent};
 use crate::prelude::*;
+use crate::profiling::{time_pass, FramePass};
 #[cfg(debug_assertions)]
 use crate::systems::compute_matched_rules;
 use crate::systems::{binding_system, hover_system};
@@ -8,6 +9,7 @@ use crate::tree::{focus_backward, focus_forward, is_navigatable};
 #[cfg(debug_assertions)]
 use log::debug;
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use vizia_storage::LayoutParentIterator;
 #[cfg(debug_assertions)]
 use vizia_storage::ParentIterator;
@@ -43,6 +45,9 @@ impl EventManager {
         cx: &mut Context,
         mut window_event_callback: impl FnMut(&WindowEvent),
     ) {
+        let _timer = time_pass(FramePass::Events);
+        let mut processed = 0;
+
         while {
             // Clear the event queue in the event manager.
             self.event_queue.clear();
@@ -51,6 +56,10 @@ impl EventManager {
             // mutably to the view when handling events.
             self.event_queue.extend(cx.event_queue.drain(0..));
 
+            // Remove events which are made redundant by later events.
+            coalesce_events(&mut self.event_queue);
+            processed += self.event_queue.len();
+
             // Loop over the events in the event queue.
             'events: for event in self.event_queue.iter_mut() {
                 // Handle internal events.
@@ -61,6 +70,31 @@ impl EventManager {
                             ResourceContext::new(cx).load_image(path, image, policy);
                         }
                     }
+                    #[cfg(feature = "dialogs")]
+                    InternalEvent::FileDialogClosed { source, callback, paths } => {
+                        cx.with_current(source, |cx| (callback)(&mut EventContext::new(cx), paths));
+                    }
+                    InternalEvent::TransitionBinding { binding: entity, finished } => {
+                        if let Some(mut binding) = cx.bindings.remove(&entity) {
+                            cx.with_current(entity, |cx| binding.transition(cx, finished));
+                            cx.bindings.insert(entity, binding);
+                        }
+                    }
+                    InternalEvent::ScheduleEvent { event, at, handle } => {
+                        if cx.entity_manager.is_alive(event.meta.origin) {
+                            cx.event_schedule.push(TimedEvent { event, time: at, ident: handle });
+                        }
+                    }
+                    InternalEvent::CancelScheduled(handle) => cx.cancel_scheduled(handle),
+                    InternalEvent::ViewPanicked(panic) => handle_panic(cx, panic),
+                    #[cfg(feature = "inspector")]
+                    InternalEvent::OpenInspector(window) => {
+                        crate::views::open_inspector(cx, window)
+                    }
+                    #[cfg(feature = "inspector")]
+                    InternalEvent::CloseInspector(window) => {
+                        crate::views::close_inspector(cx, window)
+                    }
                 });
 
                 // Send events to any global listeners.
@@ -78,15 +112,21 @@ impl EventManager {
                 for entity in listeners {
                     if let Some(listener) = cx.listeners.remove(&entity) {
                         if let Some(mut event_handler) = cx.views.remove(&entity) {
-                            cx.with_current(entity, |cx| {
-                                (listener)(
-                                    event_handler.as_mut(),
-                                    &mut EventContext::new(cx),
-                                    event,
-                                );
+                            let result = cx.with_current(entity, |cx| {
+                                let cx = &mut EventContext::new(cx);
+                                let (panic_handling, tree) = (cx.panic_handling, cx.tree);
+                                panic_handling.catch(tree, entity, || {
+                                    (listener)(event_handler.as_mut(), cx, event);
+                                })
                             });
 
                             cx.views.insert(entity, event_handler);
+
+                            if let Err(panic) = result {
+                                cx.event_queue.push_back(
+                                    Event::new(InternalEvent::ViewPanicked(panic)).direct(entity),
+                                );
+                            }
                         }
 
                         cx.listeners.insert(entity, listener);
@@ -99,6 +139,13 @@ impl EventManager {
 
                 // Handle state updates for window events.
                 event.map(|window_event, meta| {
+                    // Remember which views register global shortcuts so that they can be unregistered when the
+                    // views are removed.
+                    #[cfg(feature = "global-shortcuts")]
+                    if let WindowEvent::RegisterGlobalShortcut(..) = window_event {
+                        cx.global_shortcut_sources.insert(meta.origin);
+                    }
+
                     if cx.windows.contains_key(&meta.origin) {
                         internal_state_updates(cx, window_event, meta);
                     }
@@ -114,6 +161,11 @@ impl EventManager {
                 // Copy the target to prevent multiple mutable borrows error.
                 let target = event.meta.target;
 
+                // Send event to the ancestors of the target, from the root down, before the target.
+                if capture_event(cx, target, event) {
+                    continue 'events;
+                }
+
                 // Send event to target.
                 visit_entity(cx, target, event);
 
@@ -159,15 +211,128 @@ impl EventManager {
                 });
             }
 
-            binding_system(cx);
+            {
+                let _timer = time_pass(FramePass::Bindings);
+                binding_system(cx);
+            }
 
             // Return true if there are new events in the queue.
             !cx.event_queue.is_empty()
         } {}
+
+        cx.events_per_frame = processed;
+    }
+}
+
+/// Removes events from a batch which are made redundant by other events in the same batch.
+///
+/// Successive cursor moves from the same window collapse to the last position, repeated redraw requests are dropped,
+/// and geometry changes of the same view are merged into the last of them. Cursor moves are only collapsed when no
+/// other event is between them, so their order relative to button presses and releases is preserved. The cursor
+/// position from before the collapsed moves is still used as the previous position, so cursor deltas span all of them.
+fn coalesce_events(events: &mut Vec<Event>) {
+    fn window_event(event: &Event) -> Option<&WindowEvent> {
+        event.message.as_ref()?.downcast_ref()
+    }
+
+    fn is_mouse_move(event: &Event) -> bool {
+        matches!(
+            window_event(event),
+            Some(WindowEvent::MouseMove(x, y)) if !x.is_nan() && !y.is_nan()
+        )
+    }
+
+    let mut keep = vec![true; events.len()];
+    let mut internal_redraw = false;
+    let mut window_redraws = HashSet::new();
+    let mut geometry_changes = HashMap::new();
+
+    for index in 0..events.len() {
+        let event = &events[index];
+        let meta = &event.meta;
+
+        if let Some(InternalEvent::Redraw) = event.message.as_ref().and_then(|m| m.downcast_ref()) {
+            keep[index] = !internal_redraw;
+            internal_redraw = true;
+            continue;
+        }
+
+        match window_event(event) {
+            Some(WindowEvent::MouseMove(..)) => {
+                if let Some(next) = events.get(index + 1) {
+                    if is_mouse_move(event)
+                        && is_mouse_move(next)
+                        && next.meta.origin == meta.origin
+                        && next.meta.target == meta.target
+                    {
+                        keep[index] = false;
+                    }
+                }
+            }
+
+            Some(WindowEvent::Redraw) => {
+                keep[index] = window_redraws.insert((meta.origin, meta.target));
+            }
+
+            Some(WindowEvent::GeometryChanged(_)) => {
+                let Some(previous) = geometry_changes.insert(meta.target, index) else {
+                    continue;
+                };
+
+                keep[previous] = false;
+                if let Some(WindowEvent::GeometryChanged(previous_geo)) =
+                    window_event(&events[previous]).cloned()
+                {
+                    if let Some(WindowEvent::GeometryChanged(geo)) =
+                        events[index].message.as_mut().and_then(|m| m.downcast_mut())
+                    {
+                        *geo |= previous_geo;
+                    }
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    let mut keep = keep.into_iter();
+    events.retain(|_| keep.next().unwrap_or(true));
+}
+
+// Sends an event to the capture phase handlers of the views of the ancestors of its target, from the root down to the
+// parent of the target. Returns true if the event was consumed.
+fn capture_event(cx: &mut EventContext, target: Entity, event: &mut Event) -> bool {
+    if cx.capturing_views.is_empty() {
+        return false;
+    }
+
+    let ancestors = target
+        .parent_iter(cx.tree)
+        .skip(1)
+        .filter(|entity| cx.capturing_views.contains(entity))
+        .collect::<Vec<_>>();
+    for entity in ancestors.into_iter().rev() {
+        if let Some(mut view) = cx.views.remove(&entity) {
+            cx.current = entity;
+            let (panic_handling, tree) = (cx.panic_handling, cx.tree);
+            let result = panic_handling.catch(tree, entity, || view.event_capture(cx, event));
+
+            cx.views.insert(entity, view);
+
+            if let Err(panic) = result {
+                cx.emit_custom(Event::new(InternalEvent::ViewPanicked(panic)).direct(entity));
+            }
+        }
+
+        if event.meta.consumed {
+            return true;
+        }
     }
+
+    false
 }
 
-fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
+pub(crate) fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
     // Send event to models attached to the entity
     if let Some(ids) =
         cx.models.get(&entity).map(|models| models.keys().cloned().collect::<Vec<_>>())
@@ -177,10 +342,14 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
                 cx.models.get_mut(&entity).and_then(|models| models.remove(&id))
             {
                 cx.current = entity;
-
-                model.event(cx, event);
+                let (panic_handling, tree) = (cx.panic_handling, cx.tree);
+                let result = panic_handling.catch(tree, entity, || model.event(cx, event));
 
                 cx.models.get_mut(&entity).and_then(|models| models.insert(id, model));
+
+                if let Err(panic) = result {
+                    cx.emit_custom(Event::new(InternalEvent::ViewPanicked(panic)).direct(entity));
+                }
             }
         }
     }
@@ -190,12 +359,21 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
         return;
     }
 
-    // Send event to the view attached to the entity
+    // Send event to the view attached to the entity, unless its content was replaced after it panicked
+    if cx.panic_handling.panicked.contains(&entity) {
+        return;
+    }
+
     if let Some(mut view) = cx.views.remove(&entity) {
         cx.current = entity;
-        view.event(cx, event);
+        let (panic_handling, tree) = (cx.panic_handling, cx.tree);
+        let result = panic_handling.catch(tree, entity, || view.event(cx, event));
 
         cx.views.insert(entity, view);
+
+        if let Err(panic) = result {
+            cx.emit_custom(Event::new(InternalEvent::ViewPanicked(panic)).direct(entity));
+        }
     }
 }
 
@@ -204,18 +382,41 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
     cx.current = meta.target;
 
     match window_event {
-        WindowEvent::Drop(drop_data) => {
-            cx.drop_data = Some(drop_data.clone());
+        WindowEvent::Drop(_) => {
+            // Send the dropped data to the drop target under the cursor.
+            let target = drop_target(cx);
+            set_drag_target(cx, Entity::null(), false);
+            cx.drop_data = None;
+
+            if target != Entity::null() {
+                meta.target = target;
+                meta.propagation = Propagation::Up;
+            }
+        }
+
+        WindowEvent::FileHovered(path) => {
+            cx.drop_data = Some(DropData::File(path.clone()));
+
+            // Cursor events may not be received while a file is dragged into the window.
+            if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(meta.origin) {
+                pseudo_classes.set(PseudoClassFlags::OVER, true);
+            }
+
+            hover_system(cx, meta.origin);
+            update_drag_target(cx);
+        }
+
+        WindowEvent::FileHoverCancelled => {
+            cx.drop_data = None;
+            set_drag_target(cx, Entity::null(), true);
         }
 
         WindowEvent::MouseMove(x, y) => {
             if !x.is_nan() && !y.is_nan() {
-                cx.mouse.previous_cursor_x = cx.mouse.cursor_x;
-                cx.mouse.previous_cursor_y = cx.mouse.cursor_y;
-                cx.mouse.cursor_x = *x;
-                cx.mouse.cursor_y = *y;
+                cx.mouse.move_cursor(*x, *y, crate::util::now());
 
                 hover_system(cx, meta.origin);
+                update_drag_target(cx);
 
                 mutate_direct_or_up(meta, cx.captured, cx.hovered, false);
             }
@@ -235,6 +436,50 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             //     );
             // }
         }
+        WindowEvent::Touch { id, phase, position } => {
+            let previous_distance = pinch_distance(&cx.touches);
+
+            match phase {
+                TouchPhase::Started => cx.touches.push((*id, *position)),
+                TouchPhase::Moved => {
+                    if let Some(touch) = cx.touches.iter_mut().find(|(touch_id, _)| touch_id == id)
+                    {
+                        touch.1 = *position;
+                    }
+                }
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    cx.touches.retain(|(touch_id, _)| touch_id != id)
+                }
+            }
+
+            // Recognize a pinch from the change in distance between the first two touches.
+            if *phase == TouchPhase::Moved {
+                if let (Some(previous), Some(current)) =
+                    (previous_distance, pinch_distance(&cx.touches))
+                {
+                    if previous > 0.0 {
+                        let (_, (x1, y1)) = cx.touches[0];
+                        let (_, (x2, y2)) = cx.touches[1];
+                        let center = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+                        emit_direct_or_up(
+                            cx,
+                            WindowEvent::Gesture(Gesture::Pinch(current / previous, center)),
+                            Entity::null(),
+                            cx.hovered,
+                            true,
+                        );
+                    }
+                }
+            }
+
+            mutate_direct_or_up(meta, Entity::null(), cx.hovered, true);
+        }
+        WindowEvent::Gesture(_) => {
+            mutate_direct_or_up(meta, Entity::null(), cx.hovered, true);
+        }
+        WindowEvent::Pen { .. } => {
+            mutate_direct_or_up(meta, cx.captured, cx.hovered, true);
+        }
         WindowEvent::MouseDown(button) => {
             // do direct state-updates
             match button {
@@ -292,7 +537,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             }
 
             // track double/triple -click
-            let new_click_time = Instant::now();
+            let new_click_time = crate::util::now();
             let click_duration = new_click_time - cx.click_time;
             let new_click_pos = (cx.mouse.cursor_x, cx.mouse.cursor_y);
             if click_duration <= DOUBLE_CLICK_INTERVAL
@@ -338,6 +583,16 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             }
 
             if matches!(button, MouseButton::Left) {
+                // Drop the data of a drag and drop within the application onto the drop target under the cursor.
+                if let Some(drop_data) = cx.drop_data.take() {
+                    let target = drop_target(cx);
+                    set_drag_target(cx, Entity::null(), false);
+                    if target != Entity::null() {
+                        cx.event_queue
+                            .push_back(Event::new(WindowEvent::Drop(drop_data)).target(target));
+                    }
+                }
+
                 if cx.hovered == cx.triggered {
                     let disabled = cx.style.disabled.get(cx.hovered).copied().unwrap_or_default();
 
@@ -369,6 +624,13 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
         WindowEvent::KeyDown(code, _) => {
             meta.target = cx.focused;
 
+            #[cfg(feature = "inspector")]
+            if *code == Code::KeyI && cx.modifiers == Modifiers::CTRL | Modifiers::SHIFT {
+                crate::views::toggle_inspector(cx, meta.origin);
+                meta.consume();
+                return;
+            }
+
             #[cfg(debug_assertions)]
             if *code == Code::KeyP && cx.modifiers.ctrl() {
                 for entity in TreeIterator::full(&cx.tree) {
@@ -510,7 +772,9 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             }
 
             if *code == Code::F5 {
-                EventContext::new(cx).reload_styles().unwrap();
+                if let Err(error) = EventContext::new(cx).reload_styles() {
+                    log::warn!("{}", error);
+                }
             }
 
             if *code == Code::Tab {
@@ -645,6 +909,14 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
                 pseudo_class.set(PseudoClassFlags::OVER, false);
             }
 
+            set_drag_target(cx, Entity::null(), true);
+
+            // The cursor has left the window, so the hovered view will not be told by the hover system.
+            if cx.hovered != Entity::null() {
+                cx.event_queue.push_back(Event::new(WindowEvent::MouseLeave).direct(cx.hovered));
+                cx.event_queue.push_back(Event::new(WindowEvent::MouseOut).target(cx.hovered));
+            }
+
             let parent_iter = LayoutParentIterator::new(&cx.tree, cx.hovered);
             for ancestor in parent_iter {
                 if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(ancestor) {
@@ -660,6 +932,74 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
     }
 }
 
+/// Returns the innermost view under the cursor which accepts dropped data.
+fn drop_target(cx: &Context) -> Entity {
+    if cx.hovered == Entity::null() {
+        return Entity::null();
+    }
+
+    cx.hovered
+        .parent_iter(&cx.tree)
+        .find(|entity| {
+            cx.style
+                .abilities
+                .get(*entity)
+                .is_some_and(|abilities| abilities.contains(Abilities::DROPPABLE))
+        })
+        .unwrap_or(Entity::null())
+}
+
+/// Updates the drop target under the cursor during a drag and drop operation and sends it the position of the cursor.
+fn update_drag_target(cx: &mut Context) {
+    let target = if cx.drop_data.is_some() { drop_target(cx) } else { Entity::null() };
+
+    set_drag_target(cx, target, true);
+
+    if target != Entity::null() {
+        let bounds = cx.cache.get_bounds(target);
+        let (x, y) = (cx.mouse.cursor_x - bounds.x, cx.mouse.cursor_y - bounds.y);
+        cx.event_queue.push_back(Event::new(WindowEvent::DragOver(x, y)).direct(target));
+    }
+}
+
+/// Sets the current drop target, moving the `:drag-over` pseudoclass and sending drag enter and leave events.
+fn set_drag_target(cx: &mut Context, target: Entity, leave: bool) {
+    let previous = cx.drag_target;
+    if target == previous {
+        return;
+    }
+
+    if previous != Entity::null() {
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(previous) {
+            pseudo_classes.set(PseudoClassFlags::DRAG_OVER, false);
+        }
+        cx.style.needs_restyle(previous);
+
+        if leave {
+            cx.event_queue.push_back(Event::new(WindowEvent::DragLeave).direct(previous));
+        }
+    }
+
+    if target != Entity::null() {
+        if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(target) {
+            pseudo_classes.set(PseudoClassFlags::DRAG_OVER, true);
+        }
+        cx.style.needs_restyle(target);
+
+        cx.event_queue.push_back(Event::new(WindowEvent::DragEnter).direct(target));
+    }
+
+    cx.drag_target = target;
+}
+
+// Returns the distance between the first two active touches.
+fn pinch_distance(touches: &[(u64, (f32, f32))]) -> Option<f32> {
+    match touches {
+        [(_, (x1, y1)), (_, (x2, y2)), ..] => Some((x2 - x1).hypot(y2 - y1)),
+        _ => None,
+    }
+}
+
 fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: bool) {
     if direct != Entity::null() {
         meta.target = direct;
@@ -683,3 +1023,177 @@ fn emit_direct_or_up<M: Any + Send>(
     mutate_direct_or_up(&mut event.meta, direct, up, root);
     cx.emit_custom(event);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn window_event(message: WindowEvent) -> Event {
+        Event::new(message).origin(Entity::root()).target(Entity::root())
+    }
+
+    #[test]
+    fn coalesces_redundant_events() {
+        let mut events = vec![
+            window_event(WindowEvent::MouseMove(1.0, 1.0)),
+            window_event(WindowEvent::MouseMove(2.0, 2.0)),
+            window_event(WindowEvent::MouseDown(MouseButton::Left)),
+            window_event(WindowEvent::MouseMove(3.0, 3.0)),
+            window_event(WindowEvent::Redraw),
+            window_event(WindowEvent::MouseUp(MouseButton::Left)),
+            window_event(WindowEvent::Redraw),
+            window_event(WindowEvent::GeometryChanged(GeoChanged::WIDTH_CHANGED)),
+            window_event(WindowEvent::GeometryChanged(GeoChanged::HEIGHT_CHANGED)),
+        ];
+
+        coalesce_events(&mut events);
+
+        let events = events
+            .iter()
+            .filter_map(|event| event.message.as_ref()?.downcast_ref::<WindowEvent>())
+            .map(|event| format!("{:?}", event))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            events[..5],
+            [
+                "MouseMove(2.0, 2.0)",
+                "MouseDown(Left)",
+                "MouseMove(3.0, 3.0)",
+                "Redraw",
+                "MouseUp(Left)"
+            ]
+        );
+
+        let geo = GeoChanged::WIDTH_CHANGED | GeoChanged::HEIGHT_CHANGED;
+        assert_eq!(events[5..], [format!("GeometryChanged({:?})", geo)]);
+    }
+
+    // A view which records the events it receives, and optionally consumes events in the capture phase.
+    struct Recorder {
+        name: &'static str,
+        consume_capture: bool,
+        log: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl View for Recorder {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|_: &&'static str, _| {
+                self.log.borrow_mut().push(format!("{} event", self.name))
+            });
+        }
+
+        fn event_capture(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|_: &&'static str, meta| {
+                self.log.borrow_mut().push(format!("{} capture", self.name));
+                if self.consume_capture {
+                    meta.consume();
+                }
+            });
+        }
+    }
+
+    fn recorder_tree(consume_capture: bool) -> (TestHarness, Rc<RefCell<Vec<String>>>) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let recorder = |name, consume_capture| Recorder { name, consume_capture, log: log.clone() };
+
+        let mut harness = TestHarness::new(|cx| {
+            recorder("outer", false).build(cx, |cx| {
+                recorder("inner", consume_capture).build(cx, |cx| {
+                    recorder("target", false).build(cx, |_| {}).id("target");
+                });
+            });
+        });
+
+        let target = harness.query("#target").unwrap();
+        harness
+            .context()
+            .emit_custom(Event::new("message").target(target).propagate(Propagation::Up));
+        harness.update();
+
+        (harness, log)
+    }
+
+    #[test]
+    fn captures_events_before_target() {
+        let (_, log) = recorder_tree(false);
+
+        assert_eq!(
+            *log.borrow(),
+            ["outer capture", "inner capture", "target event", "inner event", "outer event"]
+        );
+    }
+
+    #[test]
+    fn consumes_events_in_capture_phase() {
+        let (_, log) = recorder_tree(true);
+
+        assert_eq!(*log.borrow(), ["outer capture", "inner capture"]);
+    }
+
+    #[test]
+    fn skips_views_without_capture_handlers() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let recorder = |name| Recorder { name, consume_capture: false, log: log.clone() };
+
+        let mut harness = TestHarness::new(|cx| {
+            recorder("outer")
+                .build(cx, |cx| {
+                    VStack::new(cx, |cx| {
+                        recorder("target").build(cx, |_| {}).id("target");
+                    })
+                    .id("stack");
+                })
+                .id("outer");
+        });
+
+        let target = harness.query("#target").unwrap();
+        harness
+            .context()
+            .emit_custom(Event::new("message").target(target).propagate(Propagation::Up));
+        harness.update();
+        assert_eq!(*log.borrow(), ["outer capture", "target event", "outer event"]);
+
+        // Views with the default capture handler leave the capture phase once an event has passed through them.
+        let outer = harness.query("#outer").unwrap();
+        let stack = harness.query("#stack").unwrap();
+        assert!(harness.context().capturing_views.contains(&outer));
+        assert!(!harness.context().capturing_views.contains(&stack));
+    }
+
+    #[test]
+    fn emits_scheduled_events() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let recorder = |name| Recorder { name, consume_capture: false, log: log.clone() };
+
+        let mut harness = TestHarness::new(|cx| {
+            recorder("a").build(cx, |_| {}).id("a");
+            recorder("b").build(cx, |_| {}).id("b");
+        });
+
+        let a = harness.query("#a").unwrap();
+        let b = harness.query("#b").unwrap();
+        let cx = harness.context();
+        cx.with_current(a, |cx| cx.emit_after("message", Duration::from_millis(100)));
+        // An event can be cancelled from an entity other than the one which scheduled it.
+        let handle = cx.with_current(b, |cx| cx.emit_after("message", Duration::from_millis(100)));
+        cx.with_current(a, |cx| cx.cancel_scheduled(handle));
+
+        harness.advance_time(Duration::from_millis(50));
+        assert!(log.borrow().is_empty());
+        harness.advance_time(Duration::from_millis(50));
+        assert_eq!(*log.borrow(), ["a event"]);
+
+        // Events scheduled by a removed entity are dropped.
+        let cx = harness.context();
+        cx.with_current(a, |cx| {
+            let at = crate::util::now() + Duration::from_millis(100);
+            cx.schedule_emit_to(b, "message", at)
+        });
+        cx.remove(a);
+        harness.advance_time(Duration::from_millis(200));
+        assert_eq!(*log.borrow(), ["a event"]);
+    }
+}
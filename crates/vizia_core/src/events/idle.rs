@@ -0,0 +1,80 @@
+use crate::{context::Context, entity::Entity};
+
+/// Work which runs when the event loop is otherwise idle, on behalf of a view.
+pub(crate) struct IdleTask {
+    pub(crate) entity: Entity,
+    // Called with the view as the current entity. Returns true when the work is finished.
+    pub(crate) work: Box<dyn FnMut(&mut Context) -> bool>,
+}
+
+impl IdleTask {
+    /// Creates a task which runs `work` once.
+    pub(crate) fn once(entity: Entity, work: impl FnOnce(&mut Context) + 'static) -> Self {
+        let mut work = Some(work);
+        Self {
+            entity,
+            work: Box::new(move |cx| {
+                if let Some(work) = work.take() {
+                    (work)(cx);
+                }
+
+                true
+            }),
+        }
+    }
+
+    /// Creates a task which calls `build` with up to `chunk_size` items each time it runs, until there are no items
+    /// left.
+    pub(crate) fn chunked<I: Iterator + 'static>(
+        entity: Entity,
+        mut items: I,
+        chunk_size: usize,
+        build: impl Fn(&mut Context, I::Item) + 'static,
+    ) -> Self {
+        let chunk_size = chunk_size.max(1);
+        Self {
+            entity,
+            work: Box::new(move |cx| {
+                for _ in 0..chunk_size {
+                    match items.next() {
+                        Some(item) => (build)(cx, item),
+                        None => return true,
+                    }
+                }
+
+                false
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[test]
+    fn builds_incrementally_until_removed() {
+        let mut harness = TestHarness::new(|cx| {
+            VStack::new(cx, |cx| {
+                cx.build_incrementally(0..250, 10, |cx, index| {
+                    Label::new(cx, format!("{}", index)).class("row");
+                });
+            })
+            .id("built");
+        });
+
+        assert_eq!(harness.query_all(".row").len(), 250);
+
+        let cx = harness.context();
+        let stack = VStack::new(cx, |_| {}).entity();
+        cx.with_current(stack, |cx| {
+            cx.build_incrementally(0..250, 10, |cx, _| {
+                Label::new(cx, "").class("cancelled");
+            });
+        });
+        cx.remove(stack);
+        harness.update();
+
+        assert!(harness.query(".cancelled").is_none());
+    }
+}
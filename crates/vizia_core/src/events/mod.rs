@@ -86,15 +86,22 @@
 
 /// Dispatches events to views and models.
 pub mod event_manager;
+pub(crate) use event_manager::visit_entity;
 pub use event_manager::EventManager;
 
 mod event;
 pub(crate) use event::TimedEvent;
 pub use event::{Event, EventMeta, Propagation, TimedEventHandle};
 
+mod drag;
+pub use drag::{DragGesture, DragPhase, DragTracker};
+
 mod event_handler;
 pub(crate) use event_handler::ViewHandler;
 
+mod idle;
+pub(crate) use idle::IdleTask;
+
 mod timer;
 pub(crate) use timer::TimerState;
 pub use timer::{Timer, TimerAction};
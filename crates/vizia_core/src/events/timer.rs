@@ -1,5 +1,9 @@
 use std::{cmp::Ordering, rc::Rc};
 
+use vizia_id::{
+    impl_generational_id, GenerationalId, GENERATIONAL_ID_GENERATION_MASK,
+    GENERATIONAL_ID_INDEX_BITS, GENERATIONAL_ID_INDEX_MASK,
+};
 use web_time::{Duration, Instant};
 
 use crate::{context::EventContext, entity::Entity};
@@ -22,6 +26,8 @@ pub enum TimerAction {
 #[derive(Clone)]
 pub struct TimerState {
     pub(crate) entity: Entity,
+    // The entity which added the timer. The timer is removed along with it.
+    pub(crate) owner: Entity,
     pub(crate) id: Timer,
     pub(crate) time: Instant,
     pub(crate) interval: Duration,
@@ -94,5 +100,10 @@ impl Ord for TimerState {
 }
 
 /// A handle used to start, stop, and check the running status of a timer added with `cx.add_timer()`.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub struct Timer(pub usize);
+///
+/// A timer is removed along with the view which added it. The handle has a generation, so a handle to a removed timer
+/// does nothing rather than referring to a timer which is added later in its place.
+#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Timer(u64);
+
+impl_generational_id!(Timer);
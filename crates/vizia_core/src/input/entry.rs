@@ -1,16 +1,21 @@
 use crate::context::EventContext;
+use vizia_input::KeyChord;
 
 /// An entry inside of a [`Keymap`](crate::prelude::Keymap).
 ///
 /// It consists of an action which is usually just an enum variant
 /// and a callback function that gets called if the action got triggered.
-#[derive(Copy, Clone)]
+///
+/// An entry can also be triggered by a sequence of key chords, such as `CTRL+K` followed by `CTRL+C`,
+/// by adding the chords which follow the chord the entry is bound to with [`then`](KeymapEntry::then).
+#[derive(Clone)]
 pub struct KeymapEntry<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
     action: T,
     on_action: fn(&mut EventContext),
+    sequence: Vec<KeyChord>,
 }
 
 impl<T> KeymapEntry<T>
@@ -32,7 +37,33 @@ where
     /// KeymapEntry::new(Action::One, |_| debug!("Action One"));
     /// ```
     pub fn new(action: T, on_action: fn(&mut EventContext)) -> Self {
-        Self { action, on_action }
+        Self { action, on_action, sequence: Vec::new() }
+    }
+
+    /// Adds a key chord which has to be pressed after the previous chords of the entry to trigger the action.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Copy, Clone, PartialEq)]
+    /// # enum Action {
+    /// #     Comment,
+    /// # }
+    /// #
+    /// // Bound to CTRL+K, this entry is triggered by CTRL+K followed by CTRL+C.
+    /// KeymapEntry::new(Action::Comment, |_| debug!("Comment"))
+    ///     .then(KeyChord::new(Modifiers::CTRL, Code::KeyC));
+    /// ```
+    pub fn then(mut self, chord: KeyChord) -> Self {
+        self.sequence.push(chord);
+        self
+    }
+
+    /// Returns the key chords which have to be pressed after the chord the entry is bound to.
+    pub fn sequence(&self) -> &[KeyChord] {
+        &self.sequence
     }
 
     /// Returns the action of the keymap entry.
@@ -51,7 +82,7 @@ where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.action == other.action
+        self.action == other.action && self.sequence == other.sequence
     }
 }
 
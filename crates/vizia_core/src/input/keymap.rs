@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use indexmap::IndexMap;
+use log::warn;
 
 /// A keymap that associates key chords with actions.
 ///
@@ -42,12 +43,61 @@ use indexmap::IndexMap;
 ///     (KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyC), KeymapEntry::new(Action::Three, |_| debug!("Action Three"))),
 /// ]);
 /// ```
-#[derive(Default)]
+///
+/// An action can also be bound to a sequence of key chords by adding the chords which follow the first one to the
+/// entry with [`KeymapEntry::then`]. The action is triggered once the whole sequence has been pressed, and the
+/// sequence is cancelled if a different chord is pressed or if the next chord isn't pressed within the
+/// [`sequence_timeout`](Keymap::sequence_timeout).
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(PartialEq, Copy, Clone)]
+/// # enum Action {
+/// #     Comment,
+/// # }
+/// #
+/// let keymap = Keymap::from(vec![(
+///     KeyChord::new(Modifiers::CTRL, Code::KeyK),
+///     KeymapEntry::new(Action::Comment, |_| debug!("Comment"))
+///         .then(KeyChord::new(Modifiers::CTRL, Code::KeyC)),
+/// )]);
+/// ```
+///
+/// # Scopes
+///
+/// Key events are sent to the focused view and then to each of its ancestors, so a keymap built inside of a view
+/// only handles key chords while the focus is within that view. When more than one keymap handles the same key
+/// chord, the innermost keymap wins, and a key chord which isn't bound in a keymap falls through to the keymaps
+/// outside of it. A textbox which is being edited handles the standard text editing shortcuts itself, so they
+/// don't reach any keymaps.
+///
+/// A keymap can also be limited to a [`mode`](Keymap::mode), which the application enables and disables with
+/// [`EnvironmentEvent::SetKeymapMode`].
+///
+/// # Saving bindings
+///
+/// With the `serde` feature a keymap can be serialized, for example with [`to_toml`](Keymap::to_toml), to save the
+/// key chords a user has bound to each action. Callbacks can't be serialized, so a deserialized keymap only holds the
+/// bindings, and is applied to the keymap of the application with [`with_bindings`](Keymap::with_bindings).
 pub struct Keymap<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
     entries: IndexMap<KeyChord, Vec<KeymapEntry<T>>>,
+    sequence_timeout: Duration,
+    mode: Option<String>,
+    // The chords of a partially pressed sequence and the time the last of them was pressed.
+    pending: Option<(Vec<KeyChord>, Instant)>,
+}
+
+impl<T> Default for Keymap<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Keymap<T>
@@ -71,7 +121,184 @@ where
     /// let keymap = Keymap::<Action>::new();
     /// ```
     pub fn new() -> Self {
-        Self { entries: IndexMap::new() }
+        Self {
+            entries: IndexMap::new(),
+            sequence_timeout: Duration::from_secs(1),
+            mode: None,
+            pending: None,
+        }
+    }
+
+    /// Limits the keymap to a mode, so that it only handles key chords while the mode is enabled with
+    /// [`EnvironmentEvent::SetKeymapMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// let keymap = Keymap::<Action>::new().mode("normal");
+    ///
+    /// cx.emit(EnvironmentEvent::SetKeymapMode(String::from("normal"), true));
+    /// ```
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    /// Sets the maximum time between the key chords of a sequence, after which a partially pressed sequence is
+    /// cancelled. Defaults to one second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// let keymap = Keymap::<Action>::new().sequence_timeout(Duration::from_millis(500));
+    /// ```
+    pub fn sequence_timeout(mut self, timeout: Duration) -> Self {
+        self.sequence_timeout = timeout;
+        self
+    }
+
+    /// Replaces the key chords bound to the actions of the keymap with the ones bound in another keymap, such as one
+    /// loaded from a config file, keeping the callbacks of this keymap.
+    ///
+    /// Actions which aren't bound in `bindings` keep their key chords, and actions which aren't in this keymap are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     Save,
+    /// # }
+    /// #
+    /// let defaults = Keymap::from(vec![(
+    ///     KeyChord::new(Modifiers::CTRL, Code::KeyS),
+    ///     KeymapEntry::new(Action::Save, |_| debug!("Save")),
+    /// )]);
+    ///
+    /// let user = Keymap::from(vec![(
+    ///     KeyChord::new(Modifiers::ALT, Code::KeyS),
+    ///     KeymapEntry::new(Action::Save, |_| {}),
+    /// )]);
+    ///
+    /// let keymap = defaults.with_bindings(user);
+    /// assert_eq!(keymap.bindings(&Action::Save), vec![vec![KeyChord::new(Modifiers::ALT, Code::KeyS)]]);
+    /// ```
+    pub fn with_bindings(mut self, bindings: Keymap<T>) -> Self {
+        let mut rebound: Vec<(T, fn(&mut EventContext))> = Vec::new();
+        for (chord, entry) in bindings.export() {
+            let action = entry.action();
+            let known = rebound.iter().find(|(rebound, _)| rebound == action).map(|(_, f)| *f);
+            let on_action = match known {
+                Some(on_action) => on_action,
+                None => {
+                    let Some(on_action) = self.export().into_iter().find_map(|(_, existing)| {
+                        (existing.action() == action).then_some(*existing.on_action())
+                    }) else {
+                        warn!("Ignored keymap binding for {:?} with an unknown action", chord);
+                        continue;
+                    };
+
+                    self.entries.retain(|_, entries| {
+                        entries.retain(|existing| existing.action() != action);
+                        !entries.is_empty()
+                    });
+                    rebound.push((action.clone(), on_action));
+                    on_action
+                }
+            };
+
+            let entry = entry
+                .sequence()
+                .iter()
+                .fold(KeymapEntry::new(action.clone(), on_action), |entry, chord| {
+                    entry.then(*chord)
+                });
+            self.insert(*chord, entry);
+        }
+
+        self
+    }
+
+    /// Serializes the bindings of the keymap to TOML.
+    ///
+    /// Each binding is saved as a table with the action and the key chords which trigger it, for example
+    /// `{ action = "Save", keys = ["Ctrl+S"] }`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone, serde::Serialize)]
+    /// # enum Action {
+    /// #     Save,
+    /// # }
+    /// #
+    /// let keymap = Keymap::from(vec![(
+    ///     KeyChord::new(Modifiers::CTRL, Code::KeyS),
+    ///     KeymapEntry::new(Action::Save, |_| debug!("Save")),
+    /// )]);
+    ///
+    /// std::fs::write("keymap.toml", keymap.to_toml().unwrap()).ok();
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error>
+    where
+        T: serde::Serialize,
+    {
+        toml::to_string(self)
+    }
+
+    /// Deserializes the bindings of a keymap from TOML which was written by [`to_toml`](Keymap::to_toml).
+    ///
+    /// The callbacks of the returned keymap do nothing, so it should be applied to the keymap of the application
+    /// with [`with_bindings`](Keymap::with_bindings).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone, serde::Deserialize)]
+    /// # enum Action {
+    /// #     Save,
+    /// # }
+    /// #
+    /// let defaults = Keymap::from(vec![(
+    ///     KeyChord::new(Modifiers::CTRL, Code::KeyS),
+    ///     KeymapEntry::new(Action::Save, |_| debug!("Save")),
+    /// )]);
+    ///
+    /// let keymap = match std::fs::read_to_string("keymap.toml") {
+    ///     Ok(contents) => defaults.with_bindings(Keymap::from_toml(&contents).unwrap_or_default()),
+    ///     Err(_) => defaults,
+    /// };
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        toml::from_str(toml)
     }
 
     /// Inserts an entry into the keymap.
@@ -79,13 +306,145 @@ where
     /// This method is for internal use only.
     /// To insert an entry into the keymap at runtime use the [`KeymapEvent::InsertAction`] event.
     fn insert(&mut self, chord: KeyChord, keymap_entry: KeymapEntry<T>) {
-        if let Some(actions) = self.entries.get_mut(&chord) {
-            if !actions.contains(&keymap_entry) {
-                actions.push(keymap_entry);
+        let conflicts = self.conflicts(&chord, &keymap_entry);
+        if conflicts.contains(&&keymap_entry) {
+            warn!("Ignored duplicate keymap binding for {:?}", chord);
+            return;
+        }
+
+        if !conflicts.is_empty() {
+            warn!(
+                "Keymap binding for {:?} conflicts with {} existing binding(s)",
+                chord,
+                conflicts.len()
+            );
+        }
+
+        self.entries.entry(chord).or_default().push(keymap_entry);
+    }
+
+    /// Returns the entries which conflict with binding an entry to a key chord.
+    ///
+    /// An entry conflicts with another if they are triggered by the same sequence of key chords, or if the sequence
+    /// of one starts with the whole sequence of the other, in which case the longer sequence can never be completed
+    /// without also triggering the shorter one. An entry with the same action and sequence is a duplicate, which is
+    /// ignored when inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// #     Two,
+    /// # }
+    /// #
+    /// let chord = KeyChord::new(Modifiers::CTRL, Code::KeyA);
+    /// let keymap = Keymap::from(vec![(chord, KeymapEntry::new(Action::One, |_| {}))]);
+    ///
+    /// let conflicts = keymap.conflicts(&chord, &KeymapEntry::new(Action::Two, |_| {}));
+    /// assert_eq!(conflicts[0].action(), &Action::One);
+    /// ```
+    pub fn conflicts(
+        &self,
+        chord: &KeyChord,
+        keymap_entry: &KeymapEntry<T>,
+    ) -> Vec<&KeymapEntry<T>> {
+        let sequence = keymap_entry.sequence();
+        self.entries
+            .get(chord)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|entry| {
+                        entry.sequence().starts_with(sequence)
+                            || sequence.starts_with(entry.sequence())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the sequences of key chords bound to an action, in the order they were inserted.
+    ///
+    /// This is useful for showing the keyboard shortcut of an action, for example in a menu.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # let keymap = Keymap::<Action>::new();
+    /// #
+    /// for sequence in keymap.bindings(&Action::One) {
+    ///     debug!("The action is triggered by {:?}", sequence);
+    /// }
+    /// ```
+    pub fn bindings(&self, action: &T) -> Vec<Vec<KeyChord>> {
+        self.entries
+            .iter()
+            .flat_map(|(chord, entries)| {
+                entries.iter().filter(|entry| entry.action() == action).map(|entry| {
+                    std::iter::once(*chord).chain(entry.sequence().iter().copied()).collect()
+                })
+            })
+            .collect()
+    }
+
+    /// Returns true if the keymap has no mode or its mode is enabled.
+    fn is_active(&self, cx: &EventContext) -> bool {
+        self.mode.as_ref().map_or(true, |mode| {
+            cx.data::<Environment>()
+                .is_some_and(|environment| environment.keymap_modes.contains(mode))
+        })
+    }
+
+    /// Handles a pressed key chord, triggering the actions of any entries whose sequence of key chords is completed
+    /// by it. Returns true if the chord was handled, either by triggering an action or by continuing a sequence.
+    fn press(&mut self, cx: &mut EventContext, chord: KeyChord) -> bool {
+        let now = crate::util::now();
+        let mut sequence = match self.pending.take() {
+            // Pressing a modifier key on its own doesn't cancel a sequence.
+            Some((sequence, time)) if now.duration_since(time) <= self.sequence_timeout => {
+                if is_modifier(chord.code) {
+                    self.pending = Some((sequence, time));
+                    return true;
+                }
+
+                sequence
             }
-        } else {
-            self.entries.insert(chord, vec![keymap_entry]);
+
+            _ => Vec::new(),
+        };
+
+        sequence.push(chord);
+        let (first, rest) = sequence.split_first().unwrap();
+
+        let mut handled = false;
+        let mut is_pending = false;
+        if let Some(entries) = self.entries.get(first) {
+            for entry in entries {
+                if entry.sequence() == rest {
+                    (entry.on_action())(cx);
+                    handled = true;
+                } else if entry.sequence().starts_with(rest) {
+                    is_pending = true;
+                }
+            }
+        }
+
+        if is_pending {
+            self.pending = Some((sequence, now));
         }
+
+        handled || is_pending
     }
 
     /// Removes an entry of the keymap.
@@ -177,12 +536,16 @@ where
             KeymapEvent::InsertAction(chord, entry) => self.insert(*chord, entry.clone()),
             KeymapEvent::RemoveAction(chord, action) => self.remove(chord, action),
         });
-        event.map(|window_event, _| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::KeyDown(code, _) => {
-                if let Some(entries) = self.entries.get(&KeyChord::new(*cx.modifiers, *code)) {
-                    for entry in entries {
-                        (entry.on_action())(cx)
-                    }
+                if !self.is_active(cx) {
+                    self.pending = None;
+                    return;
+                }
+
+                // Handled key chords don't reach any keymaps further up the tree.
+                if self.press(cx, KeyChord::new(*cx.modifiers, *code)) {
+                    meta.consume();
                 }
             }
             _ => {}
@@ -190,6 +553,20 @@ where
     }
 }
 
+fn is_modifier(code: Code) -> bool {
+    matches!(
+        code,
+        Code::ShiftLeft
+            | Code::ShiftRight
+            | Code::ControlLeft
+            | Code::ControlRight
+            | Code::AltLeft
+            | Code::AltRight
+            | Code::MetaLeft
+            | Code::MetaRight
+    )
+}
+
 impl<T> From<Vec<(KeyChord, KeymapEntry<T>)>> for Keymap<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
@@ -203,6 +580,65 @@ where
     }
 }
 
+// The representation of a keymap when it is serialized. Only the bindings are saved, as a list of actions and the
+// sequences of key chords which trigger them.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeymapBindings<T> {
+    bindings: Vec<KeymapBinding<T>>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KeymapBinding<T> {
+    action: T,
+    keys: Vec<KeyChord>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Keymap<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bindings = self
+            .export()
+            .into_iter()
+            .map(|(chord, entry)| KeymapBinding {
+                action: entry.action(),
+                keys: std::iter::once(*chord).chain(entry.sequence().iter().copied()).collect(),
+            })
+            .collect();
+
+        serde::Serialize::serialize(&KeymapBindings { bindings }, serializer)
+    }
+}
+
+// The callbacks of a deserialized keymap do nothing until it is applied with `Keymap::with_bindings`.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Keymap<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let KeymapBindings { bindings } =
+            <KeymapBindings<T> as serde::Deserialize>::deserialize(deserializer)?;
+        let mut keymap = Self::new();
+        for KeymapBinding { action, keys } in bindings {
+            let Some((chord, sequence)) = keys.split_first() else {
+                return Err(serde::de::Error::custom("keymap binding has no keys"));
+            };
+
+            let entry = sequence
+                .iter()
+                .fold(KeymapEntry::new(action, |_| {}), |entry, chord| entry.then(*chord));
+            keymap.insert(*chord, entry);
+        }
+
+        Ok(keymap)
+    }
+}
+
 /// An event used to interact with a [`Keymap`] at runtime.
 pub enum KeymapEvent<T>
 where
@@ -249,3 +685,168 @@ where
     /// ```
     RemoveAction(KeyChord, T),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Lens)]
+    struct AppData {
+        actions: Vec<&'static str>,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|action: &&'static str, _| self.actions.push(action));
+        }
+    }
+
+    fn harness() -> TestHarness {
+        TestHarness::new(|cx| {
+            AppData { actions: Vec::new() }.build(cx);
+            Keymap::from(vec![
+                (
+                    KeyChord::new(Modifiers::empty(), Code::KeyK),
+                    KeymapEntry::new("KC", |cx| cx.emit("KC"))
+                        .then(KeyChord::new(Modifiers::empty(), Code::KeyC)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::KeyA),
+                    KeymapEntry::new("A", |cx| cx.emit("A")),
+                ),
+            ])
+            .build(cx);
+        })
+    }
+
+    fn actions(harness: &mut TestHarness) -> Vec<&'static str> {
+        AppData::actions.get(harness.context())
+    }
+
+    #[test]
+    fn sequence_fires_when_complete() {
+        let mut harness = harness();
+        harness.key(Code::KeyK);
+        assert!(actions(&mut harness).is_empty());
+
+        harness.key(Code::KeyC);
+        assert_eq!(actions(&mut harness), vec!["KC"]);
+    }
+
+    #[test]
+    fn sequence_cancelled_by_mismatch_or_timeout() {
+        let mut harness = harness();
+        harness.key(Code::KeyK);
+        harness.key(Code::KeyA);
+        harness.key(Code::KeyC);
+        assert!(actions(&mut harness).is_empty());
+
+        harness.key(Code::KeyK);
+        harness.advance_time(Duration::from_secs(2));
+        harness.key(Code::KeyC);
+        assert!(actions(&mut harness).is_empty());
+
+        harness.key(Code::KeyA);
+        assert_eq!(actions(&mut harness), vec!["A"]);
+    }
+
+    fn a_keymap(action: &'static str, on_action: fn(&mut EventContext)) -> Keymap<&'static str> {
+        Keymap::from(vec![(
+            KeyChord::new(Modifiers::empty(), Code::KeyA),
+            KeymapEntry::new(action, on_action),
+        )])
+    }
+
+    #[test]
+    fn innermost_keymap_wins() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { actions: Vec::new() }.build(cx);
+            a_keymap("Outer", |cx| cx.emit("Outer")).build(cx);
+            VStack::new(cx, |cx| {
+                a_keymap("Inner", |cx| cx.emit("Inner")).build(cx);
+                Button::new(cx, |cx| Label::new(cx, "Inner")).id("inner");
+            });
+            Button::new(cx, |cx| Label::new(cx, "Outer")).id("outer");
+            Textbox::new(cx, AppData::actions.map(|actions| actions.join(","))).id("textbox");
+        });
+
+        harness.click("#inner");
+        harness.key(Code::KeyA);
+        assert_eq!(actions(&mut harness), vec!["Inner"]);
+
+        harness.click("#outer");
+        harness.key(Code::KeyA);
+        assert_eq!(actions(&mut harness), vec!["Inner", "Outer"]);
+
+        // Typing into a textbox doesn't trigger keymaps.
+        harness.click("#textbox");
+        harness.key(Code::KeyA);
+        assert_eq!(actions(&mut harness), vec!["Inner", "Outer"]);
+    }
+
+    #[test]
+    fn mode_activates_keymap() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { actions: Vec::new() }.build(cx);
+            a_keymap("A", |cx| cx.emit("A")).mode("normal").build(cx);
+        });
+
+        harness.key(Code::KeyA);
+        assert!(actions(&mut harness).is_empty());
+
+        harness.emit(EnvironmentEvent::SetKeymapMode(String::from("normal"), true));
+        harness.key(Code::KeyA);
+        assert_eq!(actions(&mut harness), vec!["A"]);
+    }
+
+    #[test]
+    fn detects_conflicts() {
+        let chord = KeyChord::new(Modifiers::CTRL, Code::KeyK);
+        let keymap = Keymap::from(vec![
+            (chord, KeymapEntry::new("Single", |_| {})),
+            (chord, KeymapEntry::new("Single", |_| {})),
+            (chord, KeymapEntry::new("Sequence", |_| {}).then(chord)),
+        ]);
+
+        assert_eq!(keymap.bindings(&"Single"), vec![vec![chord]]);
+        assert_eq!(keymap.bindings(&"Sequence"), vec![vec![chord, chord]]);
+        assert_eq!(keymap.conflicts(&chord, &KeymapEntry::new("Other", |_| {})).len(), 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn restores_saved_bindings() {
+        #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+        enum Action {
+            Save,
+            Comment,
+        }
+
+        let ctrl = |code| KeyChord::new(Modifiers::CTRL, code);
+        let saved = Keymap::from(vec![
+            (ctrl(Code::KeyW), KeymapEntry::new(Action::Save, |_| {})),
+            (ctrl(Code::KeyK), KeymapEntry::new(Action::Comment, |_| {}).then(ctrl(Code::KeyC))),
+        ])
+        .to_toml()
+        .unwrap();
+        assert!(saved.contains(r#"keys = ["Ctrl+K", "Ctrl+C"]"#));
+
+        let mut harness = TestHarness::new(|cx| {
+            AppData { actions: Vec::new() }.build(cx);
+            Keymap::from(vec![
+                (ctrl(Code::KeyS), KeymapEntry::new(Action::Save, |cx| cx.emit("Save"))),
+                (ctrl(Code::Slash), KeymapEntry::new(Action::Comment, |cx| cx.emit("Comment"))),
+            ])
+            .with_bindings(Keymap::from_toml(&saved).unwrap())
+            .build(cx);
+        });
+
+        harness.context().modifiers = Modifiers::CTRL;
+        harness.key(Code::KeyS);
+        harness.key(Code::KeyW);
+        harness.key(Code::KeyK);
+        harness.key(Code::KeyC);
+        assert_eq!(actions(&mut harness), vec!["Save", "Comment"]);
+    }
+}
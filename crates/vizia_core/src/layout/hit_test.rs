@@ -0,0 +1,111 @@
+use skia_safe::Path;
+
+use super::BoundingBox;
+
+/// A test which determines whether a point is over a view, given the bounds of the view and the point in window
+/// coordinates, both in physical pixels.
+pub(crate) type HitTestFn = Box<dyn Fn(BoundingBox, (f32, f32)) -> bool + Send + Sync>;
+
+/// Built-in hit tests for use with the [`hit_test`](crate::modifiers::StyleModifiers::hit_test) modifier.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Element::new(cx).size(Pixels(100.0)).hit_test(HitTest::circle());
+/// ```
+pub struct HitTest;
+
+impl HitTest {
+    /// Returns a hit test for the largest ellipse which fits within the bounds of a view, which is a circle for a
+    /// view with an equal width and height.
+    pub fn circle() -> impl Fn(BoundingBox, (f32, f32)) -> bool + Send + Sync + 'static {
+        |bounds, (x, y)| {
+            let (cx, cy) = bounds.center();
+            let (rx, ry) = (bounds.w / 2.0, bounds.h / 2.0);
+            if rx <= 0.0 || ry <= 0.0 {
+                return false;
+            }
+
+            let (dx, dy) = ((x - cx) / rx, (y - cy) / ry);
+            dx * dx + dy * dy <= 1.0
+        }
+    }
+
+    /// Returns a hit test for the bounds of a view with rounded corners of the given radius in physical pixels.
+    pub fn rounded_rect(
+        radius: f32,
+    ) -> impl Fn(BoundingBox, (f32, f32)) -> bool + Send + Sync + 'static {
+        move |bounds, point| contains_rounded_rect(bounds, [radius; 4], point)
+    }
+
+    /// Returns a hit test for a path, in physical pixels relative to the top-left corner of the bounds of a view.
+    pub fn path(path: Path) -> impl Fn(BoundingBox, (f32, f32)) -> bool + Send + Sync + 'static {
+        move |bounds, (x, y)| path.contains((x - bounds.x, y - bounds.y))
+    }
+}
+
+/// Returns true if a point is within the bounds with rounded corners given by the top-left, top-right, bottom-right,
+/// and bottom-left radii.
+pub(crate) fn contains_rounded_rect(
+    bounds: BoundingBox,
+    radii: [f32; 4],
+    (x, y): (f32, f32),
+) -> bool {
+    if !bounds.contains_point(x, y) {
+        return false;
+    }
+
+    let max_radius = bounds.w.min(bounds.h) / 2.0;
+    let corners = [
+        (bounds.left(), bounds.top(), 1.0, 1.0),
+        (bounds.right(), bounds.top(), -1.0, 1.0),
+        (bounds.right(), bounds.bottom(), -1.0, -1.0),
+        (bounds.left(), bounds.bottom(), 1.0, -1.0),
+    ];
+
+    corners.iter().zip(radii).all(|(&(corner_x, corner_y, sx, sy), radius)| {
+        let radius = radius.min(max_radius);
+        if radius <= 0.0 {
+            return true;
+        }
+
+        // The center of the arc of the corner.
+        let (cx, cy) = (corner_x + sx * radius, corner_y + sy * radius);
+        let (dx, dy) = (x - cx, y - cy);
+
+        // Points which are not between the corner and the center of its arc in both axes aren't affected by it.
+        if dx * sx > 0.0 || dy * sy > 0.0 {
+            return true;
+        }
+
+        dx * dx + dy * dy <= radius * radius
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[test]
+    fn rounded_corners_are_not_hovered() {
+        let mut harness = TestHarness::new(|cx| {
+            Element::new(cx).size(Pixels(100.0)).corner_radius(Pixels(50.0)).id("round");
+            Element::new(cx)
+                .size(Pixels(100.0))
+                .corner_radius(Pixels(50.0))
+                .hit_test(HitTest::rounded_rect(0.0))
+                .id("square");
+        });
+
+        harness.hover(2.0, 2.0);
+        assert!(!harness.is_hovered("#round"));
+
+        harness.hover(50.0, 50.0);
+        assert!(harness.is_hovered("#round"));
+
+        // An explicit hit test replaces the one given by the corner radii.
+        harness.hover(2.0, 102.0);
+        assert!(harness.is_hovered("#square"));
+    }
+}
@@ -37,4 +37,8 @@ pub mod bounds;
 
 pub use bounds::*;
 
+mod hit_test;
+pub use hit_test::HitTest;
+pub(crate) use hit_test::{contains_rounded_rect, HitTestFn};
+
 pub use cache::GeoChanged;
@@ -25,9 +25,11 @@ pub mod layout;
 pub mod localization;
 pub mod model;
 pub mod modifiers;
+pub mod profiling;
 pub mod resource;
 pub mod style;
 pub(crate) mod systems;
+pub mod testing;
 pub(crate) mod text;
 #[doc(hidden)]
 pub mod tree;
@@ -59,7 +61,7 @@ pub mod backend {
 pub mod prelude {
     pub use super::binding::{
         Binding, Data, Index, Lens, LensExt, LensValue, Map, MapRef, Res, ResGet, StaticLens, Then,
-        UnwrapLens, Wrapper,
+        TransitionState, UnwrapLens, Wrapper,
     };
 
     pub use super::impl_res_simple;
@@ -69,31 +71,40 @@ pub mod prelude {
     pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
-        EventContext, ProxyEmitError, WindowState,
+        EventContext, ProxyEmitError, TextOverrides, ViewPanic, WindowState,
     };
     pub use super::entity::Entity;
-    pub use super::environment::{AppTheme, Environment, EnvironmentEvent, ThemeMode};
-    pub use super::events::{Event, Propagation, Timer, TimerAction};
+    pub use super::environment::{AppTheme, Environment, EnvironmentEvent, ThemeMode, WindowData};
+    pub use super::events::{
+        DragGesture, DragPhase, DragTracker, Event, Propagation, Timer, TimerAction,
+    };
     pub use super::include_style;
     pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
-    pub use super::layout::{BoundingBox, GeoChanged};
-    pub use super::localization::{Localized, ToStringLocalized};
+    pub use super::layout::{BoundingBox, GeoChanged, HitTest};
+    pub use super::localization::{LocaleFormatting, Localized, ToStringLocalized};
     pub use super::modifiers::{
         AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers,
         LinearGradientBuilder, ShadowBuilder, StyleModifiers, TextModifiers,
     };
     pub use super::resource::{ImageId, ImageRetentionPolicy};
-    pub use super::util::{IntoCssStr, CSS};
+    pub use super::util::{IntoCssStr, StylesheetError, StylesheetPath, CSS};
     pub use super::view::{Handle, View};
     pub use super::views::*;
-    pub use super::window::{DropData, WindowEvent};
+    #[cfg(feature = "global-shortcuts")]
+    pub use super::window::GlobalShortcutError;
+    pub use super::window::{DragData, DragResult, DropData, Gesture, TouchPhase, WindowEvent};
+    #[cfg(feature = "dialogs")]
+    pub use super::window::{FileDialog, FileDialogFilter, FileDialogKind, FileDialogOptions};
     pub use accesskit::{Action, Live, Role};
     pub use skia_safe::Canvas;
     pub use vizia_derive::{Data, Lens};
     pub use vizia_id::GenerationalId;
     pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
     pub use vizia_storage::{Tree, TreeExt};
-    pub use vizia_window::{WindowButtons, WindowPosition, WindowSize};
+    pub use vizia_window::{
+        Monitor, MonitorAnchor, ResizeDirection, WindowButtons, WindowGeometry, WindowLevel,
+        WindowPosition, WindowSize,
+    };
 
     pub use super::style::*;
 
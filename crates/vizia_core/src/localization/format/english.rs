@@ -0,0 +1,169 @@
+use chrono::{Datelike, Weekday};
+use unic_langid::LanguageIdentifier;
+
+use super::ZERO_DECIMAL_CURRENCIES;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// Regions in which the week starts on Sunday or Saturday, rather than Monday.
+const SUNDAY_REGIONS: &[&str] =
+    &["US", "CA", "MX", "BR", "JP", "KR", "TW", "HK", "IL", "IN", "PH", "SA", "ZA"];
+const SATURDAY_REGIONS: &[&str] =
+    &["EG", "AE", "AF", "BH", "DZ", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"];
+
+// Scripts, and languages usually written in them, which are written from right to left.
+const RIGHT_TO_LEFT_SCRIPTS: &[&str] = &["Arab", "Hebr", "Thaa", "Syrc", "Nkoo", "Adlm", "Rohg"];
+const RIGHT_TO_LEFT_LANGUAGES: &[&str] =
+    &["ar", "he", "fa", "ur", "ps", "sd", "ug", "yi", "dv", "ckb", "syr"];
+
+// Formats a number with the given number of fraction digits and grouped thousands, removing trailing zeros from the
+// fraction if `trim` is true.
+fn format_decimal(value: f64, fraction_digits: usize, trim: bool) -> String {
+    let formatted = format!("{:.*}", fraction_digits, value.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+    let fraction = if trim { fraction.trim_end_matches('0') } else { fraction };
+
+    let mut result = String::new();
+    if value.is_sign_negative() && formatted.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+        result.push('-');
+    }
+
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(digit);
+    }
+
+    if !fraction.is_empty() {
+        result.push('.');
+        result.push_str(fraction);
+    }
+
+    result
+}
+
+/// Formats a number with grouped thousands and up to three fraction digits, for example `1,234.5`.
+///
+/// Without the `localization` feature numbers are formatted with English conventions in every locale.
+pub fn format_number(_locale: &LanguageIdentifier, value: f64) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    format_decimal(value, 3, true)
+}
+
+/// Formats a whole number, such as a day of the month, without grouping.
+pub fn format_integer(_locale: &LanguageIdentifier, value: i64) -> String {
+    value.to_string()
+}
+
+/// Formats an amount of a currency, given by its ISO 4217 code such as `"EUR"`, for example `€1,234.50`.
+///
+/// Without the `localization` feature amounts are formatted with English conventions in every locale.
+pub fn format_currency(_locale: &LanguageIdentifier, value: f64, currency: &str) -> String {
+    let symbol = match currency {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" => "¥",
+        _ => currency,
+    };
+    let fraction_digits = if ZERO_DECIMAL_CURRENCIES.contains(&currency) { 0 } else { 2 };
+
+    if !value.is_finite() {
+        return format!("{} {}", symbol, value);
+    }
+
+    let amount = format_decimal(value, fraction_digits, false);
+    let separator = if symbol == currency { "\u{A0}" } else { "" };
+    match amount.strip_prefix('-') {
+        Some(amount) => format!("-{}{}{}", symbol, separator, amount),
+        None => format!("{}{}{}", symbol, separator, amount),
+    }
+}
+
+/// Formats a date with the name of its month, for example `March 5, 2024`.
+///
+/// Without the `localization` feature dates are formatted in English in every locale.
+pub fn format_date(_locale: &LanguageIdentifier, date: &impl Datelike) -> String {
+    format!("{} {}, {}", MONTHS[date.month0() as usize], date.day(), date.year())
+}
+
+/// Returns the name of a month, from 1 for January to 12 for December.
+///
+/// Without the `localization` feature the English name is returned in every locale.
+pub fn month_name(_locale: &LanguageIdentifier, month: u32) -> String {
+    MONTHS[(month.clamp(1, 12) - 1) as usize].to_owned()
+}
+
+/// Returns the short name of a day of the week, such as `Mo` for Monday.
+///
+/// Without the `localization` feature the English name is returned in every locale.
+pub fn weekday_name(_locale: &LanguageIdentifier, weekday: Weekday) -> String {
+    weekday.to_string()[..2].to_owned()
+}
+
+/// Returns the day on which weeks start in the region of a locale, or in the most common region of its language if
+/// it has no region.
+pub fn first_day_of_week(locale: &LanguageIdentifier) -> Weekday {
+    match locale.region.as_ref().map(|region| region.as_str()) {
+        Some(region) if SUNDAY_REGIONS.contains(&region) => Weekday::Sun,
+        Some(region) if SATURDAY_REGIONS.contains(&region) => Weekday::Sat,
+        Some(_) => Weekday::Mon,
+        None => match locale.language.as_str() {
+            "en" => Weekday::Sun,
+            "ar" => Weekday::Sat,
+            _ => Weekday::Mon,
+        },
+    }
+}
+
+/// Returns whether the script of a locale is written from right to left, such as Arabic or Hebrew.
+pub fn is_right_to_left(locale: &LanguageIdentifier) -> bool {
+    match locale.script.as_ref() {
+        Some(script) => RIGHT_TO_LEFT_SCRIPTS.contains(&script.as_str()),
+        None => RIGHT_TO_LEFT_LANGUAGES.contains(&locale.language.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn formats_in_english() {
+        let de: LanguageIdentifier = "de-DE".parse().unwrap();
+        let ar: LanguageIdentifier = "ar-EG".parse().unwrap();
+
+        assert_eq!(format_number(&de, -1234567.891), "-1,234,567.891");
+        assert_eq!(format_currency(&de, -1234.5, "USD"), "-$1,234.50");
+        assert_eq!(format_currency(&de, 1234.0, "JPY"), "¥1,234");
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(format_date(&de, &date), "March 5, 2024");
+        assert_eq!(month_name(&de, 3), "March");
+        assert_eq!(weekday_name(&de, Weekday::Mon), "Mo");
+
+        assert_eq!(first_day_of_week(&de), Weekday::Mon);
+        assert_eq!(first_day_of_week(&ar), Weekday::Sat);
+
+        assert!(!is_right_to_left(&de));
+        assert!(is_right_to_left(&ar));
+    }
+}
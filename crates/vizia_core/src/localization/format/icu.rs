@@ -0,0 +1,208 @@
+use chrono::{Datelike, Weekday};
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_calendar::week::WeekCalculator;
+use icu_calendar::{Date, Gregorian};
+use icu_datetime::options::length;
+use icu_datetime::provider::calendar::{months, GregorianDateSymbolsV1Marker};
+use icu_datetime::provider::Baked;
+use icu_datetime::TypedDateFormatter;
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::FixedDecimalFormatter;
+use icu_experimental::dimension::currency::formatter::{CurrencyCode, CurrencyFormatter};
+use icu_locid_transform::LocaleDirectionality;
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use tinystr::TinyAsciiStr;
+use unic_langid::LanguageIdentifier;
+use writeable::Writeable;
+
+use super::ZERO_DECIMAL_CURRENCIES;
+
+// Converts a locale to the locale used to look up ICU data. Locales without data of their own fall back to their
+// parent locales, and then to the root locale, when the data is loaded.
+fn data_locale(locale: &LanguageIdentifier) -> DataLocale {
+    locale
+        .to_string()
+        .parse::<icu_locid::LanguageIdentifier>()
+        .map(|locale| DataLocale::from(&locale))
+        .unwrap_or_default()
+}
+
+fn decimal_formatter(
+    locale: &LanguageIdentifier,
+    grouping_strategy: GroupingStrategy,
+) -> Option<FixedDecimalFormatter> {
+    let options = FixedDecimalFormatterOptions::from(grouping_strategy);
+    FixedDecimalFormatter::try_new(&data_locale(locale), options).ok()
+}
+
+// Rounds a number to the given number of fraction digits.
+fn to_decimal(value: f64, fraction_digits: usize) -> Option<FixedDecimal> {
+    let mut decimal: FixedDecimal = format!("{:.*}", fraction_digits, value).parse().ok()?;
+    if decimal.is_zero() {
+        decimal.set_sign(Sign::None);
+    }
+
+    Some(decimal)
+}
+
+fn date_symbols(locale: &LanguageIdentifier) -> Option<DataPayload<GregorianDateSymbolsV1Marker>> {
+    let request = DataRequest { locale: &data_locale(locale), metadata: Default::default() };
+    DataProvider::<GregorianDateSymbolsV1Marker>::load(&Baked, request).ok()?.take_payload().ok()
+}
+
+/// Formats a number for a locale, with grouped thousands and up to three fraction digits, for example `1,234.5` in
+/// `en-US` and `1.234,5` in `de-DE`.
+pub fn format_number(locale: &LanguageIdentifier, value: f64) -> String {
+    match (decimal_formatter(locale, GroupingStrategy::Auto), to_decimal(value, 3)) {
+        (Some(formatter), Some(decimal)) => formatter.format_to_string(&decimal.trimmed_end()),
+        _ => value.to_string(),
+    }
+}
+
+/// Formats a whole number, such as a day of the month, with the digits of a locale and without grouping.
+pub fn format_integer(locale: &LanguageIdentifier, value: i64) -> String {
+    match decimal_formatter(locale, GroupingStrategy::Never) {
+        Some(formatter) => formatter.format_to_string(&FixedDecimal::from(value)),
+        None => value.to_string(),
+    }
+}
+
+/// Formats an amount of a currency, given by its ISO 4217 code such as `"EUR"`, for a locale, for example `€1,234.50`
+/// in `en-US` and `1.234,50 €` in `de-DE`.
+pub fn format_currency(locale: &LanguageIdentifier, value: f64, currency: &str) -> String {
+    let fraction_digits = if ZERO_DECIMAL_CURRENCIES.contains(&currency) { 0 } else { 2 };
+    let amount = match (
+        decimal_formatter(locale, GroupingStrategy::Auto),
+        to_decimal(value, fraction_digits),
+    ) {
+        (Some(formatter), Some(decimal)) => {
+            formatter.format_to_string(&decimal.padded_end(-(fraction_digits as i16)))
+        }
+        _ => value.to_string(),
+    };
+
+    let code = currency.parse::<TinyAsciiStr<3>>().ok().map(CurrencyCode);
+    let formatter = CurrencyFormatter::try_new(&data_locale(locale), Default::default()).ok();
+    match (code, formatter) {
+        // The currency formatter places the symbol but does not localize the amount itself, so the pattern is
+        // written with a zero that is then replaced with the formatted amount.
+        (Some(code), Some(formatter)) => formatter
+            .format_fixed_decimal(&FixedDecimal::from(0), code)
+            .write_to_string()
+            .replacen('0', &amount, 1),
+        _ => format!("{}\u{A0}{}", amount, currency),
+    }
+}
+
+/// Formats a date for a locale with the name of its month, for example `March 5, 2024` in `en-US` and `5. März 2024`
+/// in `de-DE`.
+pub fn format_date(locale: &LanguageIdentifier, date: &impl Datelike) -> String {
+    let formatter = TypedDateFormatter::<Gregorian>::try_new_with_length(
+        &data_locale(locale),
+        length::Date::Long,
+    )
+    .ok();
+    let gregorian =
+        Date::try_new_gregorian_date(date.year(), date.month() as u8, date.day() as u8).ok();
+
+    match (formatter, gregorian) {
+        (Some(formatter), Some(gregorian)) => formatter.format_to_string(&gregorian),
+        _ => format!("{}-{:02}-{:02}", date.year(), date.month(), date.day()),
+    }
+}
+
+/// Returns the name of a month, from 1 for January to 12 for December, in the language of a locale.
+pub fn month_name(locale: &LanguageIdentifier, month: u32) -> String {
+    let month = month.clamp(1, 12);
+    date_symbols(locale)
+        .and_then(|symbols| {
+            let months = &symbols.get().months;
+            let names = months
+                .stand_alone
+                .as_ref()
+                .and_then(|stand_alone| stand_alone.wide.as_ref())
+                .unwrap_or(&months.format.wide);
+            match names {
+                months::SymbolsV1::SolarTwelve(names) => {
+                    Some(names[month as usize - 1].to_string())
+                }
+                months::SymbolsV1::Other(_) => None,
+            }
+        })
+        .unwrap_or_else(|| month.to_string())
+}
+
+/// Returns the short name of a day of the week, such as `Mo` for Monday in `en-US`, in the language of a locale.
+pub fn weekday_name(locale: &LanguageIdentifier, weekday: Weekday) -> String {
+    date_symbols(locale)
+        .map(|symbols| {
+            let weekdays = &symbols.get().weekdays;
+            let names = weekdays
+                .stand_alone
+                .as_ref()
+                .and_then(|stand_alone| stand_alone.short.as_ref())
+                .or(weekdays.format.short.as_ref())
+                .unwrap_or(&weekdays.format.abbreviated);
+            // Day names in the data start from Sunday.
+            names.0[weekday.num_days_from_sunday() as usize].to_string()
+        })
+        .unwrap_or_else(|| weekday.to_string())
+}
+
+/// Returns the day on which weeks start in the region of a locale, or in the most common region of its language if
+/// it has no region.
+pub fn first_day_of_week(locale: &LanguageIdentifier) -> Weekday {
+    WeekCalculator::try_new(&data_locale(locale))
+        .ok()
+        .and_then(|calculator| Weekday::try_from(calculator.first_weekday as u8 - 1).ok())
+        .unwrap_or(Weekday::Mon)
+}
+
+/// Returns whether the script of a locale is written from right to left, such as Arabic or Hebrew.
+pub fn is_right_to_left(locale: &LanguageIdentifier) -> bool {
+    locale
+        .to_string()
+        .parse::<icu_locid::LanguageIdentifier>()
+        .is_ok_and(|locale| LocaleDirectionality::new().is_right_to_left(&locale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn formats_for_locale() {
+        let en: LanguageIdentifier = "en-US".parse().unwrap();
+        let de: LanguageIdentifier = "de-DE".parse().unwrap();
+        let ar: LanguageIdentifier = "ar-EG".parse().unwrap();
+
+        assert_eq!(format_number(&en, -1234567.891), "-1,234,567.891");
+        assert_eq!(format_number(&de, 1234.5), "1.234,5");
+        assert_eq!(format_number(&ar, 1234.5), "١٬٢٣٤٫٥");
+
+        assert_eq!(format_currency(&en, 1234.5, "USD"), "$1,234.50");
+        assert_eq!(format_currency(&en, 1234.0, "JPY"), "¥1,234");
+        assert_eq!(format_currency(&de, 1234.5, "EUR"), "1.234,50\u{A0}€");
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(format_date(&en, &date), "March 5, 2024");
+        assert_eq!(format_date(&de, &date), "5. März 2024");
+        assert_eq!(format_date(&ar, &date), "٥ مارس ٢٠٢٤");
+
+        assert_eq!(month_name(&de, 3), "März");
+        assert_eq!(weekday_name(&en, Weekday::Mon), "Mo");
+
+        // Other languages use their own conventions rather than falling back to English ones.
+        let pl: LanguageIdentifier = "pl-PL".parse().unwrap();
+        assert_eq!(format_number(&pl, 1234.5), "1234,5");
+        assert_eq!(month_name(&pl, 3), "marzec");
+
+        assert_eq!(first_day_of_week(&en), Weekday::Sun);
+        assert_eq!(first_day_of_week(&de), Weekday::Mon);
+        assert_eq!(first_day_of_week(&ar), Weekday::Sat);
+
+        assert!(!is_right_to_left(&en));
+        assert!(is_right_to_left(&ar));
+    }
+}
@@ -0,0 +1,49 @@
+// The formatting functions use the ICU data for every locale when the `localization` feature is enabled. Otherwise
+// they use English conventions, so that the ICU crates and their data are only compiled into applications which use
+// them.
+#[cfg(feature = "localization")]
+mod icu;
+#[cfg(feature = "localization")]
+pub use icu::*;
+
+#[cfg(not(feature = "localization"))]
+mod english;
+#[cfg(not(feature = "localization"))]
+pub use english::*;
+
+use chrono::Datelike;
+use unic_langid::LanguageIdentifier;
+
+use crate::prelude::*;
+
+// ISO 4217 currencies without minor units.
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND",
+    "VUV", "XAF", "XOF", "XPF",
+];
+
+/// Methods for formatting values according to the locale of the [`Environment`].
+pub trait LocaleFormatting: DataContext {
+    /// Returns the locale of the [`Environment`].
+    fn locale(&self) -> LanguageIdentifier {
+        self.data::<Environment>().map(|environment| environment.locale.clone()).unwrap_or_default()
+    }
+
+    /// Formats a number for the current locale. See [`format_number`].
+    fn format_number(&self, value: f64) -> String {
+        format_number(&self.locale(), value)
+    }
+
+    /// Formats an amount of a currency, given by its ISO 4217 code, for the current locale. See
+    /// [`format_currency`].
+    fn format_currency(&self, value: f64, currency: &str) -> String {
+        format_currency(&self.locale(), value, currency)
+    }
+
+    /// Formats a date for the current locale. See [`format_date`].
+    fn format_date(&self, date: &impl Datelike) -> String {
+        format_date(&self.locale(), date)
+    }
+}
+
+impl<T: DataContext> LocaleFormatting for T {}
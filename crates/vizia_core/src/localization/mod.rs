@@ -22,6 +22,10 @@
 //! ## Setting the Locale
 //! The application will use the system locale by default, however an environment event can be used to set a custom locale.
 //! If no fluent file can be found for the specified locale, then a fallback fluent file is used from the list of available files.
+//! Changing the locale updates all localized text, and the layout of the views which display it.
+//!
+//! Messages which are missing from the translations of the locale are looked up in the translations of the fallback
+//! locales, which can be set with `cx.set_fallback_locales(...)`, and missing messages are logged once each.
 //! ```ignore
 //! # use vizia_core::prelude::*;
 //! # let mut cx = &mut Context::default();
@@ -68,6 +72,24 @@
 //! # }
 //! Label::new(cx, Localized::new("welcome").arg("user", AppData::user));
 //! ```
+//!
+//! ## Formatting
+//! Numbers, amounts of currency, and dates can be formatted for the locale of the application with the methods of
+//! the [`LocaleFormatting`] trait, which is implemented for all contexts, or for a given locale with functions such
+//! as [`format_number`].
+//!
+//! Formatting follows the conventions of every locale when the `localization` feature is enabled, using the data of
+//! the ICU4X project. Without it, values are formatted with English conventions.
+//! ```ignore
+//! # use vizia_core::prelude::*;
+//! # let mut cx = &mut Context::default();
+//! // Displays "1,234.5" in en-US and "1.234,5" in de-DE.
+//! let text = cx.format_number(1234.5);
+//! Label::new(cx, text);
+//! ```
+mod format;
+pub use format::*;
+
 use crate::context::LocalizationContext;
 use crate::prelude::*;
 use fluent_bundle::FluentArgs;
@@ -138,11 +160,12 @@ pub struct Localized {
     key: String,
     args: HashMap<String, Box<dyn FluentStore>>,
     map: Rc<dyn Fn(&str) -> String + 'static>,
+    fallback: Option<String>,
 }
 
 impl PartialEq for Localized {
     fn eq(&self, other: &Self) -> bool {
-        self.key == other.key
+        self.key == other.key && self.fallback == other.fallback
     }
 }
 
@@ -152,6 +175,7 @@ impl Clone for Localized {
             key: self.key.clone(),
             args: self.args.iter().map(|(k, v)| (k.clone(), v.make_clone())).collect(),
             map: self.map.clone(),
+            fallback: self.fallback.clone(),
         }
     }
 }
@@ -179,7 +203,29 @@ impl Localized {
     /// })
     /// .run();
     pub fn new(key: &str) -> Self {
-        Self { key: key.to_owned(), args: HashMap::new(), map: Rc::new(|s| s.to_string()) }
+        Self {
+            key: key.to_owned(),
+            args: HashMap::new(),
+            map: Rc::new(|s| s.to_string()),
+            fallback: None,
+        }
+    }
+
+    /// Sets the text to use when no translation of the message can be found for the current locale or any of the
+    /// fallback locales, instead of the key of the message.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     Label::new(cx, Localized::new("save").fallback("Save"));
+    /// })
+    /// .run();
+    pub fn fallback(mut self, text: impl Into<String>) -> Self {
+        self.fallback = Some(text.into());
+
+        self
     }
 
     /// Sets a mapping function to apply to the translated text.
@@ -191,7 +237,8 @@ impl Localized {
 
     /// Add a variable argument binding to the Localized type.
     ///
-    /// Takes a key name and a lens to the value for the argument.
+    /// Takes a key name and a lens to the value for the argument. The message is formatted again whenever the value
+    /// changes, so a numeric argument can select a plural form of the message for the current locale.
     ///
     /// # Example
     /// ```no_run
@@ -245,30 +292,7 @@ impl ResGet<String> for Localized {
     }
 
     fn get(&self, cx: &impl DataContext) -> String {
-        let cx = cx.localization_context().expect("Failed to get context");
-        let locale = &cx.environment().locale;
-        let bundle = cx.resource_manager.current_translation(locale);
-        let message = if let Some(msg) = bundle.get_message(&self.key) {
-            msg
-        } else {
-            return (self.map)(&self.key);
-        };
-
-        let value = if let Some(value) = message.value() {
-            value
-        } else {
-            return (self.map)(&self.key);
-        };
-
-        let mut err = vec![];
-        let args = self.get_args(&cx);
-        let res = bundle.format_pattern(value, Some(&args), &mut err);
-
-        if err.is_empty() {
-            (self.map)(&res)
-        } else {
-            format!("{} {{ERROR: {:?}}}", res, err)
-        }
+        self.to_string_local(cx)
     }
 }
 
@@ -316,6 +340,9 @@ impl<T: ToString> ToStringLocalized for T {
 }
 
 /// A trait for converting from [Localized] to a `String` via a translation using fluent.
+///
+/// The trait is implemented for all types which implement [`ToString`], such as numbers, `bool`, and `String`, so a
+/// lens to any of these types can be passed directly to views such as [`Label`] without mapping it to a string.
 pub trait ToStringLocalized {
     /// Method for converting the current type to a `String` via a translation using fluent.
     fn to_string_local(&self, cx: &impl DataContext) -> String;
@@ -324,22 +351,21 @@ pub trait ToStringLocalized {
 impl ToStringLocalized for Localized {
     fn to_string_local(&self, cx: &impl DataContext) -> String {
         let cx = cx.localization_context().expect("Failed to get context");
-
         let locale = &cx.environment().locale;
-        let bundle = cx.resource_manager.current_translation(locale);
-        let message = if let Some(msg) = bundle.get_message(&self.key) {
-            msg
-        } else {
-            // Warn here of missing key
-            return (self.map)(&self.key);
+        let chain = cx.resource_manager.translation_chain(locale);
+
+        // Messages which are missing for the locale are looked up in the fallback translations.
+        let Some((index, bundle, value)) = chain.iter().enumerate().find_map(|(index, bundle)| {
+            let value = bundle.get_message(&self.key)?.value()?;
+            Some((index, bundle, value))
+        }) else {
+            cx.resource_manager.report_missing_translation(locale, &self.key);
+            return (self.map)(self.fallback.as_deref().unwrap_or(&self.key));
         };
 
-        let value = if let Some(value) = message.value() {
-            value
-        } else {
-            // Warn here of missing value
-            return (self.map)(&self.key);
-        };
+        if index > 0 {
+            cx.resource_manager.report_missing_translation(locale, &self.key);
+        }
 
         let mut err = vec![];
         let args = self.get_args(&cx);
@@ -352,3 +378,100 @@ impl ToStringLocalized for Localized {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[derive(Lens)]
+    struct AppData {
+        count: usize,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|count: &usize, _| self.count = *count);
+        }
+    }
+
+    #[test]
+    fn displays_lens_values_without_mapping() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { count: 1 }.build(cx);
+            Label::new(cx, AppData::count).id("count");
+            Label::new(cx, AppData::count.map(|count| *count > 1)).id("many");
+        });
+
+        assert_eq!(harness.text("#count"), Some("1"));
+        assert_eq!(harness.text("#many"), Some("false"));
+
+        harness.emit(3usize);
+        assert_eq!(harness.text("#count"), Some("3"));
+        assert_eq!(harness.text("#many"), Some("true"));
+    }
+
+    #[test]
+    fn plural_argument_follows_lens() {
+        let mut harness = TestHarness::new(|cx| {
+            cx.add_translation(
+                "en-US".parse().unwrap(),
+                "items-left = { NUMBER($count) ->\n    [one] { $count } item left\n   *[other] { $count } items left\n}\n"
+                    .to_owned(),
+            );
+            cx.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+
+            AppData { count: 1 }.build(cx);
+            Label::new(cx, Localized::new("items-left").arg("count", AppData::count)).id("footer");
+        });
+
+        // Placeables are wrapped in bidi isolation marks by fluent.
+        let text = |harness: &TestHarness| {
+            harness.text("#footer").unwrap().replace(['\u{2068}', '\u{2069}'], "")
+        };
+
+        assert_eq!(text(&harness), "1 item left");
+
+        harness.emit(3usize);
+        assert_eq!(text(&harness), "3 items left");
+    }
+
+    #[test]
+    fn switching_locale_updates_text_and_style() {
+        let mut harness = TestHarness::new(|cx| {
+            cx.add_translation("en-US".parse().unwrap(), "greeting = Hello\nfarewell = Goodbye\n");
+            cx.add_translation("fr".parse().unwrap(), "greeting = Bonjour\n");
+            cx.set_fallback_locales(["en-US".parse().unwrap()]);
+            cx.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+
+            Label::new(cx, Localized::new("greeting")).id("greeting");
+            Label::new(cx, Localized::new("farewell")).id("farewell");
+        });
+
+        assert_eq!(harness.text("#greeting"), Some("Hello"));
+        assert!(harness.matches("#greeting", ":lang(en)"));
+
+        harness.emit(EnvironmentEvent::SetLocale("fr-CA".parse().unwrap()));
+        assert_eq!(harness.text("#greeting"), Some("Bonjour"));
+        assert!(harness.matches("#greeting", ":lang(fr)"));
+        assert!(!harness.matches("#greeting", ":lang(en)"));
+
+        // A message missing from the french translations falls back to english.
+        assert_eq!(harness.text("#farewell"), Some("Goodbye"));
+    }
+
+    #[test]
+    fn uses_fallback_text_for_missing_message() {
+        let harness = TestHarness::new(|cx| {
+            cx.add_translation("en-US".parse().unwrap(), "greeting = Hello\n");
+            cx.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+
+            Label::new(cx, Localized::new("greeting").fallback("Hi")).id("greeting");
+            Label::new(cx, Localized::new("farewell").fallback("Bye")).id("farewell");
+            Label::new(cx, Localized::new("welcome")).id("welcome");
+        });
+
+        assert_eq!(harness.text("#greeting"), Some("Hello"));
+        assert_eq!(harness.text("#farewell"), Some("Bye"));
+        assert_eq!(harness.text("#welcome"), Some("welcome"));
+    }
+}
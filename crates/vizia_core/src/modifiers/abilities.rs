@@ -117,6 +117,58 @@ pub trait AbilityModifiers: internal::Modifiable {
 
         self
     }
+
+    /// Sets the views which are focused before and after the view during keyboard navigation, instead of the views
+    /// before and after it in the tree.
+    ///
+    /// Either view can be [`Entity::null()`] to keep the tree order in that direction. This can be used to navigate a
+    /// grid of inputs by column instead of by row, for example.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let last = Element::new(cx).navigable(true).entity();
+    /// Element::new(cx).navigable(true).focus_order(Entity::null(), last);
+    /// ```
+    fn focus_order(mut self, prev: Entity, next: Entity) -> Self {
+        let entity = self.entity();
+        self.context().style.focus_order.insert(entity, FocusOrder { prev, next });
+
+        self
+    }
 }
 
 impl<V> AbilityModifiers for Handle<'_, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[test]
+    fn focus_order_overrides_tree_order() {
+        let mut harness = TestHarness::new(|cx| {
+            let first = Element::new(cx).navigable(true).id("first").entity();
+            let second = Element::new(cx).navigable(true).id("second").entity();
+            let third =
+                Element::new(cx).navigable(true).id("third").focus_order(first, second).entity();
+
+            cx.style.focus_order.insert(first, FocusOrder { prev: Entity::null(), next: third });
+            cx.style.focus_order.insert(second, FocusOrder { prev: third, next: Entity::null() });
+        });
+
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#first"));
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#third"));
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#second"));
+
+        harness.context().modifiers = Modifiers::SHIFT;
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#third"));
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#first"));
+    }
+}
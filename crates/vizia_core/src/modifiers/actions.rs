@@ -1,3 +1,4 @@
+use crate::events::TimedEventHandle;
 use crate::prelude::*;
 use std::any::TypeId;
 
@@ -62,6 +63,9 @@ impl Model for ModalModel {
     }
 }
 
+/// The distance in logical pixels the cursor must move after pressing a view before a native drag is started.
+const DRAG_OUT_THRESHOLD: f32 = 4.0;
+
 pub(crate) struct ActionsModel {
     pub(crate) on_press: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_press_down: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
@@ -70,6 +74,12 @@ pub(crate) struct ActionsModel {
     pub(crate) on_hover_out: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_over: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_over_out: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    pub(crate) on_hover_enter: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    pub(crate) on_hover_leave: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    pub(crate) on_hover_dwell: Option<(Duration, Box<dyn Fn(&mut EventContext) + Send + Sync>)>,
+    // The scheduled event which fires the hover dwell action, which is dropped if the view is removed.
+    pub(crate) hover_dwell: Option<TimedEventHandle>,
+    pub(crate) hover_entered: bool,
     pub(crate) on_mouse_move: Option<Box<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>>,
     pub(crate) on_mouse_down: Option<Box<dyn Fn(&mut EventContext, MouseButton) + Send + Sync>>,
     pub(crate) on_mouse_up: Option<Box<dyn Fn(&mut EventContext, MouseButton) + Send + Sync>>,
@@ -78,6 +88,18 @@ pub(crate) struct ActionsModel {
     pub(crate) on_geo_changed: Option<Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>>,
     pub(crate) on_drag_start: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_drop: Option<Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>>,
+    pub(crate) on_drag_enter: Option<Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>>,
+    pub(crate) on_drag_over:
+        Option<Box<dyn Fn(&mut EventContext, f32, f32, DropData) + Send + Sync>>,
+    pub(crate) on_drag_leave: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    pub(crate) on_drag_out:
+        Option<Box<dyn Fn(&mut EventContext) -> Option<DragData> + Send + Sync>>,
+    pub(crate) on_drag_end: Option<Box<dyn Fn(&mut EventContext, DragResult) + Send + Sync>>,
+    pub(crate) on_drag_gesture: Option<Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>>,
+    pub(crate) drag_tracker: DragTracker,
+    pub(crate) drag_out_started: bool,
+    pub(crate) window_drag_area: bool,
+    pub(crate) window_resize_border: Option<ResizeDirection>,
 }
 
 impl ActionsModel {
@@ -90,6 +112,11 @@ impl ActionsModel {
             on_hover_out: None,
             on_over: None,
             on_over_out: None,
+            on_hover_enter: None,
+            on_hover_leave: None,
+            on_hover_dwell: None,
+            hover_dwell: None,
+            hover_entered: false,
             on_mouse_move: None,
             on_mouse_down: None,
             on_mouse_up: None,
@@ -98,10 +125,29 @@ impl ActionsModel {
             on_geo_changed: None,
             on_drag_start: None,
             on_drop: None,
+            on_drag_enter: None,
+            on_drag_over: None,
+            on_drag_leave: None,
+            on_drag_out: None,
+            on_drag_end: None,
+            on_drag_gesture: None,
+            drag_tracker: DragTracker::default(),
+            drag_out_started: false,
+            window_drag_area: false,
+            window_resize_border: None,
         }
     }
 }
 
+impl ActionsModel {
+    // Returns true if the hovered view is the current view or one of its descendants.
+    fn contains_hovered(&self, cx: &EventContext) -> bool {
+        let hovered = cx.hovered();
+        hovered != Entity::null()
+            && (hovered == cx.current() || hovered.is_descendant_of(cx.tree, cx.current()))
+    }
+}
+
 impl Model for ActionsModel {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.take(|actions_event, _| match actions_event {
@@ -133,6 +179,27 @@ impl Model for ActionsModel {
                 self.on_over_out = Some(on_over_out);
             }
 
+            ActionsEvent::OnHoverEnter(on_hover_enter) => {
+                self.on_hover_enter = Some(on_hover_enter);
+            }
+
+            ActionsEvent::OnHoverLeave(on_hover_leave) => {
+                self.on_hover_leave = Some(on_hover_leave);
+            }
+
+            ActionsEvent::OnHoverDwell(duration, on_hover_dwell) => {
+                self.on_hover_dwell = Some((duration, on_hover_dwell));
+            }
+
+            ActionsEvent::HoverDwellElapsed => {
+                self.hover_dwell = None;
+                if let Some((_, action)) = &self.on_hover_dwell {
+                    if self.hover_entered {
+                        (action)(cx);
+                    }
+                }
+            }
+
             ActionsEvent::OnMouseMove(on_move) => {
                 self.on_mouse_move = Some(on_move);
             }
@@ -166,6 +233,47 @@ impl Model for ActionsModel {
             ActionsEvent::OnDrop(on_drop) => {
                 self.on_drop = Some(on_drop);
             }
+
+            ActionsEvent::OnDragEnter(on_drag_enter) => {
+                self.on_drag_enter = Some(on_drag_enter);
+            }
+
+            ActionsEvent::OnDragOver(on_drag_over) => {
+                self.on_drag_over = Some(on_drag_over);
+            }
+
+            ActionsEvent::OnDragLeave(on_drag_leave) => {
+                self.on_drag_leave = Some(on_drag_leave);
+            }
+
+            ActionsEvent::OnDragOut(on_drag_out) => {
+                self.on_drag_out = Some(on_drag_out);
+            }
+
+            ActionsEvent::OnDragEnd(on_drag_end) => {
+                self.on_drag_end = Some(on_drag_end);
+            }
+
+            ActionsEvent::OnDragGesture(on_drag_gesture, threshold) => {
+                self.on_drag_gesture = Some(on_drag_gesture);
+                self.drag_tracker = DragTracker::new(threshold);
+            }
+
+            ActionsEvent::WindowDragArea => {
+                self.window_drag_area = true;
+            }
+
+            ActionsEvent::WindowResizeBorder(direction) => {
+                self.window_resize_border = Some(direction);
+            }
+        });
+
+        event.map(|window_event, _| {
+            if let Some(action) = &self.on_drag_gesture {
+                if let Some(gesture) = self.drag_tracker.handle(cx, window_event) {
+                    (action)(cx, gesture);
+                }
+            }
         });
 
         event.map(|window_event, meta| match window_event {
@@ -199,6 +307,10 @@ impl Model for ActionsModel {
                     if let Some(action) = &self.on_double_click {
                         (action)(cx, *button);
                     }
+
+                    if self.window_drag_area && *button == MouseButton::Left {
+                        cx.toggle_maximize_window();
+                    }
                 }
             }
 
@@ -222,6 +334,20 @@ impl Model for ActionsModel {
                 if let Some(action) = &self.on_over {
                     (action)(cx);
                 }
+
+                if !self.hover_entered && self.contains_hovered(cx) {
+                    self.hover_entered = true;
+
+                    if let Some(action) = &self.on_hover_enter {
+                        (action)(cx);
+                    }
+
+                    if let Some((duration, _)) = &self.on_hover_dwell {
+                        let at = crate::util::now() + *duration;
+                        self.hover_dwell =
+                            Some(cx.schedule_emit(ActionsEvent::HoverDwellElapsed, at));
+                    }
+                }
             }
 
             WindowEvent::MouseOut => {
@@ -230,6 +356,18 @@ impl Model for ActionsModel {
                     (action)(cx);
                 }
 
+                if self.hover_entered && !self.contains_hovered(cx) {
+                    self.hover_entered = false;
+
+                    if let Some(handle) = self.hover_dwell.take() {
+                        cx.cancel_scheduled(handle);
+                    }
+
+                    if let Some(action) = &self.on_hover_leave {
+                        (action)(cx);
+                    }
+                }
+
                 if cx.mouse.left.state == MouseButtonState::Pressed
                     && cx.mouse.left.pressed == cx.current()
                     && cx.is_draggable()
@@ -245,10 +383,23 @@ impl Model for ActionsModel {
                 if let Some(action) = &self.on_mouse_move {
                     (action)(cx, *x, *y);
                 }
-                if cx.mouse.left.state == MouseButtonState::Released {
-                    if let Some(drop_data) = cx.drop_data.take() {
-                        if let Some(action) = &self.on_drop {
-                            (action)(cx, drop_data);
+
+                // Start a native drag once the cursor has moved far enough from where the view was pressed.
+                if let Some(action) = &self.on_drag_out {
+                    let pressed = cx.mouse.left.pressed;
+                    if !self.drag_out_started
+                        && cx.mouse.left.state == MouseButtonState::Pressed
+                        && (pressed == cx.current()
+                            || pressed.is_descendant_of(cx.tree, cx.current()))
+                        && !cx.is_disabled()
+                    {
+                        let (down_x, down_y) = cx.mouse.left.pos_down;
+                        let threshold = DRAG_OUT_THRESHOLD * cx.scale_factor();
+                        if (*x - down_x).abs() > threshold || (*y - down_y).abs() > threshold {
+                            self.drag_out_started = true;
+                            if let Some(data) = (action)(cx) {
+                                cx.start_drag(data);
+                            }
                         }
                     }
                 }
@@ -258,19 +409,56 @@ impl Model for ActionsModel {
                 if let Some(action) = &self.on_mouse_down {
                     (action)(cx, *mouse_button);
                 }
+
+                if *mouse_button == MouseButton::Left {
+                    self.drag_out_started = false;
+                }
+
+                if meta.target == cx.current && *mouse_button == MouseButton::Left {
+                    if self.window_drag_area {
+                        cx.emit(WindowEvent::DragWindow);
+                    }
+
+                    if let Some(direction) = self.window_resize_border {
+                        cx.emit(WindowEvent::DragResizeWindow(direction));
+                    }
+                }
             }
 
             WindowEvent::MouseUp(mouse_button) => {
                 if let Some(action) = &self.on_mouse_up {
                     (action)(cx, *mouse_button);
                 }
-                if let Some(drop_data) = cx.drop_data.take() {
+            }
+
+            WindowEvent::Drop(drop_data) => {
+                if meta.target == cx.current() {
                     if let Some(action) = &self.on_drop {
-                        (action)(cx, drop_data);
+                        (action)(cx, drop_data.clone());
                     }
                 }
             }
 
+            WindowEvent::DragEnter => {
+                if let (Some(action), Some(drop_data)) = (&self.on_drag_enter, cx.drop_data.clone())
+                {
+                    (action)(cx, drop_data);
+                }
+            }
+
+            WindowEvent::DragOver(x, y) => {
+                if let (Some(action), Some(drop_data)) = (&self.on_drag_over, cx.drop_data.clone())
+                {
+                    (action)(cx, *x, *y, drop_data);
+                }
+            }
+
+            WindowEvent::DragLeave => {
+                if let Some(action) = &self.on_drag_leave {
+                    (action)(cx);
+                }
+            }
+
             WindowEvent::FocusIn => {
                 if let Some(action) = &self.on_focus_in {
                     (action)(cx);
@@ -291,6 +479,14 @@ impl Model for ActionsModel {
                 }
             }
 
+            WindowEvent::DragEnded(result) => {
+                if meta.target == cx.current() {
+                    if let Some(action) = &self.on_drag_end {
+                        (action)(cx, *result);
+                    }
+                }
+            }
+
             _ => {}
         });
     }
@@ -304,6 +500,10 @@ pub(crate) enum ActionsEvent {
     OnHoverOut(Box<dyn Fn(&mut EventContext) + Send + Sync>),
     OnOver(Box<dyn Fn(&mut EventContext) + Send + Sync>),
     OnOverOut(Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    OnHoverEnter(Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    OnHoverLeave(Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    OnHoverDwell(Duration, Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    HoverDwellElapsed,
     OnMouseMove(Box<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>),
     OnMouseDown(Box<dyn Fn(&mut EventContext, MouseButton) + Send + Sync>),
     OnMouseUp(Box<dyn Fn(&mut EventContext, MouseButton) + Send + Sync>),
@@ -312,6 +512,14 @@ pub(crate) enum ActionsEvent {
     OnGeoChanged(Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>),
     OnDragStart(Box<dyn Fn(&mut EventContext) + Send + Sync>),
     OnDrop(Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>),
+    OnDragEnter(Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>),
+    OnDragOver(Box<dyn Fn(&mut EventContext, f32, f32, DropData) + Send + Sync>),
+    OnDragLeave(Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    OnDragOut(Box<dyn Fn(&mut EventContext) -> Option<DragData> + Send + Sync>),
+    OnDragEnd(Box<dyn Fn(&mut EventContext, DragResult) + Send + Sync>),
+    OnDragGesture(Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>, f32),
+    WindowDragArea,
+    WindowResizeBorder(ResizeDirection),
 }
 
 /// Modifiers which add an action callback to a view.
@@ -408,6 +616,53 @@ pub trait ActionModifiers<V> {
     where
         F: 'static + Fn(&mut EventContext) + Send + Sync;
 
+    /// Adds a callback which is performed once when the mouse pointer moves over a view or any of its descendants.
+    ///
+    /// Unlike `on_over`, moving the mouse pointer between the descendants of the view does not trigger the callback
+    /// again until the pointer has left the view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_hover_enter(|_| debug!("Mouse cursor entered the view or its children!"));
+    /// ```
+    fn on_hover_enter<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync;
+
+    /// Adds a callback which is performed once when the mouse pointer has left a view and all of its descendants,
+    /// after it triggered `on_hover_enter`.
+    ///
+    /// The callback is also triggered when the mouse pointer leaves the window, or when the view is removed while
+    /// the pointer is over it, so it can be relied upon to undo anything done when the pointer entered the view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_hover_leave(|_| debug!("Mouse cursor left the view and its children!"));
+    /// ```
+    fn on_hover_leave<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync;
+
+    /// Adds a callback which is performed when the mouse pointer has stayed over a view, or any of its descendants,
+    /// for the given duration.
+    ///
+    /// The callback is cancelled if the pointer leaves the view before the duration has elapsed, which makes it
+    /// useful for prefetching data or showing expensive previews only when the user intends to look at a view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_hover_dwell(Duration::from_millis(500), |_| debug!("Show preview"));
+    /// ```
+    fn on_hover_dwell<F>(self, duration: Duration, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync;
+
     /// Adds a callback which is performed when the mouse pointer moves within the bounds of a view.
     ///
     /// # Example
@@ -494,9 +749,129 @@ pub trait ActionModifiers<V> {
         F: 'static + Fn(&mut EventContext) + Send + Sync;
 
     /// Adds a callback which is performed when data is dropped on the view during a drag and drop operation.
+    ///
+    /// Adding this callback, or any of the [`on_drag_enter`](Self::on_drag_enter),
+    /// [`on_drag_over`](Self::on_drag_over), or [`on_drag_leave`](Self::on_drag_leave) callbacks, makes the view a
+    /// drop target. When drop targets are nested, dropped data is received by the innermost drop target under the
+    /// cursor. Data can be dropped from a drag and drop within the application, or by dragging files onto the window.
     fn on_drop<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventContext, DropData) + Send + Sync;
+
+    /// Adds a callback which is performed when a drag and drop operation enters the view, providing the data
+    /// being dragged.
+    ///
+    /// While data is dragged over a drop target the view has the `:drag-over` pseudoclass, which can be used to
+    /// highlight the view with a stylesheet.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .on_drag_enter(|_, data| debug!("Dragging {:?}", data))
+    ///     .on_drag_leave(|_| debug!("Drag left"))
+    ///     .on_drop(|_, data| debug!("Dropped {:?}", data));
+    /// ```
+    fn on_drag_enter<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DropData) + Send + Sync;
+
+    /// Adds a callback which is performed when the cursor moves over the view during a drag and drop operation,
+    /// providing the position of the cursor relative to the view and the data being dragged.
+    ///
+    /// No cursor movement is reported by some platforms while files are dragged over a window, in which case the
+    /// callback is only performed when the files first enter the view.
+    fn on_drag_over<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32, f32, DropData) + Send + Sync;
+
+    /// Adds a callback which is performed when a drag and drop operation leaves the view without the data being
+    /// dropped on the view.
+    fn on_drag_leave<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync;
+
+    /// Adds a callback which provides the data for a native drag and drop operation, allowing the view to be
+    /// dragged out of the application and dropped onto another application.
+    ///
+    /// The callback is performed when the view is pressed with the left mouse button and the cursor is then moved
+    /// a few pixels. Returning `None` from the callback cancels the drag. An image of the view is shown under the
+    /// cursor during the drag, and the result of the drag is reported to the [`on_drag_end`](Self::on_drag_end)
+    /// callback.
+    ///
+    /// Native drags are currently supported for the following payloads:
+    ///
+    /// | Platform              | [`DragData::Files`] | [`DragData::Text`] | [`DragData::Bytes`] |
+    /// |-----------------------|---------------------|--------------------|---------------------|
+    /// | Windows (winit)       | Yes                 | No                 | No                  |
+    /// | macOS (winit)         | Yes                 | No                 | No                  |
+    /// | Linux (winit)         | No                  | No                 | No                  |
+    /// | baseview              | No                  | No                 | No                  |
+    ///
+    /// With the winit backend an unsupported drag finishes immediately with [`DragResult::Cancelled`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # use std::path::PathBuf;
+    /// # let mut cx = &mut Context::default();
+    /// Label::new(cx, "report.pdf")
+    ///     .on_drag_start(|_| Some(DragData::Files(vec![PathBuf::from("report.pdf")])))
+    ///     .on_drag_end(|_, result| debug!("Drag finished: {:?}", result));
+    /// ```
+    fn on_drag_start<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) -> Option<DragData> + Send + Sync;
+
+    /// Adds a callback which is performed when a native drag and drop operation started by the view has finished.
+    ///
+    /// The source can use this to remove the dragged item after a successful move.
+    fn on_drag_end<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragResult) + Send + Sync;
+
+    /// Adds a callback which is performed during a drag gesture with the left mouse button on the view, such as
+    /// dragging a knob or panning a canvas.
+    ///
+    /// The gesture starts once the cursor has moved more than `threshold` logical pixels from where the view was
+    /// pressed, so a press without movement is still reported to the [`on_press`](Self::on_press) callback. The
+    /// view captures the mouse for the rest of the gesture. See [`DragTracker`] for handling drag gestures in the
+    /// event handler of a custom view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_drag_gesture(4.0, |_, gesture| match gesture.phase {
+    ///     DragPhase::Started | DragPhase::Moved => debug!("Dragged by {:?}", gesture.delta),
+    ///     DragPhase::Ended => debug!("Released with velocity {:?}", gesture.velocity),
+    /// });
+    /// ```
+    fn on_drag_gesture<F>(self, threshold: f32, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync;
+
+    /// Marks the view as an area which can be used to move the window, such as a custom titlebar.
+    ///
+    /// Pressing the left mouse button on the view starts a window drag, and double-clicking the view
+    /// toggles whether the window is maximized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// HStack::new(cx, |cx| {
+    ///     Label::new(cx, "Title");
+    ///     Button::new(cx, |cx| Label::new(cx, "_")).on_press(|cx| cx.minimize_window());
+    ///     Button::new(cx, |cx| Label::new(cx, "X")).on_press(|cx| cx.request_window_close());
+    /// })
+    /// .window_drag_area();
+    /// ```
+    fn window_drag_area(self) -> Self;
+
+    /// Marks the view as a handle which can be used to resize the window from the given edge or corner.
+    fn window_resize_border(self, direction: ResizeDirection) -> Self;
 }
 
 // If the entity doesn't have an `ActionsModel` then add one to the entity
@@ -509,6 +884,13 @@ fn build_action_model(cx: &mut Context, entity: Entity) {
     }
 }
 
+// Marks the entity as a target for data dropped during a drag and drop.
+fn set_droppable(cx: &mut Context, entity: Entity) {
+    if let Some(abilities) = cx.style.abilities.get_mut(entity) {
+        abilities.set(Abilities::DROPPABLE, true);
+    }
+}
+
 fn build_modal_model(cx: &mut Context, entity: Entity) {
     if cx.models.get(&entity).and_then(|models| models.get(&TypeId::of::<ModalModel>())).is_none() {
         cx.with_current(entity, |cx| {
@@ -649,6 +1031,51 @@ impl<V: View> ActionModifiers<V> for Handle<'_, V> {
         self
     }
 
+    fn on_hover_enter<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnHoverEnter(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_hover_leave<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnHoverLeave(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_hover_dwell<F>(self, duration: Duration, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnHoverDwell(duration, Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
     fn on_over<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventContext) + Send + Sync,
@@ -793,6 +1220,7 @@ impl<V: View> ActionModifiers<V> for Handle<'_, V> {
         F: 'static + Fn(&mut EventContext, DropData) + Send + Sync,
     {
         build_action_model(self.cx, self.entity);
+        set_droppable(self.cx, self.entity);
 
         self.cx.emit_custom(
             Event::new(ActionsEvent::OnDrop(Box::new(action)))
@@ -802,4 +1230,215 @@ impl<V: View> ActionModifiers<V> for Handle<'_, V> {
 
         self
     }
+
+    fn on_drag_enter<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DropData) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+        set_droppable(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragEnter(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_over<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32, f32, DropData) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+        set_droppable(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragOver(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_leave<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+        set_droppable(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragLeave(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_start<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) -> Option<DragData> + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragOut(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_end<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragResult) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragEnd(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_gesture<F>(self, threshold: f32, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragGesture(Box::new(action), threshold))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn window_drag_area(self) -> Self {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::WindowDragArea).target(self.entity).origin(self.entity),
+        );
+
+        self.hoverable(true)
+    }
+
+    fn window_resize_border(self, direction: ResizeDirection) -> Self {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::WindowResizeBorder(direction))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        let cursor = match direction {
+            ResizeDirection::East => CursorIcon::EResize,
+            ResizeDirection::North => CursorIcon::NResize,
+            ResizeDirection::NorthEast => CursorIcon::NeResize,
+            ResizeDirection::NorthWest => CursorIcon::NwResize,
+            ResizeDirection::South => CursorIcon::SResize,
+            ResizeDirection::SouthEast => CursorIcon::SeResize,
+            ResizeDirection::SouthWest => CursorIcon::SwResize,
+            ResizeDirection::West => CursorIcon::WResize,
+        };
+
+        self.hoverable(true).cursor(cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Lens)]
+    struct AppData {
+        shown: bool,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|shown, _| self.shown = *shown);
+        }
+    }
+
+    fn harness(log: &Arc<Mutex<Vec<&'static str>>>) -> TestHarness {
+        let (enter, leave, dwell) = (log.clone(), log.clone(), log.clone());
+        TestHarness::new(move |cx| {
+            AppData { shown: true }.build(cx);
+            Binding::new(cx, AppData::shown, move |cx, shown| {
+                if shown.get(cx) {
+                    let (enter, leave, dwell) = (enter.clone(), leave.clone(), dwell.clone());
+                    HStack::new(cx, |cx| {
+                        Element::new(cx).size(Pixels(50.0)).id("child");
+                    })
+                    .size(Pixels(100.0))
+                    .id("view")
+                    .on_hover_enter(move |_| enter.lock().unwrap().push("enter"))
+                    .on_hover_leave(move |_| leave.lock().unwrap().push("leave"))
+                    .on_hover_dwell(Duration::from_millis(500), move |_| {
+                        dwell.lock().unwrap().push("dwell")
+                    });
+                }
+            });
+        })
+    }
+
+    #[test]
+    fn hover_dwell_fires_after_delay() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut harness = harness(&log);
+
+        let bounds = harness.bounds("#view");
+        harness.hover(bounds.x + 75.0, bounds.y + 75.0);
+        // Moving onto a child of the view does not leave the view.
+        harness.hover(bounds.x + 25.0, bounds.y + 25.0);
+        harness.advance_time(Duration::from_millis(600));
+        harness.hover(bounds.right() + 100.0, bounds.bottom() + 100.0);
+
+        assert_eq!(*log.lock().unwrap(), ["enter", "dwell", "leave"]);
+    }
+
+    #[test]
+    fn hover_leave_fires_when_left_early_or_removed() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut harness = harness(&log);
+
+        let (x, y) = harness.bounds("#view").center();
+        harness.hover(x, y);
+        harness.advance_time(Duration::from_millis(100));
+        harness.emit(WindowEvent::MouseLeave);
+        harness.advance_time(Duration::from_millis(600));
+        assert_eq!(*log.lock().unwrap(), ["enter", "leave"]);
+
+        harness.emit(WindowEvent::MouseEnter);
+        harness.hover(x, y);
+        harness.emit(false);
+        assert_eq!(*log.lock().unwrap(), ["enter", "leave", "enter", "leave"]);
+    }
+
+    #[test]
+    fn hover_leave_fires_when_hovered_child_is_removed() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut harness = harness(&log);
+
+        let (x, y) = harness.bounds("#child").center();
+        harness.hover(x, y);
+        let child = harness.query("#child").unwrap();
+        harness.context().remove(child);
+        assert_eq!(*log.lock().unwrap(), ["enter", "leave"]);
+    }
 }
@@ -1,4 +1,4 @@
-use vizia_style::{ColorStop, CornerRadius, Rect};
+use vizia_style::{Atom, ColorStop, CornerRadius, Rect};
 
 use super::internal;
 use crate::prelude::*;
@@ -27,7 +27,7 @@ pub trait StyleModifiers: internal::Modifiable {
         // TODO - What should happen if the id already exists?
         let id = id.into();
         let entity = self.entity();
-        self.context().style.ids.insert(entity, id.clone());
+        self.context().style.ids.insert(entity, Atom::new(&id));
         self.context().needs_restyle(entity);
 
         self.context().entity_identifiers.insert(id, entity);
@@ -54,7 +54,7 @@ pub trait StyleModifiers: internal::Modifiable {
     fn class(mut self, name: &str) -> Self {
         let entity = self.entity();
         if let Some(class_list) = self.context().style.classes.get_mut(entity) {
-            class_list.insert(name.to_string());
+            class_list.insert(Atom::new(name));
         }
 
         self.context().needs_restyle(entity);
@@ -64,7 +64,7 @@ pub trait StyleModifiers: internal::Modifiable {
 
     /// Sets whether a view should have the given class name.
     fn toggle_class(mut self, name: &str, applied: impl Res<bool>) -> Self {
-        let name = name.to_owned();
+        let name = Atom::new(name);
         let entity = self.entity();
         let current = self.current();
         self.context().with_current(current, |cx| {
@@ -299,8 +299,25 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
-    /// Sets the backdrop filter for the view.
-    fn backdrop_filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
+    modifier!(
+        /// Sets how the view, together with its descendants, is blended with the content behind it, like the CSS
+        /// `mix-blend-mode` property.
+        blend_mode,
+        BlendMode,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets the filter functions applied to the content behind the view, such as a blur and an increase in saturation
+    /// for a frosted glass effect.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .backdrop_filter(vec![FilterFunction::Blur(Length::px(16.0)), FilterFunction::Saturate(1.8)]);
+    /// ```
+    fn backdrop_filter<U: Into<Vec<FilterFunction>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
         self.context().with_current(current, |cx| {
@@ -315,6 +332,22 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets the filter functions applied to the view and its descendants when they are drawn.
+    fn filter<U: Into<Vec<FilterFunction>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.filter.insert(cx.current, value);
+
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
     /// Add a shadow to the view.
     fn shadow<U: Into<Shadow>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -632,6 +665,48 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets whether the view and its descendants are drawn into a cached image which is reused between frames.
+    ///
+    /// This is useful for subtrees which are expensive to draw but rarely change, such as a complex chart. The
+    /// image is drawn again when any view within the subtree needs to be redrawn, or when the bounds or transform
+    /// of the subtree change other than by a translation of whole pixels, such as when scrolling. Descendants with a
+    /// positive z-index are drawn separately and are not cached.
+    fn cache_layer(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            flag.set_or_bind(cx, entity, move |cx, v| {
+                let flag = v.get(cx);
+                cx.style.cache_layer.insert(cx.current, flag);
+                cx.needs_redraw(cx.current);
+            });
+        });
+
+        self
+    }
+
+    /// Sets the test used to determine whether the mouse cursor is over the view, which is given the bounds of the
+    /// view and the position of the cursor, both in physical window coordinates.
+    ///
+    /// The test is only called for positions within the bounds of the view. By default the corner radii of the view
+    /// are used to exclude its rounded corners. See [`HitTest`] for some built-in tests.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).size(Pixels(100.0)).hit_test(HitTest::circle());
+    /// ```
+    fn hit_test(
+        mut self,
+        test: impl Fn(BoundingBox, (f32, f32)) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let entity = self.entity();
+        self.context().style.hit_test.insert(entity, Box::new(test));
+
+        self
+    }
+
     /// Sets the transform of the view with a list of transform functions.
     fn transform<U: Into<Vec<Transform>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -115,6 +115,14 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REFLOW
     );
 
+    modifier!(
+        /// Sets the base direction of text within the view, which is otherwise taken from the first strongly
+        /// directional character of the text.
+        direction,
+        Direction,
+        SystemFlags::REFLOW
+    );
+
     modifier!(
         /// Sets the text overflow.
         text_overflow,
@@ -147,6 +155,27 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REFLOW
     );
 
+    modifier!(
+        /// Sets the additional spacing between characters of the text, where `em` units are relative to the font size.
+        letter_spacing,
+        Length,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional spacing between words of the text, where `em` units are relative to the font size.
+        word_spacing,
+        Length,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the height of each line of the text, either as a multiple of the font size or as a fixed length.
+        line_height,
+        LineHeight,
+        SystemFlags::REFLOW
+    );
+
     modifier!(
         /// Sets the paint style of the text stroke.
         /// You can either draw text with a stroke, or just the stroke outline.
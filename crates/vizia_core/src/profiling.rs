@@ -0,0 +1,170 @@
+//! Measurements of the time spent in each pass of the systems which update and draw views.
+//!
+//! The time of each pass is always measured, and the times of the last drawn frame can be read with
+//! [`frame_timings`] or shown in an application with a [`FrameTimeHud`](crate::views::FrameTimeHud). With the
+//! `tracing` feature enabled each pass, and the drawing of each view, is also recorded as a
+//! [`tracing`](https://docs.rs/tracing) span, so that a subscriber such as `tracing-tracy` or `tracing-chrome` can
+//! show where the time of a frame goes.
+
+use std::cell::RefCell;
+
+use crate::prelude::*;
+
+/// A pass of the systems which update and draw the views of an application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FramePass {
+    /// Dispatching events to views and models, including updating bindings.
+    Events,
+    /// Updating the views bound to data which changed while handling events.
+    Bindings,
+    /// Matching style rules and computing the style of views.
+    Style,
+    /// Playing animations and transitions.
+    Animations,
+    /// Building the paragraphs of views with text.
+    Text,
+    /// Computing the size and position of views.
+    Layout,
+    /// Updating the accessibility tree.
+    Accessibility,
+    /// Drawing the views of a window.
+    Draw,
+}
+
+impl FramePass {
+    /// All of the passes, in the order they run within a frame.
+    pub const ALL: [FramePass; 8] = [
+        FramePass::Events,
+        FramePass::Bindings,
+        FramePass::Style,
+        FramePass::Animations,
+        FramePass::Text,
+        FramePass::Layout,
+        FramePass::Accessibility,
+        FramePass::Draw,
+    ];
+
+    /// Returns the name of the pass.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FramePass::Events => "events",
+            FramePass::Bindings => "bindings",
+            FramePass::Style => "style",
+            FramePass::Animations => "animations",
+            FramePass::Text => "text",
+            FramePass::Layout => "layout",
+            FramePass::Accessibility => "accessibility",
+            FramePass::Draw => "draw",
+        }
+    }
+}
+
+/// The time spent in each pass of the systems during a frame, see [`frame_timings`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameTimings {
+    passes: [Duration; FramePass::ALL.len()],
+    /// The time between the end of the frame and the end of the frame before it.
+    pub frame: Duration,
+}
+
+impl FrameTimings {
+    /// Returns the total time spent in a pass during the frame. A pass can run more than once per frame.
+    pub fn pass(&self, pass: FramePass) -> Duration {
+        self.passes[pass as usize]
+    }
+}
+
+#[derive(Default)]
+struct Timings {
+    // The times of the passes which have run since the last frame was drawn.
+    current: [Duration; FramePass::ALL.len()],
+    last: FrameTimings,
+    last_frame_end: Option<Instant>,
+}
+
+thread_local! {
+    static TIMINGS: RefCell<Timings> = RefCell::new(Timings::default());
+}
+
+/// Returns the time spent in each pass during the last frame which was drawn.
+///
+/// # Example
+/// ```
+/// # use vizia_core::profiling::{frame_timings, FramePass};
+/// let timings = frame_timings();
+/// for pass in FramePass::ALL {
+///     println!("{}: {:?}", pass.name(), timings.pass(pass));
+/// }
+/// ```
+pub fn frame_timings() -> FrameTimings {
+    TIMINGS.with_borrow(|timings| timings.last)
+}
+
+/// Measures the time of a pass until it is dropped.
+pub(crate) struct PassTimer {
+    pass: FramePass,
+    start: Instant,
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Starts measuring the time of a pass, which ends when the returned timer is dropped.
+pub(crate) fn time_pass(pass: FramePass) -> PassTimer {
+    #[cfg(feature = "tracing")]
+    let span = match pass {
+        FramePass::Events => tracing::info_span!("events"),
+        FramePass::Bindings => tracing::info_span!("bindings"),
+        FramePass::Style => tracing::info_span!("style"),
+        FramePass::Animations => tracing::info_span!("animations"),
+        FramePass::Text => tracing::info_span!("text"),
+        FramePass::Layout => tracing::info_span!("layout"),
+        FramePass::Accessibility => tracing::info_span!("accessibility"),
+        FramePass::Draw => tracing::info_span!("draw"),
+    };
+
+    PassTimer {
+        pass,
+        start: Instant::now(),
+        #[cfg(feature = "tracing")]
+        _span: span.entered(),
+    }
+}
+
+impl Drop for PassTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        TIMINGS.with_borrow_mut(|timings| timings.current[self.pass as usize] += elapsed);
+    }
+}
+
+/// Records the times of the passes which have run since the last frame as the times of a frame.
+pub(crate) fn end_frame() {
+    let now = Instant::now();
+    TIMINGS.with_borrow_mut(|timings| {
+        let frame = timings.last_frame_end.map(|end| now.duration_since(end)).unwrap_or_default();
+        timings.last = FrameTimings { passes: std::mem::take(&mut timings.current), frame };
+        timings.last_frame_end = Some(now);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_pass_times_per_frame() {
+        {
+            let _timer = time_pass(FramePass::Layout);
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        end_frame();
+
+        let timings = frame_timings();
+        assert!(timings.pass(FramePass::Layout) >= Duration::from_millis(2));
+        assert_eq!(timings.pass(FramePass::Draw), Duration::ZERO);
+
+        // Passes which run after a frame count towards the next one.
+        end_frame();
+        assert_eq!(frame_timings().pass(FramePass::Layout), Duration::ZERO);
+    }
+}
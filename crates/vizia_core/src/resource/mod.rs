@@ -7,10 +7,11 @@ use vizia_id::{GenerationalId, IdManager};
 
 use crate::context::ResourceContext;
 use crate::entity::Entity;
-use crate::prelude::IntoCssStr;
+use crate::prelude::{IntoCssStr, ThemeMode};
 // use crate::view::Canvas;
 use fluent_bundle::{FluentBundle, FluentResource};
 use hashbrown::{HashMap, HashSet};
+use std::cell::RefCell;
 use unic_langid::LanguageIdentifier;
 
 pub(crate) enum ImageOrSvg {
@@ -42,6 +43,9 @@ pub enum ImageRetentionPolicy {
 pub struct ResourceManager {
     pub themes: Vec<String>, // Themes are the string content stylesheets
     pub styles: Vec<Box<dyn IntoCssStr>>,
+    // The theme modes and stylesheets which apply only to the views within a particular window.
+    pub window_themes: HashMap<Entity, ThemeMode>,
+    pub window_styles: HashMap<Entity, Vec<Box<dyn IntoCssStr>>>,
 
     pub(crate) image_id_manager: IdManager<ImageId>,
     pub(crate) images: HashMap<ImageId, StoredImage>,
@@ -51,6 +55,12 @@ pub struct ResourceManager {
 
     pub language: LanguageIdentifier,
 
+    /// Locales which are searched, in order, for messages which are missing from the translations of the locale of
+    /// the application, before the negotiated language.
+    pub fallback_locales: Vec<LanguageIdentifier>,
+    // Messages which have been reported as missing for a locale, so that each is only logged once.
+    pub(crate) missing_translations: RefCell<HashSet<(LanguageIdentifier, String)>>,
+
     pub image_loader: Option<Box<dyn Fn(&mut ResourceContext, &str)>>,
 }
 
@@ -117,13 +127,17 @@ impl ResourceManager {
             images,
             image_ids: HashMap::new(),
             styles: Vec::new(),
+            window_themes: HashMap::new(),
+            window_styles: HashMap::new(),
 
             translations: HashMap::from([(
                 LanguageIdentifier::default(),
-                FluentBundle::new(vec![LanguageIdentifier::default()]),
+                new_bundle(LanguageIdentifier::default()),
             )]),
 
             language: locale,
+            fallback_locales: Vec::new(),
+            missing_translations: RefCell::new(HashSet::new()),
             image_loader: default_image_loader,
         }
     }
@@ -151,8 +165,7 @@ impl ResourceManager {
     pub fn add_translation(&mut self, lang: LanguageIdentifier, ftl: String) {
         let res = fluent_bundle::FluentResource::try_new(ftl)
             .expect("Failed to parse translation as FTL");
-        let bundle =
-            self.translations.entry(lang.clone()).or_insert_with(|| FluentBundle::new(vec![lang]));
+        let bundle = self.translations.entry(lang.clone()).or_insert_with(|| new_bundle(lang));
         bundle.add_resource(res).expect("Failed to add resource to bundle");
         self.renegotiate_language();
     }
@@ -168,6 +181,45 @@ impl ResourceManager {
         }
     }
 
+    /// Returns the bundles to search for a message in the given locale, in order: the translations which match the
+    /// locale, such as `fr` for `fr-CA`, then those which match the fallback locales, and then the negotiated language.
+    pub fn translation_chain(
+        &self,
+        locale: &LanguageIdentifier,
+    ) -> Vec<&FluentBundle<FluentResource>> {
+        let available = self
+            .translations
+            .keys()
+            .filter(|&x| x != &LanguageIdentifier::default())
+            .collect::<Vec<_>>();
+        let requested =
+            std::iter::once(locale).chain(self.fallback_locales.iter()).collect::<Vec<_>>();
+        let langs = fluent_langneg::negotiate::negotiate_languages(
+            &requested,
+            &available,
+            None,
+            fluent_langneg::NegotiationStrategy::Filtering,
+        );
+
+        let mut chain: Vec<&FluentBundle<FluentResource>> = Vec::new();
+        for lang in langs.into_iter().map(|lang| *lang).chain(std::iter::once(&self.language)) {
+            if let Some(bundle) = self.translations.get(lang) {
+                if !chain.iter().any(|other| std::ptr::eq(*other, bundle)) {
+                    chain.push(bundle);
+                }
+            }
+        }
+
+        chain
+    }
+
+    /// Logs that a message is missing from the translations of a locale, once for each message and locale.
+    pub(crate) fn report_missing_translation(&self, locale: &LanguageIdentifier, key: &str) {
+        if self.missing_translations.borrow_mut().insert((locale.clone(), key.to_owned())) {
+            log::warn!("Missing translation for \"{}\" in locale {}", key, locale);
+        }
+    }
+
     pub fn mark_images_unused(&mut self) {
         for (_, img) in self.images.iter_mut() {
             img.used = false;
@@ -196,3 +248,10 @@ impl ResourceManager {
         }
     }
 }
+
+// Creates an empty bundle for a locale with the builtin functions, such as `NUMBER`, available to its messages.
+fn new_bundle(lang: LanguageIdentifier) -> FluentBundle<FluentResource> {
+    let mut bundle = FluentBundle::new(vec![lang]);
+    bundle.add_builtins().expect("Failed to add builtin functions to bundle");
+    bundle
+}
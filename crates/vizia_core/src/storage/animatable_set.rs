@@ -1,7 +1,36 @@
-use crate::animation::{AnimationState, Interpolator};
+use crate::animation::{AnimationState, Interpolator, Keyframe};
 use crate::prelude::*;
 use vizia_storage::{SparseSet, SparseSetGeneric, SparseSetIndex};
 
+/// The options with which an animation is played on an entity.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PlayOptions {
+    /// The time at which the animation starts.
+    pub start_time: Instant,
+    /// The duration of the animation.
+    pub duration: Duration,
+    /// The delay before the animation starts.
+    pub delay: Duration,
+    /// Whether the animation starts from the current value of the property for the entity, including the output of
+    /// any animation already playing on it, instead of from the value of its first keyframe.
+    pub from_current: bool,
+    /// The maximum time by which the animation advances each tick.
+    pub max_time_step: Option<Duration>,
+}
+
+impl PlayOptions {
+    /// Creates options for an animation which starts at the given time without a delay.
+    pub fn new(start_time: Instant, duration: Duration) -> Self {
+        Self {
+            start_time,
+            duration,
+            delay: Duration::ZERO,
+            from_current: false,
+            max_time_step: None,
+        }
+    }
+}
+
 const INDEX_MASK: u32 = u32::MAX / 4;
 const INLINE_MASK: u32 = 1 << 31;
 const INHERITED_MASK: u32 = 1 << 30;
@@ -185,7 +214,7 @@ where
             let parent_sparse_index = self.inline_data.sparse[parent_index];
 
             if parent_sparse_index.data_index.is_inline()
-                && parent_sparse_index.data_index.index() < self.inline_data.dense.len()
+                && parent_sparse_index.data_index.index() < self.inline_data.len()
             {
                 if entity_index >= self.inline_data.sparse.len() {
                     self.inline_data.sparse.resize(entity_index + 1, InlineIndex::null());
@@ -196,7 +225,7 @@ where
                 if self.inline_data.sparse[entity_index].data_index.index()
                     != parent_sparse_index.data_index.index()
                 {
-                    if entity_sparse_index.data_index.index() < self.inline_data.dense.len() {
+                    if entity_sparse_index.data_index.index() < self.inline_data.len() {
                         if entity_sparse_index.data_index.is_inherited()
                             && entity_sparse_index.data_index.is_inline()
                         {
@@ -232,7 +261,7 @@ where
             let parent_sparse_index = self.inline_data.sparse[parent_index];
 
             if !parent_sparse_index.data_index.is_inline()
-                && parent_sparse_index.data_index.index() < self.shared_data.dense.len()
+                && parent_sparse_index.data_index.index() < self.shared_data.len()
             {
                 if entity_index >= self.inline_data.sparse.len() {
                     self.inline_data.sparse.resize(entity_index + 1, InlineIndex::null());
@@ -244,7 +273,7 @@ where
                     && self.inline_data.sparse[entity_index].data_index.index()
                         != parent_sparse_index.data_index.index()
                 {
-                    if entity_sparse_index.data_index.index() < self.shared_data.dense.len() {
+                    if entity_sparse_index.data_index.index() < self.shared_data.len() {
                         if entity_sparse_index.data_index.is_inherited() {
                             self.inline_data.sparse[entity_index] = InlineIndex {
                                 data_index: DataIndex::shared(
@@ -307,83 +336,81 @@ where
         }
     }
 
+    /// Plays an animation on an entity with the given options.
     pub(crate) fn play_animation(
         &mut self,
         entity: Entity,
         animation: Animation,
-        start_time: Instant,
-        duration: Duration,
-        delay: Duration,
+        options: PlayOptions,
     ) {
+        let PlayOptions { start_time, duration, delay, from_current, max_time_step } = options;
         let entity_index = entity.index();
 
-        if !self.animations.contains(animation) {
+        let Some(description) = self.animations.get(animation) else {
             return;
-        }
+        };
+
+        let current = if from_current { self.get(entity).cloned() } else { None };
+        let start_value = current.clone().unwrap_or_else(|| description.keyframes[0].value.clone());
 
         // If there is no inline or shared data for the entity then add the entity as animation only
         if entity_index >= self.inline_data.sparse.len() {
             self.inline_data.sparse.resize(entity_index + 1, InlineIndex::null());
         }
 
-        if entity_index < self.inline_data.sparse.len() {
-            let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
-            if active_anim_index < self.active_animations.len() {
-                let anim_state = &mut self.active_animations[active_anim_index];
-                if anim_state.id == animation {
-                    anim_state.active = true;
-                    anim_state.t = 0.0;
-                    anim_state.start_time = start_time;
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                } else {
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                    anim_state.entities.remove(&entity);
-                }
+        let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+        if active_anim_index < self.active_animations.len() {
+            let anim_state = &mut self.active_animations[active_anim_index];
+            if anim_state.id == animation {
+                anim_state.active = true;
+                anim_state.t = 0.0;
+                anim_state.start_time = start_time;
+            } else {
+                anim_state.entities.remove(&entity);
             }
+            anim_state.output = Some(start_value.clone());
+        }
 
-            // Safe to unwrap because already checked that the animation exists
-            let mut anim_state = self.animations.get(animation).cloned().unwrap();
-            anim_state.duration = duration;
-            anim_state.delay = delay;
-            anim_state.dt = delay.as_secs_f32() / duration.as_secs_f32();
-            anim_state.output = Some(
-                self.animations
-                    .get(animation)
-                    .cloned()
-                    .unwrap()
+        // Safe to unwrap because already checked that the animation exists
+        let mut anim_state = self.animations.get(animation).cloned().unwrap();
+        anim_state.duration = duration;
+        anim_state.delay = delay;
+        anim_state.dt = delay.as_secs_f32() / duration.as_secs_f32();
+        anim_state.max_time_step = max_time_step;
+        if let Some(current) = current {
+            // The animation starts from the current value. A first keyframe which is set later in the animation is
+            // kept, with the current value leading into it.
+            let first = &anim_state.keyframes[0];
+            if first.time > 0.0 {
+                let timing_function = first.timing_function;
+                anim_state
                     .keyframes
-                    .first()
-                    .unwrap()
-                    .value
-                    .clone(),
-            );
-            anim_state.play(entity);
-            self.inline_data.sparse[entity_index].anim_index = self.active_animations.len() as u32;
-            self.active_animations.push(anim_state);
+                    .insert(0, Keyframe { time: 0.0, value: current, timing_function });
+            } else {
+                anim_state.keyframes[0].value = current;
+            }
         }
+        anim_state.output = Some(start_value);
+        anim_state.play(entity);
+        self.inline_data.sparse[entity_index].anim_index = self.active_animations.len() as u32;
+        self.active_animations.push(anim_state);
     }
 
+    /// Advances the active animations to the given time, returning the entities whose animated values changed.
     pub fn tick(&mut self, time: Instant) -> Vec<Entity> {
+        self.tick_inner(time, None)
+    }
+
+    /// Advances the active animations to the given time like [`tick`](Self::tick), but by at most `max_dt` since the
+    /// previous tick of each animation.
+    ///
+    /// When frames are dropped the animations are delayed by the time beyond `max_dt`, so that they are spread over
+    /// more frames instead of jumping forward. Animations with their own maximum time step use the smaller of the two.
+    pub fn tick_capped(&mut self, time: Instant, max_dt: Duration) -> Vec<Entity> {
+        self.tick_inner(time, Some(max_dt))
+    }
+
+    fn tick_inner(&mut self, time: Instant, max_dt: Option<Duration>) -> Vec<Entity> {
         self.remove_innactive_animations();
 
         if self.has_animations() {
@@ -398,6 +425,22 @@ where
                     continue;
                 }
 
+                // Delay the animation by any time beyond the maximum time step since its previous tick. The start
+                // time is used instead of the previous tick when the animation was restarted since then.
+                let max_time_step = match (max_dt, state.max_time_step) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                if let Some(max_time_step) = max_time_step {
+                    let previous =
+                        state.last_tick.map_or(state.start_time, |t| t.max(state.start_time));
+                    let step = time.saturating_duration_since(previous);
+                    if step > max_time_step {
+                        state.start_time += step - max_time_step;
+                    }
+                }
+                state.last_tick = Some(time);
+
                 let elapsed_time = time.duration_since(state.start_time);
                 let mut normalised_time =
                     (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.dt;
@@ -418,7 +461,11 @@ where
                 state.t = normalised_time;
 
                 let timing_t = start.timing_function.value(normalised_elapsed_time);
-                state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
+                state.output = Some(if state.allow_discrete {
+                    T::interpolate_discrete(&start.value, &end.value, timing_t)
+                } else {
+                    T::interpolate(&start.value, &end.value, timing_t)
+                });
             }
 
             self.active_animations
@@ -430,6 +477,38 @@ where
         }
     }
 
+    /// Stops the active animation of the given entity so that the entity returns to its inline or shared value.
+    ///
+    /// Only a transition is stopped if `transition` is true, and only an animation which is not a transition otherwise.
+    /// Returns true if an animation was stopped.
+    pub(crate) fn stop_animation(&mut self, entity: Entity, transition: bool) -> bool {
+        let entity_index = entity.index();
+        if entity_index >= self.inline_data.sparse.len() {
+            return false;
+        }
+
+        let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+        if let Some(state) = self.active_animations.get_mut(anim_index) {
+            if state.is_transition() != transition {
+                return false;
+            }
+
+            state.entities.remove(&entity);
+
+            // An animation state which no longer animates any entities is removed on the next tick.
+            if state.entities.is_empty() {
+                state.t = 1.0;
+                state.persistent = false;
+            }
+
+            self.inline_data.sparse[entity_index].anim_index = u32::MAX;
+
+            return true;
+        }
+
+        false
+    }
+
     // Returns true if the given entity is linked to an active animation
     // pub fn is_animating(&self, entity: Entity) -> bool {
     //     let entity_index = entity.index();
@@ -563,11 +642,11 @@ where
 
             let data_index = self.inline_data.sparse[entity_index].data_index;
             if data_index.is_inline() {
-                if data_index.index() < self.inline_data.dense.len() {
-                    return Some(&self.inline_data.dense[data_index.index()].value);
+                if data_index.index() < self.inline_data.len() {
+                    return Some(&self.inline_data[data_index.index()]);
                 }
-            } else if data_index.index() < self.shared_data.dense.len() {
-                return Some(&self.shared_data.dense[data_index.index()].value);
+            } else if data_index.index() < self.shared_data.len() {
+                return Some(&self.shared_data[data_index.index()]);
             }
         }
 
@@ -614,28 +693,22 @@ where
                                 current_anim_state.from_rule = current_anim_state.to_rule;
                                 current_anim_state.to_rule = rule_data_index;
                                 current_anim_state.keyframes.first_mut().unwrap().value =
-                                    self.shared_data.dense[current_anim_state.from_rule]
-                                        .value
-                                        .clone();
+                                    self.shared_data[current_anim_state.from_rule].clone();
 
                                 current_anim_state.keyframes.last_mut().unwrap().value =
-                                    self.shared_data.dense[current_anim_state.to_rule]
-                                        .value
-                                        .clone();
+                                    self.shared_data[current_anim_state.to_rule].clone();
 
                                 current_anim_state.dt = current_anim_state.t - 1.0;
-                                current_anim_state.start_time = Instant::now();
+                                current_anim_state.start_time = crate::util::now();
                             } else {
                                 // Transitioning to new rule
                                 current_anim_state.to_rule = rule_data_index;
                                 current_anim_state.keyframes.first_mut().unwrap().value =
                                     current_value;
                                 current_anim_state.keyframes.last_mut().unwrap().value =
-                                    self.shared_data.dense[current_anim_state.to_rule]
-                                        .value
-                                        .clone();
+                                    self.shared_data[current_anim_state.to_rule].clone();
                                 current_anim_state.t = 0.0;
-                                current_anim_state.start_time = Instant::now();
+                                current_anim_state.start_time = crate::util::now();
                             }
                         }
                     }
@@ -646,10 +719,9 @@ where
                     let entity_data_index = self.inline_data.sparse[entity_index].data_index;
 
                     if !entity_data_index.is_inline()
-                        && entity_data_index.index() < self.shared_data.dense.len()
+                        && entity_data_index.index() < self.shared_data.len()
                     {
-                        let start_data =
-                            self.shared_data.dense[entity_data_index.index()].value.clone();
+                        let start_data = self.shared_data[entity_data_index.index()].clone();
                         transition_state.keyframes.first_mut().unwrap().value = start_data;
                     } else {
                         transition_state.keyframes.first_mut().unwrap().value = end.clone();
@@ -669,9 +741,7 @@ where
                         self.play_animation(
                             entity,
                             rule_animation,
-                            Instant::now(),
-                            duration,
-                            delay,
+                            PlayOptions { delay, ..PlayOptions::new(crate::util::now(), duration) },
                         );
                     }
                     //}
@@ -795,4 +865,234 @@ mod tests {
         animatable_storage.insert(Entity::root(), 5.0);
         //assert_eq!(animatable_storage.entity_indices.first().unwrap().data_index, DataIndex::inline(0));
     }
+
+    /// Test that a dropped frame advances a capped animation by at most the maximum time step.
+    #[test]
+    fn tick_capped() {
+        use crate::animation::{Keyframe, TimingFunction};
+
+        let timing_function = TimingFunction::linear();
+        let animation = Animation::new(0, 0);
+        let duration = Duration::from_millis(400);
+        let max_time_step = Duration::from_millis(20);
+
+        let mut animatable_storage = AnimatableSet::<Opacity>::default();
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(Keyframe { time: 0.0, value: Opacity(0.0), timing_function })
+                .with_keyframe(Keyframe { time: 1.0, value: Opacity(1.0), timing_function }),
+        );
+
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            PlayOptions {
+                max_time_step: Some(max_time_step),
+                ..PlayOptions::new(Instant::now(), duration)
+            },
+        );
+        let start_time =
+            animatable_storage.get_active_animation(Entity::root()).unwrap().start_time;
+        let opacity = |storage: &AnimatableSet<Opacity>| storage.get(Entity::root()).unwrap().0;
+
+        animatable_storage.tick(start_time + Duration::from_millis(20));
+        assert!((opacity(&animatable_storage) - 0.05).abs() < 1e-4);
+
+        // A 200ms stall only advances the animation by the maximum time step.
+        animatable_storage.tick(start_time + Duration::from_millis(220));
+        assert!((opacity(&animatable_storage) - 0.1).abs() < 1e-4);
+
+        // Capping all animations while ticking uses the smaller of the two time steps.
+        animatable_storage
+            .tick_capped(start_time + Duration::from_millis(240), Duration::from_millis(8));
+        assert!((opacity(&animatable_storage) - 0.12).abs() < 1e-4);
+    }
+
+    /// Test that a `steps(4, end)` timing function produces four discrete values over the animation.
+    #[test]
+    fn tick_steps() {
+        use crate::animation::{Keyframe, TimingFunction};
+        use vizia_style::StepPosition;
+
+        let timing_function = TimingFunction::steps(4, StepPosition::End);
+        let animation = Animation::new(0, 0);
+        let duration = Duration::from_secs(1);
+
+        let mut animatable_storage = AnimatableSet::<Opacity>::default();
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(Keyframe { time: 0.0, value: Opacity(0.0), timing_function })
+                .with_keyframe(Keyframe { time: 1.0, value: Opacity(1.0), timing_function }),
+        );
+
+        let start_time = Instant::now();
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            PlayOptions::new(start_time, duration),
+        );
+        let start_time =
+            animatable_storage.get_active_animation(Entity::root()).unwrap().start_time;
+
+        let mut values = Vec::new();
+        for i in 0..100 {
+            animatable_storage.tick(start_time + duration.mul_f32(i as f32 / 100.0));
+            let value = animatable_storage.get(Entity::root()).unwrap().0;
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        assert_eq!(values, vec![0.0, 0.25, 0.5, 0.75]);
+    }
+
+    /// Test that an animation played from the current value continues from the output of the interrupted animation.
+    #[test]
+    fn play_from_current_value() {
+        use crate::animation::{Keyframe, TimingFunction};
+
+        let timing_function = TimingFunction::linear();
+        let fade_in = Animation::new(0, 0);
+        let fade_out = Animation::new(1, 0);
+        let duration = Duration::from_secs(1);
+
+        let mut animatable_storage = AnimatableSet::<Opacity>::default();
+        animatable_storage.insert_animation(
+            fade_in,
+            AnimationState::new(fade_in)
+                .with_keyframe(Keyframe { time: 0.0, value: Opacity(0.0), timing_function })
+                .with_keyframe(Keyframe { time: 1.0, value: Opacity(1.0), timing_function }),
+        );
+        animatable_storage.insert_animation(
+            fade_out,
+            AnimationState::new(fade_out)
+                .with_keyframe(Keyframe { time: 0.0, value: Opacity(1.0), timing_function })
+                .with_keyframe(Keyframe { time: 1.0, value: Opacity(0.0), timing_function }),
+        );
+
+        animatable_storage.play_animation(
+            Entity::root(),
+            fade_in,
+            PlayOptions::new(Instant::now(), duration),
+        );
+        let start_time =
+            animatable_storage.get_active_animation(Entity::root()).unwrap().start_time;
+        animatable_storage.tick(start_time + duration / 2);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.5)));
+
+        animatable_storage.play_animation(
+            Entity::root(),
+            fade_out,
+            PlayOptions { from_current: true, ..PlayOptions::new(Instant::now(), duration) },
+        );
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.5)));
+
+        let start_time =
+            animatable_storage.get_active_animation(Entity::root()).unwrap().start_time;
+        animatable_storage.tick(start_time + duration / 2);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.25)));
+    }
+
+    /// Test that playing from the current value keeps a first keyframe which is not at the start of the animation.
+    #[test]
+    fn play_from_current_value_keeps_first_keyframe() {
+        use crate::animation::{Keyframe, TimingFunction};
+
+        let timing_function = TimingFunction::linear();
+        let animation = Animation::new(0, 0);
+        let duration = Duration::from_secs(1);
+
+        let mut animatable_storage = AnimatableSet::<Opacity>::default();
+        animatable_storage.insert(Entity::root(), Opacity(0.0));
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(Keyframe { time: 0.5, value: Opacity(1.0), timing_function })
+                .with_keyframe(Keyframe { time: 1.0, value: Opacity(0.5), timing_function }),
+        );
+
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            PlayOptions { from_current: true, ..PlayOptions::new(Instant::now(), duration) },
+        );
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.0)));
+
+        let start_time =
+            animatable_storage.get_active_animation(Entity::root()).unwrap().start_time;
+        animatable_storage.tick(start_time + duration / 4);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.5)));
+        animatable_storage.tick(start_time + duration / 2);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(1.0)));
+    }
+
+    /// Test that stopping an animation returns the entity to its inline value.
+    #[test]
+    fn stop_animation() {
+        use crate::animation::{Keyframe, TimingFunction};
+
+        let timing_function = TimingFunction::linear();
+        let animation = Animation::new(0, 0);
+
+        let mut animatable_storage = AnimatableSet::<Opacity>::default();
+        animatable_storage.insert(Entity::root(), Opacity(0.5));
+        animatable_storage.insert_animation(
+            animation,
+            AnimationState::new(animation)
+                .with_keyframe(Keyframe { time: 0.0, value: Opacity(0.0), timing_function })
+                .with_keyframe(Keyframe { time: 1.0, value: Opacity(1.0), timing_function }),
+        );
+
+        animatable_storage.play_animation(
+            Entity::root(),
+            animation,
+            PlayOptions::new(Instant::now(), Duration::from_secs(1)),
+        );
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.0)));
+
+        // Keyframe animations are not stopped when stopping transitions.
+        assert!(!animatable_storage.stop_animation(Entity::root(), true));
+        assert!(animatable_storage.stop_animation(Entity::root(), false));
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&Opacity(0.5)));
+
+        animatable_storage.tick(Instant::now());
+        assert!(!animatable_storage.has_animations());
+    }
+
+    /// Test that a view being hidden with `allow-discrete` stays displayed until the end of the transition.
+    #[test]
+    fn tick_allow_discrete() {
+        use crate::animation::{Keyframe, TimingFunction};
+
+        let timing_function = TimingFunction::linear();
+        let duration = Duration::from_secs(1);
+
+        let display_at = |allow_discrete: bool, t: f32| {
+            let animation = Animation::new(0, 0);
+            let mut animatable_storage = AnimatableSet::<Display>::default();
+            animatable_storage.insert_animation(
+                animation,
+                AnimationState::new(animation)
+                    .with_allow_discrete(allow_discrete)
+                    .with_keyframe(Keyframe { time: 0.0, value: Display::Flex, timing_function })
+                    .with_keyframe(Keyframe { time: 1.0, value: Display::None, timing_function }),
+            );
+
+            animatable_storage.play_animation(
+                Entity::root(),
+                animation,
+                PlayOptions::new(Instant::now(), duration),
+            );
+            let start_time =
+                animatable_storage.get_active_animation(Entity::root()).unwrap().start_time;
+            animatable_storage.tick(start_time + duration.mul_f32(t));
+            *animatable_storage.get(Entity::root()).unwrap()
+        };
+
+        assert_eq!(display_at(false, 0.75), Display::None);
+        assert_eq!(display_at(true, 0.75), Display::Flex);
+        assert_eq!(display_at(true, 1.0), Display::None);
+    }
 }
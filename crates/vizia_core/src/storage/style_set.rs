@@ -147,7 +147,7 @@ where
             let parent_sparse_index = self.inline_data.sparse[parent_index];
 
             if parent_sparse_index.data_index.is_inline()
-                && parent_sparse_index.data_index.index() < self.inline_data.dense.len()
+                && parent_sparse_index.data_index.index() < self.inline_data.len()
             {
                 if entity_index >= self.inline_data.sparse.len() {
                     self.inline_data.sparse.resize(entity_index + 1, Index::null());
@@ -158,7 +158,7 @@ where
                 if self.inline_data.sparse[entity_index].data_index.index()
                     != parent_sparse_index.data_index.index()
                 {
-                    if entity_sparse_index.data_index.index() < self.inline_data.dense.len() {
+                    if entity_sparse_index.data_index.index() < self.inline_data.len() {
                         if entity_sparse_index.data_index.is_inherited()
                             && entity_sparse_index.data_index.is_inline()
                         {
@@ -194,7 +194,7 @@ where
             let parent_sparse_index = self.inline_data.sparse[parent_index];
 
             if !parent_sparse_index.data_index.is_inline()
-                && parent_sparse_index.data_index.index() < self.shared_data.dense.len()
+                && parent_sparse_index.data_index.index() < self.shared_data.len()
             {
                 if entity_index >= self.inline_data.sparse.len() {
                     self.inline_data.sparse.resize(entity_index + 1, Index::null());
@@ -206,7 +206,7 @@ where
                     && self.inline_data.sparse[entity_index].data_index.index()
                         != parent_sparse_index.data_index.index()
                 {
-                    if entity_sparse_index.data_index.index() < self.shared_data.dense.len() {
+                    if entity_sparse_index.data_index.index() < self.shared_data.len() {
                         if entity_sparse_index.data_index.is_inherited() {
                             self.inline_data.sparse[entity_index] = Index {
                                 data_index: DataIndex::shared(
@@ -298,11 +298,11 @@ where
         if entity_index < self.inline_data.sparse.len() {
             let data_index = self.inline_data.sparse[entity_index].data_index;
             if data_index.is_inline() {
-                if data_index.index() < self.inline_data.dense.len() {
-                    return Some(&self.inline_data.dense[data_index.index()].value);
+                if data_index.index() < self.inline_data.len() {
+                    return Some(&self.inline_data[data_index.index()]);
                 }
-            } else if data_index.index() < self.shared_data.dense.len() {
-                return Some(&self.shared_data.dense[data_index.index()].value);
+            } else if data_index.index() < self.shared_data.len() {
+                return Some(&self.shared_data[data_index.index()]);
             }
         }
 
@@ -313,8 +313,8 @@ where
         let entity_index = entity.index();
         if entity_index < self.inline_data.sparse.len() {
             let data_index = self.inline_data.sparse[entity_index].data_index;
-            if data_index.is_inline() && data_index.index() < self.inline_data.dense.len() {
-                return Some(&mut self.inline_data.dense[data_index.index()].value);
+            if data_index.is_inline() && data_index.index() < self.inline_data.len() {
+                return Some(&mut self.inline_data[data_index.index()]);
             }
         }
 
@@ -48,14 +48,14 @@
 //! Element::new(cx).class("foo");
 //! ```
 //!
-//! To add an external css stylesheet which is read from a file at runtime, use [`add_stylesheet()`](crate::context::Context::add_stylesheet()) on [`Context`].
+//! To add an external css stylesheet which is read from a file at runtime, pass a [`StylesheetPath`] to [`add_stylesheet()`](crate::context::Context::add_stylesheet()) on [`Context`].
 //! Stylesheets added this way can be hot-reloaded by pressing the F5 key in the application window.
 //!
 //! ```
 //! # use vizia_core::prelude::*;
 //! # let cx = &mut Context::default();
 //!
-//! cx.add_stylesheet("path/to/stylesheet.css");
+//! cx.add_stylesheet(StylesheetPath::new("path/to/stylesheet.css"));
 //!
 //! Element::new(cx).class("foo");
 //! ```
@@ -65,23 +65,24 @@ use indexmap::IndexMap;
 use log::warn;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut, Range};
+use std::sync::{Arc, RwLock};
 use vizia_style::selectors::parser::{AncestorHashes, Selector};
 
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Alignment, Angle, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color,
-    CornerShape, CssRule, CursorIcon, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily, Gradient,
-    HorizontalPosition, HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue,
-    LineClamp, LineDirection, LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position,
-    PositionType, Scale, Shadow, TextAlign, TextDecorationLine, TextDecorationStyle, TextOverflow,
-    TextStroke, TextStrokeStyle, Transform, Transition, Translate, VerticalPosition,
-    VerticalPositionKeyword, Visibility, RGBA,
+    Alignment, Angle, BackgroundImage, BackgroundSize, BlendMode, BorderStyleKeyword, ClipPath,
+    Color, CornerShape, CssRule, CursorIcon, Direction, Display, Filter, FilterFunction,
+    FontFamily, FontSize, FontSlant, FontVariation, FontWeight, FontWeightKeyword, FontWidth,
+    GenericFontFamily, Gradient, HorizontalPosition, HorizontalPositionKeyword, Length,
+    LengthOrPercentage, LengthValue, LineClamp, LineDirection, LineHeight, LinearGradient, Matrix,
+    Opacity, Overflow, PointerEvents, Position, PositionType, Scale, Shadow, TextAlign,
+    TextDecorationLine, TextDecorationStyle, TextOverflow, TextStroke, TextStrokeStyle, Transform,
+    Transition, Translate, VerticalPosition, VerticalPositionKeyword, Visibility, RGBA,
 };
 
 use vizia_style::{
-    BlendMode, EasingFunction, KeyframeSelector, ParserOptions, Property, Selectors, StyleSheet,
+    Atom, EasingFunction, KeyframeSelector, ParserOptions, Property, Selectors, StyleSheet,
 };
 
 mod rule;
@@ -94,7 +95,8 @@ mod transform;
 pub(crate) use transform::*;
 
 use crate::animation::{AnimationState, Interpolator, Keyframe, TimingFunction};
-use crate::storage::animatable_set::AnimatableSet;
+use crate::layout::HitTestFn;
+use crate::storage::animatable_set::{AnimatableSet, PlayOptions};
 use crate::storage::style_set::StyleSet;
 use bitflags::bitflags;
 use vizia_id::IdManager;
@@ -114,6 +116,8 @@ bitflags! {
         const NAVIGABLE = 1 << 3;
         // Whether a view can be dragged during a drag and drop.
         const DRAGGABLE = 1 << 4;
+        // Whether a view is a target for data dropped during a drag and drop.
+        const DROPPABLE = 1 << 5;
     }
 }
 
@@ -123,6 +127,17 @@ impl Default for Abilities {
     }
 }
 
+/// The views which are focused before and after a view during keyboard navigation, overriding the tree order.
+///
+/// Either view can be [`Entity::null()`] to keep the tree order in that direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusOrder {
+    /// The view focused when navigating backward from the view.
+    pub prev: Entity,
+    /// The view focused when navigating forward from the view.
+    pub next: Entity,
+}
+
 bitflags! {
     pub(crate) struct SystemFlags: u8 {
         /// Layout system flag.
@@ -194,16 +209,40 @@ impl DerefMut for Bloom {
     }
 }
 
+/// The windows a style rule applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RuleScope {
+    /// The rule applies to every window.
+    All,
+    /// The rule applies only to the views within the given window.
+    Window(Entity),
+    /// The rule applies to every window except the given windows, which replace the rule with their own theme.
+    AllExcept(Vec<Entity>),
+}
+
+impl RuleScope {
+    /// Returns true if the rule applies to the views within the given window.
+    pub(crate) fn contains(&self, window: Entity) -> bool {
+        match self {
+            RuleScope::All => true,
+            RuleScope::Window(scope) => *scope == window,
+            RuleScope::AllExcept(excluded) => !excluded.contains(&window),
+        }
+    }
+}
+
 pub(crate) struct StyleRule {
     pub(crate) selector: Selector<Selectors>,
     /// The ancestor hashes associated with the selector.
     pub(crate) hashes: AncestorHashes,
+    /// The windows the rule applies to.
+    pub(crate) scope: RuleScope,
 }
 
 impl StyleRule {
-    pub(crate) fn new(selector: Selector<Selectors>) -> Self {
+    pub(crate) fn new(selector: Selector<Selectors>, scope: RuleScope) -> Self {
         let hashes = AncestorHashes::new(&selector, vizia_style::QuirksMode::NoQuirks);
-        Self { selector, hashes }
+        Self { selector, hashes, scope }
     }
 }
 
@@ -217,19 +256,27 @@ pub struct Style {
     pub(crate) animations: HashMap<String, Animation>,
     // List of animations to be started on the next frame
     pub(crate) pending_animations: Vec<(Entity, Animation, Duration, Duration)>,
+    // Animations which start from the current value of each animated property rather than their first keyframe
+    pub(crate) from_current_animations: HashSet<Animation>,
+    // The maximum time by which each animation with a capped time step advances per frame
+    pub(crate) animation_time_steps: HashMap<Animation, Duration>,
 
     // List of rules
     pub(crate) rules: IndexMap<Rule, StyleRule>,
 
     pub(crate) default_font: Vec<FamilyOwned>,
 
+    // The locale of the application, matched by the `:lang()` pseudo-class
+    pub(crate) locale: LanguageIdentifier,
+
     // CSS Selector Properties
-    pub(crate) element: SparseSet<u32>,
-    pub(crate) ids: SparseSet<String>,
-    pub(crate) classes: SparseSet<HashSet<String>>,
+    pub(crate) element: SparseSet<Atom>,
+    pub(crate) ids: SparseSet<Atom>,
+    pub(crate) classes: SparseSet<HashSet<Atom>>,
     pub(crate) pseudo_classes: SparseSet<PseudoClassFlags>,
     pub(crate) disabled: StyleSet<bool>,
     pub(crate) abilities: SparseSet<Abilities>,
+    pub(crate) focus_order: SparseSet<FocusOrder>,
 
     // Accessibility Properties
     pub(crate) name: StyleSet<String>,
@@ -241,7 +288,7 @@ pub struct Style {
     pub(crate) numeric_value: SparseSet<f64>,
 
     // Visibility
-    pub(crate) visibility: StyleSet<Visibility>,
+    pub(crate) visibility: AnimatableSet<Visibility>,
 
     // Opacity
     pub(crate) opacity: AnimatableSet<Opacity>,
@@ -257,10 +304,15 @@ pub struct Style {
     pub(crate) overflowy: StyleSet<Overflow>,
 
     // Filters
-    pub(crate) backdrop_filter: AnimatableSet<Filter>,
+    pub(crate) backdrop_filter: AnimatableSet<Vec<FilterFunction>>,
+    pub(crate) filter: AnimatableSet<Vec<FilterFunction>>,
 
     pub(crate) blend_mode: StyleSet<BlendMode>,
 
+    pub(crate) cache_layer: SparseSet<bool>,
+
+    pub(crate) hit_test: SparseSet<HitTestFn>,
+
     // Transform
     pub(crate) transform: AnimatableSet<Vec<Transform>>,
     pub(crate) transform_origin: AnimatableSet<Translate>,
@@ -310,9 +362,13 @@ pub struct Style {
     pub(crate) text_overflow: StyleSet<TextOverflow>,
     pub(crate) line_clamp: StyleSet<LineClamp>,
     pub(crate) text_align: StyleSet<TextAlign>,
+    pub(crate) direction: StyleSet<Direction>,
     pub(crate) text_decoration_line: StyleSet<TextDecorationLine>,
     pub(crate) text_stroke_width: StyleSet<Length>,
     pub(crate) text_stroke_style: StyleSet<TextStrokeStyle>,
+    pub(crate) letter_spacing: StyleSet<Length>,
+    pub(crate) word_spacing: StyleSet<Length>,
+    pub(crate) line_height: StyleSet<LineHeight>,
     pub(crate) underline_style: StyleSet<TextDecorationLine>,
     pub(crate) overline_style: StyleSet<TextDecorationStyle>,
     pub(crate) strikethrough_style: StyleSet<TextDecorationStyle>,
@@ -451,6 +507,10 @@ impl Style {
                     insert_keyframe(&mut self.display, animation_id, time, *value);
                 }
 
+                Property::Visibility(value) => {
+                    insert_keyframe(&mut self.visibility, animation_id, time, *value);
+                }
+
                 Property::Opacity(value) => {
                     insert_keyframe(&mut self.opacity, animation_id, time, *value);
                 }
@@ -459,6 +519,14 @@ impl Style {
                     insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
                 }
 
+                Property::BackdropFilter(value) => {
+                    insert_keyframe(&mut self.backdrop_filter, animation_id, time, value.clone());
+                }
+
+                Property::Filter(value) => {
+                    insert_keyframe(&mut self.filter, animation_id, time, value.clone());
+                }
+
                 // TRANSFORM
                 Property::Transform(value) => {
                     insert_keyframe(&mut self.transform, animation_id, time, value.clone());
@@ -717,6 +785,14 @@ impl Style {
             self.add_keyframe(animation_id, keyframe.time, &keyframe.properties);
         }
 
+        if animation.from_current_value {
+            self.from_current_animations.insert(animation_id);
+        }
+
+        if let Some(max_time_step) = animation.max_time_step {
+            self.animation_time_steps.insert(animation_id, max_time_step);
+        }
+
         animation_id
     }
 
@@ -731,12 +807,18 @@ impl Style {
     }
 
     pub(crate) fn play_pending_animations(&mut self) {
-        let start_time = Instant::now();
+        let start_time = crate::util::now();
 
         let pending_animations = self.pending_animations.drain(..).collect::<Vec<_>>();
 
         for (entity, animation, duration, delay) in pending_animations {
-            self.play_animation(entity, animation, start_time + delay, duration, delay)
+            let options = PlayOptions {
+                delay,
+                from_current: self.from_current_animations.contains(&animation),
+                max_time_step: self.animation_time_steps.get(&animation).copied(),
+                ..PlayOptions::new(start_time + delay, duration)
+            };
+            self.play_animation(entity, animation, options)
         }
     }
 
@@ -744,77 +826,141 @@ impl Style {
         &mut self,
         entity: Entity,
         animation: Animation,
-        start_time: Instant,
-        duration: Duration,
-        delay: Duration,
+        options: PlayOptions,
     ) {
-        self.display.play_animation(entity, animation, start_time, duration, delay);
-        self.opacity.play_animation(entity, animation, start_time, duration, delay);
-        self.clip_path.play_animation(entity, animation, start_time, duration, delay);
-
-        self.transform.play_animation(entity, animation, start_time, duration, delay);
-        self.transform_origin.play_animation(entity, animation, start_time, duration, delay);
-        self.translate.play_animation(entity, animation, start_time, duration, delay);
-        self.rotate.play_animation(entity, animation, start_time, duration, delay);
-        self.scale.play_animation(entity, animation, start_time, duration, delay);
-
-        self.border_width.play_animation(entity, animation, start_time, duration, delay);
-        self.border_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.corner_top_left_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_top_right_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_bottom_left_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-        self.corner_bottom_right_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-
-        self.outline_width.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_color.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_offset.play_animation(entity, animation, start_time, duration, delay);
-
-        self.background_color.play_animation(entity, animation, start_time, duration, delay);
-        self.background_image.play_animation(entity, animation, start_time, duration, delay);
-        self.background_size.play_animation(entity, animation, start_time, duration, delay);
-
-        self.shadow.play_animation(entity, animation, start_time, duration, delay);
-
-        self.font_color.play_animation(entity, animation, start_time, duration, delay);
-        self.font_size.play_animation(entity, animation, start_time, duration, delay);
-        self.caret_color.play_animation(entity, animation, start_time, duration, delay);
-        self.selection_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.left.play_animation(entity, animation, start_time, duration, delay);
-        self.right.play_animation(entity, animation, start_time, duration, delay);
-        self.top.play_animation(entity, animation, start_time, duration, delay);
-        self.bottom.play_animation(entity, animation, start_time, duration, delay);
-
-        self.padding_left.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_right.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_top.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_bottom.play_animation(entity, animation, start_time, duration, delay);
-        self.horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-
-        self.width.play_animation(entity, animation, start_time, duration, delay);
-        self.height.play_animation(entity, animation, start_time, duration, delay);
-
-        self.min_width.play_animation(entity, animation, start_time, duration, delay);
-        self.max_width.play_animation(entity, animation, start_time, duration, delay);
-        self.min_height.play_animation(entity, animation, start_time, duration, delay);
-        self.max_height.play_animation(entity, animation, start_time, duration, delay);
-
-        self.min_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.min_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-
-        self.underline_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.fill.play_animation(entity, animation, start_time, duration, delay);
+        self.display.play_animation(entity, animation, options);
+        self.visibility.play_animation(entity, animation, options);
+        self.opacity.play_animation(entity, animation, options);
+        self.clip_path.play_animation(entity, animation, options);
+
+        self.transform.play_animation(entity, animation, options);
+        self.transform_origin.play_animation(entity, animation, options);
+        self.translate.play_animation(entity, animation, options);
+        self.rotate.play_animation(entity, animation, options);
+        self.scale.play_animation(entity, animation, options);
+
+        self.border_width.play_animation(entity, animation, options);
+        self.border_color.play_animation(entity, animation, options);
+
+        self.corner_top_left_radius.play_animation(entity, animation, options);
+        self.corner_top_right_radius.play_animation(entity, animation, options);
+        self.corner_bottom_left_radius.play_animation(entity, animation, options);
+        self.corner_bottom_right_radius.play_animation(entity, animation, options);
+
+        self.outline_width.play_animation(entity, animation, options);
+        self.outline_color.play_animation(entity, animation, options);
+        self.outline_offset.play_animation(entity, animation, options);
+
+        self.background_color.play_animation(entity, animation, options);
+        self.background_image.play_animation(entity, animation, options);
+        self.background_size.play_animation(entity, animation, options);
+
+        self.shadow.play_animation(entity, animation, options);
+
+        self.font_color.play_animation(entity, animation, options);
+        self.font_size.play_animation(entity, animation, options);
+        self.caret_color.play_animation(entity, animation, options);
+        self.selection_color.play_animation(entity, animation, options);
+
+        self.left.play_animation(entity, animation, options);
+        self.right.play_animation(entity, animation, options);
+        self.top.play_animation(entity, animation, options);
+        self.bottom.play_animation(entity, animation, options);
+
+        self.padding_left.play_animation(entity, animation, options);
+        self.padding_right.play_animation(entity, animation, options);
+        self.padding_top.play_animation(entity, animation, options);
+        self.padding_bottom.play_animation(entity, animation, options);
+        self.horizontal_gap.play_animation(entity, animation, options);
+        self.vertical_gap.play_animation(entity, animation, options);
+
+        self.width.play_animation(entity, animation, options);
+        self.height.play_animation(entity, animation, options);
+
+        self.min_width.play_animation(entity, animation, options);
+        self.max_width.play_animation(entity, animation, options);
+        self.min_height.play_animation(entity, animation, options);
+        self.max_height.play_animation(entity, animation, options);
+
+        self.min_horizontal_gap.play_animation(entity, animation, options);
+        self.max_horizontal_gap.play_animation(entity, animation, options);
+        self.min_vertical_gap.play_animation(entity, animation, options);
+        self.max_vertical_gap.play_animation(entity, animation, options);
+
+        self.underline_color.play_animation(entity, animation, options);
+
+        self.fill.play_animation(entity, animation, options);
+    }
+
+    // Stops the animations playing on the entity, or its transitions if `transition` is true.
+    pub(crate) fn stop_animations(&mut self, entity: Entity, transition: bool) {
+        if !transition {
+            self.pending_animations.retain(|(pending_entity, ..)| *pending_entity != entity);
+        }
+
+        self.display.stop_animation(entity, transition);
+        self.visibility.stop_animation(entity, transition);
+        self.opacity.stop_animation(entity, transition);
+        self.clip_path.stop_animation(entity, transition);
+        self.transform.stop_animation(entity, transition);
+        self.transform_origin.stop_animation(entity, transition);
+        self.translate.stop_animation(entity, transition);
+        self.rotate.stop_animation(entity, transition);
+        self.scale.stop_animation(entity, transition);
+        self.border_width.stop_animation(entity, transition);
+        self.border_color.stop_animation(entity, transition);
+        self.corner_top_left_radius.stop_animation(entity, transition);
+        self.corner_top_right_radius.stop_animation(entity, transition);
+        self.corner_bottom_left_radius.stop_animation(entity, transition);
+        self.corner_bottom_right_radius.stop_animation(entity, transition);
+        self.outline_width.stop_animation(entity, transition);
+        self.outline_color.stop_animation(entity, transition);
+        self.outline_offset.stop_animation(entity, transition);
+        self.background_color.stop_animation(entity, transition);
+        self.background_image.stop_animation(entity, transition);
+        self.background_size.stop_animation(entity, transition);
+        self.shadow.stop_animation(entity, transition);
+        self.font_color.stop_animation(entity, transition);
+        self.font_size.stop_animation(entity, transition);
+        self.caret_color.stop_animation(entity, transition);
+        self.selection_color.stop_animation(entity, transition);
+        self.left.stop_animation(entity, transition);
+        self.right.stop_animation(entity, transition);
+        self.top.stop_animation(entity, transition);
+        self.bottom.stop_animation(entity, transition);
+        self.padding_left.stop_animation(entity, transition);
+        self.padding_right.stop_animation(entity, transition);
+        self.padding_top.stop_animation(entity, transition);
+        self.padding_bottom.stop_animation(entity, transition);
+        self.horizontal_gap.stop_animation(entity, transition);
+        self.vertical_gap.stop_animation(entity, transition);
+        self.width.stop_animation(entity, transition);
+        self.height.stop_animation(entity, transition);
+        self.min_width.stop_animation(entity, transition);
+        self.max_width.stop_animation(entity, transition);
+        self.min_height.stop_animation(entity, transition);
+        self.max_height.stop_animation(entity, transition);
+        self.min_horizontal_gap.stop_animation(entity, transition);
+        self.max_horizontal_gap.stop_animation(entity, transition);
+        self.min_vertical_gap.stop_animation(entity, transition);
+        self.max_vertical_gap.stop_animation(entity, transition);
+        self.underline_color.stop_animation(entity, transition);
+        self.fill.stop_animation(entity, transition);
+        self.backdrop_filter.stop_animation(entity, transition);
+        self.filter.stop_animation(entity, transition);
+        self.corner_top_left_smoothing.stop_animation(entity, transition);
+        self.corner_top_right_smoothing.stop_animation(entity, transition);
+        self.corner_bottom_left_smoothing.stop_animation(entity, transition);
+        self.corner_bottom_right_smoothing.stop_animation(entity, transition);
+        self.overline_color.stop_animation(entity, transition);
+        self.strikethrough_color.stop_animation(entity, transition);
+        self.vertical_scroll.stop_animation(entity, transition);
+        self.horizontal_scroll.stop_animation(entity, transition);
     }
 
     pub(crate) fn is_animating(&self, entity: Entity, animation: Animation) -> bool {
         self.display.has_active_animation(entity, animation)
+            | self.visibility.has_active_animation(entity, animation)
             | self.opacity.has_active_animation(entity, animation)
             | self.clip_path.has_active_animation(entity, animation)
             | self.transform.has_active_animation(entity, animation)
@@ -863,8 +1009,32 @@ impl Style {
             | self.fill.has_active_animation(entity, animation)
     }
 
-    pub(crate) fn parse_theme(&mut self, stylesheet: &str) {
-        if let Ok(stylesheet) = StyleSheet::parse(stylesheet, ParserOptions::new()) {
+    /// Parses a stylesheet and adds its rules and animations, returning the invalid rules and declarations which
+    /// were skipped.
+    pub(crate) fn parse_theme(&mut self, stylesheet: &str, scope: RuleScope) -> Vec<String> {
+        let warnings = Arc::new(RwLock::new(Vec::new()));
+        let mut options = ParserOptions::new();
+        options.warnings = Some(warnings.clone());
+        let parsed = StyleSheet::parse(stylesheet, options);
+
+        let mut warnings = warnings
+            .read()
+            .map(|warnings| {
+                warnings
+                    .iter()
+                    .map(|warning| match &warning.location {
+                        Some(location) => format!("{:?} at {}", warning.kind, location),
+                        None => format!("{:?}", warning.kind),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if let Err(error) = &parsed {
+            warnings.push(format!("{:?}", error.kind));
+        }
+
+        if let Ok(stylesheet) = parsed {
             let rules = stylesheet.rules.0;
 
             for rule in rules {
@@ -889,7 +1059,8 @@ impl Style {
                                 }
                             }
 
-                            self.rules.insert(rule_id, StyleRule::new(selector.clone()));
+                            self.rules
+                                .insert(rule_id, StyleRule::new(selector.clone(), scope.clone()));
                         }
                     }
 
@@ -922,9 +1093,9 @@ impl Style {
                     _ => {}
                 }
             }
-        } else {
-            println!("Failed to parse stylesheet");
         }
+
+        warnings
     }
 
     fn insert_transition(&mut self, rule_id: Rule, transition: &Transition) {
@@ -935,6 +1106,11 @@ impl Style {
                 self.display.insert_transition(rule_id, animation);
             }
 
+            "visibility" => {
+                self.visibility.insert_animation(animation, self.add_transition(transition));
+                self.visibility.insert_transition(rule_id, animation);
+            }
+
             "opacity" => {
                 self.opacity.insert_animation(animation, self.add_transition(transition));
                 self.opacity.insert_transition(rule_id, animation);
@@ -945,6 +1121,16 @@ impl Style {
                 self.clip_path.insert_transition(rule_id, animation);
             }
 
+            "backdrop-filter" => {
+                self.backdrop_filter.insert_animation(animation, self.add_transition(transition));
+                self.backdrop_filter.insert_transition(rule_id, animation);
+            }
+
+            "filter" => {
+                self.filter.insert_animation(animation, self.add_transition(transition));
+                self.filter.insert_transition(rule_id, animation);
+            }
+
             "transform" => {
                 self.transform.insert_animation(animation, self.add_transition(transition));
                 self.transform.insert_transition(rule_id, animation);
@@ -1238,6 +1424,10 @@ impl Style {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
 
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             // Blend Mode
             Property::BlendMode(blend_mode) => {
                 self.blend_mode.insert_rule(rule_id, blend_mode);
@@ -1617,6 +1807,11 @@ impl Style {
                 self.text_align.insert_rule(rule_id, text_align);
             }
 
+            // Text Direction
+            Property::Direction(direction) => {
+                self.direction.insert_rule(rule_id, direction);
+            }
+
             // Box Shadows
             Property::Shadow(shadows) => {
                 self.shadow.insert_rule(rule_id, shadows);
@@ -1659,6 +1854,15 @@ impl Style {
             Property::TextStrokeStyle(stroke_style) => {
                 self.text_stroke_style.insert_rule(rule_id, stroke_style);
             }
+            Property::LetterSpacing(letter_spacing) => {
+                self.letter_spacing.insert_rule(rule_id, letter_spacing);
+            }
+            Property::WordSpacing(word_spacing) => {
+                self.word_spacing.insert_rule(rule_id, word_spacing);
+            }
+            Property::LineHeight(line_height) => {
+                self.line_height.insert_rule(rule_id, line_height);
+            }
             Property::Fill(fill) => {
                 self.fill.insert_rule(rule_id, fill);
             }
@@ -1680,12 +1884,14 @@ impl Style {
                 EasingFunction::EaseOut => TimingFunction::ease_out(),
                 EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
                 EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+                EasingFunction::Steps(count, position) => TimingFunction::steps(count, position),
             })
             .unwrap_or_default();
 
         AnimationState::new(Animation::null())
             .with_duration(transition.duration)
             .with_delay(transition.delay.unwrap_or_default())
+            .with_allow_discrete(transition.allow_discrete)
             .with_keyframe(Keyframe { time: 0.0, value: Default::default(), timing_function })
             .with_keyframe(Keyframe { time: 1.0, value: Default::default(), timing_function })
     }
@@ -1707,6 +1913,7 @@ impl Style {
         self.pseudo_classes.remove(entity);
         self.disabled.remove(entity);
         self.abilities.remove(entity);
+        self.focus_order.remove(entity);
 
         self.name.remove(entity);
         self.role.remove(entity);
@@ -1731,12 +1938,19 @@ impl Style {
         self.overflowx.remove(entity);
         self.overflowy.remove(entity);
 
-        // Backdrop Filter
+        // Filters
         self.backdrop_filter.remove(entity);
+        self.filter.remove(entity);
 
         // Blend Mode
         self.blend_mode.remove(entity);
 
+        // Cache Layer
+        self.cache_layer.remove(entity);
+
+        // Hit Test
+        self.hit_test.remove(entity);
+
         // Transform
         self.transform.remove(entity);
         self.transform_origin.remove(entity);
@@ -1786,6 +2000,7 @@ impl Style {
         self.text_overflow.remove(entity);
         self.line_clamp.remove(entity);
         self.text_align.remove(entity);
+        self.direction.remove(entity);
         self.font_family.remove(entity);
         self.font_color.remove(entity);
         self.font_size.remove(entity);
@@ -1798,6 +2013,9 @@ impl Style {
         self.text_decoration_line.remove(entity);
         self.text_stroke_width.remove(entity);
         self.text_stroke_style.remove(entity);
+        self.letter_spacing.remove(entity);
+        self.word_spacing.remove(entity);
+        self.line_height.remove(entity);
 
         // Cursor
         self.cursor.remove(entity);
@@ -1893,8 +2111,9 @@ impl Style {
         // Clipping
         self.clip_path.clear_rules();
 
-        // Backdrop Filer
+        // Filters
         self.backdrop_filter.clear_rules();
+        self.filter.clear_rules();
 
         // Blend Mode
         self.blend_mode.clear_rules();
@@ -1986,6 +2205,7 @@ impl Style {
         self.text_overflow.clear_rules();
         self.line_clamp.clear_rules();
         self.text_align.clear_rules();
+        self.direction.clear_rules();
         self.font_family.clear_rules();
         self.font_weight.clear_rules();
         self.font_slant.clear_rules();
@@ -1997,6 +2217,9 @@ impl Style {
         self.text_decoration_line.clear_rules();
         self.text_stroke_width.clear_rules();
         self.text_stroke_style.clear_rules();
+        self.letter_spacing.clear_rules();
+        self.word_spacing.clear_rules();
+        self.line_height.clear_rules();
 
         self.cursor.clear_rules();
 
@@ -29,6 +29,8 @@ bitflags! {
         const OPTIONAL = 1 << 18;
         const USER_VALID = 1 << 19;
         const USER_INVALID = 1 << 20;
+        const LOADING = 1 << 21;
+        const DRAG_OVER = 1 << 22;
     }
 }
 
@@ -5,7 +5,7 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
 
     // Tick all animations
 
-    let time = Instant::now();
+    let time = crate::util::now();
 
     let mut redraw_entities = Vec::new();
     let mut reflow_entities = Vec::new();
@@ -49,6 +49,7 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
 
     // Properties which affect layout
     relayout_entities.extend(cx.style.display.tick(time));
+    relayout_entities.extend(cx.style.visibility.tick(time));
     // Border Width
     relayout_entities.extend(cx.style.border_width.tick(time));
     // Space
@@ -1,12 +1,17 @@
-use crate::{animation::Interpolator, cache::CachedData, prelude::*};
+use crate::{
+    animation::Interpolator,
+    cache::{CachedData, CachedLayer},
+    context::{InternalEvent, PanickedView},
+    events::ViewHandler,
+    prelude::*,
+};
 use morphorm::Node;
 use skia_safe::{
-    canvas::SaveLayerRec, ClipOp, ImageFilter, Matrix, Paint, Rect, SamplingOptions, Surface,
+    canvas::SaveLayerRec, color_filters, image_filters, ClipOp, ColorFilter, EncodedImageFormat,
+    IRect, IVector, Image, ImageFilter, Matrix, Paint, PictureRecorder, Rect, SamplingOptions,
+    Surface,
 };
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
 use vizia_storage::{DrawChildIterator, LayoutTreeIterator};
-use vizia_style::BlendMode;
 
 pub(crate) fn transform_system(cx: &mut Context) {
     let iter = LayoutTreeIterator::full(&cx.tree);
@@ -162,7 +167,31 @@ pub(crate) fn draw_system(
     //     return false;
     // }
 
+    for layer in cx.cache.layers.iter_mut() {
+        layer.used = false;
+    }
+
     for &entity in &redraw_list {
+        // Views within a cached layer can only be redrawn by drawing the whole layer again.
+        for ancestor in entity.parent_iter(&cx.tree) {
+            if let Some(layer) = cx.cache.layers.get_mut(ancestor) {
+                layer.valid = false;
+
+                let rect = layer.rect.with_offset(layer.offset);
+                let layer_bounds = BoundingBox::from_min_max(
+                    rect.left as f32,
+                    rect.top as f32,
+                    rect.right as f32,
+                    rect.bottom as f32,
+                );
+                if let Some(dr) = &mut dirty_rect {
+                    *dr = dr.union(&layer_bounds);
+                } else {
+                    dirty_rect = Some(layer_bounds);
+                }
+            }
+        }
+
         // Skip binding views
         if cx.tree.is_ignored(entity) {
             continue;
@@ -212,57 +241,135 @@ pub(crate) fn draw_system(
 
     cx.resource_manager.mark_images_unused();
 
-    let mut queue = BinaryHeap::new();
-    queue.push(ZEntity { index: 0, entity: window_entity, visible: true });
+    let mut queue = StackingQueue::new(window_entity, true);
 
-    while let Some(zentity) = queue.pop() {
+    let mut panics = Vec::new();
+    while let Some(context) = queue.pop() {
         canvas.save();
-        draw_entity(
-            &mut DrawContext {
-                current: zentity.entity,
-                style: &cx.style,
-                cache: &mut cx.cache,
-                tree: &cx.tree,
-                models: &cx.models,
-                views: &mut cx.views,
-                resource_manager: &cx.resource_manager,
-                text_context: &mut cx.text_context,
-                modifiers: &cx.modifiers,
-                mouse: &cx.mouse,
-                windows: &mut cx.windows,
-            },
-            &dirty_rect,
-            canvas,
-            zentity.index,
-            &mut queue,
-            zentity.visible,
-        );
+        let mut draw_cx = DrawContext {
+            current: context.entity,
+            style: &cx.style,
+            cache: &mut cx.cache,
+            tree: &cx.tree,
+            models: &cx.models,
+            views: &mut cx.views,
+            resource_manager: &cx.resource_manager,
+            text_context: &mut cx.text_context,
+            modifiers: &cx.modifiers,
+            mouse: &cx.mouse,
+            windows: &mut cx.windows,
+            dirty_rect: BoundingBox::default(),
+            panic_handling: &cx.panic_handling,
+            panics: Vec::new(),
+        };
+        draw_entity(&mut draw_cx, &dirty_rect, canvas, &context.order, &mut queue, context.data);
+        panics.append(&mut draw_cx.panics);
         canvas.restore();
     }
 
+    // Views which panicked while being drawn are replaced once the frame has been drawn.
+    for panic in panics {
+        let entity = panic.entity;
+        cx.event_queue.push_back(Event::new(InternalEvent::ViewPanicked(panic)).direct(entity));
+    }
+
     canvas.restore();
 
+    // Drop the layers of views in this window which were not drawn, e.g. because they are no longer displayed.
+    let unused_layers = cx
+        .cache
+        .layers
+        .iter()
+        .filter(|layer| {
+            !layer.used
+                && cx.tree.get_parent_window(layer.entity).unwrap_or(Entity::root())
+                    == window_entity
+        })
+        .map(|layer| layer.entity)
+        .collect::<Vec<_>>();
+    for entity in unused_layers {
+        cx.cache.layers.remove(entity);
+    }
+
     surface.canvas().clear(Color::transparent());
     dirty_surface.draw(surface.canvas(), (0, 0), SamplingOptions::default(), None);
 
-    // Debug draw dirty rect
-    // if let Some(rect) = dirty_rect.map(Rect::from) {
-    //     let mut paint = Paint::default();
-    //     paint.set_style(skia_safe::PaintStyle::Stroke);
-    //     paint.set_color(Color::red());
-    //     paint.set_stroke_width(1.0);
-    //     surface.canvas().draw_rect(rect, &paint);
-    // }
+    let window = cx.windows.get_mut(&window_entity).unwrap();
+    window.damage = dirty_rect;
+
+    // Highlight the redrawn region on the presented surface only, so that it is gone after the next redraw.
+    if window.show_damage {
+        if let Some(rect) = dirty_rect.map(Rect::from) {
+            let mut paint = Paint::default();
+            paint.set_color(Color::rgba(255, 0, 0, 48));
+            surface.canvas().draw_rect(rect, &paint);
+            paint.set_style(skia_safe::PaintStyle::Stroke);
+            paint.set_color(Color::red());
+            paint.set_stroke_width(1.0);
+            surface.canvas().draw_rect(rect, &paint);
+        }
+    }
+
+    // Highlight the bounds of a view for debugging tools, such as the inspector.
+    if let Some(entity) = window.highlight {
+        let rect = Rect::from(cx.cache.get_bounds(entity));
+        let mut paint = Paint::default();
+        paint.set_color(Color::rgba(80, 160, 255, 64));
+        surface.canvas().draw_rect(rect, &paint);
+        paint.set_style(skia_safe::PaintStyle::Stroke);
+        paint.set_color(Color::rgb(80, 160, 255));
+        paint.set_stroke_width(1.0);
+        surface.canvas().draw_rect(rect, &paint);
+    }
 
     true
 }
 
+/// Draws an entity and its descendants into a PNG encoded image the size of its draw bounds.
+pub(crate) fn draw_to_png(cx: &mut DrawContext, entity: Entity) -> Option<Vec<u8>> {
+    let bounds = draw_bounds(cx.style, cx.cache, cx.tree, entity);
+    let rect = IRect::from_ltrb(
+        bounds.left().max(0.0).floor() as i32,
+        bounds.top().max(0.0).floor() as i32,
+        bounds.right().ceil() as i32,
+        bounds.bottom().ceil() as i32,
+    );
+
+    if rect.is_empty() {
+        return None;
+    }
+
+    // Views are drawn with their window transforms, so the surface has to extend to the origin of the window.
+    let mut surface = skia_safe::surfaces::raster_n32_premul((rect.right, rect.bottom))?;
+    let canvas = surface.canvas();
+    canvas.clip_irect(rect, ClipOp::Intersect);
+
+    let dirty_rect = Some(bounds);
+    let current = cx.current;
+
+    let mut queue = StackingQueue::new(entity, true);
+
+    while let Some(context) = queue.pop() {
+        cx.current = context.entity;
+        canvas.save();
+        draw_entity(cx, &dirty_rect, canvas, &context.order, &mut queue, context.data);
+        canvas.restore();
+    }
+
+    cx.current = current;
+
+    let image = surface.image_snapshot_with_bounds(rect)?;
+    let data = image.encode(None, EncodedImageFormat::PNG, None)?;
+
+    Some(data.as_bytes().to_vec())
+}
+
 fn draw_entity(
     cx: &mut DrawContext,
     dirty_rect: &Option<BoundingBox>,
     canvas: &Canvas,
-    current_z: i32,
-    queue: &mut BinaryHeap<ZEntity>,
+    stacking_order: &StackingOrder,
+    queue: &mut StackingQueue<bool>,
     visible: bool,
 ) {
     let current = cx.current;
@@ -272,43 +379,183 @@ fn draw_entity(
         return;
     }
 
-    let z_index = cx.z_index();
+    let backdrop_filter = cx.backdrop_filter().filter(|filters| !filters.is_empty());
+    let filter = cx.filter().filter(|filters| !filters.is_empty());
+    let blend_mode = cx.style.blend_mode.get(current).copied().unwrap_or_default();
 
-    if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: current, visible });
-        return;
+    canvas.save();
+    let layer_count = if cx.opacity() != 1.0
+        || backdrop_filter.is_some()
+        || filter.is_some()
+        || blend_mode != BlendMode::Normal
+    {
+        let mut paint = Paint::default();
+        paint.set_alpha_f(cx.opacity());
+        paint.set_blend_mode(blend_mode.into());
+        if let Some(filter) = filter {
+            paint.set_image_filter(image_filter(filter, cx.scale_factor(), None));
+        }
+
+        // The backdrop is cropped to the bounds of the view before it is filtered.
+        let rect: Rect = cx.bounds().into();
+        let backdrop = backdrop_filter.and_then(|filters| {
+            image_filter(filters, cx.scale_factor(), ImageFilter::crop(rect, None, None))
+        });
+
+        let mut slr = SaveLayerRec::default().paint(&paint);
+        if let Some(backdrop) = &backdrop {
+            slr = slr.backdrop(backdrop);
+        }
+
+        Some(canvas.save_layer(&slr))
+    } else {
+        None
+    };
+
+    if cx.style.cache_layer.get(current).copied().unwrap_or_default() {
+        draw_cached_layer(cx, canvas, stacking_order, queue, visible);
+    } else {
+        draw_content(cx, dirty_rect, canvas, stacking_order, queue, visible);
     }
 
-    let backdrop_filter = cx.backdrop_filter();
-    let blend_mode = cx.style.blend_mode.get(current).copied().unwrap_or_default();
+    if let Some(count) = layer_count {
+        canvas.restore_to_count(count);
+    }
+    canvas.restore();
+    cx.current = current;
+}
 
-    canvas.save();
-    let layer_count =
-        if cx.opacity() != 1.0 || backdrop_filter.is_some() || blend_mode != BlendMode::Normal {
-            let mut paint = Paint::default();
-            paint.set_alpha_f(cx.opacity());
-            paint.set_blend_mode(blend_mode.into());
-
-            let rect: Rect = cx.bounds().into();
-            let mut filter = ImageFilter::crop(rect, None, None).unwrap();
-
-            let slr = if let Some(backdrop_filter) = backdrop_filter {
-                match backdrop_filter {
-                    Filter::Blur(radius) => {
-                        let sigma = radius.to_px().unwrap() * cx.scale_factor() / 2.0;
-                        filter = filter.blur(None, (sigma, sigma), None).unwrap();
-                        SaveLayerRec::default().paint(&paint).backdrop(&filter)
-                    }
-                }
-            } else {
-                SaveLayerRec::default().paint(&paint)
-            };
+// Builds an image filter which applies a list of filter functions in order to the output of `input`, or to the content
+// being drawn if there is no input. Lengths are scaled by the scale factor.
+fn image_filter(
+    filters: &[FilterFunction],
+    scale_factor: f32,
+    input: Option<ImageFilter>,
+) -> Option<ImageFilter> {
+    filters.iter().fold(input, |input, filter| {
+        let output = match filter {
+            FilterFunction::Blur(radius) => {
+                let sigma = radius.to_px().unwrap_or_default() * scale_factor / 2.0;
+                image_filters::blur((sigma, sigma), None, input.clone(), None)
+            }
 
-            Some(canvas.save_layer(&slr))
-        } else {
-            None
+            FilterFunction::DropShadow(shadow) => {
+                let offset = (
+                    shadow.x_offset.to_px().unwrap_or_default() * scale_factor,
+                    shadow.y_offset.to_px().unwrap_or_default() * scale_factor,
+                );
+                let blur_radius = shadow.blur_radius.as_ref().and_then(|radius| radius.to_px());
+                let sigma = blur_radius.unwrap_or_default() * scale_factor / 2.0;
+                let color: skia_safe::Color = shadow.color.unwrap_or(Color::black()).into();
+                image_filters::drop_shadow(offset, (sigma, sigma), color, None, input.clone(), None)
+            }
+
+            _ => color_filter(filter).and_then(|color_filter| {
+                image_filters::color_filter(color_filter, input.clone(), None)
+            }),
         };
 
+        // A filter function which can't be built is skipped.
+        output.or(input)
+    })
+}
+
+// Returns a color filter for a filter function which changes the color of each pixel independently, using the color
+// matrices given by the filter effects specification.
+fn color_filter(filter: &FilterFunction) -> Option<ColorFilter> {
+    // The 3x3 matrix applied to the red, green, and blue channels, an offset added to each of them, and a factor the
+    // alpha channel is multiplied by.
+    let (rgb, offset, alpha): ([f32; 9], f32, f32) = match *filter {
+        FilterFunction::Brightness(amount) => (scale_matrix(amount), 0.0, 1.0),
+
+        FilterFunction::Contrast(amount) => (scale_matrix(amount), 0.5 - 0.5 * amount, 1.0),
+
+        FilterFunction::Grayscale(amount) => {
+            let a = 1.0 - amount.clamp(0.0, 1.0);
+            #[rustfmt::skip]
+            let rgb = [
+                0.2126 + 0.7874 * a, 0.7152 - 0.7152 * a, 0.0722 - 0.0722 * a,
+                0.2126 - 0.2126 * a, 0.7152 + 0.2848 * a, 0.0722 - 0.0722 * a,
+                0.2126 - 0.2126 * a, 0.7152 - 0.7152 * a, 0.0722 + 0.9278 * a,
+            ];
+            (rgb, 0.0, 1.0)
+        }
+
+        FilterFunction::HueRotate(angle) => {
+            let (sin, cos) = angle.to_degrees().to_radians().sin_cos();
+            #[rustfmt::skip]
+            let rgb = [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+            ];
+            (rgb, 0.0, 1.0)
+        }
+
+        FilterFunction::Invert(amount) => {
+            let amount = amount.clamp(0.0, 1.0);
+            (scale_matrix(1.0 - 2.0 * amount), amount, 1.0)
+        }
+
+        FilterFunction::Opacity(amount) => (scale_matrix(1.0), 0.0, amount.clamp(0.0, 1.0)),
+
+        FilterFunction::Saturate(amount) => {
+            #[rustfmt::skip]
+            let rgb = [
+                0.213 + 0.787 * amount, 0.715 - 0.715 * amount, 0.072 - 0.072 * amount,
+                0.213 - 0.213 * amount, 0.715 + 0.285 * amount, 0.072 - 0.072 * amount,
+                0.213 - 0.213 * amount, 0.715 - 0.715 * amount, 0.072 + 0.928 * amount,
+            ];
+            (rgb, 0.0, 1.0)
+        }
+
+        FilterFunction::Sepia(amount) => {
+            let a = 1.0 - amount.clamp(0.0, 1.0);
+            #[rustfmt::skip]
+            let rgb = [
+                0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a,
+                0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a,
+                0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a,
+            ];
+            (rgb, 0.0, 1.0)
+        }
+
+        FilterFunction::Blur(_) | FilterFunction::DropShadow(_) => return None,
+    };
+
+    #[rustfmt::skip]
+    let matrix = [
+        rgb[0], rgb[1], rgb[2], 0.0, offset,
+        rgb[3], rgb[4], rgb[5], 0.0, offset,
+        rgb[6], rgb[7], rgb[8], 0.0, offset,
+        0.0, 0.0, 0.0, alpha, 0.0,
+    ];
+
+    Some(color_filters::matrix_row_major(&matrix, None))
+}
+
+// Returns a matrix which multiplies each color channel by the same amount.
+fn scale_matrix(amount: f32) -> [f32; 9] {
+    [amount, 0.0, 0.0, 0.0, amount, 0.0, 0.0, 0.0, amount]
+}
+
+// Draws a view and its descendants, other than those which form a stacking context.
+fn draw_content(
+    cx: &mut DrawContext,
+    dirty_rect: &Option<BoundingBox>,
+    canvas: &Canvas,
+    stacking_order: &StackingOrder,
+    queue: &mut StackingQueue<bool>,
+    visible: bool,
+) {
+    let current = cx.current;
+
     if let Some(transform) = cx.cache.transform.get(current) {
         canvas.set_matrix(&(transform.into()));
     }
@@ -328,8 +575,28 @@ fn draw_entity(
         if let Some(dirty_rect) = dirty_rect {
             let bounds = draw_bounds(cx.style, cx.cache, cx.tree, current);
             if bounds.intersects(dirty_rect) {
+                cx.dirty_rect = *dirty_rect;
                 if let Some(view) = cx.views.remove(&current) {
-                    view.draw(cx, canvas);
+                    #[cfg(feature = "tracing")]
+                    let _span = tracing::trace_span!(
+                        "draw_view",
+                        element = view.element().unwrap_or("view")
+                    )
+                    .entered();
+                    let (panic_handling, tree) = (cx.panic_handling, cx.tree);
+                    let save_count = canvas.save_count();
+                    // A view whose content was replaced after it panicked is drawn as a plain view.
+                    let drawn: &dyn ViewHandler = if panic_handling.panicked.contains(&current) {
+                        &PanickedView
+                    } else {
+                        view.as_ref()
+                    };
+                    if let Err(panic) =
+                        panic_handling.catch(tree, current, || drawn.draw(cx, canvas))
+                    {
+                        canvas.restore_to_count(save_count);
+                        cx.panics.push(panic);
+                    }
                     cx.views.insert(current, view);
                 }
             }
@@ -340,24 +607,243 @@ fn draw_entity(
 
     // Draw its children
     for child in child_iter {
+        // Children with a positive z-index form a stacking context which is drawn after the rest of the current one.
+        let z_index = cx.style.z_index.get(child).copied().unwrap_or_default();
+        if z_index > 0 {
+            queue.push(stacking_order, z_index, child, is_visible);
+            continue;
+        }
+
         cx.current = child;
         // TODO: Skip views with zero-sized bounding boxes here? Or let user decide if they want to skip?
-        draw_entity(cx, dirty_rect, canvas, current_z, queue, is_visible);
+        draw_entity(cx, dirty_rect, canvas, stacking_order, queue, is_visible);
     }
 
-    if let Some(count) = layer_count {
-        canvas.restore_to_count(count);
-    }
-    canvas.restore();
     cx.current = current;
 }
 
+// Draws a view and its descendants from a cached image, drawing the image again first if it is out of date.
+fn draw_cached_layer(
+    cx: &mut DrawContext,
+    canvas: &Canvas,
+    stacking_order: &StackingOrder,
+    queue: &mut StackingQueue<bool>,
+    visible: bool,
+) {
+    let current = cx.current;
+
+    // Only the part of the subtree within the window is cached.
+    let window = cx.tree.get_parent_window(current).unwrap_or(Entity::root());
+    let unclipped_bounds = unclipped_draw_bounds(cx.style, cx.cache, cx.tree, current);
+    let unclipped_rect = round_out(&unclipped_bounds);
+    let rect = round_out(&unclipped_bounds.intersection(&cx.cache.get_bounds(window)));
+    let transform = cx.cache.transform.get(current).copied().unwrap_or_default();
+
+    // A layer which has only been translated by whole pixels since it was drawn, such as when an ancestor is
+    // scrolled, is moved instead of being drawn again, unless a part of the subtree which wasn't drawn has come
+    // into the window.
+    let cached_layer = cx.cache.layers.get(current).filter(|layer| layer.valid).and_then(|layer| {
+        let offset = pixel_translation(&layer.transform, &transform)?;
+        let moved_rect = layer.rect.with_offset(offset);
+        (layer.unclipped_rect.with_offset(offset) == unclipped_rect && contains(&moved_rect, &rect))
+            .then(|| CachedLayer { offset, used: true, ..layer.clone() })
+    });
+
+    let layer = if let Some(layer) = cached_layer {
+        push_stacking_contexts(
+            cx,
+            current,
+            stacking_order,
+            queue,
+            is_visible(cx.style, current, visible),
+        );
+        layer
+    } else {
+        cx.cache.layers.remove(current);
+        let Some(image) = draw_layer_image(cx, canvas, rect, stacking_order, queue, visible) else {
+            return;
+        };
+        CachedLayer {
+            entity: current,
+            image,
+            rect,
+            unclipped_rect,
+            transform,
+            offset: IVector::default(),
+            valid: true,
+            used: true,
+        }
+    };
+
+    let position = layer.rect.with_offset(layer.offset);
+    canvas.save();
+    canvas.reset_matrix();
+    canvas.draw_image(&layer.image, (position.left as f32, position.top as f32), None);
+    canvas.restore();
+
+    cx.cache.layers.insert(current, layer);
+}
+
+// Returns the smallest rect of whole pixels containing some bounds.
+fn round_out(bounds: &BoundingBox) -> IRect {
+    IRect::from_ltrb(
+        bounds.left().floor() as i32,
+        bounds.top().floor() as i32,
+        bounds.right().ceil() as i32,
+        bounds.bottom().ceil() as i32,
+    )
+}
+
+// Returns true if a rect contains another.
+fn contains(rect: &IRect, other: &IRect) -> bool {
+    rect.left <= other.left
+        && rect.top <= other.top
+        && rect.right >= other.right
+        && rect.bottom >= other.bottom
+}
+
+// Returns the translation from one transform to another in whole pixels, or `None` if the transforms differ by more
+// than a translation of whole pixels.
+fn pixel_translation(from: &Matrix, to: &Matrix) -> Option<IVector> {
+    if from.has_perspective() || to.has_perspective() {
+        return None;
+    }
+
+    let linear = |m: &Matrix| [m.scale_x(), m.skew_x(), m.skew_y(), m.scale_y()];
+    if linear(from) != linear(to) {
+        return None;
+    }
+
+    let dx = to.translate_x() - from.translate_x();
+    let dy = to.translate_y() - from.translate_y();
+    let is_whole = |d: f32| (d - d.round()).abs() < 0.001;
+    (is_whole(dx) && is_whole(dy)).then(|| IVector::new(dx.round() as i32, dy.round() as i32))
+}
+
+// Draws a view and its descendants into an image covering the given rect in window coordinates. If the image can't
+// be created the subtree is drawn directly to the canvas instead.
+fn draw_layer_image(
+    cx: &mut DrawContext,
+    canvas: &Canvas,
+    rect: IRect,
+    stacking_order: &StackingOrder,
+    queue: &mut StackingQueue<bool>,
+    visible: bool,
+) -> Option<Image> {
+    let current = cx.current;
+
+    if rect.is_empty() {
+        push_stacking_contexts(
+            cx,
+            current,
+            stacking_order,
+            queue,
+            is_visible(cx.style, current, visible),
+        );
+        return None;
+    }
+
+    // The subtree is recorded with window coordinates and then played back into an image the size of the rect.
+    let mut recorder = PictureRecorder::new();
+    let recording = recorder.begin_recording(Rect::from_irect(rect), None);
+    let dirty_rect = Some(BoundingBox::from_min_max(
+        rect.left as f32,
+        rect.top as f32,
+        rect.right as f32,
+        rect.bottom as f32,
+    ));
+    draw_content(cx, &dirty_rect, recording, stacking_order, queue, visible);
+    let picture = recorder.finish_recording_as_picture(None)?;
+
+    // The image is drawn on a surface compatible with the canvas, so that it stays on the GPU when the window is drawn
+    // with one. A canvas without a surface, such as the recording of an outer layer, falls back to a raster surface.
+    let info = canvas.image_info().with_dimensions((rect.width(), rect.height()));
+    let Some(mut surface) = canvas
+        .new_surface(&info, None)
+        .or_else(|| skia_safe::surfaces::raster_n32_premul((rect.width(), rect.height())))
+    else {
+        canvas.save();
+        canvas.reset_matrix();
+        canvas.draw_picture(&picture, None, None);
+        canvas.restore();
+        return None;
+    };
+
+    let layer_canvas = surface.canvas();
+    layer_canvas.translate((-rect.left as f32, -rect.top as f32));
+    layer_canvas.draw_picture(&picture, None, None);
+
+    Some(surface.image_snapshot())
+}
+
+// Queues the descendants of a view which form a stacking context without drawing anything.
+fn push_stacking_contexts(
+    cx: &DrawContext,
+    entity: Entity,
+    stacking_order: &StackingOrder,
+    queue: &mut StackingQueue<bool>,
+    visible: bool,
+) {
+    for child in DrawChildIterator::new(cx.tree, entity) {
+        if cx.style.display.get(child).copied().unwrap_or_default() == Display::None {
+            continue;
+        }
+
+        let z_index = cx.style.z_index.get(child).copied().unwrap_or_default();
+        if z_index > 0 {
+            queue.push(stacking_order, z_index, child, visible);
+            continue;
+        }
+
+        push_stacking_contexts(
+            cx,
+            child,
+            stacking_order,
+            queue,
+            is_visible(cx.style, child, visible),
+        );
+    }
+}
+
+fn is_visible(style: &Style, entity: Entity, inherited: bool) -> bool {
+    match style.visibility.get(entity) {
+        None => inherited,
+        Some(Visibility::Hidden) => false,
+        Some(Visibility::Visible) => true,
+    }
+}
+
 // Must be called after transform and clipping systems to be valid.
 pub(crate) fn draw_bounds(
     style: &Style,
     cache: &CachedData,
     tree: &Tree<Entity>,
     entity: Entity,
+) -> BoundingBox {
+    let dirty_bounds = unclipped_draw_bounds(style, cache, tree, entity);
+
+    let z_index = style.z_index.get(entity).copied().unwrap_or_default();
+
+    let parent = tree
+        .get_layout_parent(entity)
+        .unwrap_or(tree.get_parent_window(entity).unwrap_or(Entity::root()));
+    if let Some(clip_bounds) = cache.clip_path.get(parent) {
+        if z_index != 0 {
+            dirty_bounds
+        } else {
+            dirty_bounds.intersection(clip_bounds)
+        }
+    } else {
+        dirty_bounds
+    }
+}
+
+// The draw bounds of an entity before they are clipped by its parent.
+fn unclipped_draw_bounds(
+    style: &Style,
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    entity: Entity,
 ) -> BoundingBox {
     let mut layout_bounds = cache.bounds.get(entity).copied().unwrap();
 
@@ -419,42 +905,108 @@ pub(crate) fn draw_bounds(
         }
     }
 
-    let z_index = style.z_index.get(entity).copied().unwrap_or_default();
+    dirty_bounds
+}
 
-    let parent = tree
-        .get_layout_parent(entity)
-        .unwrap_or(tree.get_parent_window(entity).unwrap_or(Entity::root()));
-    if let Some(clip_bounds) = cache.clip_path.get(parent) {
-        if z_index != 0 {
-            dirty_bounds
-        } else {
-            dirty_bounds.intersection(clip_bounds)
-        }
-    } else {
-        dirty_bounds
+#[cfg(test)]
+mod tests {
+    use crate::backend::BackendContext;
+    use crate::prelude::*;
+    use skia_safe::image::CachingHint;
+    use skia_safe::{AlphaType, ColorType, Data, Image, ImageInfo};
+
+    // Renders the window and returns the color of a pixel as RGBA.
+    fn render_pixel(cx: &mut BackendContext, x: i32, y: i32) -> [u8; 4] {
+        let png = cx.render_to_image().expect("Failed to render window");
+        let image = Image::from_encoded(Data::new_copy(&png)).expect("Failed to decode PNG");
+        let info = ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let mut pixel = [0u8; 4];
+        assert!(image.read_pixels(&info, &mut pixel, 4, (x, y), CachingHint::Allow));
+        pixel
     }
-}
 
-struct ZEntity {
-    pub index: i32,
-    pub entity: Entity,
-    pub visible: bool,
-}
+    // Returns the id of the cached image of a view, if it has one.
+    fn layer_image(cx: &mut BackendContext, entity: Entity) -> Option<u32> {
+        cx.context().cache.layers.get(entity).map(|layer| layer.image.unique_id())
+    }
 
-impl Ord for ZEntity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.index.cmp(&self.index)
+    // Builds a 20px view with a cached layer at the top left of a container, with a red child filling it.
+    fn cached_layer_context() -> (BackendContext, Entity, Entity, Entity) {
+        let mut cx = BackendContext::new(Context::new());
+        cx.add_headless_window(WindowSize::new(100, 100), 1.0);
+
+        let mut layer = Entity::null();
+        let mut child = Entity::null();
+        let container = VStack::new(cx.context(), |cx| {
+            layer = VStack::new(cx, |cx| {
+                child = Element::new(cx)
+                    .size(Pixels(20.0))
+                    .background_color(Color::rgb(255, 0, 0))
+                    .entity();
+            })
+            .size(Pixels(20.0))
+            .cache_layer(true)
+            .entity();
+        })
+        .entity();
+
+        (cx, container, layer, child)
     }
-}
-impl PartialOrd for ZEntity {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+
+    #[test]
+    fn reuses_cached_layer() {
+        let (mut cx, _, layer, _) = cached_layer_context();
+
+        assert_eq!(render_pixel(&mut cx, 10, 10), [255, 0, 0, 255]);
+        let image = layer_image(&mut cx, layer);
+        assert!(image.is_some());
+
+        assert_eq!(render_pixel(&mut cx, 10, 10), [255, 0, 0, 255]);
+        assert_eq!(layer_image(&mut cx, layer), image);
     }
-}
-impl PartialEq for ZEntity {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
+
+    #[test]
+    fn redraws_cached_layer_when_descendant_changes() {
+        let (mut cx, _, layer, child) = cached_layer_context();
+
+        render_pixel(&mut cx, 10, 10);
+        let image = layer_image(&mut cx, layer);
+
+        cx.context().style.background_color.insert(child, Color::rgb(0, 0, 255));
+        cx.context().needs_redraw(child);
+
+        assert_eq!(render_pixel(&mut cx, 10, 10), [0, 0, 255, 255]);
+        assert_ne!(layer_image(&mut cx, layer), image);
     }
-}
 
-impl Eq for ZEntity {}
+    #[test]
+    fn moves_cached_layer_when_translated() {
+        let (mut cx, container, layer, _) = cached_layer_context();
+
+        render_pixel(&mut cx, 10, 10);
+        let image = layer_image(&mut cx, layer);
+
+        let translate = Translate::new(LengthOrPercentage::px(30.0), LengthOrPercentage::px(0.0));
+        cx.context().style.translate.insert(container, translate);
+        cx.context().needs_redraw(container);
+
+        assert_eq!(render_pixel(&mut cx, 40, 10), [255, 0, 0, 255]);
+        assert_ne!(render_pixel(&mut cx, 10, 10), [255, 0, 0, 255]);
+        assert_eq!(layer_image(&mut cx, layer), image);
+    }
+
+    #[test]
+    fn drops_cached_layer_when_hidden() {
+        let (mut cx, _, layer, _) = cached_layer_context();
+
+        render_pixel(&mut cx, 10, 10);
+        assert!(layer_image(&mut cx, layer).is_some());
+
+        cx.context().style.display.insert(layer, Display::None);
+        cx.context().needs_relayout();
+        cx.context().needs_redraw(layer);
+
+        render_pixel(&mut cx, 10, 10);
+        assert_eq!(layer_image(&mut cx, layer), None);
+    }
+}
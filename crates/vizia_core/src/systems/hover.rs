@@ -1,6 +1,7 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
-
+use crate::layout::contains_rounded_rect;
 use crate::prelude::*;
+use crate::storage::animatable_set::AnimatableSet;
+use crate::systems::{StackingOrder, StackingQueue};
 use log::debug;
 use skia_safe::Matrix;
 use vizia_storage::{DrawChildIterator, LayoutParentIterator};
@@ -15,22 +16,20 @@ pub fn hover_system(cx: &mut Context, window_entity: Entity) {
         }
     }
 
-    let mut queue = BinaryHeap::new();
     let pointer_events: bool =
         cx.style.pointer_events.get(window_entity).copied().unwrap_or_default().into();
-    queue.push(ZEntity { index: 0, pointer_events, entity: window_entity });
+    let mut queue = StackingQueue::new(window_entity, pointer_events);
     let mut hovered = window_entity;
     let transform = Matrix::new_identity();
     // let clip_bounds = cx.cache.get_bounds(window_entity);
     let clip_bounds: BoundingBox =
         BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
-    while !queue.is_empty() {
-        let zentity = queue.pop().unwrap();
-        cx.with_current(zentity.entity, |cx| {
+    while let Some(context) = queue.pop() {
+        cx.with_current(context.entity, |cx| {
             hover_entity(
                 &mut EventContext::new(cx),
-                zentity.index,
-                zentity.pointer_events,
+                &context.order,
+                context.data,
                 &mut queue,
                 &mut hovered,
                 transform,
@@ -87,9 +86,9 @@ pub fn hover_system(cx: &mut Context, window_entity: Entity) {
 
 fn hover_entity(
     cx: &mut EventContext,
-    current_z: i32,
+    stacking_order: &StackingOrder,
     parent_pointer_events: bool,
-    queue: &mut BinaryHeap<ZEntity>,
+    queue: &mut StackingQueue<bool>,
     hovered: &mut Entity,
     parent_transform: Matrix,
     clip_bounds: &BoundingBox,
@@ -125,13 +124,6 @@ fn hover_entity(
         })
         .unwrap_or(parent_pointer_events);
 
-    // Push to queue if the z-index is higher than the current z-index.
-    let z_index = cx.style.z_index.get(cx.current).copied().unwrap_or_default();
-    if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: cx.current, pointer_events });
-        return;
-    }
-
     let bounds = cx.bounds();
 
     let cursor_x = cx.mouse.cursor_x;
@@ -159,7 +151,12 @@ fn hover_entity(
     }
 
     if pointer_events {
-        if tx >= b.left() && tx < b.right() && ty >= b.top() && ty < b.bottom() {
+        if tx >= b.left()
+            && tx < b.right()
+            && ty >= b.top()
+            && ty < b.bottom()
+            && hit_test(cx, bounds, (tx, ty))
+        {
             *hovered = cx.current;
 
             if !cx
@@ -194,31 +191,40 @@ fn hover_entity(
 
     let child_iter = DrawChildIterator::new(cx.tree, cx.current);
     for child in child_iter {
+        // Children with a positive z-index form a stacking context which is drawn, and so hovered, after the rest
+        // of the current one.
+        let z_index = cx.style.z_index.get(child).copied().unwrap_or_default();
+        if z_index > 0 {
+            queue.push(stacking_order, z_index, child, pointer_events);
+            continue;
+        }
+
         cx.current = child;
-        hover_entity(cx, current_z, pointer_events, queue, hovered, transform, &clipping);
+        hover_entity(cx, stacking_order, pointer_events, queue, hovered, transform, &clipping);
     }
 }
 
-struct ZEntity {
-    pub index: i32,
-    pub pointer_events: bool,
-    pub entity: Entity,
-}
-
-impl Ord for ZEntity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.index.cmp(&self.index)
+// Returns true if a point within the bounds of the current view is over its shape, given by its hit test or otherwise
+// by its corner radii.
+fn hit_test(cx: &EventContext, bounds: BoundingBox, point: (f32, f32)) -> bool {
+    if let Some(hit_test) = cx.style.hit_test.get(cx.current) {
+        return hit_test(bounds, point);
     }
-}
-impl PartialOrd for ZEntity {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl PartialEq for ZEntity {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
-    }
-}
 
-impl Eq for ZEntity {}
+    let scale_factor = cx.scale_factor();
+    let radius = |corner_radius: &AnimatableSet<LengthOrPercentage>| {
+        corner_radius
+            .get(cx.current)
+            .map(|radius| radius.to_pixels(bounds.w.min(bounds.h), scale_factor).round())
+            .unwrap_or_default()
+    };
+
+    let radii = [
+        radius(&cx.style.corner_top_left_radius),
+        radius(&cx.style.corner_top_right_radius),
+        radius(&cx.style.corner_bottom_right_radius),
+        radius(&cx.style.corner_bottom_left_radius),
+    ];
+
+    contains_rounded_rect(bounds, radii, point)
+}
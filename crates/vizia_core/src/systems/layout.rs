@@ -3,6 +3,7 @@ use vizia_storage::LayoutTreeIterator;
 
 use crate::layout::node::SubLayout;
 use crate::prelude::*;
+use crate::profiling::{time_pass, FramePass};
 
 use super::{text_layout_system, text_system};
 
@@ -12,7 +13,12 @@ use super::{text_layout_system, text_system};
 /// and when a node undergoes relayout remove the descendants that have been processed from the list,
 /// then continue relayout on the remaining nodes in the list.
 pub(crate) fn layout_system(cx: &mut Context) {
-    text_system(cx);
+    {
+        let _timer = time_pass(FramePass::Text);
+        text_system(cx);
+    }
+
+    let _timer = time_pass(FramePass::Layout);
 
     if cx.style.system_flags.contains(SystemFlags::RELAYOUT) {
         // Perform layout on the whole tree.
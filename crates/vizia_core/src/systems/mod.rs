@@ -5,6 +5,7 @@ pub(crate) mod draw;
 pub mod hover;
 pub(crate) mod image;
 pub(crate) mod layout;
+pub(crate) mod stacking;
 pub(crate) mod style;
 pub(crate) mod text;
 pub(crate) use self::image::*;
@@ -14,5 +15,6 @@ pub(crate) use binding::*;
 pub(crate) use draw::*;
 pub use hover::*;
 pub(crate) use layout::*;
+pub(crate) use stacking::*;
 pub(crate) use style::*;
 pub(crate) use text::*;
@@ -0,0 +1,122 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::entity::Entity;
+
+/// The position of a stacking context in paint order.
+///
+/// A view with a positive z-index forms a stacking context, which is painted after the rest of its parent stacking
+/// context. The order is given by the z-index and tree order of the context and of each of its ancestor contexts, from
+/// the root down, so a view is always painted with the stacking context it belongs to, no matter how large its z-index
+/// is relative to views in other stacking contexts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct StackingOrder(Vec<(i32, usize)>);
+
+impl StackingOrder {
+    /// Returns the order of a stacking context nested within this one.
+    pub fn nested(&self, z_index: i32, tree_order: usize) -> Self {
+        let mut order = self.0.clone();
+        order.push((z_index, tree_order));
+        Self(order)
+    }
+}
+
+/// A view which forms a stacking context and is waiting to be painted, along with some data inherited from its
+/// ancestors.
+pub(crate) struct StackingContext<T> {
+    pub order: StackingOrder,
+    pub entity: Entity,
+    pub data: T,
+}
+
+impl<T> Ord for StackingContext<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so that the binary heap pops the context which is painted first.
+        other.order.cmp(&self.order)
+    }
+}
+
+impl<T> PartialOrd for StackingContext<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for StackingContext<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.order == other.order
+    }
+}
+
+impl<T> Eq for StackingContext<T> {}
+
+/// A queue of stacking contexts which pops the contexts in paint order.
+pub(crate) struct StackingQueue<T> {
+    heap: BinaryHeap<StackingContext<T>>,
+    // The number of contexts pushed so far, used to order contexts with the same z-index by tree order.
+    pushed: usize,
+}
+
+impl<T> StackingQueue<T> {
+    /// Creates a new queue containing the root stacking context.
+    pub fn new(root: Entity, data: T) -> Self {
+        let mut heap = BinaryHeap::new();
+        heap.push(StackingContext { order: StackingOrder::default(), entity: root, data });
+        Self { heap, pushed: 0 }
+    }
+
+    /// Adds a view with the given z-index which forms a stacking context within the given parent context.
+    ///
+    /// Views must be pushed in tree order.
+    pub fn push(&mut self, parent: &StackingOrder, z_index: i32, entity: Entity, data: T) {
+        self.pushed += 1;
+        self.heap.push(StackingContext {
+            order: parent.nested(z_index, self.pushed),
+            entity,
+            data,
+        });
+    }
+
+    /// Removes the next stacking context to be painted from the queue.
+    pub fn pop(&mut self) -> Option<StackingContext<T>> {
+        self.heap.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_id::GenerationalId;
+
+    fn paint_order(queue: &mut StackingQueue<()>) -> Vec<usize> {
+        std::iter::from_fn(|| queue.pop()).map(|context| context.entity.index()).collect()
+    }
+
+    #[test]
+    fn sorts_by_z_index() {
+        let root = StackingOrder::default();
+        let mut queue = StackingQueue::new(Entity::new(0, 0), ());
+        queue.push(&root, 5, Entity::new(1, 0), ());
+        queue.push(&root, 2, Entity::new(2, 0), ());
+        queue.push(&root, 2, Entity::new(3, 0), ());
+
+        assert_eq!(paint_order(&mut queue), vec![0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn nested_contexts_stay_with_parent() {
+        let mut queue = StackingQueue::new(Entity::new(0, 0), ());
+        let root = queue.pop().unwrap();
+
+        // A parent with a z-index of 10 containing a child with a z-index of 20, and a sibling of the parent with a
+        // z-index of 15.
+        queue.push(&root.order, 10, Entity::new(1, 0), ());
+        queue.push(&root.order, 15, Entity::new(2, 0), ());
+        let parent = queue.pop().unwrap();
+        assert_eq!(parent.entity, Entity::new(1, 0));
+        queue.push(&parent.order, 20, Entity::new(3, 0), ());
+        queue.push(&parent.order, 1, Entity::new(4, 0), ());
+
+        // Both children of the parent are painted before the sibling of the parent.
+        assert_eq!(paint_order(&mut queue), vec![4, 3, 2]);
+    }
+}
@@ -14,7 +14,7 @@ use vizia_style::{
         context::{MatchingForInvalidation, NeedsSelectorFlags, SelectorCaches},
         matching::ElementSelectorFlags,
         parser::{Component, NthType},
-        OpaqueElement, SelectorImpl,
+        OpaqueElement, SelectorImpl, SelectorList,
     },
     Element, MatchingContext, MatchingMode, PseudoClass, QuirksMode, SelectorIdent, Selectors,
 };
@@ -91,7 +91,7 @@ impl Element for Node<'_, '_> {
 
     fn has_local_name(&self, local_name: &SelectorIdent) -> bool {
         if let Some(element) = self.store.element.get(self.entity) {
-            return element == &local_name.precomputed_hash();
+            return *element == local_name.0;
         }
 
         false
@@ -135,11 +135,7 @@ impl Element for Node<'_, '_> {
         name: &<Self::Impl as SelectorImpl>::Identifier,
         _case_sensitivity: CaseSensitivity,
     ) -> bool {
-        if let Some(id) = self.store.ids.get(self.entity) {
-            *id == name.0
-        } else {
-            false
-        }
+        self.store.ids.get(self.entity).is_some_and(|id| *id == name.0)
     }
 
     fn has_class(
@@ -217,7 +213,12 @@ impl Element for Node<'_, '_> {
                 PseudoClass::UserInvalid => {
                     psudeo_class_flag.contains(PseudoClassFlags::USER_INVALID)
                 }
-                PseudoClass::Lang(_) => todo!(),
+                PseudoClass::Loading => psudeo_class_flag.contains(PseudoClassFlags::LOADING),
+                PseudoClass::DragOver => psudeo_class_flag.contains(PseudoClassFlags::DRAG_OVER),
+                PseudoClass::Lang(langs) => {
+                    let locale = self.store.locale.to_string();
+                    langs.iter().any(|lang| matches_language_range(&locale, lang))
+                }
                 PseudoClass::Dir(_) => todo!(),
                 PseudoClass::Custom(name) => {
                     println!("custom: {}", name);
@@ -247,6 +248,14 @@ impl Element for Node<'_, '_> {
     }
 }
 
+// Returns true if a locale, such as `en-US`, is matched by a language range of the `:lang()` pseudo-class, such as
+// `en`, ignoring case.
+fn matches_language_range(locale: &str, range: &str) -> bool {
+    locale.eq_ignore_ascii_case(range)
+        || (locale.get(..range.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(range))
+            && locale.as_bytes().get(range.len()) == Some(&b'-'))
+}
+
 /// Link inheritable inline properties to their parent.
 pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut Vec<Entity>) {
     for entity in cx.tree.into_iter() {
@@ -267,7 +276,11 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.text_decoration_line.inherit_inline(entity, parent)
                 | cx.style.text_stroke_width.inherit_inline(entity, parent)
                 | cx.style.text_stroke_style.inherit_inline(entity, parent)
+                | cx.style.letter_spacing.inherit_inline(entity, parent)
+                | cx.style.word_spacing.inherit_inline(entity, parent)
+                | cx.style.line_height.inherit_inline(entity, parent)
                 | cx.style.font_variation_settings.inherit_inline(entity, parent)
+                | cx.style.direction.inherit_inline(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
@@ -288,7 +301,11 @@ pub(crate) fn shared_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.text_decoration_line.inherit_shared(entity, parent)
                 | cx.style.text_stroke_width.inherit_shared(entity, parent)
                 | cx.style.text_stroke_style.inherit_shared(entity, parent)
+                | cx.style.letter_spacing.inherit_shared(entity, parent)
+                | cx.style.word_spacing.inherit_shared(entity, parent)
+                | cx.style.line_height.inherit_shared(entity, parent)
                 | cx.style.font_variation_settings.inherit_shared(entity, parent)
+                | cx.style.direction.inherit_shared(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
@@ -345,6 +362,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.blend_mode.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -569,6 +590,11 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.direction.link(entity, matched_rules) {
+        should_redraw = true;
+        should_reflow = true;
+    }
+
     if style.text_overflow.link(entity, matched_rules) {
         should_redraw = true;
         should_reflow = true;
@@ -602,6 +628,21 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.letter_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_reflow = true;
+    }
+
+    if style.word_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_reflow = true;
+    }
+
+    if style.line_height.link(entity, matched_rules) {
+        should_redraw = true;
+        should_reflow = true;
+    }
+
     if style.underline_style.link(entity, matched_rules) {
         should_redraw = true;
         should_reflow = true;
@@ -741,7 +782,17 @@ pub(crate) fn compute_matched_rules(
 
     let node = Node { entity, store, tree };
 
+    let window = if tree.is_window(entity) {
+        entity
+    } else {
+        tree.get_parent_window(entity).unwrap_or(Entity::root())
+    };
+
     for (rule_id, rule) in store.rules.iter() {
+        if !rule.scope.contains(window) {
+            continue;
+        }
+
         let matches = matches_selector(&rule.selector, 0, Some(&rule.hashes), &node, &mut context);
 
         if matches {
@@ -754,6 +805,26 @@ pub(crate) fn compute_matched_rules(
     matched_rules
 }
 
+/// Returns true if the given entity matches any of the selectors in a selector list.
+pub(crate) fn matches_selector_list(
+    selectors: &SelectorList<Selectors>,
+    entity: Entity,
+    store: &Style,
+    tree: &Tree<Entity>,
+) -> bool {
+    let mut cache = SelectorCaches::default();
+    let mut context = MatchingContext::new(
+        MatchingMode::Normal,
+        None,
+        &mut cache,
+        QuirksMode::NoQuirks,
+        NeedsSelectorFlags::No,
+        MatchingForInvalidation::No,
+    );
+
+    vizia_style::matches_selector_list(selectors, &Node { entity, store, tree }, &mut context)
+}
+
 fn has_same_selector(style: &Style, entity1: Entity, entity2: Entity) -> bool {
     if let Some(element1) = style.element.get(entity1) {
         if let Some(element2) = style.element.get(entity2) {
@@ -763,10 +834,7 @@ fn has_same_selector(style: &Style, entity1: Entity, entity2: Entity) -> bool {
         }
     }
 
-    let id1 = if let Some(id) = style.ids.get(entity1) { id } else { "" };
-    let id2 = if let Some(id) = style.ids.get(entity2) { id } else { "" };
-
-    if id1 != id2 {
+    if style.ids.get(entity1) != style.ids.get(entity2) {
         return false;
     }
 
@@ -812,16 +880,16 @@ pub(crate) fn compute_element_hash(
 
     for ancestor in parent_iter {
         if let Some(element) = style.element.get(ancestor) {
-            bloom.insert_hash(*element);
+            bloom.insert_hash(element.precomputed_hash());
         }
 
         if let Some(id) = style.ids.get(ancestor) {
-            bloom.insert_hash(fxhash::hash32(id));
+            bloom.insert_hash(id.precomputed_hash());
         }
 
         if let Some(classes) = style.classes.get(ancestor) {
             for class in classes {
-                bloom.insert_hash(fxhash::hash32(class));
+                bloom.insert_hash(class.precomputed_hash());
             }
         }
     }
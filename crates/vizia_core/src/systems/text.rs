@@ -8,26 +8,50 @@ use skia_safe::{
 };
 use vizia_storage::{LayoutChildIterator, LayoutTreeIterator};
 
-use crate::{cache::CachedData, prelude::*};
+use crate::{
+    cache::CachedData,
+    prelude::*,
+    text::{first_strong_direction, TextContext},
+};
 
 pub(crate) fn text_system(cx: &mut Context) {
-    let iterator = LayoutTreeIterator::full(&cx.tree);
-    for entity in iterator {
-        if !cx.style.text_construction.contains(entity) {
-            continue;
-        }
+    // Text spans are shaped as part of the paragraph of the label containing them, so a change to a span rebuilds
+    // the paragraph of that label instead.
+    let span_labels = LayoutTreeIterator::full(&cx.tree)
+        .filter(|entity| {
+            cx.style.text_construction.contains(*entity)
+                && cx.style.text_span.get(*entity).copied().unwrap_or_default()
+        })
+        .filter_map(|entity| {
+            entity
+                .parent_iter(&cx.tree)
+                .find(|parent| !cx.style.text_span.get(*parent).copied().unwrap_or_default())
+        })
+        .collect::<Vec<_>>();
+
+    for label in span_labels {
+        cx.style.needs_text_update(label);
+    }
 
-        if cx.style.text.contains(entity)
-            && cx.style.display.get(entity).copied().unwrap_or_default() != Display::None
-        {
-            if let Some(paragraph) =
-                build_paragraph(entity, &mut cx.style, &cx.tree, cx.text_context.font_collection())
-            {
-                cx.text_context.text_paragraphs.insert(entity, paragraph);
-                cx.style.needs_relayout();
-                cx.style.needs_text_layout(entity);
-            }
-        }
+    let entities = LayoutTreeIterator::full(&cx.tree)
+        .filter(|entity| {
+            cx.style.text_construction.contains(*entity)
+                && cx.style.text.contains(*entity)
+                && cx.style.display.get(*entity).copied().unwrap_or_default() != Display::None
+        })
+        .collect::<Vec<_>>();
+
+    // The inputs of every paragraph are gathered from the style first, so that the paragraphs can then be built
+    // independently of each other.
+    let sources = entities
+        .into_iter()
+        .map(|entity| ParagraphSource::new(entity, &mut cx.style, &cx.tree))
+        .collect::<Vec<_>>();
+
+    for (entity, paragraph) in build_paragraphs(&mut cx.text_context, sources) {
+        cx.text_context.text_paragraphs.insert(entity, paragraph);
+        cx.style.needs_relayout();
+        cx.style.needs_text_layout(entity);
     }
 
     cx.style.text_construction.clear();
@@ -142,165 +166,387 @@ pub fn layout_span(
     bounds
 }
 
-pub fn build_paragraph(
+/// The inputs of the paragraph of an entity, gathered from its style and the style of its text spans, which can be
+/// built into a paragraph on any thread.
+pub(crate) struct ParagraphSource {
     entity: Entity,
-    style: &mut Style,
-    tree: &Tree<Entity>,
-    font_collection: &FontCollection,
-) -> Option<Paragraph> {
-    let mut paragraph_style = ParagraphStyle::default();
-    // paragraph_style.turn_hinting_off();
-
-    // Overflow
-    match style.text_overflow.get(entity) {
-        Some(&TextOverflow::Ellipsis) => {
-            paragraph_style.set_ellipsis("…");
+    ellipsis: &'static str,
+    max_lines: Option<usize>,
+    text_align: TextAlign,
+    direction: Direction,
+    runs: Vec<(String, ComputedTextStyle)>,
+}
+
+impl ParagraphSource {
+    /// Gathers the inputs of the paragraph of an entity, and records the range of the text of each of its text spans
+    /// within the paragraph.
+    pub fn new(entity: Entity, style: &mut Style, tree: &Tree<Entity>) -> Self {
+        // Overflow
+        let ellipsis = match style.text_overflow.get(entity) {
+            Some(&TextOverflow::Ellipsis) => "…",
+            _ => "",
+        };
+
+        // Line Clamp
+        let max_lines = style.line_clamp.get(entity).map(|line_clamp| line_clamp.0 as usize);
+
+        // Text Align
+        let text_align = text_align(style, entity);
+
+        let mut source = Self {
+            entity,
+            ellipsis,
+            max_lines,
+            text_align,
+            direction: Direction::Ltr,
+            runs: Vec::new(),
+        };
+        source.add_block(style, tree, entity, &mut 0);
+
+        // Text Direction
+        source.direction = style.direction.get(entity).copied().unwrap_or_else(|| {
+            source
+                .runs
+                .iter()
+                .find_map(|(text, _)| first_strong_direction(text))
+                .unwrap_or_default()
+        });
+
+        source
+    }
+
+    fn add_block(
+        &mut self,
+        style: &mut Style,
+        tree: &Tree<Entity>,
+        entity: Entity,
+        current: &mut usize,
+    ) {
+        if let Some(text) = style.text.get(entity) {
+            if !text.is_empty() {
+                self.runs.push((text.clone(), ComputedTextStyle::new(style, entity)));
+                style.text_range.insert(entity, *current..*current + text.len());
+                *current += text.len();
+            }
         }
 
-        Some(&TextOverflow::Clip) => {
-            paragraph_style.set_ellipsis("");
+        let iter = LayoutChildIterator::new(tree, entity);
+        for child in iter {
+            if style.text_span.get(child).copied().unwrap_or_default() {
+                self.add_block(style, tree, child, current);
+            }
         }
+    }
 
-        _ => {
-            paragraph_style.set_ellipsis("");
+    /// Builds the paragraph, with fonts from the given font collection.
+    pub fn build(&self, font_collection: &FontCollection) -> Paragraph {
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_ellipsis(self.ellipsis);
+        if let Some(max_lines) = self.max_lines {
+            paragraph_style.set_max_lines(max_lines);
+        }
+        paragraph_style.set_text_align(self.text_align.into());
+        paragraph_style.set_text_direction(self.direction.into());
+
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+        for (text, text_style) in self.runs.iter() {
+            paragraph_builder.push_style(&text_style.build());
+            paragraph_builder.add_text(text.as_str());
         }
+
+        paragraph_builder.add_text("\u{200B}");
+        paragraph_builder.build()
     }
+}
 
-    // Line Clamp
-    if let Some(line_clamp) = style.line_clamp.get(entity) {
-        paragraph_style.set_max_lines(line_clamp.0 as usize);
+// Builds the paragraphs of a list of entities one after another.
+#[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+fn build_paragraphs(
+    text_context: &mut TextContext,
+    sources: Vec<ParagraphSource>,
+) -> Vec<(Entity, Paragraph)> {
+    sources
+        .iter()
+        .map(|source| (source.entity, source.build(text_context.font_collection())))
+        .collect()
+}
+
+// The number of paragraphs below which it's faster to build them on the main thread than on the thread pool.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+const MIN_PARALLEL_PARAGRAPHS: usize = 32;
+
+// Builds the paragraphs of a list of entities on the rayon thread pool.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+fn build_paragraphs(
+    text_context: &mut TextContext,
+    sources: Vec<ParagraphSource>,
+) -> Vec<(Entity, Paragraph)> {
+    use rayon::prelude::*;
+
+    if sources.len() < MIN_PARALLEL_PARAGRAPHS {
+        return sources
+            .iter()
+            .map(|source| (source.entity, source.build(text_context.font_collection())))
+            .collect();
     }
 
-    // if let Some(text_wrap) = style.text_wrap.get(entity).copied() {
-    //     if !text_wrap {
-    //         paragraph_style.set_max_lines(1);
-    //     }
-    // }
-
-    // Text Align
-    paragraph_style.set_text_align(
-        if let Some(text_align) = style.text_align.get(entity) {
-            *text_align
-        } else if let Some(alignment) = style.alignment.get(entity) {
-            match alignment {
-                Alignment::TopLeft | Alignment::Left | Alignment::BottomLeft => TextAlign::Left,
-                Alignment::TopCenter | Alignment::Center | Alignment::BottomCenter => {
-                    TextAlign::Center
-                }
-                Alignment::TopRight | Alignment::Right | Alignment::BottomRight => TextAlign::Right,
-            }
-        } else {
-            TextAlign::Left
-        }
-        .into(),
-    );
+    // A font collection caches the typefaces it has matched without any locking, so each thread of the pool builds
+    // paragraphs with a font collection of its own.
+    let font_collections = WorkerFontCollections(text_context.worker_font_collections());
+    sources
+        .par_iter()
+        .map(|source| {
+            let index = rayon::current_thread_index().unwrap_or_default();
+            BuiltParagraph(source.entity, source.build(font_collections.get(index)))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|BuiltParagraph(entity, paragraph)| (entity, paragraph))
+        .collect()
+}
 
-    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+// The font collections of the threads of the pool, shared with all of them. Each font collection is only used by
+// the thread with the same index in the pool, so no collection is ever used by two threads at once.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+struct WorkerFontCollections<'a>(&'a [FontCollection]);
 
-    add_block(style, tree, entity, &mut paragraph_builder, &mut 0);
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+impl WorkerFontCollections<'_> {
+    // Accessed through a method so that closures capture the whole wrapper rather than the wrapped slice.
+    fn get(&self, index: usize) -> &FontCollection {
+        &self.0[index]
+    }
+}
 
-    paragraph_builder.add_text("\u{200B}");
-    paragraph_builder.build().into()
+// Safety: see above, the collections are only ever used by the thread with their index.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+unsafe impl Sync for WorkerFontCollections<'_> {}
+
+// A paragraph built on a thread of the pool. It is only used by the thread which built it until it is handed back to
+// the main thread, and doesn't share any state which isn't reference counted atomically with other paragraphs.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+struct BuiltParagraph(Entity, Paragraph);
+
+// Safety: see above, each paragraph is moved to the main thread once and never used by the worker again.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+unsafe impl Send for BuiltParagraph {}
+
+/// The text properties of an entity resolved from its computed style, which can be built into a text style on any
+/// thread.
+#[derive(Clone)]
+pub(crate) struct ComputedTextStyle {
+    font_color: Option<Color>,
+    text_decoration_line: Option<TextDecorationLine>,
+    font_families: Vec<FamilyOwned>,
+    text_stroke: Option<(f32, TextStrokeStyle)>,
+    background_color: Option<Color>,
+    font_size: f32,
+    letter_spacing: Option<f32>,
+    word_spacing: Option<f32>,
+    line_height: Option<f32>,
+    font_style: Option<(FontWeight, FontWidth, FontSlant)>,
+    font_variations: Option<Vec<FontVariation>>,
 }
 
-fn add_block(
-    style: &mut Style,
-    tree: &Tree<Entity>,
-    entity: Entity,
-    paragraph_builder: &mut ParagraphBuilder,
-    current: &mut usize,
-) {
-    // let mut new_current = current;
+impl ComputedTextStyle {
+    /// Resolves the text properties of an entity from its computed style.
+    pub fn new(style: &Style, entity: Entity) -> Self {
+        let scale_factor = style.scale_factor();
+
+        let font_families = style
+            .font_family
+            .get(entity)
+            .cloned()
+            .unwrap_or_else(|| vec![FamilyOwned::Generic(GenericFontFamily::SansSerif)]);
+
+        let text_stroke = style.text_stroke_width.get(entity).map(|text_stroke| {
+            (
+                text_stroke.to_px().unwrap_or(0.0),
+                style.text_stroke_style.get(entity).copied().unwrap_or_default(),
+            )
+        });
+
+        let background_color = style
+            .background_color
+            .get(entity)
+            .copied()
+            .filter(|_| style.text_span.get(entity).is_some());
+
+        // Font Size
+        let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
+
+        // Letter and Word Spacing
+        let letter_spacing = style
+            .letter_spacing
+            .get(entity)
+            .map(|letter_spacing| text_spacing_to_px(letter_spacing, font_size) * scale_factor);
+
+        let word_spacing = style
+            .word_spacing
+            .get(entity)
+            .map(|word_spacing| text_spacing_to_px(word_spacing, font_size) * scale_factor);
+
+        // Line Height
+        let line_height = match style.line_height.get(entity) {
+            Some(LineHeight::Number(multiplier)) => Some(*multiplier),
+            Some(LineHeight::Length(length)) if font_size > 0.0 => {
+                Some(text_spacing_to_px(length, font_size) / font_size)
+            }
+            _ => None,
+        };
+
+        // Font Style
+        let font_style = match (
+            style.font_weight.get(entity),
+            style.font_width.get(entity),
+            style.font_slant.get(entity),
+        ) {
+            (None, None, None) => None,
+            (weight, width, slant) => Some((
+                weight.copied().unwrap_or_default(),
+                width.copied().unwrap_or_default(),
+                slant.copied().unwrap_or_default(),
+            )),
+        };
+
+        Self {
+            font_color: style.font_color.get(entity).copied(),
+            text_decoration_line: style.text_decoration_line.get(entity).copied(),
+            font_families,
+            text_stroke,
+            background_color,
+            font_size: font_size * scale_factor,
+            letter_spacing,
+            word_spacing,
+            line_height,
+            font_style,
+            font_variations: style.font_variation_settings.get(entity).cloned(),
+        }
+    }
 
-    if let Some(text) = style.text.get(entity) {
-        if !text.is_empty() {
-            // Text Style
+    /// Builds the skia text style with these properties.
+    pub fn build(&self) -> TextStyle {
+        let mut text_style = TextStyle::new();
 
-            let mut text_style = TextStyle::new();
+        if let Some(text_decoration_line) = self.text_decoration_line {
+            text_style.set_decoration_type(text_decoration_line.into());
+            text_style.set_decoration_color(self.font_color.unwrap_or_default());
+        }
 
-            let font_color = style.font_color.get(entity).cloned().unwrap_or_default();
+        // Font Families
+        text_style.set_font_families(self.font_families.as_slice());
 
-            if let Some(text_decoration_line) = style.text_decoration_line.get(entity).copied() {
-                text_style.set_decoration_type(text_decoration_line.into());
-                text_style.set_decoration_color(font_color);
-            }
+        let mut paint = Paint::default();
+        // Font Color
+        if let Some(font_color) = self.font_color {
+            paint.set_color(font_color);
+            paint.set_anti_alias(false);
+            paint.set_blend_mode(BlendMode::SrcOver);
+        }
 
-            // Font Families
-            text_style.set_font_families(
-                style
-                    .font_family
-                    .get(entity)
-                    .map(Vec::as_slice)
-                    .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]),
-            );
+        if let Some((width, stroke_style)) = self.text_stroke {
+            paint.set_stroke_width(width);
+            paint.set_style(stroke_style.into());
+        }
+
+        text_style.set_foreground_paint(&paint);
 
+        if let Some(background_color) = self.background_color {
             let mut paint = Paint::default();
-            // Font Color
-            if let Some(font_color) = style.font_color.get(entity) {
-                paint.set_color(*font_color);
-                paint.set_anti_alias(false);
-                paint.set_blend_mode(BlendMode::SrcOver);
-            }
+            paint.set_color(background_color);
+            paint.set_anti_alias(false);
+            paint.set_blend_mode(BlendMode::SrcOver);
+            text_style.set_background_paint(&paint);
+        }
 
-            if let Some(text_stroke) = style.text_stroke_width.get(entity) {
-                paint.set_stroke_width(text_stroke.to_px().unwrap_or(0.0));
-                paint.set_style(
-                    (*style.text_stroke_style.get(entity).unwrap_or(&TextStrokeStyle::default()))
-                        .into(),
-                );
-            }
+        // Font Size
+        text_style.set_font_size(self.font_size);
 
-            text_style.set_foreground_paint(&paint);
+        // Letter and Word Spacing
+        if let Some(letter_spacing) = self.letter_spacing {
+            text_style.set_letter_spacing(letter_spacing);
+        }
 
-            if let Some(background_color) = style.background_color.get(entity) {
-                if style.text_span.get(entity).is_some() {
-                    let mut paint = Paint::default();
-                    paint.set_color(*background_color);
-                    paint.set_anti_alias(false);
-                    paint.set_blend_mode(BlendMode::SrcOver);
-                    text_style.set_background_paint(&paint);
-                }
-            }
+        if let Some(word_spacing) = self.word_spacing {
+            text_style.set_word_spacing(word_spacing);
+        }
 
-            // Font Size
-            let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
-            text_style.set_font_size(font_size * style.scale_factor());
-
-            // Font Style
-            match (
-                style.font_weight.get(entity),
-                style.font_width.get(entity),
-                style.font_slant.get(entity),
-            ) {
-                (None, None, None) => {}
-                (weight, width, slant) => {
-                    text_style.set_font_style(FontStyle::new(
-                        weight.copied().unwrap_or_default().into(),
-                        width.copied().unwrap_or_default().into(),
-                        slant.copied().unwrap_or_default().into(),
-                    ));
-                }
-            }
+        // Line Height
+        if let Some(line_height) = self.line_height {
+            text_style.set_height_override(true);
+            text_style.set_height(line_height);
+        }
 
-            // Font Variations
-            if let Some(coordinates) = style.font_variation_settings.get(entity) {
-                let coordinates = coordinates.iter().map(|c| c.0).collect::<Vec<_>>();
-                text_style.set_font_arguments(&FontArguments::new().set_variation_design_position(
+        // Font Style
+        if let Some((weight, width, slant)) = self.font_style {
+            text_style.set_font_style(FontStyle::new(weight.into(), width.into(), slant.into()));
+        }
+
+        // Font Variations
+        if let Some(font_variations) = &self.font_variations {
+            let coordinates = font_variations.iter().map(|c| c.0).collect::<Vec<_>>();
+            text_style
+                .set_font_arguments(&FontArguments::new().set_variation_design_position(
                     VariationPosition { coordinates: &coordinates },
                 ));
-            }
-
-            paragraph_builder.push_style(&text_style);
-            style.text_range.insert(entity, *current..*current + text.len());
-            paragraph_builder.add_text(text.as_str());
-            *current += text.len();
         }
+
+        text_style
     }
+}
 
-    let iter = LayoutChildIterator::new(tree, entity);
-    for child in iter {
-        if style.text_span.get(child).copied().unwrap_or_default() {
-            add_block(style, tree, child, paragraph_builder, current);
+/// Returns the text style of the text of an entity, resolved from its computed style.
+pub(crate) fn text_style(style: &Style, entity: Entity) -> TextStyle {
+    ComputedTextStyle::new(style, entity).build()
+}
+
+/// Returns the base direction of the text of an entity, which defaults to the direction of the first strongly
+/// directional character of its text.
+pub(crate) fn text_direction(style: &Style, entity: Entity) -> Direction {
+    style.direction.get(entity).copied().unwrap_or_else(|| {
+        style.text.get(entity).and_then(|text| first_strong_direction(text)).unwrap_or_default()
+    })
+}
+
+/// Returns the horizontal alignment of the text of an entity, which defaults to the alignment of its content.
+pub(crate) fn text_align(style: &Style, entity: Entity) -> TextAlign {
+    if let Some(text_align) = style.text_align.get(entity) {
+        *text_align
+    } else if let Some(alignment) = style.alignment.get(entity) {
+        match alignment {
+            Alignment::TopLeft | Alignment::Left | Alignment::BottomLeft => TextAlign::Left,
+            Alignment::TopCenter | Alignment::Center | Alignment::BottomCenter => TextAlign::Center,
+            Alignment::TopRight | Alignment::Right | Alignment::BottomRight => TextAlign::Right,
         }
+    } else {
+        TextAlign::Left
+    }
+}
+
+// Converts a letter or word spacing length to logical pixels, where `em` units are relative to the font size.
+fn text_spacing_to_px(length: &Length, font_size: f32) -> f32 {
+    match length {
+        Length::Value(LengthValue::Em(em)) => em * font_size,
+        _ => length.to_px().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[test]
+    fn builds_paragraphs_for_many_labels() {
+        let mut harness = TestHarness::new(|cx| {
+            for index in 0..100 {
+                Label::new(cx, format!("Label {index}")).class("label");
+            }
+        });
+
+        let labels = harness.query_all(".label");
+        assert_eq!(labels.len(), 100);
+
+        let cx = harness.context();
+        assert!(labels.iter().all(|label| cx.text_context.text_paragraphs.contains(*label)));
     }
 }
@@ -0,0 +1,490 @@
+//! A harness for testing the behavior of views without a window.
+//!
+//! A [`TestHarness`] builds a view tree into a headless window and simulates user input, running the event, style,
+//! and layout systems after each interaction, so that the state of the views can be checked with the same selectors
+//! used in stylesheets.
+//!
+//! # Example
+//! ```
+//! # use vizia_core::prelude::*;
+//! # use vizia_core::testing::TestHarness;
+//! #[derive(Lens)]
+//! struct AppData {
+//!     checked: bool,
+//! }
+//!
+//! impl Model for AppData {
+//!     fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+//!         event.map(|_: &(), _| self.checked ^= true);
+//!     }
+//! }
+//!
+//! let mut harness = TestHarness::new(|cx| {
+//!     AppData { checked: false }.build(cx);
+//!     Checkbox::new(cx, AppData::checked).on_toggle(|cx| cx.emit(())).id("checkbox");
+//! });
+//!
+//! harness.click("#checkbox");
+//! assert!(harness.matches("#checkbox", ":checked"));
+//! ```
+//!
+//! The whole view tree can also be captured as an [`EntityTree`] with [`Context::dump_tree`] or
+//! [`TestHarness::dump_tree`], which, with the `serde` feature enabled, serializes to human-readable JSON so that
+//! failing assertions show meaningful diffs.
+
+use std::any::Any;
+
+use vizia_storage::TreeIterator;
+use vizia_style::Selectors;
+
+use crate::{
+    backend::BackendContext, events::EventManager, prelude::*, systems::matches_selector_list,
+    util::set_virtual_time,
+};
+
+/// A view which can be the target of an interaction with a [`TestHarness`], given either as an [`Entity`] or as a
+/// selector string such as `"#submit"` or `"button.primary"`.
+pub trait TestTarget {
+    /// Returns the entity of the targeted view, or `None` if there is no such view.
+    fn entity(&self, harness: &TestHarness) -> Option<Entity>;
+}
+
+impl TestTarget for Entity {
+    fn entity(&self, harness: &TestHarness) -> Option<Entity> {
+        harness.cx.0.entity_manager.is_alive(*self).then_some(*self)
+    }
+}
+
+impl TestTarget for &str {
+    fn entity(&self, harness: &TestHarness) -> Option<Entity> {
+        harness.query(self)
+    }
+}
+
+/// Builds views into a headless window and simulates user input for testing the behavior of views.
+///
+/// Time is virtual while a harness is running, so timers, scheduled events, and animations only make progress
+/// when [`advance_time`](TestHarness::advance_time) is called. Interactions panic if the targeted view does not
+/// exist, which fails the test.
+pub struct TestHarness {
+    cx: BackendContext,
+    event_manager: EventManager,
+    time: Instant,
+}
+
+impl TestHarness {
+    /// Creates a new harness with an 800x600 window containing the views built by `content`.
+    pub fn new(content: impl FnOnce(&mut Context)) -> Self {
+        Self::with_size(WindowSize::new(800, 600), content)
+    }
+
+    /// Creates a new harness with a window of the given size containing the views built by `content`.
+    pub fn with_size(size: WindowSize, content: impl FnOnce(&mut Context)) -> Self {
+        let mut cx = BackendContext::new(Context::new());
+        cx.add_headless_window(size, 1.0);
+
+        let time = Instant::now();
+        set_virtual_time(Some(time));
+
+        (content)(cx.context());
+
+        let mut harness = Self { cx, event_manager: EventManager::new(), time };
+        harness.update();
+        harness
+    }
+
+    /// Returns the context of the harness, which can be used to build more views or change state directly.
+    ///
+    /// Call [`update`](TestHarness::update) afterwards to handle any emitted events.
+    pub fn context(&mut self) -> &mut Context {
+        self.cx.context()
+    }
+
+    /// Resizes the window to a logical size, as if the user had resized it, and handles the resulting events.
+    pub fn resize(&mut self, size: WindowSize) {
+        let scale_factor = self.cx.0.style.dpi_factor as f32;
+        self.cx.set_window_size(
+            Entity::root(),
+            size.width as f32 * scale_factor,
+            size.height as f32 * scale_factor,
+        );
+        self.cx.needs_refresh(Entity::root());
+        self.update();
+    }
+
+    /// Returns the current time of the virtual clock.
+    pub fn now(&self) -> Instant {
+        self.time
+    }
+
+    /// Handles any queued events and runs the style, animation, and layout systems until no more events are sent and
+    /// any scheduled idle work is finished.
+    pub fn update(&mut self) {
+        loop {
+            self.event_manager.flush_events(self.cx.context(), |_| {});
+            if !self.cx.has_queued_events() {
+                self.cx.process_idle_tasks();
+            }
+            self.cx.process_style_updates();
+            self.cx.process_animations();
+            self.cx.process_visual_updates();
+
+            if !self.cx.has_queued_events() && !self.cx.has_idle_tasks() {
+                break;
+            }
+        }
+    }
+
+    /// Sends an event to the root window and handles it.
+    pub fn emit<M: Send + Any>(&mut self, message: M) {
+        self.cx.emit_window_event(Entity::root(), message);
+        self.update();
+    }
+
+    /// Moves the mouse cursor to the given position in the window.
+    pub fn hover(&mut self, x: f32, y: f32) {
+        self.emit(WindowEvent::MouseMove(x, y));
+    }
+
+    /// Moves the mouse cursor to the given position in the window and clicks the left mouse button.
+    pub fn click_at(&mut self, x: f32, y: f32) {
+        self.hover(x, y);
+        self.emit(WindowEvent::MouseDown(MouseButton::Left));
+        self.emit(WindowEvent::MouseUp(MouseButton::Left));
+    }
+
+    /// Moves the mouse cursor to the center of a view and clicks the left mouse button.
+    pub fn click(&mut self, target: impl TestTarget) {
+        let (x, y) = self.bounds(target).center();
+        self.click_at(x, y);
+    }
+
+    /// Presses and releases a key.
+    pub fn key(&mut self, code: Code) {
+        self.emit(WindowEvent::KeyDown(code, None));
+        self.emit(WindowEvent::KeyUp(code, None));
+    }
+
+    /// Types each character of some text into the focused view.
+    pub fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            self.emit(WindowEvent::CharInput(character));
+        }
+    }
+
+    /// Advances the virtual clock, firing any timers and scheduled events, and playing any animations, which
+    /// become due along the way in the order they are due.
+    pub fn advance_time(&mut self, duration: Duration) {
+        let end = self.time + duration;
+
+        loop {
+            self.cx.process_timers();
+            self.cx.emit_scheduled_events();
+            self.cx.process_animation_frames(Duration::ZERO);
+            self.update();
+
+            if self.time == end {
+                break;
+            }
+
+            self.time = self
+                .cx
+                .get_next_timer_time()
+                .filter(|time| *time > self.time && *time < end)
+                .unwrap_or(end);
+            set_virtual_time(Some(self.time));
+        }
+    }
+
+    /// Returns the first view, in tree order, which matches a selector.
+    pub fn query(&self, selector: &str) -> Option<Entity> {
+        self.query_all(selector).into_iter().next()
+    }
+
+    /// Returns all of the views, in tree order, which match a selector.
+    pub fn query_all(&self, selector: &str) -> Vec<Entity> {
+        let Some(selectors) = Selectors::parse_list(selector) else {
+            return Vec::new();
+        };
+
+        let cx = &self.cx.0;
+        TreeIterator::full(&cx.tree)
+            .filter(|entity| matches_selector_list(&selectors, *entity, &cx.style, &cx.tree))
+            .collect()
+    }
+
+    /// Returns true if a view matches a selector, for example `":checked"` or `".selected:hover"`.
+    pub fn matches(&self, target: impl TestTarget, selector: &str) -> bool {
+        let entity = self.resolve(target);
+        let cx = &self.cx.0;
+        Selectors::parse_list(selector)
+            .is_some_and(|selectors| matches_selector_list(&selectors, entity, &cx.style, &cx.tree))
+    }
+
+    /// Returns a reference to a view of the given type.
+    pub fn get<V: View>(&self, target: impl TestTarget) -> Option<&V> {
+        let entity = self.resolve(target);
+        self.cx.0.views.get(&entity).and_then(|view| view.downcast_ref::<V>())
+    }
+
+    /// Returns the text of a view.
+    pub fn text(&self, target: impl TestTarget) -> Option<&str> {
+        let entity = self.resolve(target);
+        self.cx.0.style.text.get(entity).map(|text| text.as_str())
+    }
+
+    /// Returns the bounding box of a view in window coordinates.
+    pub fn bounds(&self, target: impl TestTarget) -> BoundingBox {
+        let entity = self.resolve(target);
+        self.cx.0.cache.get_bounds(entity)
+    }
+
+    /// Returns true if the mouse cursor is over a view and not over any of the views above it.
+    pub fn is_hovered(&self, target: impl TestTarget) -> bool {
+        self.cx.0.hovered == self.resolve(target)
+    }
+
+    /// Returns true if a view has keyboard focus.
+    pub fn is_focused(&self, target: impl TestTarget) -> bool {
+        self.cx.0.focused == self.resolve(target)
+    }
+
+    /// Returns a snapshot of the view tree. See [`Context::dump_tree`].
+    pub fn dump_tree(&self) -> EntityTree {
+        self.cx.0.dump_tree()
+    }
+
+    fn resolve(&self, target: impl TestTarget) -> Entity {
+        target.entity(self).expect("No view matches the target")
+    }
+}
+
+/// A snapshot of a view and its descendants, returned by [`Context::dump_tree`].
+///
+/// Style properties hold their computed values written as in a stylesheet, for example `"none"` for the `display`
+/// of a hidden view and `"#ff0000ff"` for a red background.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityTree {
+    /// The element name of the view, such as `"button"`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none", default))]
+    pub element: Option<String>,
+    /// The id of the view given with the `id` modifier.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none", default))]
+    pub id: Option<String>,
+    /// The class names of the view, in alphabetical order.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty", default))]
+    pub classes: Vec<String>,
+    /// The bounds of the view in window coordinates, as `[x, y, width, height]`.
+    pub bounds: [f32; 4],
+    /// The `display` of the view, either `"flex"` or `"none"`.
+    pub display: String,
+    /// The `visibility` of the view, either `"visible"` or `"hidden"`.
+    pub visibility: String,
+    /// The background color of the view as a hex string, if it has one.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none", default))]
+    pub background_color: Option<String>,
+    /// The text content of the view.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none", default))]
+    pub text: Option<String>,
+    /// The snapshots of the children of the view.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty", default))]
+    pub children: Vec<EntityTree>,
+}
+
+impl EntityTree {
+    pub(crate) fn new(cx: &Context, entity: Entity) -> Self {
+        let mut classes = cx
+            .style
+            .classes
+            .get(entity)
+            .map(|classes| classes.iter().map(|class| class.as_str().to_owned()).collect())
+            .unwrap_or_else(Vec::new);
+        classes.sort();
+
+        let bounds = cx.cache.get_bounds(entity);
+
+        Self {
+            element: cx.style.element.get(entity).map(|element| element.as_str().to_owned()),
+            id: cx.style.ids.get(entity).map(|id| id.as_str().to_owned()),
+            classes,
+            bounds: [bounds.x, bounds.y, bounds.w, bounds.h],
+            display: match cx.style.display.get(entity).copied().unwrap_or_default() {
+                Display::Flex => "flex",
+                Display::None => "none",
+            }
+            .to_owned(),
+            visibility: match cx.style.visibility.get(entity).copied().unwrap_or_default() {
+                Visibility::Visible => "visible",
+                Visibility::Hidden => "hidden",
+            }
+            .to_owned(),
+            background_color: cx.style.background_color.get(entity).map(|color| {
+                format!("#{:02x}{:02x}{:02x}{:02x}", color.r(), color.g(), color.b(), color.a())
+            }),
+            text: cx.style.text.get(entity).cloned(),
+            children: entity.child_iter(&cx.tree).map(|child| Self::new(cx, child)).collect(),
+        }
+    }
+
+    /// Returns the first view in the snapshot, in tree order, which matches a simple selector made of an optional
+    /// element name followed by any number of `#id` and `.class` parts, for example `"button.primary"`.
+    ///
+    /// Combinators and pseudo-classes are not supported. Use [`TestHarness::query`] for full selectors.
+    pub fn find(&self, selector: &str) -> Option<&EntityTree> {
+        self.iter().find(|node| node.matches(selector))
+    }
+
+    /// Returns all of the views in the snapshot, in tree order, which match a simple selector. See
+    /// [`find`](EntityTree::find).
+    pub fn find_all(&self, selector: &str) -> Vec<&EntityTree> {
+        self.iter().filter(|node| node.matches(selector)).collect()
+    }
+
+    /// Returns an iterator over this view and all of its descendants, in tree order.
+    pub fn iter(&self) -> impl Iterator<Item = &EntityTree> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    fn matches(&self, selector: &str) -> bool {
+        let mut rest = selector.trim();
+        if rest.is_empty() {
+            return false;
+        }
+
+        let element_end = rest.find(|c| c == '#' || c == '.').unwrap_or(rest.len());
+        let (element, parts) = rest.split_at(element_end);
+        if !element.is_empty() && element != "*" && self.element.as_deref() != Some(element) {
+            return false;
+        }
+
+        rest = parts;
+        while let Some(kind) = rest.chars().next() {
+            let name = &rest[1..];
+            let end = name.find(|c| c == '#' || c == '.').unwrap_or(name.len());
+            let matched = match kind {
+                '#' => self.id.as_deref() == Some(&name[..end]),
+                _ => self.classes.iter().any(|class| class == &name[..end]),
+            };
+            if !matched {
+                return false;
+            }
+            rest = &name[end..];
+        }
+
+        true
+    }
+
+    /// Serializes the snapshot to pretty-printed JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize the entity tree")
+    }
+}
+
+impl Drop for TestHarness {
+    fn drop(&mut self) {
+        set_virtual_time(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn timers_fire_on_virtual_time() {
+        let ticks = Rc::new(Cell::new(0));
+        let mut harness = TestHarness::new(|_| {});
+
+        let timer_ticks = ticks.clone();
+        let cx = harness.context();
+        let timer = cx.add_timer(Duration::from_millis(100), None, move |_, action| {
+            if let TimerAction::Tick(_) = action {
+                timer_ticks.set(timer_ticks.get() + 1);
+            }
+        });
+        cx.start_timer(timer);
+
+        harness.advance_time(Duration::from_millis(50));
+        assert_eq!(ticks.get(), 0);
+
+        harness.advance_time(Duration::from_millis(300));
+        assert_eq!(ticks.get(), 3);
+    }
+
+    #[test]
+    fn timers_are_removed_with_their_view() {
+        let ticks = Rc::new(Cell::new(0));
+        let ticker_timer = Rc::new(Cell::new(None));
+        let (timer_ticks, added_timer) = (ticks.clone(), ticker_timer.clone());
+        let mut harness = TestHarness::new(move |cx| {
+            let (timer_ticks, added_timer) = (timer_ticks.clone(), added_timer.clone());
+            Element::new(cx).id("ticker").on_build(move |cx| {
+                let timer_ticks = timer_ticks.clone();
+                let timer = cx.add_timer(Duration::from_millis(100), None, move |_, action| {
+                    if let TimerAction::Tick(_) = action {
+                        timer_ticks.set(timer_ticks.get() + 1);
+                    }
+                });
+                cx.start_timer(timer);
+                added_timer.set(Some(timer));
+            });
+        });
+        let timer = ticker_timer.get().unwrap();
+
+        harness.advance_time(Duration::from_millis(150));
+        assert_eq!(ticks.get(), 1);
+
+        let ticker = harness.query("#ticker").unwrap();
+        harness.context().remove(ticker);
+        assert!(harness.context().timers[timer.index()].is_none());
+
+        harness.advance_time(Duration::from_millis(300));
+        assert_eq!(ticks.get(), 1);
+
+        // The handle of the removed timer doesn't refer to a timer added later.
+        let cx = harness.context();
+        let other = cx.add_timer(Duration::from_millis(100), None, |_, _| {});
+        assert_ne!(other, timer);
+        cx.start_timer(timer);
+        assert!(!cx.timer_is_running(other));
+        assert!(!cx.timer_is_running(timer));
+    }
+
+    #[test]
+    fn key_moves_focus() {
+        let mut harness = TestHarness::new(|cx| {
+            Button::new(cx, |cx| Label::new(cx, "First")).id("first");
+            Button::new(cx, |cx| Label::new(cx, "Second")).id("second");
+        });
+
+        harness.click("#first");
+        assert!(harness.is_focused("#first"));
+
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#second"));
+        assert_eq!(harness.text("#second > label"), Some("Second"));
+    }
+
+    #[test]
+    fn dump_tree_finds_views() {
+        let harness = TestHarness::new(|cx| {
+            Button::new(cx, |cx| Label::new(cx, "Submit")).class("submit-button");
+            Label::new(cx, "Hidden").id("hidden").display(Display::None);
+        });
+
+        let tree = harness.dump_tree();
+        let button = tree.find("button.submit-button").unwrap();
+        assert_eq!(button.find("label").unwrap().text.as_deref(), Some("Submit"));
+        assert!(button.bounds[2] > 0.0);
+        assert_eq!(tree.find("#hidden").unwrap().display, "none");
+        assert_eq!(tree.find_all("label").len(), 2);
+    }
+}
@@ -0,0 +1,191 @@
+use std::ops::Range;
+
+use skia_safe::textlayout::{Paragraph, RectHeightStyle, RectWidthStyle, TextDirection};
+use skia_safe::Rect;
+use unicode_segmentation::UnicodeSegmentation;
+use vizia_style::Direction;
+
+/// Returns the direction of the first strongly directional character of some text, or `None` if the text has no
+/// strongly directional characters, such as text made of only digits and punctuation.
+pub fn first_strong_direction(text: &str) -> Option<Direction> {
+    text.chars().find_map(|c| {
+        if is_strong_rtl(c) {
+            Some(Direction::Rtl)
+        } else if c.is_alphabetic() {
+            Some(Direction::Ltr)
+        } else {
+            None
+        }
+    })
+}
+
+// Returns true if a character belongs to a right-to-left script, such as Hebrew or Arabic.
+fn is_strong_rtl(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}'
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+        | '\u{10800}'..='\u{10FFF}'
+        | '\u{1E800}'..='\u{1EFFF}'
+    ) && c.is_alphabetic()
+}
+
+/// Converts a byte offset into some text to the UTF-16 index used by the paragraph of the text.
+pub fn utf16_index(text: &str, offset: usize) -> usize {
+    text.get(..offset).unwrap_or(text).encode_utf16().count()
+}
+
+/// Converts a UTF-16 index into the paragraph of some text to a byte offset, rounded to the nearest grapheme
+/// boundary.
+pub fn offset_for_utf16_index(text: &str, index: usize) -> usize {
+    let mut utf16 = 0;
+    for (offset, grapheme) in text.grapheme_indices(true) {
+        let len = grapheme.encode_utf16().count();
+        if index < utf16 + len {
+            return if (index - utf16) * 2 < len { offset } else { offset + grapheme.len() };
+        }
+        utf16 += len;
+    }
+
+    text.len()
+}
+
+/// A position in a paragraph where the caret can be placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaretStop {
+    /// The byte offset of the caret in the text.
+    pub offset: usize,
+    /// The horizontal position of the caret relative to the paragraph.
+    pub x: f32,
+    /// The top of the line containing the caret, relative to the paragraph.
+    pub top: f32,
+    /// The height of the caret.
+    pub height: f32,
+}
+
+// Returns the bounds and direction of the glyphs of a byte range of the text of a paragraph. The zero-width space
+// at the end of the paragraph is used for the range after the end of the text.
+fn range_box(
+    text: &str,
+    paragraph: &Paragraph,
+    range: Range<usize>,
+) -> Option<(Rect, TextDirection)> {
+    let start = utf16_index(text, range.start);
+    let end = if range.start == text.len() { start + 1 } else { utf16_index(text, range.end) };
+    paragraph
+        .get_rects_for_range(start..end, RectHeightStyle::Tight, RectWidthStyle::Tight)
+        .first()
+        .map(|text_box| (text_box.rect, text_box.direct))
+}
+
+// Returns the horizontal position of the edge of a grapheme which comes first in the reading order of its run.
+fn leading_edge(rect: Rect, direction: TextDirection) -> f32 {
+    if direction == TextDirection::RTL {
+        rect.right
+    } else {
+        rect.left
+    }
+}
+
+// Returns the horizontal position of the edge of a grapheme which comes last in the reading order of its run.
+fn trailing_edge(rect: Rect, direction: TextDirection) -> f32 {
+    if direction == TextDirection::RTL {
+        rect.left
+    } else {
+        rect.right
+    }
+}
+
+/// Returns the caret stop at a byte offset into the text of a paragraph with the given base direction.
+///
+/// At the boundary of runs with different directions the caret is placed next to the grapheme whose direction
+/// matches the base direction, so that every offset on a line has a distinct position. Paragraphs of editable text
+/// end with a zero-width space, which is used to place the caret at the end of the text.
+pub fn caret_stop(
+    text: &str,
+    paragraph: &Paragraph,
+    offset: usize,
+    base: Direction,
+) -> Option<CaretStop> {
+    let base = TextDirection::from(base);
+    let prev = text
+        .get(..offset)?
+        .graphemes(true)
+        .next_back()
+        .and_then(|grapheme| range_box(text, paragraph, offset - grapheme.len()..offset));
+    let next_end = text[offset..].graphemes(true).next().map_or(offset, |g| offset + g.len());
+    let next = range_box(text, paragraph, offset..next_end);
+
+    let (rect, x) = match (prev, next) {
+        // The caret stays at the end of a line only if there is no following line.
+        (Some((prev, direction)), next)
+            if direction == base && next.is_none_or(|(next, _)| prev.bottom > next.top) =>
+        {
+            (prev, trailing_edge(prev, direction))
+        }
+        (_, Some((next, direction))) => (next, leading_edge(next, direction)),
+        (Some((prev, direction)), None) => (prev, trailing_edge(prev, direction)),
+        (None, None) => return None,
+    };
+
+    Some(CaretStop { offset, x, top: rect.top, height: rect.height() })
+}
+
+/// Returns the caret stops on the same line as a byte offset into the text of a paragraph with the given base
+/// direction, in visual order from left to right.
+pub fn line_caret_stops(
+    text: &str,
+    paragraph: &Paragraph,
+    offset: usize,
+    base: Direction,
+) -> Vec<CaretStop> {
+    let Some(current) = caret_stop(text, paragraph, offset, base) else {
+        return Vec::new();
+    };
+
+    // Only the offsets within the line containing the current offset are measured. Like the rest of the paragraph
+    // API apart from the rects, the line metrics use byte offsets.
+    let line = paragraph
+        .get_line_number_at(offset)
+        .and_then(|line| paragraph.get_line_metrics_at(line))
+        .map(|metrics| {
+            metrics.start_index.min(text.len())..metrics.end_including_newline.min(text.len())
+        })
+        .filter(|line| text.get(line.clone()).is_some())
+        .unwrap_or(0..text.len());
+
+    let mut stops = text[line.clone()]
+        .grapheme_indices(true)
+        .map(|(offset, _)| line.start + offset)
+        .chain(std::iter::once(line.end))
+        .filter_map(|offset| caret_stop(text, paragraph, offset, base))
+        .filter(|stop| (stop.top - current.top).abs() < current.height / 2.0)
+        .collect::<Vec<_>>();
+
+    stops.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.offset.cmp(&b.offset)));
+    stops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_direction_from_content() {
+        assert_eq!(first_strong_direction("123 hello"), Some(Direction::Ltr));
+        assert_eq!(first_strong_direction("(שלום) world"), Some(Direction::Rtl));
+        assert_eq!(first_strong_direction("٣ مرحبا"), Some(Direction::Rtl));
+        assert_eq!(first_strong_direction("42!"), None);
+    }
+
+    #[test]
+    fn converts_utf16_indices() {
+        let text = "aمَb";
+        assert_eq!(utf16_index(text, 0), 0);
+        assert_eq!(utf16_index(text, text.len()), 4);
+        assert_eq!(offset_for_utf16_index(text, 1), 1);
+        // An index inside the combining mark rounds to the end of the grapheme.
+        assert_eq!(offset_for_utf16_index(text, 2), 5);
+        assert_eq!(offset_for_utf16_index(text, 4), text.len());
+    }
+}
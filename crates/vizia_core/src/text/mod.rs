@@ -1,6 +1,9 @@
 mod movement;
 pub use movement::*;
 
+mod bidi;
+pub use bidi::*;
+
 // pub(crate) mod scrolling;
 // pub(crate) use scrolling::*;
 
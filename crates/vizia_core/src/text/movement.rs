@@ -1,7 +1,7 @@
 use log::warn;
 use skia_safe::textlayout::Paragraph;
 
-use super::{EditableText, Selection};
+use super::{line_caret_stops, EditableText, Selection};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Direction {
@@ -33,6 +33,9 @@ impl Direction {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Movement {
     Grapheme(Direction),
+    /// Moves to the grapheme boundary which is visually next to the caret on the same line, which may be logically
+    /// far away when the caret is at the boundary of runs with different directions.
+    Visual(Direction),
     Word(Direction),
     Line(Direction),
     Page(Direction),
@@ -61,27 +64,37 @@ pub enum WritingDirection {
     Natural,
 }
 
+impl From<vizia_style::Direction> for WritingDirection {
+    fn from(direction: vizia_style::Direction) -> Self {
+        match direction {
+            vizia_style::Direction::Ltr => WritingDirection::LeftToRight,
+            vizia_style::Direction::Rtl => WritingDirection::RightToLeft,
+        }
+    }
+}
+
 /// Compute the result of a [`Movement`] on a [`Selection`].
 ///
 /// returns a new selection representing the state after the movement.
 ///
+/// `writing_direction` is the base direction of the paragraph, which determines
+/// whether moving left or right by a grapheme or word is logically backwards.
+///
 /// If `modify` is true, only the 'active' edge (the `end`) of the selection
 /// should be changed; this is the case when the user moves with the shift
 /// key pressed.
-pub fn apply_movement<T: EditableText>(
+pub fn apply_movement<T: EditableText + AsRef<str>>(
     m: Movement,
     s: Selection,
     text: &T,
     paragraph: &Paragraph,
+    writing_direction: WritingDirection,
     modify: bool,
 ) -> Selection {
-    // let writing_direction = if crate::piet::util::first_strong_rtl(text.as_str()) {
-    //     WritingDirection::RightToLeft
-    // } else {
-    //     WritingDirection::LeftToRight
-    // };
-
-    let writing_direction = WritingDirection::LeftToRight;
+    let (writing_direction, base) = match writing_direction {
+        WritingDirection::RightToLeft => (writing_direction, vizia_style::Direction::Rtl),
+        _ => (WritingDirection::LeftToRight, vizia_style::Direction::Ltr),
+    };
 
     let (offset, h_pos) = match m {
         Movement::Grapheme(d) if d.is_upstream_for_direction(writing_direction) => {
@@ -100,6 +113,38 @@ pub fn apply_movement<T: EditableText>(
                 (s.max(), None)
             }
         }
+        Movement::Visual(d) => {
+            let stops = line_caret_stops(text.as_ref(), paragraph, s.active, base);
+            let x = |offset| stops.iter().find(|stop| stop.offset == offset).map(|stop| stop.x);
+            if s.is_caret() || modify {
+                stops
+                    .iter()
+                    .position(|stop| stop.offset == s.active)
+                    .and_then(|index| match d {
+                        Direction::Left => index.checked_sub(1),
+                        Direction::Right => Some(index + 1),
+                        _ => None,
+                    })
+                    .and_then(|index| stops.get(index))
+                    .map(|stop| stop.offset)
+                    // At the visual edge of a line, continue logically onto the adjacent line.
+                    .or_else(|| {
+                        if d.is_upstream_for_direction(writing_direction) {
+                            text.prev_grapheme_offset(s.active)
+                        } else {
+                            text.next_grapheme_offset(s.active)
+                        }
+                    })
+                    .map(|offset| (offset, None))
+                    .unwrap_or((s.active, s.h_pos))
+            } else {
+                // Collapse the selection to its visually leftmost or rightmost edge.
+                let (min, max) = (s.min(), s.max());
+                let min_is_left = x(min).zip(x(max)).is_none_or(|(min_x, max_x)| min_x <= max_x);
+                let left = matches!(d, Direction::Left | Direction::Upstream);
+                (if min_is_left == left { min } else { max }, None)
+            }
+        }
         Movement::Vertical(VerticalMovement::LineUp) => {
             let cluster = paragraph.get_glyph_cluster_at(s.active).unwrap();
             let glyph_bounds = cluster.bounds;
@@ -182,16 +227,28 @@ pub fn apply_movement<T: EditableText>(
         Movement::Vertical(VerticalMovement::PageDown)
         | Movement::Vertical(VerticalMovement::PageUp) => (s.active, s.h_pos),
 
+        // The logical start and end of the visual line, which are on the right of the line in a right-to-left
+        // paragraph.
         Movement::LineStart => {
-            let line = paragraph.get_line_number_at(s.active).unwrap();
-            let lm = paragraph.get_line_metrics_at(line).unwrap();
-            (lm.start_index, None)
+            let stops = line_caret_stops(text.as_ref(), paragraph, s.active, base);
+            (stops.iter().map(|stop| stop.offset).min().unwrap_or(s.active), None)
         }
 
         Movement::LineEnd => {
-            let line = paragraph.get_line_number_at(s.active).unwrap();
-            let lm = paragraph.get_line_metrics_at(line).unwrap();
-            (lm.end_index - 1, None)
+            let stops = line_caret_stops(text.as_ref(), paragraph, s.active, base);
+            let end = stops.iter().map(|stop| stop.offset).max().unwrap_or(s.active);
+            // Move past the last grapheme of a line which ends with a line break or the end of the text, but not
+            // past the last grapheme of a soft-wrapped line, which would place the caret on the next line.
+            let text_str = text.as_ref();
+            match text.next_grapheme_offset(end) {
+                Some(next)
+                    if !text_str[end..].starts_with('\n')
+                        && (next == text.len() || text_str[next..].starts_with('\n')) =>
+                {
+                    (next, None)
+                }
+                _ => (end, None),
+            }
         }
 
         other => {
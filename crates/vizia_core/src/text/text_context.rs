@@ -1,8 +1,13 @@
-use skia_safe::textlayout::{Paragraph, TypefaceFontProvider};
+use skia_safe::textlayout::{Paragraph, TextStyle, TypefaceFontProvider};
 use skia_safe::{textlayout::FontCollection, FontMgr};
 use vizia_storage::SparseSet;
 
 use crate::{entity::Entity, layout::BoundingBox};
+use vizia_style::TextAlign;
+
+/// The maximum number of paragraphs drawn with `DrawContext::draw_string` which are cached for each view. This is
+/// enough for views which draw many strings every frame, such as the labels of a chart, to keep all of them.
+const MAX_CACHED_STRINGS: usize = 256;
 
 pub struct TextContext {
     pub font_collection: FontCollection,
@@ -10,9 +15,82 @@ pub struct TextContext {
     pub asset_provider: TypefaceFontProvider,
     pub text_bounds: SparseSet<BoundingBox>,
     pub text_paragraphs: SparseSet<Paragraph>,
+    pub string_paragraphs: SparseSet<Vec<(StringKey, Paragraph)>>,
+    // Whether fonts installed on the system are used, see `Context::disable_system_fonts`.
+    pub(crate) system_fonts: bool,
+    // A font collection for each thread of the rayon thread pool, created when text is first built in parallel.
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    pub(crate) worker_font_collections: Vec<FontCollection>,
+}
+
+/// The inputs a paragraph drawn with `DrawContext::draw_string` was built and laid out with.
+#[derive(Clone, PartialEq)]
+pub(crate) struct StringKey {
+    pub text: String,
+    pub text_style: TextStyle,
+    pub text_align: TextAlign,
+    pub max_lines: Option<usize>,
+    pub width: f32,
 }
 
 impl TextContext {
+    pub(crate) fn new() -> Self {
+        let default_font_manager = FontMgr::default();
+        let asset_provider = TypefaceFontProvider::new();
+
+        let mut text_context = Self {
+            font_collection: FontCollection::new(),
+            default_font_manager,
+            asset_provider,
+            text_bounds: Default::default(),
+            text_paragraphs: Default::default(),
+            string_paragraphs: Default::default(),
+            system_fonts: true,
+            #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+            worker_font_collections: Vec::new(),
+        };
+
+        text_context.font_collection = text_context.new_font_collection();
+        text_context
+    }
+
+    /// Creates a font collection with the system fonts, if they are enabled, and the fonts added to the context.
+    pub(crate) fn new_font_collection(&self) -> FontCollection {
+        let mut font_collection = FontCollection::new();
+
+        if self.system_fonts {
+            font_collection.set_default_font_manager(self.default_font_manager.clone(), None);
+        } else {
+            font_collection.set_default_font_manager(None::<FontMgr>, None::<&str>);
+            font_collection.disable_font_fallback();
+        }
+
+        let asset_font_manager: FontMgr = self.asset_provider.clone().into();
+        font_collection.set_asset_font_manager(asset_font_manager);
+
+        font_collection
+    }
+
+    /// Returns a font collection for each thread of the rayon thread pool, so that paragraphs can be built in
+    /// parallel.
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    pub(crate) fn worker_font_collections(&mut self) -> &[FontCollection] {
+        let threads = rayon::current_num_threads();
+        if self.worker_font_collections.len() != threads {
+            self.worker_font_collections =
+                (0..threads).map(|_| self.new_font_collection()).collect();
+        }
+
+        &self.worker_font_collections
+    }
+
+    /// Discards the font collections of the thread pool after the available fonts change, so that they are created
+    /// again with the new fonts.
+    pub(crate) fn fonts_changed(&mut self) {
+        #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+        self.worker_font_collections.clear();
+    }
+
     #[allow(dead_code)]
     pub(crate) fn font_collection(&self) -> &FontCollection {
         &self.font_collection
@@ -21,4 +99,84 @@ impl TextContext {
     pub(crate) fn set_text_bounds(&mut self, entity: Entity, bounds: BoundingBox) {
         self.text_bounds.insert(entity, bounds);
     }
+
+    /// Returns the cached paragraph for a string drawn by a view, building and caching it if the string or the
+    /// style it is drawn with has changed. When the cache of the view is full the least recently drawn string is
+    /// dropped.
+    pub(crate) fn string_paragraph(
+        &mut self,
+        entity: Entity,
+        key: StringKey,
+        build: impl FnOnce(&FontCollection) -> Paragraph,
+    ) -> &Paragraph {
+        if !self.string_paragraphs.contains(entity) {
+            self.string_paragraphs.insert(entity, Vec::new());
+        }
+
+        let font_collection = &self.font_collection;
+        let cached = self.string_paragraphs.get_mut(entity).unwrap();
+        // The strings are kept in the order they were last drawn, most recent last.
+        match cached.iter().position(|(cached_key, _)| *cached_key == key) {
+            Some(index) => {
+                let string = cached.remove(index);
+                cached.push(string);
+            }
+            None => {
+                if cached.len() >= MAX_CACHED_STRINGS {
+                    cached.remove(0);
+                }
+                cached.push((key, (build)(font_collection)));
+            }
+        }
+
+        &cached.last().unwrap().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skia_safe::textlayout::{ParagraphBuilder, ParagraphStyle};
+    use vizia_id::GenerationalId;
+
+    // Draws some strings for the root view through the cache, returning how many paragraphs had to be built.
+    fn draw_strings(text_context: &mut TextContext, strings: impl Iterator<Item = usize>) -> usize {
+        let mut built = 0;
+        for string in strings {
+            let key = StringKey {
+                text: string.to_string(),
+                text_style: TextStyle::new(),
+                text_align: TextAlign::Start,
+                max_lines: None,
+                width: 100.0,
+            };
+            text_context.string_paragraph(Entity::root(), key, |font_collection| {
+                built += 1;
+                ParagraphBuilder::new(&ParagraphStyle::new(), font_collection).build()
+            });
+        }
+
+        built
+    }
+
+    #[test]
+    fn keeps_strings_drawn_every_frame() {
+        let mut text_context = TextContext::new();
+
+        assert_eq!(draw_strings(&mut text_context, 0..100), 100);
+        assert_eq!(draw_strings(&mut text_context, 0..100), 0);
+    }
+
+    #[test]
+    fn drops_least_recently_drawn_string() {
+        let mut text_context = TextContext::new();
+        draw_strings(&mut text_context, 0..MAX_CACHED_STRINGS);
+
+        // Drawing the first string again makes the second one the least recently drawn.
+        assert_eq!(draw_strings(&mut text_context, 0..1), 0);
+        assert_eq!(draw_strings(&mut text_context, MAX_CACHED_STRINGS..MAX_CACHED_STRINGS + 1), 1);
+
+        assert_eq!(draw_strings(&mut text_context, 0..1), 0);
+        assert_eq!(draw_strings(&mut text_context, 1..2), 1);
+    }
 }
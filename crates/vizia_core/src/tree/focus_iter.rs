@@ -46,12 +46,20 @@ pub(crate) fn is_navigatable(
 }
 
 /// Get the next entity to be focused during forward keyboard navigation.
+///
+/// An explicit next entity set by the focus order of the node is used if it can be navigated to.
 pub(crate) fn focus_forward(
     tree: &Tree<Entity>,
     style: &Style,
     node: Entity,
     lock_focus_to: Entity,
 ) -> Option<Entity> {
+    if let Some(next) = style.focus_order.get(node).map(|focus_order| focus_order.next) {
+        if !next.is_null() && is_navigatable(tree, style, next, lock_focus_to) {
+            return Some(next);
+        }
+    }
+
     FocusTreeIterator::new(
         tree,
         DoubleEndedTreeTour::new(Some(node), Some(Entity::root())),
@@ -65,12 +73,20 @@ pub(crate) fn focus_forward(
 }
 
 /// Get the next entity to be focused during backward keybaord navigation.
+///
+/// An explicit previous entity set by the focus order of the node is used if it can be navigated to.
 pub(crate) fn focus_backward(
     tree: &Tree<Entity>,
     style: &Style,
     node: Entity,
     lock_focus_to: Entity,
 ) -> Option<Entity> {
+    if let Some(prev) = style.focus_order.get(node).map(|focus_order| focus_order.prev) {
+        if !prev.is_null() && is_navigatable(tree, style, prev, lock_focus_to) {
+            return Some(prev);
+        }
+    }
+
     let mut iter = FocusTreeIterator::new(
         tree,
         DoubleEndedTreeTour::new_raw(
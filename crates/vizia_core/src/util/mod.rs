@@ -1,6 +1,14 @@
-use std::path::{Path, PathBuf};
+use std::{
+    cell::Cell,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use web_time::Instant;
 
 /// Helper trait for getting CSS from a string or path.
+///
+/// Strings are always treated as CSS. To load a stylesheet from a file use a [`StylesheetPath`].
 pub trait IntoCssStr: 'static {
     /// Returns a string containing CSS.
     fn get_style(&self) -> Result<String, std::io::Error>;
@@ -22,6 +30,12 @@ impl IntoCssStr for &'static str {
     }
 }
 
+impl IntoCssStr for String {
+    fn get_style(&self) -> Result<String, std::io::Error> {
+        Ok(self.clone())
+    }
+}
+
 impl IntoCssStr for PathBuf {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
@@ -34,6 +48,65 @@ impl IntoCssStr for Path {
     }
 }
 
+/// The path of a stylesheet file, which is read when the stylesheet is added and whenever the styles are reloaded.
+///
+/// # Example
+/// ```no_run
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// cx.add_stylesheet(StylesheetPath::new("assets/style.css")).expect("Failed to add stylesheet");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StylesheetPath(pub PathBuf);
+
+impl StylesheetPath {
+    /// Creates a new stylesheet path.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self(path.as_ref().to_owned())
+    }
+}
+
+impl IntoCssStr for StylesheetPath {
+    fn get_style(&self) -> Result<String, std::io::Error> {
+        std::fs::read_to_string(&self.0)
+    }
+}
+
+/// An error returned when adding a stylesheet.
+#[derive(Debug)]
+pub enum StylesheetError {
+    /// The stylesheet file could not be read, so the stylesheet was not added.
+    IoError(std::io::Error),
+    /// The stylesheet was added, but some of its rules or declarations were invalid and were skipped.
+    ParseWarning(Vec<String>),
+}
+
+impl Display for StylesheetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StylesheetError::IoError(error) => write!(f, "Failed to read stylesheet: {}", error),
+            StylesheetError::ParseWarning(warnings) => {
+                write!(f, "Stylesheet has invalid rules: {}", warnings.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for StylesheetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StylesheetError::IoError(error) => Some(error),
+            StylesheetError::ParseWarning(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for StylesheetError {
+    fn from(error: std::io::Error) -> Self {
+        StylesheetError::IoError(error)
+    }
+}
+
 #[doc(hidden)]
 pub enum CSS {
     Path(PathBuf),
@@ -82,3 +155,43 @@ macro_rules! include_style {
         )))
     };
 }
+
+thread_local! {
+    // The time of the virtual clock used by the test harness, if one is running on this thread.
+    static VIRTUAL_TIME: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Returns the current time used for timers, scheduled events, and animations.
+///
+/// This is the time of the virtual clock while a [`TestHarness`](crate::testing::TestHarness) is running on the
+/// current thread, so that tests don't depend on how long they take to run.
+pub(crate) fn now() -> Instant {
+    VIRTUAL_TIME.with(|time| time.get()).unwrap_or_else(Instant::now)
+}
+
+/// Sets the time of the virtual clock for the current thread, or switches back to the system clock if `None`.
+pub(crate) fn set_virtual_time(time: Option<Instant>) {
+    VIRTUAL_TIME.with(|virtual_time| virtual_time.set(time));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn stylesheet_errors() {
+        let cx = &mut Context::default();
+
+        assert!(cx.add_stylesheet(".valid { width: 10px; }").is_ok());
+        assert!(matches!(
+            cx.add_stylesheet(String::from(".valid { width: 10px; } }{")),
+            Err(StylesheetError::ParseWarning(_))
+        ));
+        // Warnings are only returned for the stylesheet which was added.
+        assert!(cx.add_stylesheet(".other { height: 10px; }").is_ok());
+        assert!(matches!(
+            cx.add_stylesheet(StylesheetPath::new("does/not/exist.css")),
+            Err(StylesheetError::IoError(_))
+        ));
+    }
+}
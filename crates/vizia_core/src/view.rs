@@ -16,6 +16,7 @@ use crate::accessibility::IntoNode;
 use crate::prelude::*;
 use crate::systems::get_access_node;
 use std::any::{Any, TypeId};
+use vizia_style::Atom;
 mod handle;
 pub use handle::Handle;
 use hashbrown::HashMap;
@@ -114,7 +115,13 @@ pub trait View: 'static + Sized {
     where
         F: FnOnce(&mut Context),
     {
-        let id = cx.entity_manager.create();
+        let id = match cx.stable_id.take() {
+            Some(key) => cx.entity_manager.create_stable(key).unwrap_or_else(|| {
+                log::warn!("Stable id {} is out of range or already in use", key);
+                cx.entity_manager.create()
+            }),
+            None => cx.entity_manager.create(),
+        };
         let current = cx.current();
         cx.tree.add(id, current).expect("Failed to add to tree");
         cx.cache.add(id);
@@ -122,10 +129,12 @@ pub trait View: 'static + Sized {
         cx.needs_redraw(id);
 
         if let Some(element) = self.element() {
-            cx.style.element.insert(id, fxhash::hash32(element));
+            register_element::<Self>(cx, element);
+            cx.style.element.insert(id, Atom::new(element));
         }
 
         cx.views.insert(id, Box::new(self));
+        cx.capturing_views.insert(id);
 
         let parent_id = cx.tree.get_layout_parent(id).unwrap();
         let parent_node_id = parent_id.accesskit_id();
@@ -160,7 +169,11 @@ pub trait View: 'static + Sized {
         handle
     }
 
-    /// Specifies a name for the view type which can be used as an element selector in css.
+    /// The element name of the view type, which can be used as a type selector in css.
+    ///
+    /// Element names should be lowercase, starting with a letter and containing only letters, digits, `-` and `_`.
+    /// A warning is logged when a view with an invalid element name is built, or when two view types use the same
+    /// element name, including the names of the built-in views.
     ///
     /// # Example
     /// ```
@@ -174,19 +187,27 @@ pub trait View: 'static + Sized {
     /// }
     ///
     /// impl View for CustomView {
-    ///     fn element(&self) -> Option<&'static str> {
-    ///         Some("custom_view")
-    ///     }
+    ///     const ELEMENT: Option<&'static str> = Some("custom-view");
     /// }
     /// ```
-    /// Then in css:
+    /// Then in css, where the element name can be used like the name of any built-in view:
     /// ```css
-    /// custom_view {
+    /// custom-view {
     ///     background-color: red;
     /// }
+    ///
+    /// :is(custom-view, button) > label {
+    ///     color: white;
+    /// }
     /// ```
+    const ELEMENT: Option<&'static str> = None;
+
+    /// Specifies a name for the view which can be used as an element selector in css.
+    ///
+    /// Returns [`ELEMENT`](View::ELEMENT) by default. This method can be overridden to choose the element name of a
+    /// view at runtime.
     fn element(&self) -> Option<&'static str> {
-        None
+        Self::ELEMENT
     }
 
     /// Handles any events received by the view.
@@ -220,6 +241,36 @@ pub trait View: 'static + Sized {
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 
+    /// Handles events on their way to a descendant of the view, before the descendant receives them.
+    ///
+    /// Every event is first passed to this method of each ancestor of its target, from the root down to the parent of
+    /// the target, whatever the propagation of the event. The event is then sent to the target and, depending on its
+    /// [`Propagation`], to the [`event`](View::event) method of the ancestors or descendants of the target. Consuming
+    /// the event in this method stops it from reaching the target, or any other views.
+    ///
+    /// Models and listeners don't take part in this phase. Listeners receive events before any views.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// pub struct ReadOnlyForm {}
+    ///
+    /// impl View for ReadOnlyForm {
+    ///     fn event_capture(&mut self, cx: &mut EventContext, event: &mut Event) {
+    ///         // Stop any keys from reaching the text fields of the form.
+    ///         event.map(|window_event, meta| match window_event {
+    ///             WindowEvent::KeyDown(..) | WindowEvent::CharInput(_) => meta.consume(),
+    ///             _ => {}
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    #[allow(unused_variables)]
+    fn event_capture(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // Views which don't handle the capture phase are skipped by it from now on.
+        cx.capturing_views.remove(&cx.current);
+    }
+
     /// Provides custom drawing for the view.
     ///
     /// Usually the look of a view is determined by the style and layout properties of the view. However, the `draw` method of
@@ -275,6 +326,45 @@ pub trait View: 'static + Sized {
     fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode) {}
 }
 
+// Returns true if an element name is a lowercase CSS identifier.
+fn is_valid_element_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+// Records the element name of a view type the first time a view of the type is built, logging a warning if the name
+// is invalid or is already used by another view type. Built-in views may share element names with each other.
+fn register_element<V: View>(cx: &mut Context, element: &'static str) {
+    let type_id = TypeId::of::<V>();
+    if cx.element_names.contains_key(&type_id) {
+        return;
+    }
+
+    let type_name = std::any::type_name::<V>();
+    if !is_valid_element_name(element) {
+        log::warn!(
+            "The element name `{}` of {} is not a valid css type selector. Element names should be lowercase, starting with a letter and containing only letters, digits, `-` and `_`.",
+            element,
+            type_name
+        );
+    }
+
+    let is_builtin = |type_name: &str| type_name.starts_with("vizia_core::");
+    for (other_element, other_type_name) in cx.element_names.values() {
+        if *other_element == element && !(is_builtin(type_name) && is_builtin(other_type_name)) {
+            log::warn!(
+                "The element name `{}` of {} is already used by {}. Both views will match the same css selectors.",
+                element,
+                type_name,
+                other_type_name
+            );
+        }
+    }
+
+    cx.element_names.insert(type_id, (element, type_name));
+}
+
 impl<T: View> ViewHandler for T
 where
     T: std::marker::Sized + View + 'static,
@@ -287,6 +377,10 @@ where
         <T as View>::event(self, cx, event);
     }
 
+    fn event_capture(&mut self, cx: &mut EventContext, event: &mut Event) {
+        <T as View>::event_capture(self, cx, event);
+    }
+
     fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
         <T as View>::draw(self, cx, canvas);
     }
@@ -307,3 +401,36 @@ where
         TypeId::of::<T>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    struct Swatch;
+
+    impl View for Swatch {
+        const ELEMENT: Option<&'static str> = Some("swatch");
+    }
+
+    #[test]
+    fn element_name_is_a_type_selector() {
+        let harness = TestHarness::new(|cx| {
+            Swatch.build(cx, |cx| {
+                Label::new(cx, "Red");
+            });
+        });
+
+        assert!(harness.query("swatch").is_some());
+        assert!(harness.query(":is(swatch, button) > label").is_some());
+    }
+
+    #[test]
+    fn validates_element_names() {
+        assert!(is_valid_element_name("swatch"));
+        assert!(is_valid_element_name("color-swatch_2"));
+        assert!(!is_valid_element_name("ColorSwatch"));
+        assert!(!is_valid_element_name("2swatch"));
+        assert!(!is_valid_element_name(""));
+    }
+}
@@ -90,6 +90,21 @@ impl<V> Handle<'_, V> {
         self
     }
 
+    /// Sets whether panics within the view and its descendants are caught, even if the application has no panic
+    /// handler.
+    ///
+    /// When a view within the boundary panics while handling an event or being drawn, the boundary is disabled and
+    /// its children are replaced with the panic message, leaving the rest of the application running.
+    pub fn panic_boundary(self, boundary: bool) -> Self {
+        if boundary {
+            self.cx.panic_handling.boundaries.insert(self.entity);
+        } else {
+            self.cx.panic_handling.boundaries.remove(&self.entity);
+        }
+
+        self
+    }
+
     /// Mody the internal data of the view.
     pub fn modify<F>(mut self, f: F) -> Self
     where
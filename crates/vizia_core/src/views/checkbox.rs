@@ -298,3 +298,60 @@ impl View for Checkbox {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Lens)]
+    struct AppData {
+        value: bool,
+    }
+
+    enum AppEvent {
+        Toggle,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|app_event, _| match app_event {
+                AppEvent::Toggle => self.value ^= true,
+            });
+        }
+    }
+
+    fn harness() -> TestHarness {
+        TestHarness::new(|cx| {
+            AppData { value: false }.build(cx);
+            Checkbox::new(cx, AppData::value)
+                .on_toggle(|cx| cx.emit(AppEvent::Toggle))
+                .id("checkbox");
+        })
+    }
+
+    #[test]
+    fn click_toggles() {
+        let mut harness = harness();
+        assert!(!harness.matches("#checkbox", ":checked"));
+
+        harness.click("#checkbox");
+        assert!(harness.matches("#checkbox", ":checked"));
+        assert!(harness.is_focused("#checkbox"));
+
+        // Wait long enough for the second click not to count as a double click.
+        harness.advance_time(Duration::from_secs(1));
+        harness.click("#checkbox");
+        assert!(!harness.matches("#checkbox", ":checked"));
+    }
+
+    #[test]
+    fn space_toggles_when_focused() {
+        let mut harness = harness();
+        harness.key(Code::Tab);
+        assert!(harness.is_focused("#checkbox"));
+
+        harness.key(Code::Space);
+        assert!(harness.matches("#checkbox", ":checked"));
+    }
+}
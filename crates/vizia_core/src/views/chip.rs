@@ -5,6 +5,8 @@ use std::sync::Arc;
 #[derive(Lens)]
 pub struct Chip {
     on_close: Option<Arc<dyn Fn(&mut EventContext) + Send + Sync>>,
+    #[lens(ignore)]
+    on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
 }
 
 impl Chip {
@@ -22,9 +24,12 @@ impl Chip {
     where
         T: ToStringLocalized,
     {
-        Self { on_close: None }
+        Self { on_close: None, on_toggle: None }
             .build(cx, move |cx| {
-                Label::new(cx, text).height(Stretch(1.0)).alignment(Alignment::Left);
+                Label::new(cx, text)
+                    .height(Stretch(1.0))
+                    .alignment(Alignment::Left)
+                    .hoverable(false);
                 Binding::new(cx, Chip::on_close.map(|on_close| on_close.is_some()), |cx, val| {
                     if val.get(cx) {
                         let on_close = Chip::on_close.get(cx).unwrap();
@@ -46,6 +51,25 @@ impl View for Chip {
     fn element(&self) -> Option<&'static str> {
         Some("chip")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::Press { mouse } => {
+                let over = if *mouse { cx.hovered() } else { cx.focused() };
+                if cx.current() != over && !over.is_descendant_of(cx.tree, cx.current()) {
+                    return;
+                }
+
+                if !cx.is_disabled() && cx.current() == meta.target {
+                    if let Some(callback) = &self.on_toggle {
+                        (callback)(cx);
+                    }
+                }
+            }
+
+            _ => {}
+        });
+    }
 }
 
 /// Used in conjunction with the `variant` modifier for selecting the style variant of a chip.
@@ -68,6 +92,73 @@ impl Handle<'_, Chip> {
         })
     }
 
+    /// Adds content before the text of the chip, such as an [`Avatar`] or an icon.
+    ///
+    /// A chip with leading content can be selected with the `.leading` CSS class selector.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// Chip::new(cx, "Jane").leading(|cx| {
+    ///     Avatar::new(cx, |cx| {
+    ///         Label::new(cx, "J");
+    ///     });
+    /// });
+    /// ```
+    pub fn leading(mut self, content: impl FnOnce(&mut Context)) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        cx.with_current(entity, |cx| {
+            let leading = HStack::new(cx, content)
+                .class("chip-leading")
+                .pointer_events(PointerEvents::None)
+                .entity();
+            let _ = cx.tree.set_first_child(leading);
+        });
+
+        self.class("leading")
+    }
+
+    /// Makes the chip selectable, such as a chip used as a filter, with its selected state bound to a value or lens.
+    ///
+    /// A selected chip can be selected with the `:checked` CSS pseudo-class selector. Use
+    /// [`on_toggle`](Self::on_toggle) to change the selected state when the chip is pressed.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     vegan: bool,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # enum AppEvent {
+    /// #     ToggleVegan,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { vegan: false }.build(cx);
+    /// #
+    /// Chip::new(cx, "Vegan")
+    ///     .selectable(AppData::vegan)
+    ///     .on_toggle(|cx| cx.emit(AppEvent::ToggleVegan));
+    /// ```
+    pub fn selectable(self, selected: impl Res<bool>) -> Self {
+        self.checked(selected).navigable(true).class("selectable")
+    }
+
+    /// Sets the callback triggered when a selectable chip is pressed.
+    pub fn on_toggle(self, callback: impl 'static + Fn(&mut EventContext)) -> Self {
+        self.modify(|chip: &mut Chip| chip.on_toggle = Some(Box::new(callback)))
+    }
+
     /// Selects the style variant to be used by the chip. Accepts a value of, or lens to, a [ChipVariant].
     ///
     /// # Example
@@ -96,3 +187,46 @@ impl Handle<'_, Chip> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[derive(Lens)]
+    struct AppData {
+        selected: bool,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|_: &(), _| self.selected ^= true);
+        }
+    }
+
+    #[test]
+    fn selectable_chip_with_leading_content() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { selected: false }.build(cx);
+            Chip::new(cx, "Vegan")
+                .leading(|cx| {
+                    Svg::new(cx, crate::icons::ICON_CHECK).id("icon");
+                })
+                .selectable(AppData::selected)
+                .on_toggle(|cx| cx.emit(()))
+                .id("chip");
+        });
+
+        // The leading content comes before the text of the chip.
+        let chip = harness.query("#chip").unwrap();
+        let leading = harness.query("#chip > .chip-leading").unwrap();
+        assert_eq!(harness.context().tree.get_first_child(chip), Some(leading));
+        assert!(harness.bounds("#icon").right() <= harness.bounds("#chip label").left());
+        assert!(harness.matches(chip, ".leading"));
+
+        assert!(!harness.matches(chip, ":checked"));
+        harness.click("#chip label");
+        assert!(harness.matches(chip, ":checked"));
+        harness.click("#icon");
+        assert!(!harness.matches(chip, ":checked"));
+    }
+}
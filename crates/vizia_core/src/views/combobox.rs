@@ -1,5 +1,8 @@
+use std::fmt::{self, Display};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
+use super::picklist::group_rows;
 use crate::prelude::*;
 
 /// A ComboBox view which combines a textbox with a picklist, allowing users to filter to only the options matching a query.
@@ -21,6 +24,11 @@ pub struct ComboBox<
     selected: L2,
     // Whether the popup list is visible.
     is_open: bool,
+    // Returns whether an item of the list is an option, or the header or an option of a group.
+    #[lens(ignore)]
+    row_kind: fn(&T) -> RowKind,
+    // Callback triggered when an option of a grouped combobox is selected.
+    on_select_grouped: Option<Box<dyn Fn(&mut EventContext, usize, usize)>>,
 
     p: PhantomData<T>,
 }
@@ -30,6 +38,104 @@ pub(crate) enum ComboBoxEvent {
     SetFilterText(String),
 }
 
+// The kind of an item of the popup list of a combobox.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RowKind {
+    // An option of a combobox without groups.
+    Option,
+    // The header of the group with the given index.
+    Header(usize),
+    // An option with the index of its group and its index within the group.
+    GroupedOption(usize, usize),
+}
+
+// Returns whether an item of the popup list of a combobox matches the lowercase filter text. A group header matches
+// if any option of its group matches.
+fn is_shown<T: ToString>(list: &[T], item: &T, row_kind: fn(&T) -> RowKind, filter: &str) -> bool {
+    let matches =
+        |item: &T| filter.is_empty() || item.to_string().to_ascii_lowercase().contains(filter);
+
+    match row_kind(item) {
+        RowKind::Header(group) => list.iter().any(|row| {
+            matches!(row_kind(row), RowKind::GroupedOption(g, _) if g == group) && matches(row)
+        }),
+        _ => matches(item),
+    }
+}
+
+/// A row of the popup list of a [grouped](ComboBox::grouped) combobox.
+#[derive(Debug, Clone)]
+pub enum ComboBoxRow<T> {
+    /// The header of a group, with the index and name of the group.
+    Header(usize, String),
+    /// An option, with the index of its group, its index within the group, and the option.
+    Option(usize, usize, T),
+}
+
+impl<T> ComboBoxRow<T> {
+    fn kind(&self) -> RowKind {
+        match self {
+            ComboBoxRow::Header(group, _) => RowKind::Header(*group),
+            ComboBoxRow::Option(group, index, _) => RowKind::GroupedOption(*group, *index),
+        }
+    }
+}
+
+impl<T: Data> Data for ComboBoxRow<T> {
+    fn same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ComboBoxRow::Header(g1, a), ComboBoxRow::Header(g2, b)) => g1 == g2 && a == b,
+            (ComboBoxRow::Option(g1, i1, a), ComboBoxRow::Option(g2, i2, b)) => {
+                g1 == g2 && i1 == i2 && a.same(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T: ToString> Display for ComboBoxRow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComboBoxRow::Header(_, name) => f.write_str(name),
+            ComboBoxRow::Option(_, _, option) => f.write_str(&option.to_string()),
+        }
+    }
+}
+
+/// A lens to the row of the popup list of a [grouped](ComboBox::grouped) combobox which contains the selected option,
+/// given a lens to the groups and a lens to the index of the group and the index of the option within the group.
+///
+/// If the selected option doesn't exist, the lens targets the first option.
+#[derive(Debug, Clone, Copy, Hash)]
+pub struct GroupedSelection<G, S> {
+    groups: G,
+    selected: S,
+}
+
+impl<G, S, T> Lens for GroupedSelection<G, S>
+where
+    G: Lens<Target = Vec<(String, Vec<T>)>>,
+    S: Lens<Source = G::Source, Target = (usize, usize)>,
+    T: 'static,
+{
+    type Source = G::Source;
+    type Target = usize;
+
+    fn view<'a>(&self, source: &'a Self::Source) -> Option<LensValue<'a, Self::Target>> {
+        let groups = self.groups.view(source)?;
+        let (group, option) = self.selected.view(source)?.into_owned();
+        let rows =
+            group_rows(groups.as_slice(), |_, _| None, |group, index, _| Some((group, index)));
+        let row = rows
+            .iter()
+            .position(|row| *row == Some((group, option)))
+            .or_else(|| rows.iter().position(Option::is_some))
+            .unwrap_or(0);
+
+        Some(LensValue::Owned(row))
+    }
+}
+
 impl<L1, L2, T> ComboBox<L1, L2, T>
 where
     L1: Copy + Lens<Target = Vec<T>>,
@@ -38,6 +144,24 @@ where
 {
     /// Creates a new [ComboBox] view.
     pub fn new(cx: &mut Context, list_lens: L1, selected: L2) -> Handle<Self> {
+        Self::build_combobox(cx, list_lens, selected, |_| RowKind::Option)
+    }
+
+    // Builds a combobox with a list whose items are of the given kinds.
+    fn build_combobox(
+        cx: &mut Context,
+        list_lens: L1,
+        selected: L2,
+        row_kind: fn(&T) -> RowKind,
+    ) -> Handle<Self> {
+        let headers = list_lens.map(move |list| {
+            list.iter()
+                .enumerate()
+                .filter(|(_, item)| matches!(row_kind(item), RowKind::Header(_)))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>()
+        });
+
         Self {
             filter_text: String::from(""),
             on_select: None,
@@ -46,6 +170,8 @@ where
             p: PhantomData,
             is_open: false,
             placeholder: String::from("One"),
+            row_kind,
+            on_select_grouped: None,
         }
         .build(cx, |cx| {
             // Add listener to defocus when mouse is pressed outside the combobox.
@@ -88,24 +214,23 @@ where
                     Popup::new(cx, move |cx: &mut Context| {
                         // Binding to the filter text.
                         Binding::new(cx, Self::filter_text, move |cx, filter_text| {
-                            let f = filter_text.get(cx);
+                            let f = filter_text.get(cx).to_ascii_lowercase();
+                            let list = Rc::new(list_lens.get(cx));
                             List::new_filtered(
                                 cx,
                                 list_lens,
-                                move |item| {
-                                    if f.is_empty() {
-                                        true
-                                    } else {
-                                        item.to_string()
-                                            .to_ascii_lowercase()
-                                            .contains(&f.to_ascii_lowercase())
-                                    }
-                                },
-                                |cx, _, item| {
-                                    Label::new(cx, item);
+                                move |item| is_shown(&list, item, row_kind, &f),
+                                move |cx, _, item| {
+                                    Label::new(cx, item).toggle_class(
+                                        "group-header",
+                                        item.map(move |item| {
+                                            matches!(row_kind(item), RowKind::Header(_))
+                                        }),
+                                    );
                                 },
                             )
                             .selectable(Selectable::Single)
+                            .disabled_items(headers)
                             .selected(selected.map(|s| vec![*s]))
                             .on_select(|cx, index| {
                                 cx.emit(ComboBoxEvent::SetOption(index));
@@ -125,6 +250,53 @@ where
     }
 }
 
+impl<G, S, T> ComboBox<Map<G, Vec<ComboBoxRow<T>>>, GroupedSelection<G, S>, ComboBoxRow<T>>
+where
+    G: Lens<Target = Vec<(String, Vec<T>)>>,
+    S: Lens<Source = G::Source, Target = (usize, usize)>,
+    T: 'static + Data + ToString,
+{
+    /// Creates a new [ComboBox] view with options divided into named groups.
+    ///
+    /// Takes a lens to a list of groups, each with a name and a list of options, and a lens to the index of the group
+    /// of the selected option and the index of the option within the group, which must read from the same model or
+    /// view as the lens to the groups. The name of each group is shown as a header above its options in the popup
+    /// list, which can't be selected, is skipped by keyboard navigation, and is hidden if none of the options of the
+    /// group match the filter text. Headers can be selected with the `.group-header` CSS class selector. Groups with
+    /// an empty name have no header.
+    ///
+    /// The [`on_select_grouped`](Handle::on_select_grouped) callback is given the group and option indices of the
+    /// selected option, while the [`on_select`](Handle::on_select) callback is given the index of the option across
+    /// all groups.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     fonts: Vec<(String, Vec<String>)>,
+    /// #     font: (usize, usize),
+    /// # }
+    /// # impl Model for AppData {}
+    /// # enum AppEvent { SetFont(usize, usize) }
+    /// # AppData { fonts: vec![], font: (0, 0) }.build(cx);
+    /// ComboBox::grouped(cx, AppData::fonts, AppData::font)
+    ///     .on_select_grouped(|cx, group, index| cx.emit(AppEvent::SetFont(group, index)));
+    /// ```
+    pub fn grouped(cx: &mut Context, groups: G, selected: S) -> Handle<Self> {
+        let rows = groups.map(|groups| {
+            group_rows(
+                groups,
+                |group, name| ComboBoxRow::Header(group, name.to_owned()),
+                |group, index, option| ComboBoxRow::Option(group, index, option.clone()),
+            )
+        });
+
+        Self::build_combobox(cx, rows, GroupedSelection { groups, selected }, ComboBoxRow::kind)
+    }
+}
+
 impl<L1, L2, T> View for ComboBox<L1, L2, T>
 where
     L1: Lens<Target = Vec<T>>,
@@ -139,12 +311,31 @@ where
         event.map(|combobox_event, _| match combobox_event {
             ComboBoxEvent::SetOption(index) => {
                 // Set the placeholder text to the selected item.
-                let selected_item = self.list_lens.idx(*index).get(cx);
+                let list = self.list_lens.get(cx);
+                let Some(selected_item) = list.get(*index) else {
+                    return;
+                };
+
+                let kind = (self.row_kind)(selected_item);
+                if let RowKind::Header(_) = kind {
+                    return;
+                }
+
                 self.placeholder = selected_item.to_string();
 
-                // Call the on_select callback.
+                // Call the on_select callback with the index of the option, not counting group headers.
                 if let Some(callback) = &self.on_select {
-                    (callback)(cx, *index);
+                    let option_index = list[..*index]
+                        .iter()
+                        .filter(|item| !matches!((self.row_kind)(item), RowKind::Header(_)))
+                        .count();
+                    (callback)(cx, option_index);
+                }
+
+                if let (RowKind::GroupedOption(group, option), Some(callback)) =
+                    (kind, &self.on_select_grouped)
+                {
+                    (callback)(cx, group, option);
                 }
 
                 // Close the popup.
@@ -259,4 +450,62 @@ where
             combobox.on_select = Some(Box::new(callback))
         })
     }
+
+    /// Set the callback triggered when an option of a [grouped](ComboBox::grouped) combobox is selected, which is
+    /// given the index of the group and the index of the option within the group.
+    pub fn on_select_grouped<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, usize),
+    {
+        self.modify(|combobox: &mut ComboBox<L1, L2, T>| {
+            combobox.on_select_grouped = Some(Box::new(callback))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Lens)]
+    struct AppData {
+        groups: Vec<(String, Vec<String>)>,
+        selected: (usize, usize),
+    }
+
+    impl Model for AppData {}
+
+    #[test]
+    fn grouped_combobox_filters_headers() {
+        let chosen = Rc::new(RefCell::new(None));
+
+        let combobox_chosen = chosen.clone();
+        let mut harness = TestHarness::new(move |cx| {
+            AppData {
+                groups: vec![
+                    (String::from("Fruit"), vec![String::from("Apple"), String::from("Pear")]),
+                    (String::from("Vegetables"), vec![String::from("Leek")]),
+                ],
+                selected: (0, 1),
+            }
+            .build(cx);
+
+            ComboBox::grouped(cx, AppData::groups, AppData::selected).on_select_grouped(
+                move |_, group, index| *combobox_chosen.borrow_mut() = Some((group, index)),
+            );
+        });
+
+        harness.click("combobox textbox");
+        assert_eq!(harness.query_all("list-item .group-header").len(), 2);
+
+        // Only the header of the group with options matching the filter text is shown.
+        harness.type_text("ee");
+        let rows = harness.query_all("list-item");
+        assert_eq!(rows.len(), 2);
+        assert!(harness.matches(rows[0], ".disabled"));
+
+        harness.click(rows[1]);
+        assert_eq!(*chosen.borrow(), Some((1, 0)));
+    }
 }
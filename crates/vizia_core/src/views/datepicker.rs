@@ -1,12 +1,15 @@
 use chrono::{Datelike, NaiveDate, Weekday};
 
+use crate::localization::{first_day_of_week, format_integer, month_name, weekday_name};
 use crate::prelude::*;
 
 /// A control used to select a date.
+///
+/// The digits of the days and the first day of the week follow the locale of the [`Environment`]. The names of months
+/// and days are taken from the translations of the application when it has them, and from the locale otherwise.
 #[derive(Lens)]
 pub struct Datepicker {
     view_date: NaiveDate,
-    months: Vec<Localized>,
     selected_month: usize,
 
     #[lens(ignore)]
@@ -83,11 +86,16 @@ impl Datepicker {
         )
     }
 
-    fn get_day_number(y: u32, x: u32, view_date: &NaiveDate) -> (u32, bool) {
+    // Returns the day of the month shown in a cell of the calendar grid, where the first column is the given first
+    // day of the week, and whether the day is outside of the viewed month.
+    fn get_day_number(y: u32, x: u32, view_date: &NaiveDate, week_start: Weekday) -> (u32, bool) {
         let (_, days_prev_month) = Self::view_month_info(view_date, -1);
         let (first_day_this_month, days_this_month) = Self::view_month_info(view_date, 0);
 
-        let mut fdtm_i = first_day_this_month as usize as u32;
+        // The column of the first day of the month, which starts on the second row if it falls on the first column.
+        let mut fdtm_i = (first_day_this_month.num_days_from_monday() + 7
+            - week_start.num_days_from_monday())
+            % 7;
         if fdtm_i == 0 {
             fdtm_i = 7;
         }
@@ -116,8 +124,16 @@ impl Datepicker {
     {
         let view_date = lens.get(cx);
 
+        // Month names from the translations of the application take precedence over the names of the locale.
+        let months = Environment::locale.map(|locale| {
+            MONTHS
+                .iter()
+                .zip(1..=12)
+                .map(|(month, number)| Localized::new(month).fallback(month_name(locale, number)))
+                .collect::<Vec<_>>()
+        });
+
         Self {
-            months: MONTHS.iter().map(|m| Localized::new(m)).collect::<Vec<_>>(),
             selected_month: view_date.month() as usize - 1,
             view_date: NaiveDate::from_ymd_opt(view_date.year(), view_date.month(), 1).unwrap(),
             on_select: None,
@@ -125,7 +141,7 @@ impl Datepicker {
         .build(cx, move |cx| {
             HStack::new(cx, |cx| {
                 Spinbox::custom(cx, |cx| {
-                    PickList::new(cx, Datepicker::months, Datepicker::selected_month, false)
+                    PickList::new(cx, months, Datepicker::selected_month, false)
                         .on_select(|ex, index| ex.emit(DatepickerEvent::SelectMonth(index)))
                         .width(Stretch(1.0))
                 })
@@ -150,62 +166,80 @@ impl Datepicker {
             VStack::new(cx, move |cx| {
                 // Days of the week
                 HStack::new(cx, |cx| {
-                    for h in DAYS_HEADER {
-                        Label::new(cx, Localized::new(h).map(|day| day[0..2].to_string()))
+                    Binding::new(cx, Environment::locale, |cx, locale| {
+                        let locale = locale.get(cx);
+                        let mut day = first_day_of_week(&locale);
+                        for _ in 0..7 {
+                            Label::new(
+                                cx,
+                                Localized::new(DAYS_HEADER[day.num_days_from_monday() as usize])
+                                    .fallback(weekday_name(&locale, day))
+                                    .map(|day| day.chars().take(2).collect()),
+                            )
                             .class("datepicker-calendar-header");
-                    }
+                            day = day.succ();
+                        }
+                    });
                 })
                 .class("datepicker-calendar-headers");
 
                 // Numbered days in a grid
                 VStack::new(cx, move |cx| {
-                    for y in 0..6 {
-                        HStack::new(cx, |cx| {
-                            for x in 0..7 {
-                                Label::new(cx, "").bind(
-                                    Datepicker::view_date,
-                                    move |handle, view_date| {
-                                        let view_date = view_date.get(&handle);
-
-                                        let (day_number, disabled) =
-                                            Self::get_day_number(y, x, &view_date);
-
-                                        handle.bind(lens, move |handle, selected_date| {
-                                            let selected_date = selected_date.get(&handle);
-
-                                            handle
-                                                .text(&day_number.to_string())
-                                                .class("datepicker-calendar-day")
-                                                .navigable(!disabled)
-                                                .toggle_class(
-                                                    "datepicker-calendar-day-disabled",
-                                                    disabled,
-                                                )
-                                                .on_press(move |ex| {
-                                                    if !disabled {
-                                                        ex.emit(DatepickerEvent::SelectDate(
-                                                            NaiveDate::from_ymd_opt(
-                                                                view_date.year(),
-                                                                view_date.month(),
-                                                                day_number,
-                                                            )
-                                                            .unwrap(),
-                                                        ))
-                                                    }
-                                                })
-                                                .checked(
-                                                    !disabled
-                                                        && selected_date.day() == day_number
-                                                        && selected_date.month()
-                                                            == view_date.month()
-                                                        && selected_date.year() == view_date.year(),
-                                                );
-                                        });
-                                    },
-                                );
-                            }
-                        });
-                    }
+                    Binding::new(cx, Environment::locale, move |cx, locale| {
+                        let locale = locale.get(cx);
+                        let week_start = first_day_of_week(&locale);
+                        for y in 0..6 {
+                            HStack::new(cx, |cx| {
+                                for x in 0..7 {
+                                    let locale = locale.clone();
+                                    Label::new(cx, "").bind(
+                                        Datepicker::view_date,
+                                        move |handle, view_date| {
+                                            let view_date = view_date.get(&handle);
+
+                                            let (day_number, disabled) =
+                                                Self::get_day_number(y, x, &view_date, week_start);
+                                            let day_text =
+                                                format_integer(&locale, day_number as i64);
+
+                                            handle.bind(lens, move |handle, selected_date| {
+                                                let selected_date = selected_date.get(&handle);
+
+                                                handle
+                                                    .text(&day_text)
+                                                    .class("datepicker-calendar-day")
+                                                    .navigable(!disabled)
+                                                    .toggle_class(
+                                                        "datepicker-calendar-day-disabled",
+                                                        disabled,
+                                                    )
+                                                    .on_press(move |ex| {
+                                                        if !disabled {
+                                                            ex.emit(DatepickerEvent::SelectDate(
+                                                                NaiveDate::from_ymd_opt(
+                                                                    view_date.year(),
+                                                                    view_date.month(),
+                                                                    day_number,
+                                                                )
+                                                                .unwrap(),
+                                                            ))
+                                                        }
+                                                    })
+                                                    .checked(
+                                                        !disabled
+                                                            && selected_date.day() == day_number
+                                                            && selected_date.month()
+                                                                == view_date.month()
+                                                            && selected_date.year()
+                                                                == view_date.year(),
+                                                    );
+                                            });
+                                        },
+                                    );
+                                }
+                            });
+                        }
+                    });
                 })
                 // This shouldn't be needed but apparently grid size isn't propagated up the tree during layout
                 .width(Pixels(32.0 * 7.0))
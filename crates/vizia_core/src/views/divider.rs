@@ -18,6 +18,27 @@ impl Divider {
     pub fn vertical(cx: &mut Context) -> Handle<Self> {
         Self::new(cx).class("vertical")
     }
+
+    /// Creates a dividing line with some content, such as a label, centered between two lines which fill the
+    /// remaining space. Orientation is determined by context.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Divider::with_label(cx, |cx| {
+    ///     Label::new(cx, "OR");
+    /// });
+    /// ```
+    pub fn with_label(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        Self {}
+            .build(cx, |cx| {
+                Element::new(cx).class("line");
+                content(cx);
+                Element::new(cx).class("line");
+            })
+            .class("labeled")
+    }
 }
 
 impl View for Divider {
@@ -39,3 +60,31 @@ impl Handle<'_, Divider> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[test]
+    fn centers_label_between_lines() {
+        let harness = TestHarness::new(|cx| {
+            VStack::new(cx, |cx| {
+                Divider::with_label(cx, |cx| {
+                    Label::new(cx, "OR").id("label");
+                })
+                .id("divider");
+            });
+        });
+
+        let divider = harness.bounds("#divider");
+        let label = harness.bounds("#label");
+        let lines = harness.query_all("#divider > .line");
+        let (first, second) = (harness.bounds(lines[0]), harness.bounds(lines[1]));
+
+        assert_eq!(divider.width(), 800.0);
+        assert_eq!(divider.height(), label.height());
+        assert_eq!(first.width(), second.width());
+        assert_eq!(first.height(), 1.0);
+        assert!((label.center().0 - divider.center().0).abs() < 1.0);
+    }
+}
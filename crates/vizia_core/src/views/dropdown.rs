@@ -124,6 +124,8 @@ pub struct Dropdown {
     pub show_arrow: bool,
     pub arrow_size: Length,
     pub should_reposition: bool,
+    on_open: Option<Box<dyn Fn(&mut EventContext)>>,
+    on_close: Option<Box<dyn Fn(&mut EventContext)>>,
 }
 
 impl Dropdown {
@@ -149,6 +151,8 @@ impl Dropdown {
             show_arrow: true,
             arrow_size: Length::Value(LengthValue::Px(4.0)),
             should_reposition: true,
+            on_open: None,
+            on_close: None,
         }
         .build(cx, move |cx| {
             (trigger)(cx);
@@ -175,7 +179,15 @@ impl View for Dropdown {
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        let was_open = self.is_open.is_open;
         self.is_open.event(cx, event);
+
+        if self.is_open.is_open != was_open {
+            let callback = if self.is_open.is_open { &self.on_open } else { &self.on_close };
+            if let Some(callback) = callback {
+                (callback)(cx);
+            }
+        }
     }
 }
 
@@ -205,4 +217,38 @@ impl Handle<'_, Dropdown> {
     pub fn should_reposition(self, flag: bool) -> Self {
         self.modify(|dropdown| dropdown.should_reposition = flag)
     }
+
+    /// Sets the callback triggered when the dropdown opens.
+    ///
+    /// The open state of the dropdown can also be bound to with `Dropdown::is_open.then(PopupData::is_open)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Dropdown::new(cx, |cx| Label::new(cx, "Text"), |_| {})
+    ///     .on_open(|_| debug!("Dropdown opened"));
+    /// ```
+    pub fn on_open<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.modify(|dropdown| dropdown.on_open = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered when the dropdown closes.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Dropdown::new(cx, |cx| Label::new(cx, "Text"), |_| {})
+    ///     .on_close(|_| debug!("Dropdown closed"));
+    /// ```
+    pub fn on_close<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.modify(|dropdown| dropdown.on_close = Some(Box::new(callback)))
+    }
 }
@@ -0,0 +1,64 @@
+use crate::prelude::*;
+use crate::vg;
+
+/// A view which draws the output of an external renderer, such as a 3D viewport rendered with wgpu or OpenGL.
+///
+/// The render callback is called whenever the view is drawn, with the size of the view in physical pixels. The
+/// external renderer should render at this size, which changes when the view is resized or the scale factor of the
+/// window changes, so it never renders into a stale target. The callback returns the rendered frame as an image which
+/// is stretched to fill the bounds of the view, or `None` to leave the view empty.
+///
+/// A GPU backed image can be created from a texture shared with the external renderer using the recording context of
+/// the canvas, for example with [`skia_safe::gpu::images::borrow_texture_from`]. To render continuously, call
+/// [`needs_redraw`](DrawContext::needs_redraw) from within the callback.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_core::vg;
+/// # let cx = &mut Context::default();
+/// ExternalTextureView::new(cx, |cx, canvas, width, height| {
+///     // Render the external frame here and wrap the resulting texture in an image.
+///     None::<vg::Image>
+/// })
+/// .size(Stretch(1.0));
+/// ```
+pub struct ExternalTextureView {
+    on_render: Box<dyn Fn(&mut DrawContext, &Canvas, u32, u32) -> Option<vg::Image>>,
+}
+
+impl ExternalTextureView {
+    /// Creates a new [ExternalTextureView] which draws the image returned by the given render callback.
+    pub fn new<F>(cx: &mut Context, on_render: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut DrawContext, &Canvas, u32, u32) -> Option<vg::Image>,
+    {
+        Self { on_render: Box::new(on_render) }.build(cx, |_| {})
+    }
+}
+
+impl View for ExternalTextureView {
+    fn element(&self) -> Option<&'static str> {
+        Some("external-texture")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+
+        let (width, height) = (bounds.w.round() as u32, bounds.h.round() as u32);
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        cx.draw_background(canvas);
+        cx.draw_shadows(canvas);
+
+        if let Some(image) = (self.on_render)(cx, canvas, width, height) {
+            let rect: vg::Rect = bounds.into();
+            canvas.draw_image_rect(&image, None, rect, &vg::Paint::default());
+        }
+
+        cx.draw_border(canvas);
+        cx.draw_outline(canvas);
+    }
+}
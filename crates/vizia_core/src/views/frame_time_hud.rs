@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use crate::profiling::{frame_timings, FramePass, FrameTimings};
+
+// How often the shown timings are updated, so that they can be read.
+const UPDATE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A view which shows the time spent in each pass of the last frame, for checking where the time of a frame goes
+/// without attaching a profiler.
+///
+/// The timings are read from [`frame_timings`](crate::profiling::frame_timings) and updated a few times per second.
+/// The view is positioned absolutely in the top-left corner of its parent by default.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// FrameTimeHud::new(cx).left(Stretch(1.0)).right(Pixels(8.0)).top(Pixels(8.0));
+/// ```
+pub struct FrameTimeHud {
+    timings: FrameTimings,
+    last_update: Instant,
+}
+
+impl FrameTimeHud {
+    /// Creates a new [FrameTimeHud].
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        let mut handle = Self { timings: frame_timings(), last_update: Instant::now() }
+            .build(cx, |_| {})
+            .position_type(PositionType::Absolute)
+            .width(Pixels(170.0))
+            .height(Pixels(150.0))
+            .padding(Pixels(6.0))
+            .background_color(Color::rgba(0, 0, 0, 200))
+            .color(Color::white())
+            .pointer_events(PointerEvents::None);
+
+        let entity = handle.entity();
+        handle.cx.animation_frame_requests.insert(entity);
+
+        handle
+    }
+}
+
+impl View for FrameTimeHud {
+    fn element(&self) -> Option<&'static str> {
+        Some("frame-time-hud")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| {
+            if let WindowEvent::AnimationFrame(_) = window_event {
+                // Measured with the real clock, like the timings themselves.
+                if self.last_update.elapsed() >= UPDATE_INTERVAL {
+                    self.timings = frame_timings();
+                    self.last_update = Instant::now();
+                    cx.needs_redraw();
+                }
+
+                cx.request_animation_frame();
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        cx.draw_background(canvas);
+
+        let millis = |duration: Duration| duration.as_secs_f32() * 1000.0;
+        let mut text = format!("frame {:.1} ms", millis(self.timings.frame));
+        for pass in FramePass::ALL {
+            text.push_str(&format!("\n{} {:.2} ms", pass.name(), millis(self.timings.pass(pass))));
+        }
+
+        let padding = 6.0 * cx.scale_factor();
+        let bounds = cx.bounds().shrink(padding);
+        cx.draw_string(canvas, &text, bounds, &TextOverrides::new().font_size(11.0));
+    }
+}
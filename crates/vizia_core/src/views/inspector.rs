@@ -0,0 +1,431 @@
+use crate::{icons::ICON_X, prelude::*};
+
+// How often the tree and the details of the selected view are updated.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A row of the tree of an [`Inspector`].
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct InspectorRow {
+    /// The view shown in the row.
+    pub entity: Entity,
+    /// The number of ancestors of the view below the inspected window.
+    pub depth: usize,
+    /// The element name, id, classes, and pseudo-classes of the view, written as a selector.
+    pub label: String,
+}
+
+enum InspectorEvent {
+    Refresh,
+    Select(usize),
+    Highlight(Option<usize>),
+    TogglePicking,
+    Close,
+}
+
+/// A view for debugging the views of a window, built from vizia views.
+///
+/// The inspector shows a live tree of the views of a window, with the element name, id, classes, and pseudo-classes
+/// of each view, and the computed style and layout of the selected view. Hovering a row of the tree highlights the
+/// view in the window, and the pick button selects the next view which is clicked in the window.
+///
+/// The inspector of a window can be shown with [`Context::open_inspector`] or by pressing `Ctrl+Shift+I`. With the
+/// winit backend it is shown in a separate window, and with the baseview backend as an overlay of the inspected window.
+/// It can also be placed in a window directly.
+///
+/// # Example
+/// ```ignore
+/// Window::new(cx, |cx| {
+///     Inspector::new(cx, Entity::root());
+/// })
+/// .title("Inspector");
+/// ```
+#[derive(Lens)]
+pub struct Inspector {
+    target: Entity,
+    rows: Vec<InspectorRow>,
+    selected: Option<Entity>,
+    selected_row: Option<usize>,
+    details: Vec<(String, String)>,
+    picking: bool,
+    #[lens(ignore)]
+    refresh_timer: Timer,
+}
+
+impl Inspector {
+    /// Creates a new [Inspector] which shows the views of the given window.
+    pub fn new(cx: &mut Context, window: Entity) -> Handle<Self> {
+        Self::with_close_button(cx, window, false)
+    }
+
+    fn with_close_button(cx: &mut Context, window: Entity, close_button: bool) -> Handle<Self> {
+        let refresh_timer = refresh_timer(cx);
+
+        let handle = Self {
+            target: window,
+            rows: Vec::new(),
+            selected: None,
+            selected_row: None,
+            details: Vec::new(),
+            picking: false,
+            refresh_timer,
+        }
+        .build(cx, |cx| {
+            cx.emit(InspectorEvent::Refresh);
+
+            // Picks the view under the cursor in the inspected window while picking.
+            cx.add_listener(|inspector: &mut Self, cx, event| {
+                let hovered = cx.hovered();
+                if !inspector.picking || hovered.is_descendant_of(cx.tree, cx.current()) {
+                    return;
+                }
+
+                event.map(|window_event, meta| match window_event {
+                    WindowEvent::MouseMove(_, _) => cx.highlight_view(Some(hovered)),
+                    WindowEvent::MouseDown(MouseButton::Left) => {
+                        inspector.picking = false;
+                        inspector.selected = Some(hovered);
+                        inspector.refresh(cx);
+                        meta.consume();
+                    }
+                    _ => {}
+                });
+            });
+
+            HStack::new(cx, |cx| {
+                ToggleButton::new(cx, Inspector::picking, |cx| Label::new(cx, "Pick"))
+                    .on_toggle(|cx| cx.emit(InspectorEvent::TogglePicking));
+                Label::new(cx, "Inspector").class("title");
+                if close_button {
+                    Button::new(cx, |cx| Svg::new(cx, ICON_X))
+                        .on_press(|cx| cx.emit(InspectorEvent::Close));
+                }
+            })
+            .class("toolbar");
+
+            ScrollView::new(cx, |cx| {
+                List::new(cx, Inspector::rows, |cx, index, row| {
+                    Label::new(cx, row.map(|row| row.label.clone()))
+                        .class("row")
+                        .padding_left(row.map(|row| Pixels(8.0 + row.depth as f32 * 12.0)))
+                        .toggle_class(
+                            "selected",
+                            Inspector::selected_row.map(move |selected| *selected == Some(index)),
+                        )
+                        .on_press(move |cx| cx.emit(InspectorEvent::Select(index)))
+                        .on_hover(move |cx| cx.emit(InspectorEvent::Highlight(Some(index))))
+                        .on_hover_out(|cx| cx.emit(InspectorEvent::Highlight(None)));
+                });
+            })
+            .class("tree");
+
+            ScrollView::new(cx, |cx| {
+                List::new(cx, Inspector::details, |cx, _, detail| {
+                    HStack::new(cx, |cx| {
+                        Label::new(cx, detail.map(|(name, _)| name.clone())).class("name");
+                        Label::new(cx, detail.map(|(_, value)| value.clone())).class("value");
+                    })
+                    .class("property");
+                });
+            })
+            .class("details");
+        });
+
+        if !handle.cx.timer_is_running(refresh_timer) {
+            handle.cx.with_current(Entity::root(), |cx| cx.start_timer(refresh_timer));
+        }
+
+        handle
+    }
+
+    // Updates the rows of the tree and the details of the selected view.
+    fn refresh(&mut self, cx: &mut EventContext) {
+        let mut rows = vec![row(cx, self.target, 0)];
+        collect_rows(cx, self.target, 1, &mut rows);
+
+        self.selected =
+            self.selected.filter(|selected| rows.iter().any(|row| row.entity == *selected));
+        self.selected_row =
+            self.selected.and_then(|selected| rows.iter().position(|row| row.entity == selected));
+        self.details = self.selected.map(|selected| details(cx, selected)).unwrap_or_default();
+        self.rows = rows;
+    }
+}
+
+impl View for Inspector {
+    fn element(&self) -> Option<&'static str> {
+        Some("inspector")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|inspector_event, _| match inspector_event {
+            InspectorEvent::Refresh => self.refresh(cx),
+
+            InspectorEvent::Select(index) => {
+                if let Some(row) = self.rows.get(*index) {
+                    self.selected = Some(row.entity);
+                    self.refresh(cx);
+                }
+            }
+
+            InspectorEvent::Highlight(index) => {
+                let entity = index.and_then(|index| self.rows.get(index)).map(|row| row.entity);
+                cx.highlight_view(entity);
+            }
+
+            InspectorEvent::TogglePicking => {
+                self.picking ^= true;
+                if !self.picking {
+                    cx.highlight_view(None);
+                }
+            }
+
+            InspectorEvent::Close => cx.close_inspector(),
+        });
+
+        event.map(|window_event, _| {
+            // Stop refreshing once the last inspector is removed.
+            if let WindowEvent::Destroyed = window_event {
+                if !cx.views.values().any(|view| view.downcast_ref::<Inspector>().is_some()) {
+                    cx.stop_timer(self.refresh_timer);
+                }
+            }
+        });
+    }
+}
+
+// Adds a row for each view below an entity, skipping bindings, other windows, and the inspector itself.
+fn collect_rows(cx: &EventContext, entity: Entity, depth: usize, rows: &mut Vec<InspectorRow>) {
+    for child in entity.child_iter(cx.tree) {
+        if child == cx.current() || cx.tree.is_window(child) {
+            continue;
+        }
+
+        if cx.tree.is_ignored(child) {
+            collect_rows(cx, child, depth, rows);
+        } else {
+            rows.push(row(cx, child, depth));
+            collect_rows(cx, child, depth + 1, rows);
+        }
+    }
+}
+
+fn row(cx: &EventContext, entity: Entity, depth: usize) -> InspectorRow {
+    let mut label = cx
+        .style
+        .element
+        .get(entity)
+        .map(|element| element.as_str().to_owned())
+        .unwrap_or_else(|| String::from("view"));
+
+    if let Some(id) = cx.style.ids.get(entity) {
+        label.push_str(&format!("#{}", id.as_str()));
+    }
+
+    for class in classes(cx, entity) {
+        label.push_str(&format!(".{}", class));
+    }
+
+    for pseudo_class in pseudo_classes(cx, entity) {
+        label.push_str(&format!(":{}", pseudo_class));
+    }
+
+    InspectorRow { entity, depth, label }
+}
+
+// Returns the class names of a view in alphabetical order.
+fn classes(cx: &EventContext, entity: Entity) -> Vec<String> {
+    let mut classes = cx
+        .style
+        .classes
+        .get(entity)
+        .map(|classes| classes.iter().map(|class| class.as_str().to_owned()).collect())
+        .unwrap_or_else(Vec::new);
+    classes.sort();
+    classes
+}
+
+// Returns the names of the pseudo-classes which apply to a view, as written in a stylesheet.
+fn pseudo_classes(cx: &EventContext, entity: Entity) -> Vec<String> {
+    cx.style
+        .pseudo_classes
+        .get(entity)
+        .map(|flags| {
+            flags.iter_names().map(|(name, _)| name.to_lowercase().replace('_', "-")).collect()
+        })
+        .unwrap_or_default()
+}
+
+// Returns the computed style and layout properties of a view.
+fn details(cx: &EventContext, entity: Entity) -> Vec<(String, String)> {
+    let style = &cx.style;
+    let bounds = cx.cache.get_bounds(entity);
+    let color = |color: Option<&Color>| match color {
+        Some(color) => {
+            format!("#{:02x}{:02x}{:02x}{:02x}", color.r(), color.g(), color.b(), color.a())
+        }
+        None => String::from("none"),
+    };
+    let list =
+        |names: Vec<String>| if names.is_empty() { String::from("none") } else { names.join(" ") };
+
+    [
+        ("entity", entity.to_string()),
+        (
+            "element",
+            style.element.get(entity).map_or("none", |element| element.as_str()).to_owned(),
+        ),
+        ("id", style.ids.get(entity).map_or("none", |id| id.as_str()).to_owned()),
+        ("classes", list(classes(cx, entity))),
+        ("pseudo-classes", list(pseudo_classes(cx, entity))),
+        ("bounds", format!("{} {} {} {}", bounds.x, bounds.y, bounds.w, bounds.h)),
+        ("display", format!("{:?}", style.display.get(entity).copied().unwrap_or_default())),
+        ("visibility", format!("{:?}", style.visibility.get(entity).copied().unwrap_or_default())),
+        ("opacity", style.opacity.get(entity).map_or(1.0, |opacity| opacity.0).to_string()),
+        ("width", format!("{:?}", style.width.get(entity).copied().unwrap_or_default())),
+        ("height", format!("{:?}", style.height.get(entity).copied().unwrap_or_default())),
+        ("background-color", color(style.background_color.get(entity))),
+        ("color", color(style.font_color.get(entity))),
+        (
+            "font-size",
+            style.font_size.get(entity).map_or(String::from("inherit"), |size| size.0.to_string()),
+        ),
+        ("text", style.text.get(entity).cloned().unwrap_or_default()),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_owned(), value))
+    .collect()
+}
+
+// Returns the timer which refreshes every inspector, adding it for the first inspector.
+fn refresh_timer(cx: &mut Context) -> Timer {
+    if let Some(timer) = cx.inspector_timer {
+        return timer;
+    }
+
+    // The timer is added by the root so that it is not removed along with any of the inspectors.
+    let timer = cx.with_current(Entity::root(), |cx| {
+        cx.add_timer(REFRESH_INTERVAL, None, |cx, action| {
+            if let TimerAction::Tick(_) = action {
+                cx.emit_custom(
+                    Event::new(InspectorEvent::Refresh)
+                        .target(Entity::root())
+                        .propagate(Propagation::Subtree),
+                );
+            }
+        })
+    });
+    *cx.inspector_timer.insert(timer)
+}
+
+// Returns the inspector of a window, or the inspector shown in a window, if there is one. The inspector of a window is
+// either an overlay of the window or shown in a separate window.
+fn find_inspector(cx: &Context, window: Entity) -> Option<(Entity, Entity)> {
+    cx.views.iter().find_map(|(entity, view)| {
+        let inspector = view.downcast_ref::<Inspector>()?;
+        let shown_in = cx.tree.get_parent_window(*entity).unwrap_or(Entity::root());
+        (inspector.target == window || shown_in == window).then_some((*entity, inspector.target))
+    })
+}
+
+/// Shows an inspector of a window, unless it already has one.
+///
+/// The inspector is shown in a separate window if the backend supports it, and otherwise as an overlay on the right
+/// side of the window.
+pub(crate) fn open_inspector(cx: &mut Context, window: Entity) {
+    if find_inspector(cx, window).is_some() {
+        return;
+    }
+
+    if let Some(builder) = cx.inspector_window.take() {
+        cx.with_current(window, |cx| (builder)(cx, window));
+        cx.inspector_window = Some(builder);
+        return;
+    }
+
+    cx.with_current(window, |cx| {
+        Inspector::with_close_button(cx, window, true)
+            .position_type(PositionType::Absolute)
+            .left(Stretch(1.0))
+            .width(Pixels(360.0))
+            .height(Stretch(1.0))
+            .z_index(1000);
+    });
+}
+
+/// Removes the inspector of a window, or the inspector shown in a window, if there is one.
+pub(crate) fn close_inspector(cx: &mut Context, window: Entity) {
+    if let Some((inspector, target)) = find_inspector(cx, window) {
+        EventContext::new(cx).highlight_view(None);
+        // An inspector shown in a separate window is removed along with its window.
+        let shown_in = cx.tree.get_parent_window(inspector).unwrap_or(Entity::root());
+        cx.remove(if shown_in == target { inspector } else { shown_in });
+    }
+}
+
+/// Shows or hides the inspector of a window.
+pub(crate) fn toggle_inspector(cx: &mut Context, window: Entity) {
+    if find_inspector(cx, window).is_some() {
+        close_inspector(cx, window);
+    } else {
+        open_inspector(cx, window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[test]
+    fn shows_tree_and_details() {
+        let mut harness = TestHarness::new(|cx| {
+            VStack::new(cx, |cx| {
+                Label::new(cx, "Hello").id("greeting").class("large");
+            });
+            cx.open_inspector();
+        });
+        harness.update();
+
+        let labels = harness
+            .query_all("inspector .row")
+            .into_iter()
+            .filter_map(|row| harness.text(row))
+            .collect::<Vec<_>>();
+        assert!(labels.contains(&"label#greeting.large"));
+
+        assert!(harness.query("inspector .row.selected").is_none());
+
+        let row = harness
+            .query_all("inspector .row")
+            .into_iter()
+            .find(|row| harness.text(*row).is_some_and(|text| text.starts_with("label#greeting")))
+            .unwrap();
+        harness.click(row);
+
+        let values = harness
+            .query_all("inspector .value")
+            .into_iter()
+            .filter_map(|value| harness.text(value))
+            .collect::<Vec<_>>();
+        assert!(values.contains(&"Hello"));
+        assert!(harness.query("inspector .row.selected").is_some());
+    }
+
+    #[test]
+    fn reuses_refresh_timer() {
+        let mut harness = TestHarness::new(|cx| cx.open_inspector());
+        let timers = harness.context().timers.iter().flatten().count();
+        let timer = harness.context().inspector_timer.unwrap();
+
+        toggle_inspector(harness.context(), Entity::root());
+        harness.update();
+        assert!(harness.query("inspector").is_none());
+        assert!(!harness.context().timer_is_running(timer));
+
+        toggle_inspector(harness.context(), Entity::root());
+        harness.update();
+        assert!(harness.query("inspector").is_some());
+        assert!(harness.context().timer_is_running(timer));
+        assert_eq!(harness.context().timers.iter().flatten().count(), timers);
+    }
+}
@@ -4,6 +4,7 @@
 use crate::vg;
 use morphorm::Units;
 
+use crate::localization::format_number;
 use crate::prelude::*;
 
 static DEFAULT_DRAG_SCALAR: f32 = 0.0042;
@@ -18,8 +19,7 @@ pub struct Knob<L> {
     lens: L,
     default_normal: f32,
 
-    is_dragging: bool,
-    prev_drag_y: f32,
+    drag: DragTracker,
     continuous_normal: f32,
 
     drag_scalar: f32,
@@ -27,6 +27,11 @@ pub struct Knob<L> {
     arrow_scalar: f32,
     modifier_scalar: f32,
 
+    step: Option<f32>,
+    // The function used to format the value label, or `None` to format the value for the current locale.
+    format: Option<Box<dyn Fn(f32) -> String>>,
+    value_label: Entity,
+
     on_changing: Option<Box<dyn Fn(&mut EventContext, f32)>>,
 }
 
@@ -38,12 +43,13 @@ impl<L: Lens<Target = f32>> Knob<L> {
         lens: L,
         centered: bool,
     ) -> Handle<Self> {
+        let mut value_label = Entity::null();
+
         Self {
             lens,
             default_normal: normalized_default.get(cx),
 
-            is_dragging: false,
-            prev_drag_y: 0.0,
+            drag: DragTracker::new(0.0),
             continuous_normal: lens.get(cx),
 
             drag_scalar: DEFAULT_DRAG_SCALAR,
@@ -51,10 +57,14 @@ impl<L: Lens<Target = f32>> Knob<L> {
             arrow_scalar: DEFAULT_ARROW_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
 
+            step: None,
+            format: None,
+            value_label: Entity::null(),
+
             on_changing: None,
         }
-        .build(cx, move |cx| {
-            ZStack::new(cx, move |cx| {
+        .build(cx, |cx| {
+            ZStack::new(cx, |cx| {
                 ArcTrack::new(
                     cx,
                     centered,
@@ -72,8 +82,23 @@ impl<L: Lens<Target = f32>> Knob<L> {
                 })
                 .rotate(lens.map(|v| Angle::Deg(*v * 300.0 - 150.0)))
                 .class("knob-head");
+
+                value_label = Label::new(cx, "").class("knob-value").hoverable(false).entity();
             });
         })
+        .modify(|knob| knob.value_label = value_label)
+        .role(Role::Slider)
+        .numeric_value(lens.map(|v| *v as f64))
+        .bind(lens, |mut handle, lens| {
+            let value = lens.get(&handle);
+            let entity = handle.entity();
+            Self::update_value_label(handle.context(), entity, value);
+        })
+        .bind(Environment::locale, move |mut handle, _| {
+            let value = lens.get(&handle);
+            let entity = handle.entity();
+            Self::update_value_label(handle.context(), entity, value);
+        })
         .navigable(true)
     }
 
@@ -91,8 +116,7 @@ impl<L: Lens<Target = f32>> Knob<L> {
             lens,
             default_normal,
 
-            is_dragging: false,
-            prev_drag_y: 0.0,
+            drag: DragTracker::new(0.0),
             continuous_normal: lens.get(cx),
 
             drag_scalar: DEFAULT_DRAG_SCALAR,
@@ -100,6 +124,10 @@ impl<L: Lens<Target = f32>> Knob<L> {
             arrow_scalar: DEFAULT_ARROW_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
 
+            step: None,
+            format: None,
+            value_label: Entity::null(),
+
             on_changing: None,
         }
         .build(cx, move |cx| {
@@ -107,6 +135,41 @@ impl<L: Lens<Target = f32>> Knob<L> {
                 (content)(cx, lens).width(Percentage(100.0)).height(Percentage(100.0));
             });
         })
+        .role(Role::Slider)
+        .numeric_value(lens.map(|v| *v as f64))
+    }
+
+    // Snaps a normalized value to the nearest multiple of the step, if one is set.
+    fn snap(&self, normal: f32) -> f32 {
+        let normal = match self.step {
+            Some(step) if step > 0.0 => (normal / step).round() * step,
+            _ => normal,
+        };
+
+        normal.clamp(0.0, 1.0)
+    }
+
+    // Updates the text of the value label using the format function of the knob.
+    fn update_value_label(cx: &mut Context, entity: Entity, value: f32) {
+        let locale = cx.locale();
+        let Some((label, text)) =
+            cx.views.get(&entity).and_then(|view| view.downcast_ref::<Self>()).map(|knob| {
+                let text = match &knob.format {
+                    Some(format) => (format)(value),
+                    None => format_number(&locale, (value as f64 * 100.0).round() / 100.0),
+                };
+                (knob.value_label, text)
+            })
+        else {
+            return;
+        };
+
+        if label != Entity::null() {
+            cx.style.text.insert(label, text);
+            cx.style.needs_text_update(label);
+            cx.needs_relayout();
+            cx.needs_redraw(label);
+        }
     }
 }
 
@@ -124,6 +187,39 @@ impl<L: Lens<Target = f32>> Handle<'_, Knob<L>> {
 
         self
     }
+
+    /// Sets the step which the normalized value of the knob snaps to when changed.
+    pub fn step(self, step: f32) -> Self {
+        self.modify(|knob| knob.step = Some(step))
+    }
+
+    /// Sets the normalized value which the knob is reset to when double-clicked.
+    pub fn default_value(self, default_value: f32) -> Self {
+        self.modify(|knob| knob.default_normal = default_value)
+    }
+
+    /// Sets whether a label showing the current value is displayed in the center of the knob.
+    pub fn show_value(self, flag: impl Res<bool>) -> Self {
+        self.toggle_class("show-value", flag)
+    }
+
+    /// Sets the function used to format the value shown by the value label. By default the value is rounded to two
+    /// fraction digits and formatted for the locale of the [`Environment`].
+    pub fn format(self, format: impl Fn(f32) -> String + 'static) -> Self {
+        let mut lens = None;
+        let mut handle = self.modify(|knob| {
+            knob.format = Some(Box::new(format));
+            lens = Some(knob.lens);
+        });
+
+        if let Some(lens) = lens {
+            let value = lens.get(&handle);
+            let entity = handle.entity();
+            Knob::<L>::update_value_label(handle.context(), entity, value);
+        }
+
+        handle
+    }
 }
 
 impl<L: Lens<Target = f32>> View for Knob<L> {
@@ -131,48 +227,53 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
         Some("knob")
     }
 
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_numeric_value_step(self.step.unwrap_or(self.arrow_scalar) as f64);
+        node.set_min_numeric_value(0.0);
+        node.set_max_numeric_value(1.0);
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         let move_virtual_slider = |self_ref: &mut Self, cx: &mut EventContext, new_normal: f32| {
             self_ref.continuous_normal = new_normal.clamp(0.0, 1.0);
 
+            let normal = self_ref.snap(self_ref.continuous_normal);
+
             if let Some(callback) = &self_ref.on_changing {
-                (callback)(cx, self_ref.continuous_normal);
+                (callback)(cx, normal);
             }
         };
 
-        event.map(|window_event, _| match window_event {
-            WindowEvent::MouseDown(button) if *button == MouseButton::Left => {
-                self.is_dragging = true;
-                self.prev_drag_y = cx.mouse.left.pos_down.1;
+        // Arrow keys and accessibility actions change the value by one step.
+        let arrow_step = self.step.unwrap_or(self.arrow_scalar);
 
-                cx.capture();
-                cx.focus_with_visibility(false);
+        event.map(|window_event, _| {
+            let Some(gesture) = self.drag.handle(cx, window_event) else {
+                return;
+            };
 
-                self.continuous_normal = self.lens.get(cx);
-            }
+            if gesture.phase != DragPhase::Ended && !cx.is_disabled() {
+                let mut delta_normal = gesture.physical_delta.1 * self.drag_scalar;
 
-            WindowEvent::MouseUp(button) if *button == MouseButton::Left => {
-                self.is_dragging = false;
+                if cx.modifiers.shift() {
+                    delta_normal *= self.modifier_scalar;
+                }
 
-                self.continuous_normal = self.lens.get(cx);
+                let new_normal = self.continuous_normal - delta_normal;
 
-                cx.release();
+                move_virtual_slider(self, cx, new_normal);
             }
+        });
 
-            WindowEvent::MouseMove(_, y) => {
-                if self.is_dragging && !cx.is_disabled() {
-                    let mut delta_normal = (*y - self.prev_drag_y) * self.drag_scalar;
-
-                    self.prev_drag_y = *y;
-
-                    if cx.modifiers.shift() {
-                        delta_normal *= self.modifier_scalar;
-                    }
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseDown(button) if *button == MouseButton::Left => {
+                cx.focus_with_visibility(false);
 
-                    let new_normal = self.continuous_normal - delta_normal;
+                self.continuous_normal = self.lens.get(cx);
+            }
 
-                    move_virtual_slider(self, cx, new_normal);
-                }
+            WindowEvent::MouseUp(button) if *button == MouseButton::Left => {
+                self.continuous_normal = self.lens.get(cx);
             }
 
             WindowEvent::MouseScroll(_, y) => {
@@ -186,21 +287,39 @@ impl<L: Lens<Target = f32>> View for Knob<L> {
             }
 
             WindowEvent::MouseDoubleClick(button) if *button == MouseButton::Left => {
-                self.is_dragging = false;
-
                 move_virtual_slider(self, cx, self.default_normal);
             }
 
             WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
                 self.continuous_normal = self.lens.get(cx);
-                move_virtual_slider(self, cx, self.continuous_normal + self.arrow_scalar);
+                move_virtual_slider(self, cx, self.continuous_normal + arrow_step);
             }
 
             WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
                 self.continuous_normal = self.lens.get(cx);
-                move_virtual_slider(self, cx, self.continuous_normal - self.arrow_scalar);
+                move_virtual_slider(self, cx, self.continuous_normal - arrow_step);
             }
 
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Increment => {
+                    self.continuous_normal = self.lens.get(cx);
+                    move_virtual_slider(self, cx, self.continuous_normal + arrow_step);
+                }
+
+                Action::Decrement => {
+                    self.continuous_normal = self.lens.get(cx);
+                    move_virtual_slider(self, cx, self.continuous_normal - arrow_step);
+                }
+
+                Action::SetValue => {
+                    if let Some(ActionData::NumericValue(val)) = action.data {
+                        move_virtual_slider(self, cx, val as f32);
+                    }
+                }
+
+                _ => {}
+            },
+
             _ => {}
         });
     }
@@ -557,3 +676,45 @@ impl Handle<'_, TickKnob> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Lens)]
+    struct AppData {
+        value: f32,
+    }
+
+    enum AppEvent {
+        SetValue(f32),
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|app_event, _| match app_event {
+                AppEvent::SetValue(value) => self.value = *value,
+            });
+        }
+    }
+
+    #[test]
+    fn dragging_up_increases_value() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { value: 0.5 }.build(cx);
+            Knob::new(cx, 0.5, AppData::value, false)
+                .on_change(|cx, value| cx.emit(AppEvent::SetValue(value)))
+                .id("knob");
+        });
+
+        let (x, y) = harness.bounds("#knob").center();
+        harness.hover(x, y);
+        harness.emit(WindowEvent::MouseDown(MouseButton::Left));
+        harness.hover(x, y - 20.0);
+        harness.emit(WindowEvent::MouseUp(MouseButton::Left));
+
+        let value = harness.context().data::<AppData>().unwrap().value;
+        assert!(value > 0.5);
+    }
+}
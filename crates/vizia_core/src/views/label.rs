@@ -107,6 +107,28 @@ impl Label {
     }
 
     /// Creates a new rich [Label] view.
+    ///
+    /// The text of a rich label is followed by the text of any [TextSpan] views built within the `children` closure,
+    /// which are shaped together into a single paragraph. Each span can be styled separately, for example with its
+    /// own font weight, color, size, or text decoration, which makes it possible to mix styles within one label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// Label::rich(cx, "Hello ", |cx| {
+    ///     TextSpan::new(cx, "bold", |_| {}).font_weight(FontWeightKeyword::Bold);
+    ///     TextSpan::new(cx, ", ", |_| {});
+    ///     TextSpan::new(cx, "red", |_| {}).color(Color::red());
+    ///     TextSpan::new(cx, " and ", |_| {});
+    ///     TextSpan::new(cx, "underlined", |_| {})
+    ///         .text_decoration_line(TextDecorationLine::Underline);
+    ///     TextSpan::new(cx, " text", |_| {}).font_size(20.0);
+    /// });
+    /// ```
     pub fn rich<T>(
         cx: &mut Context,
         text: impl Res<T> + Clone,
@@ -123,6 +145,57 @@ impl Label {
             .role(Role::Label)
             .name(text)
     }
+
+    /// Creates a new [Label] view with a mnemonic, which is the letter or digit following an `&` in the text, such as
+    /// the `F` of `&File`. Any other `&` is shown as it is, and `&&` can be used to show an `&` before a letter.
+    ///
+    /// The mnemonic is shown as a [TextSpan] with the `mnemonic` class. Within a [MenuBar] it is underlined while
+    /// `Alt` is held, and pressing it activates the menu or menu item containing the label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// Label::mnemonic(cx, "&File");
+    /// ```
+    pub fn mnemonic(cx: &mut Context, text: &str) -> Handle<Self> {
+        let (before, mnemonic, after) = split_mnemonic(text);
+        let name = format!("{}{}{}", before, mnemonic, after);
+        Self::rich(cx, before, move |cx| {
+            if !mnemonic.is_empty() {
+                TextSpan::new(cx, &mnemonic, |_| {}).class("mnemonic");
+            }
+            if !after.is_empty() {
+                TextSpan::new(cx, &after, |_| {});
+            }
+        })
+        .name(name)
+    }
+}
+
+// Splits text into the text before its mnemonic, the mnemonic, and the text after it, replacing any escaped `&&`
+// with `&`. Only the first `&` followed by a letter or digit marks a mnemonic, so other uses of `&` are kept.
+fn split_mnemonic(text: &str) -> (String, String, String) {
+    let mut parts = [String::new(), String::new(), String::new()];
+    let mut part = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' && chars.next_if_eq(&'&').is_none() && part == 0 {
+            if let Some(mnemonic) = chars.next_if(|next| next.is_alphanumeric()) {
+                parts[1].push(mnemonic);
+                part = 2;
+                continue;
+            }
+        }
+
+        parts[part].push(c);
+    }
+
+    let [before, mnemonic, after] = parts;
+    (before, mnemonic, after)
 }
 
 impl Handle<'_, Label> {
@@ -192,7 +265,11 @@ impl View for Label {
     }
 }
 
-/// A view which represents a span of text within a label.
+/// A view which represents a span of text within a rich label.
+///
+/// A text span is not drawn itself, but contributes a run of text to the paragraph of the [Label] containing it,
+/// using the text properties of the span. Spans can be nested, in which case the inner spans inherit the text
+/// properties of the outer spans. See [`Label::rich`] for an example.
 pub struct TextSpan {}
 
 impl TextSpan {
@@ -218,3 +295,22 @@ impl View for TextSpan {
         Some("text-span")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_mnemonic() {
+        let split = |text| {
+            let (before, mnemonic, after) = split_mnemonic(text);
+            format!("{}[{}]{}", before, mnemonic, after)
+        };
+        assert_eq!(split("&File"), "[F]ile");
+        assert_eq!(split("Save &As"), "Save [A]s");
+        assert_eq!(split("R&&D"), "R&D[]");
+        assert_eq!(split("&Find && Replace"), "[F]ind & Replace");
+        assert_eq!(split("Find & Replace"), "Find & Replace[]");
+        assert_eq!(split("Find & &Replace"), "Find & [R]eplace");
+    }
+}
@@ -26,21 +26,45 @@ pub enum ListEvent {
     FocusNext,
     ///  Moves the focus to the previous item in the list.
     FocusPrev,
+    /// Moves the focus to the list item with the given index, unless it is disabled.
+    Focus(usize),
     /// Deselects all items from the list
     ClearSelection,
 }
 
+// Sent by the scroll view of a list with the fraction of the content beyond the end of the visible area along each
+// axis whenever it is scrolled or laid out, used to decide whether more items should be fetched.
+pub(crate) enum ListScrollEvent {
+    Remaining(f32, f32),
+    // Sent when loading finishes, to fetch more items if the end of the list is still visible.
+    Check,
+}
+
+/// The default number of items from the end of a list within which scrolling fetches more items.
+const DEFAULT_FETCH_THRESHOLD: usize = 3;
+
 /// A view for creating a list of items from a binding to an iteratable list.
 #[derive(Lens)]
 pub struct List {
     list_len: usize,
     selected: BTreeSet<usize>,
+    // Items which cannot be focused or selected, such as group headers.
+    disabled: BTreeSet<usize>,
     selectable: Selectable,
     focused: Option<usize>,
     focus_visible: bool,
     selection_follows_focus: bool,
     horizontal: bool,
+    is_loading: bool,
     on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    on_fetch_more: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    fetch_threshold: usize,
+    // The fraction of the content beyond the end of the visible area along each axis, and the length of the list when
+    // it was measured.
+    remaining: (f32, f32),
+    measured_len: Option<usize>,
+    // The length of the list when more items were last fetched, so that they are only fetched once for each length.
+    fetched_len: Option<usize>,
 }
 
 impl List {
@@ -101,14 +125,23 @@ impl List {
         Self {
             list_len: num_items.get(cx),
             selected: BTreeSet::default(),
+            disabled: BTreeSet::default(),
             selectable: Selectable::None,
             focused: None,
             focus_visible: false,
             selection_follows_focus: false,
             horizontal: false,
+            is_loading: false,
             on_select: None,
+            on_fetch_more: None,
+            fetch_threshold: DEFAULT_FETCH_THRESHOLD,
+            remaining: (1.0, 1.0),
+            measured_len: None,
+            fetched_len: None,
         }
         .build(cx, move |cx| {
+            let list_entity = cx.current();
+
             Keymap::from(vec![
                 (
                     KeyChord::new(Modifiers::empty(), Code::ArrowDown),
@@ -177,7 +210,55 @@ impl List {
                         });
                     }
                 });
-            });
+
+                Binding::new(cx, List::is_loading, |cx, is_loading| {
+                    if is_loading.get(cx) {
+                        ProgressBar::horizontal(cx, List::is_loading.map(|_| None::<f32>))
+                            .class("list-loading");
+                    }
+                });
+            })
+            // The remaining content is measured whenever the content is scrolled or laid out, including when added
+            // items still fit within the list.
+            .bind(
+                ScrollView::root.map(|scroll_view| {
+                    (
+                        (
+                            scroll_view.scroll_x,
+                            scroll_view.container_width,
+                            scroll_view.inner_width,
+                        ),
+                        (
+                            scroll_view.scroll_y,
+                            scroll_view.container_height,
+                            scroll_view.inner_height,
+                        ),
+                    )
+                }),
+                move |mut handle, geometry| {
+                    // Nothing is remaining along an axis where the content fits, and everything is remaining
+                    // until the scroll view has been laid out.
+                    let remaining = |(progress, container, content): (f32, f32, f32)| {
+                        if container == 0.0 {
+                            1.0
+                        } else if content > container {
+                            (1.0 - progress) * (content - container) / content
+                        } else {
+                            0.0
+                        }
+                    };
+
+                    let (x, y) = geometry.get(&handle);
+                    handle.context().emit_to(
+                        list_entity,
+                        ListScrollEvent::Remaining(remaining(x), remaining(y)),
+                    );
+                },
+            );
+        })
+        .bind(num_items, |handle, num_items| {
+            let num_items = num_items.get(&handle);
+            handle.modify(|list: &mut List| list.list_len = num_items);
         })
         .toggle_class("selectable", List::selectable.map(|s| *s != Selectable::None))
         .toggle_class("horizontal", List::horizontal)
@@ -186,14 +267,86 @@ impl List {
     }
 }
 
+impl List {
+    // Returns the index of the next enabled item after the given index, or before it if `forward` is false, wrapping
+    // around the ends of the list. Returns `None` if every item is disabled.
+    fn next_enabled(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.list_len;
+        if len == 0 {
+            return None;
+        }
+
+        let mut index = from;
+        for _ in 0..len {
+            let next = match (index, forward) {
+                (Some(i), true) if i + 1 < len => i + 1,
+                (_, true) => 0,
+                (Some(0), false) | (None, false) => len - 1,
+                (Some(i), false) => i.min(len) - 1,
+            };
+
+            if !self.disabled.contains(&next) {
+                return Some(next);
+            }
+
+            index = Some(next);
+        }
+
+        None
+    }
+
+    // Calls the fetch more callback if the end of the visible area is within the threshold of the end of the list,
+    // unless a fetch is in progress or more items have already been fetched for the current length of the list. The
+    // visible area is only compared once it has been measured with the items of the current length, since items
+    // which were just added have not been laid out yet.
+    fn fetch_more_if_needed(&mut self, cx: &mut EventContext) {
+        let Some(on_fetch_more) = &self.on_fetch_more else {
+            return;
+        };
+
+        if self.measured_len != Some(self.list_len) {
+            return;
+        }
+
+        let remaining = if self.horizontal { self.remaining.0 } else { self.remaining.1 };
+        let remaining_items = remaining * self.list_len as f32;
+
+        if remaining_items <= self.fetch_threshold as f32
+            && !self.is_loading
+            && self.fetched_len != Some(self.list_len)
+        {
+            self.fetched_len = Some(self.list_len);
+            on_fetch_more(cx, self.list_len);
+        }
+    }
+}
+
 impl View for List {
     fn element(&self) -> Option<&'static str> {
         Some("list")
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|scroll_event, meta| match scroll_event {
+            ListScrollEvent::Remaining(x, y) => {
+                self.remaining = (*x, *y);
+                self.measured_len = Some(self.list_len);
+                self.fetch_more_if_needed(cx);
+                meta.consume();
+            }
+
+            ListScrollEvent::Check => {
+                self.fetch_more_if_needed(cx);
+                meta.consume();
+            }
+        });
+
         event.take(|list_event, _| match list_event {
             ListEvent::Select(index) => {
+                if self.disabled.contains(&index) {
+                    return;
+                }
+
                 cx.focus();
                 match self.selectable {
                     Selectable::Single => {
@@ -240,15 +393,7 @@ impl View for List {
             }
 
             ListEvent::FocusNext => {
-                if let Some(focused) = &mut self.focused {
-                    *focused = focused.saturating_add(1);
-
-                    if *focused >= self.list_len {
-                        *focused = 0;
-                    }
-                } else {
-                    self.focused = Some(0);
-                }
+                self.focused = self.next_enabled(self.focused, true);
 
                 self.focus_visible = true;
 
@@ -258,15 +403,7 @@ impl View for List {
             }
 
             ListEvent::FocusPrev => {
-                if let Some(focused) = &mut self.focused {
-                    if *focused == 0 {
-                        *focused = self.list_len;
-                    }
-
-                    *focused = focused.saturating_sub(1);
-                } else {
-                    self.focused = Some(self.list_len.saturating_sub(1));
-                }
+                self.focused = self.next_enabled(self.focused, false);
 
                 self.focus_visible = true;
 
@@ -274,6 +411,17 @@ impl View for List {
                     cx.emit(ListEvent::SelectFocused);
                 }
             }
+
+            ListEvent::Focus(index) => {
+                if index < self.list_len && !self.disabled.contains(&index) {
+                    self.focused = Some(index);
+                    self.focus_visible = true;
+
+                    if self.selection_follows_focus {
+                        cx.emit(ListEvent::SelectFocused);
+                    }
+                }
+            }
         })
     }
 }
@@ -295,6 +443,21 @@ impl Handle<'_, List> {
         })
     }
 
+    /// Sets the items of the list which are disabled. Takes a lens to a list of indices.
+    ///
+    /// Disabled items cannot be selected and are skipped when moving the focus with the keyboard, which is useful for
+    /// items such as group headers. They can be selected with the `.disabled` CSS class selector, but are not
+    /// disabled themselves, so that they still inherit the disabled state of the list.
+    pub fn disabled_items<S: Lens>(self, disabled: S) -> Self
+    where
+        S::Target: Deref<Target = [usize]> + Data,
+    {
+        self.bind(disabled, |handle, d| {
+            let disabled = d.get(&handle).iter().copied().collect();
+            handle.modify(|list: &mut List| list.disabled = disabled);
+        })
+    }
+
     /// Sets the callback triggered when a [ListItem] is selected.
     pub fn on_select<F>(self, callback: F) -> Self
     where
@@ -303,6 +466,56 @@ impl Handle<'_, List> {
         self.modify(|list: &mut List| list.on_select = Some(Box::new(callback)))
     }
 
+    /// Sets the callback triggered when the list is scrolled to within the fetch threshold of its end, which can be
+    /// used to load the next page of items from a paginated data source. The callback is given the number of items
+    /// currently in the list.
+    ///
+    /// The callback is called at most once for each length of the list, and not while the list is
+    /// [loading](Self::is_loading).
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     items: Vec<u32>,
+    /// #     loading: bool,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # enum AppEvent { FetchPage(usize) }
+    /// # AppData { items: vec![], loading: false }.build(cx);
+    /// List::new(cx, AppData::items, |cx, _, item| {
+    ///     Label::new(cx, item);
+    /// })
+    /// .is_loading(AppData::loading)
+    /// .on_fetch_more(|cx, loaded_count| cx.emit(AppEvent::FetchPage(loaded_count)));
+    /// ```
+    pub fn on_fetch_more<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|list: &mut List| list.on_fetch_more = Some(Box::new(callback)))
+    }
+
+    /// Sets the number of items from the end of the list within which scrolling calls the
+    /// [`on_fetch_more`](Self::on_fetch_more) callback. Defaults to 3.
+    pub fn fetch_threshold(self, threshold: usize) -> Self {
+        self.modify(|list: &mut List| list.fetch_threshold = threshold)
+    }
+
+    /// Sets whether more items are being loaded, which shows an indeterminate progress bar at the end of the list.
+    pub fn is_loading(self, flag: impl Res<bool>) -> Self {
+        self.bind(flag, |handle, is_loading| {
+            let is_loading = is_loading.get(&handle);
+            let entity = handle.entity();
+            handle
+                .modify(|list: &mut List| list.is_loading = is_loading)
+                .context()
+                .emit_to(entity, ListScrollEvent::Check);
+        })
+    }
+
     /// Set the selectable state of the [List].
     pub fn selectable<U: Into<Selectable>>(self, selectable: impl Res<U>) -> Self {
         self.bind(selectable, |handle, selectable| {
@@ -346,6 +559,7 @@ impl ListItem {
             })
             .role(Role::ListItem)
             .checked(List::selected.map(move |selected| selected.contains(&index)))
+            .toggle_class("disabled", List::disabled.map(move |disabled| disabled.contains(&index)))
             //.toggle_class("focused", List::focused.map(move |focused| *focused == Some(index)))
             .focused_with_visibility(
                 List::focused.map(move |f| *f == Some(index)),
@@ -360,3 +574,79 @@ impl View for ListItem {
         Some("list-item")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Lens)]
+    struct AppData {
+        items: Vec<u32>,
+        loading: bool,
+    }
+
+    struct StartLoading;
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|count: &u32, _| {
+                self.items.extend(0..*count);
+                self.loading = false;
+            });
+            event.map(|_: &StartLoading, _| self.loading = true);
+        }
+    }
+
+    #[test]
+    fn fetches_more_once_per_length() {
+        let fetched = Rc::new(RefCell::new(Vec::new()));
+
+        let list_fetched = fetched.clone();
+        let mut harness = TestHarness::new(move |cx| {
+            AppData { items: (0..5).collect(), loading: false }.build(cx);
+            List::new(cx, AppData::items, |cx, _, item| {
+                Label::new(cx, item);
+            })
+            .on_fetch_more(move |_, loaded_count| list_fetched.borrow_mut().push(loaded_count));
+        });
+
+        // The items fit within the list, so the end of the list is visible straight away.
+        assert_eq!(*fetched.borrow(), vec![5]);
+
+        // Adding more items which overflow the list doesn't fetch more until it is scrolled near the end.
+        harness.emit(100u32);
+        assert_eq!(*fetched.borrow(), vec![5]);
+
+        let scroll_view = harness.query("scrollview").unwrap();
+        harness.context().emit_to(scroll_view, ScrollEvent::SetY(1.0));
+        harness.update();
+        assert_eq!(*fetched.borrow(), vec![5, 105]);
+    }
+
+    #[test]
+    fn fetches_more_while_fetched_items_fit() {
+        let fetched = Rc::new(RefCell::new(Vec::new()));
+
+        let list_fetched = fetched.clone();
+        let mut harness = TestHarness::new(move |cx| {
+            AppData { items: (0..5).collect(), loading: false }.build(cx);
+            List::new(cx, AppData::items, |cx, _, item| {
+                Label::new(cx, item);
+            })
+            .is_loading(AppData::loading)
+            .on_fetch_more(move |cx, loaded_count| {
+                list_fetched.borrow_mut().push(loaded_count);
+                cx.emit(StartLoading);
+            });
+        });
+        assert_eq!(*fetched.borrow(), vec![5]);
+
+        // The fetched items still fit within the list, so more are fetched without scrolling.
+        harness.emit(2u32);
+        assert_eq!(*fetched.borrow(), vec![5, 7]);
+
+        harness.emit(2u32);
+        assert_eq!(*fetched.borrow(), vec![5, 7, 9]);
+    }
+}
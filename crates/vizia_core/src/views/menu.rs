@@ -1,16 +1,40 @@
+use crate::events::TimedEventHandle;
 use crate::modifiers::ModalEvent;
-use crate::{icons::ICON_CHEVRON_RIGHT, prelude::*};
+use crate::{
+    icons::{ICON_CHECK, ICON_CHEVRON_RIGHT},
+    prelude::*,
+};
+use vizia_style::Atom;
 
 /// A view which represents a horizontal group of menus.
-#[derive(Lens)]
+///
+/// The menus of a menu bar can be operated with the keyboard. Pressing and releasing `Alt` focuses the first menu,
+/// and pressing it again returns focus to the view which was focused before. Pressing `Enter`, `Space` or the down
+/// arrow on a focused menu opens it and moves focus into it, after which the arrow keys navigate between items and
+/// menus, and `Escape` closes one level of menus. Focus returns to the view which was focused before once a menu item
+/// is pressed or `Escape` is pressed on a closed menu.
+///
+/// Menus and items with a [mnemonic label](Label::mnemonic), such as `&File`, can also be activated by pressing their
+/// mnemonic, together with `Alt` for the menus of the menu bar. Mnemonics are underlined while `Alt` is held or the
+/// menu bar is being used with the keyboard.
+///
+/// `Alt` activation and mnemonics are not available on macOS, where `Option` and a letter types a character.
+#[derive(Lens, Default)]
 pub struct MenuBar {
     is_open: bool,
+    hover_delay: Duration,
+    // Whether `Alt` is held, and whether it has been held without pressing any other keys.
+    alt_held: bool,
+    alt_alone: bool,
+    // The view which was focused before the menu bar was activated with the keyboard.
+    #[lens(ignore)]
+    restore_focus: Option<Entity>,
 }
 
 impl MenuBar {
     /// Creates a new [MenuBar] view.
     pub fn new(cx: &mut Context, content: impl Fn(&mut Context)) -> Handle<Self> {
-        Self { is_open: false }
+        Self::default()
             .build(cx, |cx| {
                 cx.add_listener(move |menu_bar: &mut Self, cx, event| {
                     let flag: bool = menu_bar.is_open;
@@ -23,6 +47,54 @@ impl MenuBar {
                                         cx.emit(MenuEvent::CloseAll);
                                     }
                                 }
+
+                                // Clicking outside of the menu bar ends keyboard access without moving focus back.
+                                if !cx.hovered.is_descendant_of(cx.tree, cx.current) {
+                                    menu_bar.restore_focus = None;
+                                    menu_bar.update_mnemonics(cx);
+                                }
+                            }
+
+                            #[cfg(not(target_os = "macos"))]
+                            WindowEvent::KeyDown(Code::AltLeft | Code::AltRight, _) => {
+                                if !menu_bar.alt_held {
+                                    menu_bar.alt_held = true;
+                                    menu_bar.alt_alone = true;
+                                    menu_bar.update_mnemonics(cx);
+                                }
+                            }
+
+                            #[cfg(not(target_os = "macos"))]
+                            WindowEvent::KeyUp(Code::AltLeft | Code::AltRight, _) => {
+                                menu_bar.alt_held = false;
+                                if std::mem::take(&mut menu_bar.alt_alone) {
+                                    menu_bar.toggle_keyboard_access(cx);
+                                }
+                                menu_bar.update_mnemonics(cx);
+                            }
+
+                            // Pressing the mnemonic of a menu together with `Alt` opens the menu.
+                            #[cfg(not(target_os = "macos"))]
+                            WindowEvent::KeyDown(code, _) => {
+                                menu_bar.alt_alone = false;
+                                let Some(key) = key_char(*code).filter(|_| cx.modifiers.alt())
+                                else {
+                                    return;
+                                };
+
+                                if let Some(menu) = menus(cx)
+                                    .into_iter()
+                                    .find(|menu| mnemonic(cx, *menu) == Some(key))
+                                {
+                                    menu_bar.save_focus(cx);
+                                    cx.emit_custom(
+                                        Event::new(MenuEvent::Close)
+                                            .target(cx.current)
+                                            .propagate(Propagation::Subtree),
+                                    );
+                                    activate_item(cx, menu);
+                                    meta.consume();
+                                }
                             }
 
                             _ => {}
@@ -32,8 +104,100 @@ impl MenuBar {
 
                 (content)(cx);
             })
+            .role(Role::MenuBar)
             .layout_type(LayoutType::Row)
     }
+
+    /// Creates a new [MenuBar] view from a [MenuModel], with a menu for each item of the model.
+    ///
+    /// When the model changes only the menus of the items which have changed are rebuilt.
+    ///
+    /// # Example
+    /// ```ignore
+    /// MenuBar::from_model(cx, AppData::recent_files.map(|recent_files| {
+    ///     MenuModel::new().item(
+    ///         MenuItem::new("File")
+    ///             .submenu(recent_files.iter().map(|file| MenuItem::new(file).action(AppEvent::Open(file.clone())))),
+    ///     )
+    /// }));
+    /// ```
+    pub fn from_model<A, L>(cx: &mut Context, model: L) -> Handle<Self>
+    where
+        A: 'static + Clone + PartialEq + Send + Sync,
+        L: Lens<Target = MenuModel<A>>,
+    {
+        Self::new(cx, move |cx| build_menu_items(cx, model))
+    }
+}
+
+impl Handle<'_, MenuBar> {
+    /// Sets the time the mouse must rest on a menu before it opens while another menu of the menu bar is open.
+    ///
+    /// Defaults to no delay. A delay of around 200ms avoids opening menus by accident while moving the mouse across
+    /// the menu bar.
+    pub fn hover_delay(self, delay: Duration) -> Self {
+        self.modify(|menu_bar| menu_bar.hover_delay = delay)
+    }
+}
+
+impl MenuBar {
+    // Remembers the focused view when the menu bar is activated with the keyboard, unless focus is already within it.
+    fn save_focus(&mut self, cx: &mut EventContext) {
+        let focused = cx.focused();
+        if focused != cx.current() && !focused.is_descendant_of(cx.tree, cx.current()) {
+            self.restore_focus = Some(focused);
+        }
+        self.update_mnemonics(cx);
+    }
+
+    // Returns focus to the view which was focused before the menu bar was activated with the keyboard.
+    fn restore_focus(&mut self, cx: &mut EventContext) {
+        if let Some(entity) = self.restore_focus.take() {
+            if cx.views.contains_key(&entity) {
+                cx.with_current(entity, |cx| cx.focus_with_visibility(false));
+            }
+        }
+        self.update_mnemonics(cx);
+    }
+
+    // Focuses the first menu when `Alt` is pressed on its own, or leaves the menu bar if focus is already within it.
+    #[cfg(not(target_os = "macos"))]
+    fn toggle_keyboard_access(&mut self, cx: &mut EventContext) {
+        let focused = cx.focused();
+        if focused.is_descendant_of(cx.tree, cx.current()) {
+            cx.emit(MenuEvent::CloseAll);
+            self.restore_focus(cx);
+        } else if let Some(menu) = menus(cx).first().copied() {
+            self.save_focus(cx);
+            cx.with_current(menu, |cx| cx.focus_with_visibility(true));
+        }
+    }
+
+    fn update_mnemonics(&self, cx: &mut EventContext) {
+        cx.toggle_class("show-mnemonics", self.alt_held || self.restore_focus.is_some());
+    }
+}
+
+// Returns the menus of the menu bar, ignoring any nested submenus.
+fn menus(cx: &EventContext) -> Vec<Entity> {
+    cx.current()
+        .branch_iter(cx.tree)
+        .filter(|entity| {
+            cx.views
+                .get(entity)
+                .and_then(|view| view.downcast_ref::<Submenu>())
+                .is_some_and(|submenu| !submenu.is_submenu)
+        })
+        .collect()
+}
+
+// Returns the character of a letter or digit key, which is matched against the mnemonics of menus.
+#[cfg(not(target_os = "macos"))]
+fn key_char(code: Code) -> Option<char> {
+    let code = code.to_string();
+    let mut chars = code.strip_prefix("Key").or_else(|| code.strip_prefix("Digit"))?.chars();
+    let key = chars.next()?;
+    chars.next().is_none().then_some(key.to_ascii_lowercase())
 }
 
 impl View for MenuBar {
@@ -52,6 +216,52 @@ impl View for MenuBar {
                 cx.emit_custom(
                     Event::new(MenuEvent::Close).target(cx.current).propagate(Propagation::Subtree),
                 );
+
+                // Focus stays on the menu when it is closed with `Escape`, and otherwise returns to the view which
+                // was focused before the menu bar was activated with the keyboard.
+                if !menus(cx).contains(&cx.focused()) {
+                    self.restore_focus(cx);
+                }
+            }
+
+            _ => {}
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::Escape, _) if self.restore_focus.is_some() => {
+                self.restore_focus(cx);
+                meta.consume();
+            }
+
+            // Move between the menus of the menu bar, keeping a menu open if one is already open.
+            WindowEvent::KeyDown(code @ (Code::ArrowLeft | Code::ArrowRight), _) => {
+                let focused = cx.focused();
+                let menus = menus(cx);
+
+                let Some(index) = menus
+                    .iter()
+                    .position(|menu| focused == *menu || focused.is_descendant_of(cx.tree, *menu))
+                else {
+                    return;
+                };
+
+                let menu = if *code == Code::ArrowRight {
+                    menus[(index + 1) % menus.len()]
+                } else {
+                    menus[(index + menus.len() - 1) % menus.len()]
+                };
+
+                if self.is_open {
+                    cx.emit_custom(
+                        Event::new(MenuEvent::Close)
+                            .target(cx.current)
+                            .propagate(Propagation::Subtree),
+                    );
+                    cx.emit_to(menu, SubmenuEvent::KeyboardOpen);
+                }
+
+                cx.with_current(menu, |cx| cx.focus_with_visibility(true));
+                meta.consume();
             }
 
             _ => {}
@@ -73,12 +283,30 @@ pub enum MenuEvent {
     MenuIsOpen,
 }
 
+pub(crate) enum SubmenuEvent {
+    // Opens the submenu once the mouse has rested on it for the hover delay of the menu bar.
+    HoverOpen,
+    // Opens the submenu and moves keyboard focus into its menu.
+    KeyboardOpen,
+}
+
+// The time after which the typed characters used to select a menu item are discarded.
+const TYPE_TO_SELECT_TIMEOUT: Duration = Duration::from_millis(500);
+
 /// A view which represents a submenu within a menu.
-#[derive(Lens)]
+#[derive(Lens, Default)]
 pub struct Submenu {
     is_open: bool,
     open_on_hover: bool,
     is_submenu: bool,
+    // Whether the submenu was opened with the keyboard, in which case focus is trapped within its menu.
+    keyboard_open: bool,
+    // The characters typed to select a menu item and the time the last character was typed.
+    #[lens(ignore)]
+    search: Option<(String, Instant)>,
+    // The scheduled event which opens the submenu after the hover delay.
+    #[lens(ignore)]
+    hover_open: Option<TimedEventHandle>,
 }
 
 impl Submenu {
@@ -90,7 +318,7 @@ impl Submenu {
     ) -> Handle<Self> {
         let is_submenu = cx.data::<Submenu>().is_some();
 
-        let handle = Self { is_open: false, open_on_hover: is_submenu, is_submenu }
+        let handle = Self { open_on_hover: is_submenu, is_submenu, ..Default::default() }
             .build(cx, |cx| {
                 cx.add_listener(move |menu_button: &mut Self, cx, event| {
                     let flag: bool = menu_button.is_open;
@@ -118,9 +346,10 @@ impl Submenu {
                 // });
                 Binding::new(cx, Submenu::is_open, move |cx, is_open| {
                     if is_open.get(cx) {
-                        Popup::new(cx, |cx| {
+                        let popup = Popup::new(cx, |cx| {
                             (menu)(cx);
                         })
+                        .role(Role::Menu)
                         .placement(Submenu::is_submenu.map(|is_submenu| {
                             if *is_submenu {
                                 Placement::RightStart
@@ -137,11 +366,17 @@ impl Submenu {
                                     .propagate(Propagation::Subtree),
                             )
                         });
+
+                        // Trap focus within the menu when it was opened with the keyboard.
+                        if Submenu::keyboard_open.get(&popup) {
+                            popup.lock_focus_to_within();
+                        }
                     }
                 });
                 // .on_press_down(|cx| cx.emit(MenuEvent::CloseAll));
                 // .on_blur(|cx| cx.emit(MenuEvent::CloseAll));
             })
+            .role(Role::MenuItem)
             .navigable(true)
             .checked(Submenu::is_open)
             .layout_type(LayoutType::Row)
@@ -158,6 +393,136 @@ impl Submenu {
     }
 }
 
+// Returns the enabled items of the open menu, ignoring the items of any nested submenus.
+fn menu_items(cx: &EventContext) -> Vec<Entity> {
+    let current = cx.current();
+    let is_submenu = |entity: &Entity| {
+        cx.views.get(entity).is_some_and(|view| view.downcast_ref::<Submenu>().is_some())
+    };
+    let is_item = |entity: &Entity| {
+        is_submenu(entity)
+            || cx.views.get(entity).is_some_and(|view| view.downcast_ref::<MenuButton>().is_some())
+    };
+
+    // The view of the current submenu is not stored while it handles an event, so the current entity is checked
+    // directly when looking for the submenu an item belongs to.
+    current
+        .branch_iter(cx.tree)
+        .skip(1)
+        .filter(|entity| {
+            is_item(entity)
+                && !cx.style.disabled.get(*entity).copied().unwrap_or_default()
+                && entity
+                    .parent_iter(cx.tree)
+                    .skip(1)
+                    .find(|ancestor| *ancestor == current || is_submenu(ancestor))
+                    == Some(current)
+        })
+        .collect()
+}
+
+// Returns the first view with text within a menu item, which is the label of the item.
+fn item_label(cx: &EventContext, item: Entity) -> Option<Entity> {
+    item.branch_iter(cx.tree).find(|descendant| cx.style.text.get(*descendant).is_some())
+}
+
+// Returns the first enabled item of the open menu with a label starting with the typed characters,
+// ignoring the items of any nested submenus.
+fn find_item(cx: &EventContext, search: &str) -> Option<Entity> {
+    menu_items(cx).into_iter().find(|entity| {
+        item_label(cx, *entity).is_some_and(|label| {
+            // The text of a mnemonic label is split between the label and its spans.
+            label
+                .branch_iter(cx.tree)
+                .filter_map(|descendant| cx.style.text.get(descendant))
+                .map(|text| text.as_str())
+                .collect::<String>()
+                .to_lowercase()
+                .starts_with(search)
+        })
+    })
+}
+
+// Returns the mnemonic of a menu item in lowercase, if it has a mnemonic label.
+#[cfg(not(target_os = "macos"))]
+fn mnemonic(cx: &EventContext, item: Entity) -> Option<char> {
+    let class = Atom::new("mnemonic");
+    item_label(cx, item)?
+        .branch_iter(cx.tree)
+        .find(|descendant| {
+            cx.style.classes.get(*descendant).is_some_and(|classes| classes.contains(&class))
+        })
+        .and_then(|span| cx.style.text.get(span))
+        .and_then(|text| text.chars().next())
+        .and_then(|c| c.to_lowercase().next())
+}
+
+// Focuses a menu item and activates it, opening the menu of a submenu or pressing a menu button.
+fn activate_item(cx: &mut EventContext, item: Entity) {
+    cx.with_current(item, |cx| cx.focus_with_visibility(true));
+    if cx.views.get(&item).is_some_and(|view| view.downcast_ref::<Submenu>().is_some()) {
+        cx.emit_to(item, SubmenuEvent::KeyboardOpen);
+    } else {
+        cx.emit_to(item, WindowEvent::Press { mouse: false });
+    }
+}
+
+// Moves focus to the next or previous item of the open menu, wrapping around at either end.
+fn focus_item(cx: &mut EventContext, forward: bool) {
+    let items = menu_items(cx);
+    let focused = cx.focused();
+    let index =
+        items.iter().position(|item| focused == *item || focused.is_descendant_of(cx.tree, *item));
+
+    let index = match (index, forward) {
+        (Some(index), true) => (index + 1) % items.len(),
+        (Some(index), false) => (index + items.len() - 1) % items.len(),
+        (None, true) => 0,
+        (None, false) => items.len().wrapping_sub(1),
+    };
+
+    if let Some(item) = items.get(index).copied() {
+        cx.with_current(item, |cx| cx.focus_with_visibility(true));
+        scroll_into_view(cx, item);
+    }
+}
+
+// Closes any open submenus of the parent menu and opens the current submenu.
+fn open_on_hover(cx: &mut EventContext) {
+    let parent = cx.tree.get_layout_parent(cx.current).unwrap();
+    cx.emit_custom(Event::new(MenuEvent::Close).target(parent).propagate(Propagation::Subtree));
+    cx.emit(MenuEvent::Open);
+}
+
+// Scrolls the closest scroll view containing the item so that the item is visible.
+fn scroll_into_view(cx: &mut EventContext, item: Entity) {
+    let Some((scrollview, overflow, scroll_y)) = item.parent_iter(cx.tree).find_map(|entity| {
+        cx.views.get(&entity).and_then(|view| view.downcast_ref::<ScrollView>()).map(|scrollview| {
+            (entity, scrollview.inner_height - scrollview.container_height, scrollview.scroll_y)
+        })
+    }) else {
+        return;
+    };
+
+    if overflow <= 0.0 {
+        return;
+    }
+
+    let view_bounds = cx.cache.get_bounds(scrollview);
+    let item_bounds = cx.cache.get_bounds(item);
+    let offset = overflow * scroll_y;
+
+    let offset = if item_bounds.top() < view_bounds.top() {
+        offset - (view_bounds.top() - item_bounds.top())
+    } else if item_bounds.bottom() > view_bounds.bottom() {
+        offset + (item_bounds.bottom() - view_bounds.bottom())
+    } else {
+        return;
+    };
+
+    cx.emit_to(scrollview, ScrollEvent::SetY((offset / overflow).clamp(0.0, 1.0)));
+}
+
 impl View for Submenu {
     fn element(&self) -> Option<&'static str> {
         Some("submenu")
@@ -171,21 +536,40 @@ impl View for Submenu {
                     //     cx.focus();
                     // }
                     if self.open_on_hover {
-                        // Close any open submenus of the parent
-                        let parent = cx.tree.get_parent(cx.current).unwrap();
-                        cx.emit_custom(
-                            Event::new(MenuEvent::Close)
-                                .target(parent)
-                                .propagate(Propagation::Subtree),
-                        );
-                        // Open this submenu
-                        cx.emit(MenuEvent::Open);
+                        self.keyboard_open = false;
+                        let delay = cx
+                            .data::<MenuBar>()
+                            .map(|menu_bar| menu_bar.hover_delay)
+                            .unwrap_or_default();
+                        if delay.is_zero() {
+                            open_on_hover(cx);
+                        } else {
+                            self.hover_open = Some(cx.schedule_emit(
+                                SubmenuEvent::HoverOpen,
+                                crate::util::now() + delay,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            WindowEvent::MouseLeave => {
+                if meta.target == cx.current {
+                    if let Some(hover_open) = self.hover_open.take() {
+                        cx.cancel_scheduled(hover_open);
                     }
                 }
             }
 
+            WindowEvent::PressDown { mouse } => {
+                if meta.target == cx.current {
+                    self.keyboard_open = !*mouse;
+                }
+            }
+
             WindowEvent::KeyDown(code, _) => match code {
-                Code::ArrowLeft => {
+                // Moving between the menus of a menu bar is handled by the menu bar.
+                Code::ArrowLeft if self.is_submenu || cx.data::<MenuBar>().is_none() => {
                     // if cx.is_focused() {
                     if self.is_open {
                         self.is_open = false;
@@ -195,18 +579,98 @@ impl View for Submenu {
                     // }
                 }
 
-                Code::ArrowRight => {
-                    if !self.is_open {
-                        self.is_open = true;
+                Code::ArrowRight if self.is_submenu || cx.data::<MenuBar>().is_none() => {
+                    if meta.target == cx.current {
+                        if !self.is_open {
+                            cx.emit(SubmenuEvent::KeyboardOpen);
+                        }
+                        meta.consume();
+                    }
+                }
+
+                Code::ArrowDown
+                    if !self.is_submenu && !self.is_open && meta.target == cx.current =>
+                {
+                    cx.emit(SubmenuEvent::KeyboardOpen);
+                    meta.consume();
+                }
+
+                Code::ArrowDown | Code::ArrowUp if self.is_open => {
+                    focus_item(cx, *code == Code::ArrowDown);
+                    meta.consume();
+                }
+
+                Code::Escape if self.is_open => {
+                    self.is_open = false;
+                    cx.focus();
+                    if !self.is_submenu {
+                        // Reset the open state of the menu bar
+                        let parent = cx.tree.get_layout_parent(cx.current).unwrap();
+                        cx.emit_custom(
+                            Event::new(MenuEvent::CloseAll)
+                                .target(parent)
+                                .propagate(Propagation::Direct),
+                        );
                     }
+                    meta.consume();
                 }
 
                 _ => {}
             },
 
+            WindowEvent::CharInput(c) if self.is_open && !c.is_control() => {
+                // Pressing the mnemonic of an item activates it.
+                #[cfg(not(target_os = "macos"))]
+                if let Some(item) = menu_items(cx)
+                    .into_iter()
+                    .find(|item| mnemonic(cx, *item) == c.to_lowercase().next())
+                {
+                    self.search = None;
+                    activate_item(cx, item);
+                    meta.consume();
+                    return;
+                }
+
+                let now = crate::util::now();
+                let mut search = match self.search.take() {
+                    Some((search, last_input))
+                        if now.duration_since(last_input) <= TYPE_TO_SELECT_TIMEOUT =>
+                    {
+                        search
+                    }
+                    _ => String::new(),
+                };
+                search.extend(c.to_lowercase());
+
+                let item = find_item(cx, &search);
+                self.search = Some((search, now));
+
+                if let Some(item) = item {
+                    cx.with_current(item, |cx| cx.focus_with_visibility(true));
+                    scroll_into_view(cx, item);
+                }
+
+                meta.consume();
+            }
+
             _ => {}
         });
 
+        event.map(|submenu_event, meta| match submenu_event {
+            SubmenuEvent::HoverOpen => {
+                self.hover_open = None;
+                open_on_hover(cx);
+                meta.consume();
+            }
+
+            SubmenuEvent::KeyboardOpen => {
+                self.keyboard_open = true;
+                self.is_open = true;
+                cx.emit(MenuEvent::MenuIsOpen);
+                meta.consume();
+            }
+        });
+
         event.map(|menu_event, meta| match menu_event {
             MenuEvent::Open => {
                 self.is_open = true;
@@ -215,16 +679,22 @@ impl View for Submenu {
 
             MenuEvent::Close => {
                 self.is_open = false;
+                if let Some(hover_open) = self.hover_open.take() {
+                    cx.cancel_scheduled(hover_open);
+                }
                 // meta.consume();
             }
 
             MenuEvent::ToggleOpen => {
+                if let Some(hover_open) = self.hover_open.take() {
+                    cx.cancel_scheduled(hover_open);
+                }
                 self.is_open ^= true;
                 if self.is_open {
                     cx.emit(MenuEvent::MenuIsOpen);
                 } else {
                     // If the parent is a MenuBar then this will reset the is_open state
-                    let parent = cx.tree.get_parent(cx.current).unwrap();
+                    let parent = cx.tree.get_layout_parent(cx.current).unwrap();
                     cx.emit_custom(
                         Event::new(MenuEvent::CloseAll)
                             .target(parent)
@@ -274,7 +744,7 @@ impl View for MenuButton {
         event.map(|window_event, meta| match window_event {
             WindowEvent::MouseEnter => {
                 if meta.target == cx.current {
-                    let parent = cx.tree.get_parent(cx.current).unwrap();
+                    let parent = cx.tree.get_layout_parent(cx.current).unwrap();
                     cx.emit_custom(
                         Event::new(MenuEvent::Close).target(parent).propagate(Propagation::Subtree),
                     );
@@ -285,3 +755,435 @@ impl View for MenuButton {
         });
     }
 }
+
+/// A description of an item of a menu, used to build menus from data with [MenuBar::from_model] and
+/// [ContextMenu::from_model].
+///
+/// Pressing an item emits its action as an event. A [MenuModel] is usually computed from application data with a
+/// lens, so the enabled and checked states of its items follow the state of the application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuItem<A> {
+    label: String,
+    icon: Option<&'static str>,
+    shortcut: Option<KeyChord>,
+    enabled: bool,
+    checked: Option<bool>,
+    action: Option<A>,
+    submenu: Vec<MenuItem<A>>,
+    is_separator: bool,
+}
+
+impl<A> MenuItem<A> {
+    /// Creates a new menu item with the given label.
+    ///
+    /// A letter or digit of the label preceded by `&`, such as the `S` of `&Save`, is the mnemonic of the item, which
+    /// activates the item when it is pressed while the menu is open. See [`Label::mnemonic`].
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            shortcut: None,
+            enabled: true,
+            checked: None,
+            action: None,
+            submenu: Vec::new(),
+            is_separator: false,
+        }
+    }
+
+    /// Creates a separator between groups of menu items.
+    pub fn separator() -> Self {
+        Self { is_separator: true, ..Self::new("") }
+    }
+
+    /// Sets the SVG data of an icon shown before the label of the item.
+    pub fn icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the key chord shown as a shortcut hint for the item.
+    ///
+    /// Items without a shortcut hint show the key chords bound to their action by a [Keymap] of the same action type,
+    /// if there is one.
+    pub fn shortcut(mut self, chord: KeyChord) -> Self {
+        self.shortcut = Some(chord);
+        self
+    }
+
+    /// Sets whether the item can be pressed. Items are enabled by default.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Makes the item checkable, showing a check mark when `checked` is true.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets the action emitted as an event when the item is pressed.
+    pub fn action(mut self, action: A) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sets the items of a submenu which is opened by the item.
+    pub fn submenu(mut self, items: impl IntoIterator<Item = MenuItem<A>>) -> Self {
+        self.submenu = items.into_iter().collect();
+        self
+    }
+}
+
+impl<A: 'static + Clone + PartialEq> Data for MenuItem<A> {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+/// A description of the items of a menu, used to build menus from data with [MenuBar::from_model] and
+/// [ContextMenu::from_model].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuModel<A> {
+    /// The items of the menu.
+    pub items: Vec<MenuItem<A>>,
+}
+
+impl<A> MenuModel<A> {
+    /// Creates a new empty menu model.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds an item to the menu model.
+    pub fn item(mut self, item: MenuItem<A>) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+impl<A> Default for MenuModel<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> FromIterator<MenuItem<A>> for MenuModel<A> {
+    fn from_iter<I: IntoIterator<Item = MenuItem<A>>>(iter: I) -> Self {
+        Self { items: iter.into_iter().collect() }
+    }
+}
+
+impl<A: 'static + Clone + PartialEq> Data for MenuModel<A> {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+// Builds the items of a menu model, rebuilding an item only when it changes.
+fn build_menu_items<A, L>(cx: &mut Context, model: L)
+where
+    A: 'static + Clone + PartialEq + Send + Sync,
+    L: Lens<Target = MenuModel<A>>,
+{
+    Binding::new(cx, model.map(|model| model.items.len()), move |cx, len| {
+        for index in 0..len.get(cx) {
+            Binding::new(cx, model.map_ref(|model| &model.items).idx(index), |cx, item| {
+                build_menu_item(cx, &item.get(cx));
+            });
+        }
+    });
+}
+
+// Returns the shortcut hint of a menu item, looking up the key chords bound to its action if it has no hint.
+fn shortcut_hint<A>(cx: &Context, item: &MenuItem<A>) -> Option<String>
+where
+    A: 'static + Clone + PartialEq + Send + Sync,
+{
+    if let Some(chord) = item.shortcut {
+        return Some(chord.to_string());
+    }
+
+    let action = item.action.as_ref()?;
+    let sequence = cx.data::<Keymap<A>>()?.bindings(action).into_iter().next()?;
+    Some(sequence.iter().map(|chord| chord.to_string()).collect::<Vec<_>>().join(" "))
+}
+
+fn build_menu_item<A>(cx: &mut Context, item: &MenuItem<A>)
+where
+    A: 'static + Clone + PartialEq + Send + Sync,
+{
+    if item.is_separator {
+        Divider::new(cx);
+        return;
+    }
+
+    let label = item.label.clone();
+    let icon = item.icon;
+
+    if !item.submenu.is_empty() {
+        let submenu = item.submenu.clone();
+        Submenu::new(
+            cx,
+            move |cx| {
+                HStack::new(cx, |cx| {
+                    if let Some(icon) = icon {
+                        Svg::new(cx, icon).class("icon");
+                    }
+                    Label::mnemonic(cx, &label);
+                })
+            },
+            move |cx| {
+                for item in submenu.iter() {
+                    build_menu_item(cx, item);
+                }
+            },
+        )
+        .disabled(!item.enabled);
+        return;
+    }
+
+    let action = item.action.clone();
+    let checked = item.checked;
+    let shortcut = shortcut_hint(cx, item);
+
+    MenuButton::new(
+        cx,
+        move |cx| {
+            if let Some(action) = &action {
+                cx.emit(action.clone());
+            }
+        },
+        move |cx| {
+            HStack::new(cx, |cx| {
+                if let Some(checked) = checked {
+                    Svg::new(cx, ICON_CHECK).class("icon").class("check").visibility(checked);
+                }
+                if let Some(icon) = icon {
+                    Svg::new(cx, icon).class("icon");
+                }
+                Label::mnemonic(cx, &label);
+                if let Some(shortcut) = &shortcut {
+                    Label::new(cx, shortcut.clone()).class("shortcut");
+                }
+            })
+            .width(Stretch(1.0))
+        },
+    )
+    .role(if checked.is_some() { Role::MenuItemCheckBox } else { Role::MenuItem })
+    .checked(checked.unwrap_or_default())
+    .disabled(!item.enabled)
+}
+
+/// A view which shows a menu at the position of the cursor when its parent view is right-clicked.
+///
+/// The menu is closed by pressing one of its items, pressing `Escape`, or clicking outside of it.
+#[derive(Lens)]
+pub struct ContextMenu {
+    is_open: bool,
+}
+
+impl ContextMenu {
+    /// Creates a new [ContextMenu] view from a [MenuModel], which opens when the parent view is right-clicked.
+    ///
+    /// When the model changes only the items which have changed are rebuilt.
+    ///
+    /// # Example
+    /// ```ignore
+    /// VStack::new(cx, |cx| {
+    ///     Label::new(cx, AppData::text);
+    ///     ContextMenu::from_model(cx, AppData::selection.map(|selection| {
+    ///         MenuModel::new()
+    ///             .item(MenuItem::new("Copy").action(AppEvent::Copy).enabled(!selection.is_empty()))
+    ///             .item(MenuItem::new("Paste").action(AppEvent::Paste))
+    ///     }));
+    /// });
+    /// ```
+    pub fn from_model<A, L>(cx: &mut Context, model: L) -> Handle<Self>
+    where
+        A: 'static + Clone + PartialEq + Send + Sync,
+        L: Lens<Target = MenuModel<A>>,
+    {
+        Self { is_open: false }
+            .build(cx, move |cx| {
+                cx.add_listener(move |menu: &mut Self, cx, event| {
+                    event.map(|window_event, _| match window_event {
+                        WindowEvent::MouseDown(MouseButton::Right) => {
+                            let parent = cx.tree.get_layout_parent(cx.current).unwrap();
+                            let hovered = *cx.hovered;
+                            if hovered == parent
+                                || (hovered.is_descendant_of(cx.tree, parent)
+                                    && !hovered.is_descendant_of(cx.tree, cx.current))
+                            {
+                                let bounds = cx.cache.get_bounds(parent);
+                                let scale = cx.scale_factor();
+                                cx.set_left(Pixels((cx.mouse.cursor_x - bounds.x) / scale));
+                                cx.set_top(Pixels((cx.mouse.cursor_y - bounds.y) / scale));
+                                menu.is_open = true;
+                            }
+                        }
+
+                        WindowEvent::MouseDown(_) => {
+                            if menu.is_open && !cx.hovered.is_descendant_of(cx.tree, cx.current) {
+                                menu.is_open = false;
+                            }
+                        }
+
+                        _ => {}
+                    });
+                });
+
+                Binding::new(cx, ContextMenu::is_open, move |cx, is_open| {
+                    if is_open.get(cx) {
+                        Popup::new(cx, |cx| build_menu_items(cx, model))
+                            .role(Role::Menu)
+                            .placement(Placement::BottomStart)
+                            .arrow_size(Pixels(0.0))
+                            .lock_focus_to_within();
+                    }
+                });
+            })
+            .position_type(PositionType::Absolute)
+            .size(Pixels(0.0))
+    }
+}
+
+impl View for ContextMenu {
+    fn element(&self) -> Option<&'static str> {
+        Some("contextmenu")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|menu_event, _| match menu_event {
+            MenuEvent::CloseAll => {
+                self.is_open = false;
+            }
+
+            _ => {}
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(code @ (Code::ArrowDown | Code::ArrowUp), _) if self.is_open => {
+                focus_item(cx, *code == Code::ArrowDown);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(Code::Escape, _) if self.is_open => {
+                self.is_open = false;
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Action {
+        Save,
+        ToggleWrap,
+    }
+
+    #[derive(Lens)]
+    struct AppData {
+        wrap: bool,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|action: &Action, _| {
+                if *action == Action::ToggleWrap {
+                    self.wrap ^= true;
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn builds_menu_from_model() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { wrap: false }.build(cx);
+            Keymap::from(vec![(
+                KeyChord::new(Modifiers::CTRL, Code::KeyS),
+                KeymapEntry::new(Action::Save, |_| {}),
+            )])
+            .build(cx);
+
+            MenuBar::from_model(
+                cx,
+                AppData::wrap.map(|wrap| {
+                    MenuModel::new()
+                        .item(MenuItem::new("Save").action(Action::Save))
+                        .item(MenuItem::new("Wrap").checked(*wrap).action(Action::ToggleWrap))
+                        .item(MenuItem::new("Print").enabled(false))
+                }),
+            );
+        });
+
+        let items = harness.query_all("menubutton");
+        assert_eq!(items.len(), 3);
+        assert_eq!(harness.text(".shortcut"), Some("Ctrl+S"));
+        assert!(!harness.matches(items[1], ":checked"));
+        assert!(harness.matches(items[2], ":disabled"));
+
+        // Only the item which changed is rebuilt.
+        harness.click(items[1]);
+        let updated = harness.query_all("menubutton");
+        assert_eq!(updated[0], items[0]);
+        assert_ne!(updated[1], items[1]);
+        assert!(harness.matches(updated[1], ":checked"));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn keyboard_access_with_mnemonics() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { wrap: false }.build(cx);
+            Button::new(cx, |cx| Label::new(cx, "Editor")).id("editor");
+            MenuBar::from_model(
+                cx,
+                AppData::wrap.map(|wrap| {
+                    MenuModel::new()
+                        .item(
+                            MenuItem::new("&File")
+                                .submenu([MenuItem::new("&Save").action(Action::Save)]),
+                        )
+                        .item(MenuItem::new("&View").submenu([
+                            MenuItem::new("&Wrap").checked(*wrap).action(Action::ToggleWrap),
+                        ]))
+                }),
+            )
+            .id("menubar");
+        });
+
+        let editor = harness.query("#editor").unwrap();
+        let menubar = harness.query("#menubar").unwrap();
+        let menus = harness.query_all("submenu");
+        harness.click(editor);
+
+        // Pressing `Alt` on its own focuses the first menu, and `Escape` returns focus.
+        harness.key(Code::AltLeft);
+        assert_eq!(harness.context().focused, menus[0]);
+        assert!(harness.matches(menubar, ".show-mnemonics"));
+        harness.key(Code::Escape);
+        assert_eq!(harness.context().focused, editor);
+        assert!(!harness.matches(menubar, ".show-mnemonics"));
+
+        // `Alt` and a mnemonic opens a menu, and the mnemonic of an item presses it.
+        harness.context().modifiers = Modifiers::ALT;
+        harness.key(Code::KeyV);
+        harness.context().modifiers = Modifiers::empty();
+        assert!(harness.matches(menus[1], ":checked"));
+
+        harness.type_text("w");
+        assert!(AppData::wrap.get(harness.context()));
+        assert!(harness.query_all("menubutton").is_empty());
+        assert_eq!(harness.context().focused, editor);
+    }
+}
@@ -10,7 +10,11 @@ mod datepicker;
 mod divider;
 mod dropdown;
 mod element;
+mod external_texture;
+mod frame_time_hud;
 mod image;
+#[cfg(feature = "inspector")]
+mod inspector;
 mod knob;
 mod label;
 mod list;
@@ -23,6 +27,7 @@ mod radio;
 mod rating;
 mod scrollbar;
 mod scrollview;
+mod shader;
 mod slider;
 mod spinbox;
 mod stack;
@@ -45,7 +50,11 @@ pub use datepicker::*;
 pub use divider::*;
 pub use dropdown::*;
 pub use element::*;
+pub use external_texture::*;
+pub use frame_time_hud::*;
 pub use image::*;
+#[cfg(feature = "inspector")]
+pub use inspector::*;
 pub use knob::*;
 pub use label::*;
 pub use list::*;
@@ -59,6 +68,7 @@ pub use radio::*;
 pub use rating::*;
 pub use scrollbar::*;
 pub use scrollview::*;
+pub use shader::*;
 pub use slider::*;
 pub use spinbox::*;
 pub use stack::*;
@@ -4,16 +4,93 @@ use crate::context::TreeProps;
 use crate::icons::{ICON_CHECK, ICON_CHEVRON_DOWN};
 use crate::prelude::*;
 
+// The time after the last typed character at which typing starts a new search for an option.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
 /// A view which allows the user to select an item from a dropdown list.
+///
+/// While the dropdown list is open, typing the start of an option moves the focus to it.
 #[derive(Lens)]
 pub struct PickList {
     on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    on_select_grouped: Option<Box<dyn Fn(&mut EventContext, usize, usize)>>,
     placeholder: String,
     is_open: bool,
+    // The row of the popup list containing the selected option.
+    selected_row: Option<usize>,
+    // Returns the text of each row of the popup list, or `None` for a group header.
+    row_text: Box<dyn Fn(&EventContext) -> Vec<Option<String>>>,
+    // The text typed while the popup list is open, the time the last character was typed, and the row it matched.
+    typeahead: String,
+    typeahead_time: Instant,
+    typeahead_row: Option<usize>,
 }
 
 pub(crate) enum PickListEvent {
     SetOption(usize),
+    // The index of the group, the index of the option within the group, and the index of the option across all groups.
+    SetGroupedOption(usize, usize, usize),
+}
+
+// A row of the popup list of a grouped picklist.
+#[derive(Clone)]
+enum PickListRow<T> {
+    Header(String),
+    // The index of the group, the index of the option within the group, and the option.
+    Option(usize, usize, T),
+}
+
+impl<T: Data> Data for PickListRow<T> {
+    fn same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PickListRow::Header(a), PickListRow::Header(b)) => a == b,
+            (PickListRow::Option(g1, i1, a), PickListRow::Option(g2, i2, b)) => {
+                g1 == g2 && i1 == i2 && a.same(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T: ToStringLocalized> ToStringLocalized for PickListRow<T> {
+    fn to_string_local(&self, cx: &impl DataContext) -> String {
+        match self {
+            PickListRow::Header(name) => name.clone(),
+            PickListRow::Option(_, _, option) => option.to_string_local(cx),
+        }
+    }
+}
+
+// Flattens groups of options into the rows of a popup list, with a header before the options of each group, given
+// the index and name of the group, and a row for each option, given the index of its group, its index within the
+// group, and the option. Groups without options are skipped, and groups with an empty name have no header.
+pub(crate) fn group_rows<T, R>(
+    groups: &[(String, Vec<T>)],
+    header: impl Fn(usize, &str) -> R,
+    option: impl Fn(usize, usize, &T) -> R,
+) -> Vec<R> {
+    let mut rows = Vec::new();
+    for (group, (name, options)) in groups.iter().enumerate() {
+        if options.is_empty() {
+            continue;
+        }
+
+        if !name.is_empty() {
+            rows.push(header(group, name));
+        }
+
+        rows.extend(options.iter().enumerate().map(|(index, item)| option(group, index, item)));
+    }
+
+    rows
+}
+
+// Returns the first row at or after `start`, wrapping around, whose text starts with the typed text, ignoring case
+// and skipping group headers.
+fn typeahead_match(rows: &[Option<String>], typed: &str, start: usize) -> Option<usize> {
+    (0..rows.len())
+        .map(|offset| (start + offset) % rows.len())
+        .find(|&row| rows[row].as_ref().is_some_and(|text| text.to_lowercase().starts_with(typed)))
 }
 
 impl PickList {
@@ -30,63 +107,213 @@ impl PickList {
         T: 'static + Data + ToStringLocalized,
         L2: Lens<Target = usize>,
     {
-        Self { on_select: None, placeholder: String::new(), is_open: false }
-            .build(cx, |cx| {
-                Button::new(cx, |cx| {
-                    // A Label and an optional Icon
-                    HStack::new(cx, move |cx| {
-                        Label::new(cx, PickList::placeholder)
-                            .bind(list, move |handle, list| {
-                                handle.bind(selected, move |handle, sel| {
-                                    let selected_index = sel.get(&handle);
-                                    let list_len = list.map(|list| list.len()).get(&handle);
-                                    if selected_index < list_len {
-                                        handle.text(list.idx(selected_index));
-                                    } else {
-                                        handle.text(PickList::placeholder);
-                                    }
-                                });
-                            })
-                            .width(Stretch(2.0))
-                            .text_wrap(false)
-                            .text_overflow(TextOverflow::Ellipsis)
-                            .hoverable(false);
-                        if show_handle {
-                            Svg::new(cx, ICON_CHEVRON_DOWN)
-                                .class("icon")
-                                .size(Pixels(16.0))
-                                .hoverable(false);
+        Self::build_picklist(
+            cx,
+            show_handle,
+            move |cx| list.get(cx).iter().map(|item| Some(item.to_string_local(cx))).collect(),
+            move |cx| {
+                Label::new(cx, PickList::placeholder).bind(list, move |handle, list| {
+                    handle.bind(selected, move |handle, sel| {
+                        let selected_index = sel.get(&handle);
+                        let list_len = list.map(|list| list.len()).get(&handle);
+                        if selected_index < list_len {
+                            handle.text(list.idx(selected_index));
+                        } else {
+                            handle.text(PickList::placeholder);
                         }
+                    });
+                })
+            },
+            move |cx| {
+                List::new(cx, list, move |cx, _, item| {
+                    Element::new(cx).class("focus-indicator");
+                    Svg::new(cx, ICON_CHECK).class("checkmark").size(Pixels(16.0));
+                    Label::new(cx, item).hoverable(false);
+                })
+                .selectable(Selectable::Single)
+                .selected(selected.map(|s| vec![*s]))
+                .on_select(|cx, index| {
+                    cx.emit(PickListEvent::SetOption(index));
+                    cx.emit(PopupEvent::Close);
+                })
+                .focused(true);
+            },
+        )
+        .bind(selected, |handle, selected| {
+            let selected = selected.get(&handle);
+            handle.modify(|picklist: &mut PickList| picklist.selected_row = Some(selected));
+        })
+    }
+
+    /// Creates a new [PickList] view with options divided into named groups.
+    ///
+    /// Takes a lens to a list of groups, each with a name and a list of options, and a lens to the index of the group
+    /// of the selected option and the index of the option within the group. The name of each group is shown as a
+    /// header above its options in the popup list, which can't be selected and is skipped by keyboard navigation and
+    /// typing. Headers can be selected with the `.group-header` CSS class selector. Groups with an empty name have no
+    /// header.
+    ///
+    /// The [`on_select_grouped`](Handle::on_select_grouped) callback is given the group and option indices of the
+    /// selected option, while the [`on_select`](Handle::on_select) callback is given the index of the option across
+    /// all groups.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     fonts: Vec<(String, Vec<String>)>,
+    /// #     font: (usize, usize),
+    /// # }
+    /// # impl Model for AppData {}
+    /// # enum AppEvent { SetFont(usize, usize) }
+    /// # AppData { fonts: vec![], font: (0, 0) }.build(cx);
+    /// PickList::grouped(cx, AppData::fonts, AppData::font, true)
+    ///     .on_select_grouped(|cx, group, index| cx.emit(AppEvent::SetFont(group, index)));
+    /// ```
+    pub fn grouped<L1, L2, T>(
+        cx: &mut Context,
+        groups: L1,
+        selected: L2,
+        show_handle: bool,
+    ) -> Handle<Self>
+    where
+        L1: Lens<Target = Vec<(String, Vec<T>)>>,
+        T: 'static + Data + ToStringLocalized,
+        L2: Lens<Target = (usize, usize)>,
+    {
+        let rows = groups.map(|groups| {
+            group_rows(
+                groups,
+                |_, name| PickListRow::Header(name.to_owned()),
+                |group, index, option| PickListRow::Option(group, index, option.clone()),
+            )
+        });
+        let headers = rows.map(|rows| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| matches!(row, PickListRow::Header(_)))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>()
+        });
+
+        Self::build_picklist(
+            cx,
+            show_handle,
+            move |cx| {
+                rows.get(cx)
+                    .iter()
+                    .map(|row| match row {
+                        PickListRow::Header(_) => None,
+                        PickListRow::Option(_, _, option) => Some(option.to_string_local(cx)),
                     })
-                    .width(Stretch(1.0))
-                    //.gap(Stretch(1.0))
-                    .gap(Pixels(8.0))
+                    .collect()
+            },
+            move |cx| {
+                Label::new(cx, PickList::placeholder).bind(
+                    PickList::selected_row,
+                    move |handle, selected_row| match selected_row.get(&handle) {
+                        Some(row) => {
+                            handle.text(rows.idx(row));
+                        }
+                        None => {
+                            handle.text(PickList::placeholder);
+                        }
+                    },
+                )
+            },
+            move |cx| {
+                List::new(cx, rows, move |cx, _, item| {
+                    Element::new(cx).class("focus-indicator");
+                    Svg::new(cx, ICON_CHECK).class("checkmark").size(Pixels(16.0));
+                    Label::new(cx, item)
+                        .toggle_class(
+                            "group-header",
+                            item.map(|row| matches!(row, PickListRow::Header(_))),
+                        )
+                        .hoverable(false);
                 })
-                .width(Stretch(1.0))
-                .on_press(|cx| cx.emit(PopupEvent::Open));
-
-                Binding::new(cx, PickList::is_open, move |cx, is_open| {
-                    if is_open.get(cx) {
-                        Popup::new(cx, |cx| {
-                            List::new(cx, list, move |cx, _, item| {
-                                Element::new(cx).class("focus-indicator");
-                                Svg::new(cx, ICON_CHECK).class("checkmark").size(Pixels(16.0));
-                                Label::new(cx, item).hoverable(false);
-                            })
-                            .selectable(Selectable::Single)
-                            .selected(selected.map(|s| vec![*s]))
-                            .on_select(|cx, index| {
-                                cx.emit(PickListEvent::SetOption(index));
-                                cx.emit(PopupEvent::Close);
-                            })
-                            .focused(true);
-                        })
-                        .arrow_size(Pixels(4.0))
-                        .on_blur(|cx| cx.emit(PopupEvent::Close));
+                .selectable(Selectable::Single)
+                .disabled_items(headers)
+                .selected(PickList::selected_row.map(|row| row.iter().copied().collect::<Vec<_>>()))
+                .on_select(move |cx, index| {
+                    let rows = rows.get(cx);
+                    if let Some(PickListRow::Option(group, option, _)) = rows.get(index) {
+                        let flat_index = rows[..index]
+                            .iter()
+                            .filter(|row| matches!(row, PickListRow::Option(..)))
+                            .count();
+                        cx.emit(PickListEvent::SetGroupedOption(*group, *option, flat_index));
                     }
+                    cx.emit(PopupEvent::Close);
+                })
+                .focused(true);
+            },
+        )
+        .bind(rows, move |handle, rows| {
+            handle.bind(selected, move |handle, selected| {
+                let (group, option) = selected.get(&handle);
+                let selected_row = rows.get(&handle).iter().position(|row| {
+                    matches!(row, PickListRow::Option(g, i, _) if *g == group && *i == option)
                 });
+                handle.modify(|picklist: &mut PickList| picklist.selected_row = selected_row);
+            });
+        })
+    }
+
+    // Builds a picklist with a button containing the given label and a popup containing the given list of options,
+    // whose rows have the given text.
+    fn build_picklist(
+        cx: &mut Context,
+        show_handle: bool,
+        row_text: impl 'static + Fn(&EventContext) -> Vec<Option<String>>,
+        label: impl FnOnce(&mut Context) -> Handle<Label>,
+        options: impl 'static + Fn(&mut Context),
+    ) -> Handle<Self> {
+        Self {
+            on_select: None,
+            on_select_grouped: None,
+            placeholder: String::new(),
+            is_open: false,
+            selected_row: None,
+            row_text: Box::new(row_text),
+            typeahead: String::new(),
+            typeahead_time: crate::util::now(),
+            typeahead_row: None,
+        }
+        .build(cx, |cx| {
+            Button::new(cx, |cx| {
+                // A Label and an optional Icon
+                HStack::new(cx, move |cx| {
+                    label(cx)
+                        .width(Stretch(2.0))
+                        .text_wrap(false)
+                        .text_overflow(TextOverflow::Ellipsis)
+                        .hoverable(false);
+                    if show_handle {
+                        Svg::new(cx, ICON_CHEVRON_DOWN)
+                            .class("icon")
+                            .size(Pixels(16.0))
+                            .hoverable(false);
+                    }
+                })
+                .width(Stretch(1.0))
+                //.gap(Stretch(1.0))
+                .gap(Pixels(8.0))
             })
-            .navigable(false)
+            .width(Stretch(1.0))
+            .on_press(|cx| cx.emit(PopupEvent::Open));
+
+            Binding::new(cx, PickList::is_open, move |cx, is_open| {
+                if is_open.get(cx) {
+                    Popup::new(cx, |cx| options(cx))
+                        .arrow_size(Pixels(4.0))
+                        .on_blur(|cx| cx.emit(PopupEvent::Close));
+                }
+            });
+        })
+        .navigable(false)
     }
 }
 
@@ -102,11 +329,60 @@ impl View for PickList {
                     (callback)(cx, *index);
                 }
             }
+
+            PickListEvent::SetGroupedOption(group, option, index) => {
+                if let Some(callback) = &self.on_select {
+                    (callback)(cx, *index);
+                }
+
+                if let Some(callback) = &self.on_select_grouped {
+                    (callback)(cx, *group, *option);
+                }
+            }
+        });
+
+        event.map(|window_event, meta| {
+            if let WindowEvent::CharInput(c) = window_event {
+                if !self.is_open || c.is_control() {
+                    return;
+                }
+
+                let now = crate::util::now();
+                if now.saturating_duration_since(self.typeahead_time) > TYPEAHEAD_TIMEOUT {
+                    self.typeahead.clear();
+                }
+                self.typeahead_time = now;
+                self.typeahead.extend(c.to_lowercase());
+
+                // Typing the same character repeatedly cycles through the rows starting with it, while typing more of
+                // the text of the focused row keeps it focused.
+                let mut chars = self.typeahead.chars();
+                let first = chars.next().unwrap_or(*c);
+                let (typed, start) = if chars.all(|c| c == first) {
+                    (first.to_string(), self.typeahead_row.map_or(0, |row| row + 1))
+                } else {
+                    (self.typeahead.clone(), self.typeahead_row.unwrap_or(0))
+                };
+
+                let rows = (self.row_text)(cx);
+                if let Some(row) = typeahead_match(&rows, &typed, start) {
+                    self.typeahead_row = Some(row);
+                    cx.emit_custom(
+                        Event::new(ListEvent::Focus(row))
+                            .target(cx.current())
+                            .propagate(Propagation::Subtree),
+                    );
+                }
+
+                meta.consume();
+            }
         });
 
         event.map(|popup_event, meta| match popup_event {
             PopupEvent::Open => {
                 self.is_open = true;
+                self.typeahead.clear();
+                self.typeahead_row = self.selected_row;
 
                 meta.consume();
             }
@@ -120,6 +396,8 @@ impl View for PickList {
 
             PopupEvent::Switch => {
                 self.is_open ^= true;
+                self.typeahead.clear();
+                self.typeahead_row = self.selected_row;
                 meta.consume();
             }
         });
@@ -142,4 +420,68 @@ impl Handle<'_, PickList> {
     {
         self.modify(|picklist: &mut PickList| picklist.on_select = Some(Box::new(callback)))
     }
+
+    /// Sets the callback triggered when an option of a [grouped](PickList::grouped) picklist is selected, which is
+    /// given the index of the group and the index of the option within the group.
+    pub fn on_select_grouped<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, usize),
+    {
+        self.modify(|picklist: &mut PickList| picklist.on_select_grouped = Some(Box::new(callback)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Lens)]
+    struct AppData {
+        groups: Vec<(String, Vec<String>)>,
+        selected: (usize, usize),
+    }
+
+    impl Model for AppData {}
+
+    #[test]
+    fn grouped_picklist_skips_headers() {
+        let chosen = Rc::new(RefCell::new(None));
+
+        let picklist_chosen = chosen.clone();
+        let mut harness = TestHarness::new(move |cx| {
+            AppData {
+                groups: vec![
+                    (String::from("Fruit"), vec![String::from("Apple"), String::from("Pear")]),
+                    (String::from("Vegetables"), vec![String::from("Leek")]),
+                ],
+                selected: (1, 0),
+            }
+            .build(cx);
+
+            PickList::grouped(cx, AppData::groups, AppData::selected, false).on_select_grouped(
+                move |_, group, index| *picklist_chosen.borrow_mut() = Some((group, index)),
+            );
+        });
+
+        assert_eq!(harness.text("picklist button label"), Some("Leek"));
+
+        harness.click("picklist button");
+        assert_eq!(harness.query_all("list-item .group-header").len(), 2);
+        let rows = harness.query_all("list-item");
+
+        // The focus starts on the selected option, so moving to the next option wraps around past the first header.
+        harness.key(Code::ArrowDown);
+        assert!(harness.is_focused(rows[1]));
+
+        // Typing matches the start of options but not group headers.
+        harness.type_text("p");
+        assert!(harness.is_focused(rows[2]));
+        harness.advance_time(Duration::from_secs(2));
+        harness.type_text("v");
+        assert!(harness.is_focused(rows[2]));
+
+        harness.key(Code::Enter);
+        assert_eq!(*chosen.borrow(), Some((0, 1)));
+    }
 }
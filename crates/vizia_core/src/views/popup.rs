@@ -584,3 +584,43 @@ impl View for Arrow {
         canvas.draw_path(&path, &paint);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    fn harness() -> TestHarness {
+        TestHarness::new(|cx| {
+            PopupData { is_open: true }.build(cx);
+            Binding::new(cx, PopupData::is_open, |cx, is_open| {
+                if is_open.get(cx) {
+                    Popup::new(cx, |cx| {
+                        Label::new(cx, "Content").id("content");
+                    })
+                    .on_blur(|cx| cx.emit(PopupEvent::Close))
+                    .size(Pixels(100.0));
+                }
+            });
+        })
+    }
+
+    #[test]
+    fn closes_on_outside_click() {
+        let mut harness = harness();
+
+        harness.click("#content");
+        assert!(harness.query("popup").is_some());
+
+        harness.click_at(700.0, 500.0);
+        assert!(harness.query("popup").is_none());
+    }
+
+    #[test]
+    fn closes_on_escape() {
+        let mut harness = harness();
+
+        harness.key(Code::Escape);
+        assert!(harness.query("popup").is_none());
+    }
+}
@@ -2,7 +2,8 @@ use crate::prelude::*;
 
 /// A simple progress bar that can be used to show the progress of something.
 ///
-/// The input lens need to be a lens to an [f32] with range of `0.0..1.0`.
+/// The input lens need to be a lens to an [f32] with range of `0.0..1.0`, or to an `Option<f32>`, where a value of `None`
+/// puts the progress bar into an indeterminate state for operations of unknown duration.
 ///
 /// # Example
 ///
@@ -50,12 +51,31 @@ use crate::prelude::*;
 ///     Label::new(cx, AppData::progress.map(|v| format!("{:.0}%", v * 100.0)));
 /// });
 /// ```
+///
+/// ### Indeterminate ProgressBar
+/// ```
+/// # use vizia_core::prelude::*;
+/// # use vizia_derive::*;
+/// # let mut cx = &mut Context::default();
+/// # #[derive(Lens, Default)]
+/// # pub struct AppData {
+/// #     progress: Option<f32>,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData::default().build(cx);
+/// ProgressBar::horizontal(cx, AppData::progress);
+/// ```
 pub struct ProgressBar;
 
 impl View for ProgressBar {
     fn element(&self) -> Option<&'static str> {
         Some("progressbar")
     }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_min_numeric_value(0.0);
+        node.set_max_numeric_value(1.0);
+    }
 }
 
 impl ProgressBar {
@@ -77,7 +97,7 @@ impl ProgressBar {
     /// ```
     pub fn new<L>(cx: &mut Context, lens: L, orientation: Orientation) -> Handle<Self>
     where
-        L: Lens<Target = f32>,
+        L: Lens<Target: Clone + Into<Option<f32>>>,
     {
         match orientation {
             Orientation::Horizontal => Self::horizontal(cx, lens),
@@ -88,22 +108,82 @@ impl ProgressBar {
     /// Creates a new horizontal progress bar bound to the value targeted by the lens.
     pub fn horizontal<L>(cx: &mut Context, lens: L) -> Handle<Self>
     where
-        L: Lens<Target = f32>,
+        L: Lens<Target: Clone + Into<Option<f32>>>,
     {
+        let progress: Map<L, Option<f32>> = lens.map(|v| v.clone().into());
+
         Self.build(cx, |cx| {
-            let progress = lens.map(|v| Units::Percentage(v * 100.0));
-            Element::new(cx).width(progress).class("progressbar-bar");
+            Binding::new(cx, progress.map(Option::is_none), move |cx, indeterminate| {
+                if indeterminate.get(cx) {
+                    Element::new(cx).class("progressbar-bar").class("indeterminate").on_build(
+                        |cx| Self::animate_indeterminate(cx, "progressbar_indeterminate"),
+                    );
+                } else {
+                    let progress =
+                        progress.map(|v| Units::Percentage(v.unwrap_or_default() * 100.0));
+                    Element::new(cx).width(progress).class("progressbar-bar");
+                }
+            });
         })
+        .progress_value(progress)
     }
 
     /// Creates a new vertical progress bar bound to the value targeted by the lens.
     pub fn vertical<L>(cx: &mut Context, lens: L) -> Handle<Self>
     where
-        L: Lens<Target = f32>,
+        L: Lens<Target: Clone + Into<Option<f32>>>,
     {
+        let progress: Map<L, Option<f32>> = lens.map(|v| v.clone().into());
+
         Self.build(cx, |cx| {
-            let progress = lens.map(|v| Units::Percentage(v * 100.0));
-            Element::new(cx).top(Stretch(1.0)).height(progress).class("progressbar-bar");
+            Binding::new(cx, progress.map(Option::is_none), move |cx, indeterminate| {
+                if indeterminate.get(cx) {
+                    Element::new(cx)
+                        .class("progressbar-bar")
+                        .class("indeterminate")
+                        .class("vertical")
+                        .on_build(|cx| {
+                            Self::animate_indeterminate(cx, "progressbar_indeterminate_vertical")
+                        });
+                } else {
+                    let progress =
+                        progress.map(|v| Units::Percentage(v.unwrap_or_default() * 100.0));
+                    Element::new(cx).top(Stretch(1.0)).height(progress).class("progressbar-bar");
+                }
+            });
+        })
+        .progress_value(progress)
+    }
+
+    // Starts a timer which replays the named indeterminate animation on the current view. The timer is added by the
+    // view, so that it is removed along with it.
+    fn animate_indeterminate(cx: &mut EventContext, animation: &'static str) {
+        let duration = Duration::from_millis(1500);
+        let timer = cx.add_timer(duration, None, move |cx, action| {
+            if matches!(action, TimerAction::Start | TimerAction::Tick(_)) {
+                cx.play_animation(animation, duration, Duration::ZERO);
+            }
+        });
+        cx.start_timer(timer);
+    }
+}
+
+impl Handle<'_, ProgressBar> {
+    // Sets the accessibility value of the progress bar, which is left unset when the progress is indeterminate.
+    fn progress_value<L>(self, progress: L) -> Self
+    where
+        L: Lens<Target = Option<f32>>,
+    {
+        self.role(Role::ProgressIndicator).bind(progress, |mut handle, progress| {
+            let entity = handle.entity();
+            let value = progress.get(&handle);
+            let cx = handle.context();
+            if let Some(value) = value {
+                cx.style.numeric_value.insert(entity, value as f64);
+            } else {
+                cx.style.numeric_value.remove(entity);
+            }
+            cx.style.needs_access_update(entity);
         })
     }
 }
@@ -1,15 +1,23 @@
-use crate::{icons::ICON_STAR_FILLED, prelude::*};
+use crate::{
+    icons::{ICON_STAR_FILLED, ICON_STAR_HALF_FILLED},
+    prelude::*,
+};
 
 /// A view which represents a rating as a number of filled stars.
+///
+/// With half precision enabled the rating can be set in increments of half a star, where hovering the left half
+/// of a star previews a half star and hovering the right half previews a full star.
 #[derive(Lens)]
 pub struct Rating {
-    rating: u32,
+    rating: f32,
+    value: f32,
     max_rating: u32,
-    on_change: Option<Box<dyn Fn(&mut EventContext, u32)>>,
+    half_precision: bool,
+    on_change: Option<Box<dyn Fn(&mut EventContext, f32)>>,
 }
 
 pub(crate) enum RatingEvent {
-    SetRating(u32),
+    SetRating(u32, bool),
     EmitRating,
     Increment,
     Decrement,
@@ -17,28 +25,63 @@ pub(crate) enum RatingEvent {
 
 impl Rating {
     /// Creates a new [Rating] view.
-    pub fn new(cx: &mut Context, max_rating: u32, lens: impl Lens<Target = u32>) -> Handle<Self> {
-        Self { rating: lens.get(cx), max_rating, on_change: None }
-            .build(cx, |cx| {
-                for i in 1..max_rating + 1 {
-                    Svg::new(cx, ICON_STAR_FILLED)
-                        // .navigable(true)
-                        .checkable(true)
-                        .numeric_value(1)
-                        .role(Role::RadioButton)
-                        .checked(lens.map(move |val| *val >= i))
-                        .toggle_class("foo", Rating::rating.map(move |val| *val >= i))
-                        .on_hover(move |ex| ex.emit(RatingEvent::SetRating(i)))
-                        .on_press(|ex| ex.emit(RatingEvent::EmitRating));
-                }
-            })
-            .numeric_value(Self::rating)
-            .navigable(true)
-            .role(Role::RadioGroup)
-            .bind(lens, |handle, lens| {
-                let val = lens.get(&handle);
-                handle.modify(|rating| rating.rating = val);
-            })
+    pub fn new(cx: &mut Context, max_rating: u32, lens: impl Lens<Target = f32>) -> Handle<Self> {
+        Self {
+            rating: lens.get(cx),
+            value: lens.get(cx),
+            max_rating,
+            half_precision: false,
+            on_change: None,
+        }
+        .build(cx, |cx| {
+            for i in 1..max_rating + 1 {
+                let star = i as f32;
+                Svg::new(
+                    cx,
+                    Rating::rating.map(move |val| {
+                        if *val < star && *val >= star - 0.5 {
+                            ICON_STAR_HALF_FILLED
+                        } else {
+                            ICON_STAR_FILLED
+                        }
+                    }),
+                )
+                // .navigable(true)
+                .checkable(true)
+                .numeric_value(1)
+                .role(Role::RadioButton)
+                .checked(lens.map(move |val| *val >= star))
+                .toggle_class("foo", Rating::rating.map(move |val| *val >= star))
+                .toggle_class(
+                    "half",
+                    Rating::rating.map(move |val| *val < star && *val >= star - 0.5),
+                )
+                .on_mouse_move(move |ex, x, _| {
+                    let bounds = ex.bounds();
+                    ex.emit(RatingEvent::SetRating(i, x < bounds.center().0));
+                })
+                .on_press(|ex| ex.emit(RatingEvent::EmitRating));
+            }
+        })
+        .numeric_value(lens)
+        .navigable(true)
+        .role(Role::RadioGroup)
+        .bind(lens, |handle, lens| {
+            let val = lens.get(&handle);
+            handle.modify(|rating| {
+                rating.rating = val;
+                rating.value = val;
+            });
+        })
+    }
+
+    // Returns the amount the rating changes by with each step.
+    fn step(&self) -> f32 {
+        if self.half_precision {
+            0.5
+        } else {
+            1.0
+        }
     }
 }
 
@@ -49,25 +92,33 @@ impl View for Rating {
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|rating_event, _| match rating_event {
-            RatingEvent::SetRating(val) => self.rating = *val,
+            RatingEvent::SetRating(val, left_half) => {
+                self.rating =
+                    if self.half_precision && *left_half { *val as f32 - 0.5 } else { *val as f32 };
+            }
             RatingEvent::EmitRating => {
                 if let Some(callback) = &self.on_change {
                     (callback)(cx, self.rating)
                 }
             }
             RatingEvent::Increment => {
-                self.rating += 1;
-                self.rating %= self.max_rating + 1;
+                self.rating += self.step();
+                if self.rating > self.max_rating as f32 {
+                    self.rating = 0.0;
+                }
                 cx.emit(RatingEvent::EmitRating);
             }
             RatingEvent::Decrement => {
-                self.rating =
-                    if self.rating == 0 { self.max_rating } else { self.rating.saturating_sub(1) };
+                self.rating = if self.rating <= 0.0 {
+                    self.max_rating as f32
+                } else {
+                    (self.rating - self.step()).max(0.0)
+                };
                 cx.emit(RatingEvent::EmitRating);
             }
         });
 
-        event.map(|window_event, _| match window_event {
+        event.map(|window_event, meta| match window_event {
             WindowEvent::KeyDown(code, _) => match code {
                 Code::ArrowLeft => {
                     cx.emit(RatingEvent::Decrement);
@@ -80,6 +131,11 @@ impl View for Rating {
                 _ => {}
             },
 
+            // Reset the previewed rating to the bound value when the mouse leaves the view.
+            WindowEvent::MouseLeave if meta.target == cx.current() => {
+                self.rating = self.value;
+            }
+
             _ => {}
         });
     }
@@ -89,8 +145,13 @@ impl Handle<'_, Rating> {
     /// Set the callback which is triggered when the rating changes.
     pub fn on_change<F>(self, callback: F) -> Self
     where
-        F: 'static + Fn(&mut EventContext, u32),
+        F: 'static + Fn(&mut EventContext, f32),
     {
         self.modify(|rating| rating.on_change = Some(Box::new(callback)))
     }
+
+    /// Sets whether the rating can be set in increments of half a star.
+    pub fn half_precision(self, flag: bool) -> Self {
+        self.modify(|rating| rating.half_precision = flag)
+    }
 }
@@ -5,6 +5,20 @@ use crate::prelude::*;
 
 pub(crate) const SCROLL_SENSITIVITY: f32 = 20.0;
 
+// The fraction of the flick velocity which remains after one second of momentum scrolling.
+const FLICK_FRICTION: f32 = 0.05;
+
+// The speed in physical pixels per second below which momentum scrolling stops.
+const FLICK_MIN_VELOCITY: f32 = 20.0;
+
+// The longest a touch can rest before being lifted for the lift to still flick the content.
+const FLICK_MAX_PAUSE: Duration = Duration::from_millis(100);
+
+// Internal events used for momentum scrolling after a touch flick.
+enum MomentumEvent {
+    Tick,
+}
+
 /// Events for setting the properties of a scroll view.
 pub enum ScrollEvent {
     /// Sets the progress of scroll position between 0 and 1 for the x axis
@@ -45,6 +59,26 @@ pub struct ScrollView {
     pub show_horizontal_scrollbar: bool,
     /// Whether the vertical scrollbar should be visible.
     pub show_vertical_scrollbar: bool,
+
+    // The id, last position, and time of the last movement of the touch which is scrolling the content.
+    #[lens(ignore)]
+    #[data(ignore)]
+    touch: Option<(u64, (f32, f32), Instant)>,
+    // The velocity of the content in physical pixels per second, used for momentum scrolling.
+    #[lens(ignore)]
+    #[data(ignore)]
+    velocity: (f32, f32),
+    // The timer which ticks momentum scrolling, added on the first flick.
+    #[lens(ignore)]
+    #[data(ignore)]
+    momentum_timer: Option<Timer>,
+    #[lens(ignore)]
+    #[data(ignore)]
+    momentum_time: Instant,
+    // A scroll position which was set before the sizes of the content and container were known.
+    #[lens(ignore)]
+    #[data(ignore)]
+    pending_scroll: Option<(f32, f32)>,
 }
 
 impl ScrollView {
@@ -64,6 +98,11 @@ impl ScrollView {
             container_height: 0.0,
             show_horizontal_scrollbar: true,
             show_vertical_scrollbar: true,
+            touch: None,
+            velocity: (0.0, 0.0),
+            momentum_timer: None,
+            momentum_time: crate::util::now(),
+            pending_scroll: None,
         }
         .build(cx, move |cx| {
             ScrollContent::new(cx, content);
@@ -119,6 +158,49 @@ impl ScrollView {
         )
     }
 
+    // Scrolls the content by the given number of physical pixels, returning whether the content could be scrolled.
+    fn scroll_by(&self, cx: &mut EventContext, x: f32, y: f32) -> bool {
+        let mut scrolled = false;
+
+        if x != 0.0 && self.inner_width > self.container_width {
+            cx.emit(ScrollEvent::ScrollX(x / (self.inner_width - self.container_width)));
+            scrolled = true;
+        }
+
+        if y != 0.0 && self.inner_height > self.container_height {
+            cx.emit(ScrollEvent::ScrollY(y / (self.inner_height - self.container_height)));
+            scrolled = true;
+        }
+
+        scrolled
+    }
+
+    // Sets the scroll position, or defers it until the content has been laid out so that it isn't lost to clamping.
+    fn set_scroll_position(&mut self, x: f32, y: f32) {
+        if self.pending_scroll.is_none() && (x, y) == (self.scroll_x, self.scroll_y) {
+            return;
+        }
+
+        self.pending_scroll = Some((x, y));
+        self.apply_pending_scroll();
+    }
+
+    fn apply_pending_scroll(&mut self) {
+        if self.inner_width == 0.0
+            || self.inner_height == 0.0
+            || self.container_width == 0.0
+            || self.container_height == 0.0
+        {
+            return;
+        }
+
+        if let Some((x, y)) = self.pending_scroll.take() {
+            self.scroll_x = x.clamp(0.0, 1.0);
+            self.scroll_y = y.clamp(0.0, 1.0);
+            self.reset();
+        }
+    }
+
     fn reset(&mut self) {
         if self.inner_width == self.container_width {
             self.scroll_x = 0.0;
@@ -139,6 +221,7 @@ impl View for ScrollView {
         event.map(|scroll_update, meta| {
             match scroll_update {
                 ScrollEvent::ScrollX(f) => {
+                    self.pending_scroll = None;
                     self.scroll_x = (self.scroll_x + *f).clamp(0.0, 1.0);
 
                     if let Some(callback) = &self.on_scroll {
@@ -147,6 +230,7 @@ impl View for ScrollView {
                 }
 
                 ScrollEvent::ScrollY(f) => {
+                    self.pending_scroll = None;
                     self.scroll_y = (self.scroll_y + *f).clamp(0.0, 1.0);
                     if let Some(callback) = &self.on_scroll {
                         (callback)(cx, self.scroll_x, self.scroll_y);
@@ -154,6 +238,7 @@ impl View for ScrollView {
                 }
 
                 ScrollEvent::SetX(f) => {
+                    self.pending_scroll = None;
                     self.scroll_x = *f;
                     if let Some(callback) = &self.on_scroll {
                         (callback)(cx, self.scroll_x, self.scroll_y);
@@ -161,6 +246,7 @@ impl View for ScrollView {
                 }
 
                 ScrollEvent::SetY(f) => {
+                    self.pending_scroll = None;
                     self.scroll_y = *f;
                     if let Some(callback) = &self.on_scroll {
                         (callback)(cx, self.scroll_x, self.scroll_y);
@@ -171,7 +257,8 @@ impl View for ScrollView {
                     let bounds = cx.bounds();
                     let scale_factor = cx.scale_factor();
 
-                    if self.inner_width != 0.0
+                    if self.pending_scroll.is_none()
+                        && self.inner_width != 0.0
                         && self.inner_height != 0.0
                         && self.container_width != 0.0
                         && self.container_height != 0.0
@@ -205,6 +292,7 @@ impl View for ScrollView {
                     self.inner_width = *w;
                     self.inner_height = *h;
                     self.reset();
+                    self.apply_pending_scroll();
                 }
             }
 
@@ -213,6 +301,27 @@ impl View for ScrollView {
             meta.consume();
         });
 
+        event.map(|momentum_event, _| match momentum_event {
+            MomentumEvent::Tick => {
+                let now = crate::util::now();
+                let dt = now.duration_since(self.momentum_time).as_secs_f32();
+                self.momentum_time = now;
+
+                let (vx, vy) = self.velocity;
+                let scrolled = self.scroll_by(cx, -vx * dt, -vy * dt);
+
+                let friction = FLICK_FRICTION.powf(dt);
+                self.velocity = (vx * friction, vy * friction);
+
+                if !scrolled || vx.hypot(vy) < FLICK_MIN_VELOCITY {
+                    self.velocity = (0.0, 0.0);
+                    if let Some(timer) = self.momentum_timer {
+                        cx.stop_timer(timer);
+                    }
+                }
+            }
+        });
+
         event.map(|window_event, meta| match window_event {
             WindowEvent::GeometryChanged(geo) => {
                 if geo.contains(GeoChanged::WIDTH_CHANGED)
@@ -221,7 +330,8 @@ impl View for ScrollView {
                     let bounds = cx.bounds();
                     let scale_factor = cx.scale_factor();
 
-                    if self.inner_width != 0.0
+                    if self.pending_scroll.is_none()
+                        && self.inner_width != 0.0
                         && self.inner_height != 0.0
                         && self.container_width != 0.0
                         && self.container_height != 0.0
@@ -251,6 +361,7 @@ impl View for ScrollView {
 
                     self.container_width = bounds.width();
                     self.container_height = bounds.height();
+                    self.apply_pending_scroll();
                 }
             }
 
@@ -279,6 +390,63 @@ impl View for ScrollView {
                 }
             }
 
+            // Drag the content with a touch and continue scrolling with momentum when the touch is lifted.
+            WindowEvent::Touch { id, phase, position: (x, y) } => match phase {
+                TouchPhase::Started if self.touch.is_none() => {
+                    if let Some(timer) = self.momentum_timer {
+                        cx.stop_timer(timer);
+                    }
+                    self.velocity = (0.0, 0.0);
+                    self.touch = Some((*id, (*x, *y), crate::util::now()));
+                }
+
+                TouchPhase::Moved => {
+                    if let Some((touch_id, (previous_x, previous_y), time)) = self.touch {
+                        if touch_id == *id {
+                            let now = crate::util::now();
+                            let (dx, dy) = (*x - previous_x, *y - previous_y);
+                            let dt = now.duration_since(time).as_secs_f32();
+                            if dt > 0.0 {
+                                self.velocity = (dx / dt, dy / dt);
+                            }
+
+                            self.touch = Some((touch_id, (*x, *y), now));
+
+                            if self.scroll_by(cx, -dx, -dy) {
+                                // Prevent event propagating to ancestor scrollviews.
+                                meta.consume();
+                            }
+                        }
+                    }
+                }
+
+                TouchPhase::Ended | TouchPhase::Cancelled => {
+                    if let Some((touch_id, _, time)) = self.touch {
+                        if touch_id == *id {
+                            self.touch = None;
+
+                            // Only flick if the touch was still moving when it was lifted.
+                            if *phase == TouchPhase::Ended
+                                && crate::util::now().duration_since(time) < FLICK_MAX_PAUSE
+                                && self.velocity.0.hypot(self.velocity.1) >= FLICK_MIN_VELOCITY
+                            {
+                                self.momentum_time = crate::util::now();
+                                let timer = *self.momentum_timer.get_or_insert_with(|| {
+                                    cx.add_timer(Duration::from_millis(16), None, |cx, action| {
+                                        if let TimerAction::Tick(_) = action {
+                                            cx.emit(MomentumEvent::Tick);
+                                        }
+                                    })
+                                });
+                                cx.start_timer(timer);
+                            }
+                        }
+                    }
+                }
+
+                _ => {}
+            },
+
             WindowEvent::MouseOut => {
                 cx.set_active(false);
             }
@@ -306,7 +474,10 @@ impl Handle<'_, ScrollView> {
     pub fn scroll_x(self, scrollx: impl Res<f32>) -> Self {
         self.bind(scrollx, |handle, scrollx| {
             let sx = scrollx.get(&handle);
-            handle.modify(|scrollview| scrollview.scroll_x = sx);
+            handle.modify(|scrollview| {
+                let sy = scrollview.pending_scroll.map_or(scrollview.scroll_y, |(_, y)| y);
+                scrollview.set_scroll_position(sx, sy);
+            });
         })
     }
 
@@ -314,7 +485,27 @@ impl Handle<'_, ScrollView> {
     pub fn scroll_y(self, scrollx: impl Res<f32>) -> Self {
         self.bind(scrollx, |handle, scrolly| {
             let sy = scrolly.get(&handle);
-            handle.modify(|scrollview| scrollview.scroll_y = sy);
+            handle.modify(|scrollview| {
+                let sx = scrollview.pending_scroll.map_or(scrollview.scroll_x, |(x, _)| x);
+                scrollview.set_scroll_position(sx, sy);
+            });
+        })
+    }
+
+    /// Binds the horizontal and vertical scroll positions of the [ScrollView], each between 0 and 1, to a lens.
+    ///
+    /// Changes to the lens scroll the view. A position set before the content has been laid out is applied once its
+    /// size is known. To write the position back when the user scrolls, emit an event from [`on_scroll`](Self::on_scroll):
+    ///
+    /// ```ignore
+    /// ScrollView::new(cx, |cx| { /* ... */ })
+    ///     .scroll_position(AppData::scroll)
+    ///     .on_scroll(|cx, x, y| cx.emit(AppEvent::SetScroll(x, y)));
+    /// ```
+    pub fn scroll_position(self, position: impl Lens<Target = (f32, f32)>) -> Self {
+        self.bind(position, |handle, position| {
+            let (x, y) = position.get(&handle);
+            handle.modify(|scrollview| scrollview.set_scroll_position(x, y));
         })
     }
 
@@ -364,3 +555,49 @@ impl View for ScrollContent {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[derive(Lens)]
+    struct AppData {
+        scroll: (f32, f32),
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|scroll: &(f32, f32), _| self.scroll = *scroll);
+        }
+    }
+
+    fn scroll_position(harness: &mut TestHarness) -> (f32, f32) {
+        let entity = harness.query("scrollview").unwrap();
+        let scrollview = harness.context().views.get(&entity).unwrap().downcast_ref::<ScrollView>();
+        scrollview.map(|scrollview| (scrollview.scroll_x, scrollview.scroll_y)).unwrap()
+    }
+
+    #[test]
+    fn binds_scroll_position() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { scroll: (0.0, 0.5) }.build(cx);
+            ScrollView::new(cx, |cx| {
+                Element::new(cx).width(Pixels(100.0)).height(Pixels(2000.0));
+            })
+            .size(Pixels(200.0))
+            .scroll_position(AppData::scroll)
+            .on_scroll(|cx, x, y| cx.emit((x, y)));
+        });
+
+        // The position is restored once the content has been laid out rather than being clamped to the start.
+        assert_eq!(scroll_position(&mut harness), (0.0, 0.5));
+
+        harness.emit((0.0f32, 0.25f32));
+        assert_eq!(scroll_position(&mut harness), (0.0, 0.25));
+
+        let scrollview = harness.query("scrollview").unwrap();
+        harness.context().emit_to(scrollview, ScrollEvent::SetY(1.0));
+        harness.update();
+        assert_eq!(scroll_position(&mut harness), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,261 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::prelude::*;
+use crate::vg::{self, Data, RuntimeEffect};
+
+thread_local! {
+    // Compiled effects by source, so that each shader is only compiled once. Sources which failed to compile are
+    // stored as `None` so that the error is only logged once.
+    static EFFECTS: RefCell<HashMap<String, Option<RuntimeEffect>>> = RefCell::new(HashMap::new());
+}
+
+/// The value of a shader uniform, made up of one or more floats.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShaderUniform(Vec<f32>);
+
+impl From<f32> for ShaderUniform {
+    fn from(value: f32) -> Self {
+        ShaderUniform(vec![value])
+    }
+}
+
+impl<const N: usize> From<[f32; N]> for ShaderUniform {
+    fn from(value: [f32; N]) -> Self {
+        ShaderUniform(value.to_vec())
+    }
+}
+
+impl From<Vec<f32>> for ShaderUniform {
+    fn from(value: Vec<f32>) -> Self {
+        ShaderUniform(value)
+    }
+}
+
+impl From<Color> for ShaderUniform {
+    fn from(color: Color) -> Self {
+        ShaderUniform(vec![
+            color.r() as f32 / 255.0,
+            color.g() as f32 / 255.0,
+            color.b() as f32 / 255.0,
+            color.a() as f32 / 255.0,
+        ])
+    }
+}
+
+/// An SkSL shader which can be used to fill shapes when drawing a view.
+///
+/// The shader is compiled the first time its source is used and reused afterwards. Compilation errors are logged,
+/// and a shader which failed to compile fills shapes with magenta.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let mut effect = ShaderEffect::new(
+///     "uniform half4 color; half4 main(float2 position) { return color; }",
+/// );
+/// effect.set_uniform("color", [1.0, 0.0, 0.0, 1.0]);
+/// ```
+pub struct ShaderEffect {
+    effect: Option<RuntimeEffect>,
+    uniforms: HashMap<String, ShaderUniform>,
+}
+
+impl ShaderEffect {
+    /// Creates a new shader from SkSL source, which must declare a `half4 main(float2 position)` function.
+    pub fn new(source: &str) -> Self {
+        let effect = EFFECTS.with_borrow_mut(|effects| {
+            effects
+                .entry(source.to_owned())
+                .or_insert_with(|| match RuntimeEffect::make_for_shader(source, None) {
+                    Ok(effect) => Some(effect),
+                    Err(error) => {
+                        log::error!("Failed to compile shader: {}", error);
+                        None
+                    }
+                })
+                .clone()
+        });
+
+        Self { effect, uniforms: HashMap::new() }
+    }
+
+    /// Returns true if the shader compiled successfully.
+    pub fn is_valid(&self) -> bool {
+        self.effect.is_some()
+    }
+
+    /// Returns true if the shader declares a uniform with the given name.
+    pub fn has_uniform(&self, name: &str) -> bool {
+        self.effect
+            .as_ref()
+            .is_some_and(|effect| effect.uniforms().iter().any(|uniform| uniform.name() == name))
+    }
+
+    /// Sets the value of a uniform by name. Uniforms which are not set are zero.
+    pub fn set_uniform(&mut self, name: &str, value: impl Into<ShaderUniform>) {
+        self.uniforms.insert(name.to_owned(), value.into());
+    }
+
+    /// Creates a paint which fills with the shader, with positions in the shader relative to the top-left corner of
+    /// the given bounds. Returns a magenta paint if the shader could not be compiled.
+    pub fn paint(&self, bounds: BoundingBox) -> vg::Paint {
+        self.paint_with_defaults(bounds, &[])
+    }
+
+    // Creates a paint which fills with the shader, using the given values for uniforms which have not been set.
+    fn paint_with_defaults(&self, bounds: BoundingBox, defaults: &[(&str, &[f32])]) -> vg::Paint {
+        let mut paint = vg::Paint::default();
+        paint.set_anti_alias(true);
+
+        let shader = self.effect.as_ref().and_then(|effect| {
+            let mut data = vec![0u8; effect.uniform_size()];
+            for uniform in effect.uniforms() {
+                let values =
+                    self.uniforms.get(uniform.name()).map(|value| &value.0[..]).or_else(|| {
+                        defaults
+                            .iter()
+                            .find(|(name, _)| *name == uniform.name())
+                            .map(|(_, value)| *value)
+                    });
+
+                if let Some(values) = values {
+                    let bytes = values.iter().flat_map(|value| value.to_ne_bytes());
+                    let range = uniform.offset()..uniform.offset() + uniform.size_in_bytes();
+                    for (byte, value) in data[range].iter_mut().zip(bytes) {
+                        *byte = value;
+                    }
+                }
+            }
+
+            let matrix = vg::Matrix::translate(bounds.top_left());
+            effect.make_shader(Data::new_copy(&data), &[], Some(&matrix))
+        });
+
+        if let Some(shader) = shader {
+            paint.set_shader(shader);
+        } else {
+            paint.set_color(vg::Color::MAGENTA);
+        }
+
+        paint
+    }
+}
+
+/// A view which fills its background shape with an SkSL shader.
+///
+/// If the shader declares a `float time` uniform it is set to the number of seconds since the view was created and
+/// the view is redrawn on every animation frame. A `float2 resolution` uniform is set to the size of the view in
+/// physical pixels. Other uniforms can be set with the [`uniform`](Handle::uniform) modifier.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// ShaderView::new(
+///     cx,
+///     r#"
+///     uniform float time;
+///     uniform float2 resolution;
+///
+///     half4 main(float2 position) {
+///         float2 uv = position / resolution;
+///         return half4(half2(uv), half(0.5 + 0.5 * sin(time)), 1);
+///     }
+///     "#,
+/// )
+/// .size(Stretch(1.0));
+/// ```
+pub struct ShaderView {
+    effect: ShaderEffect,
+    start: Instant,
+    time: f32,
+}
+
+impl ShaderView {
+    /// Creates a new [ShaderView] which fills its bounds with the shader compiled from the given SkSL source.
+    pub fn new<'a>(cx: &'a mut Context, source: &str) -> Handle<'a, Self> {
+        let effect = ShaderEffect::new(source);
+        let animated = effect.has_uniform("time");
+
+        let mut handle = Self { effect, start: crate::util::now(), time: 0.0 }.build(cx, |_| {});
+        if animated {
+            let entity = handle.entity();
+            handle.cx.animation_frame_requests.insert(entity);
+        }
+
+        handle
+    }
+}
+
+impl View for ShaderView {
+    fn element(&self) -> Option<&'static str> {
+        Some("shader")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| {
+            if let WindowEvent::AnimationFrame(now) = window_event {
+                self.time = now.duration_since(self.start).as_secs_f32();
+                cx.needs_redraw();
+                cx.request_animation_frame();
+            }
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w == 0.0 || bounds.h == 0.0 {
+            return;
+        }
+
+        cx.draw_shadows(canvas);
+
+        let paint = self.effect.paint_with_defaults(
+            bounds,
+            &[("time", &[self.time][..]), ("resolution", &[bounds.w, bounds.h][..])],
+        );
+
+        let path = cx.path();
+        canvas.draw_path(&path, &paint);
+
+        cx.draw_border(canvas);
+        cx.draw_outline(canvas);
+    }
+}
+
+impl Handle<'_, ShaderView> {
+    /// Sets the value of a uniform of the shader by name. Accepts a value, or lens to a value, which can be converted
+    /// to a [`ShaderUniform`], such as an `f32` or a [`Color`].
+    pub fn uniform<U: Into<ShaderUniform>>(self, name: &'static str, value: impl Res<U>) -> Self {
+        self.bind(value, move |handle, value| {
+            let value = value.get(&handle).into();
+            let entity = handle.entity();
+            handle
+                .modify(|shader: &mut ShaderView| shader.effect.set_uniform(name, value))
+                .context()
+                .needs_redraw(entity);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[test]
+    fn time_advances_and_invalid_shaders_do_not_panic() {
+        let mut harness = TestHarness::new(|cx| {
+            ShaderView::new(
+                cx,
+                "uniform float time; half4 main(float2 position) { return half4(half(fract(time)), 0, 0, 1); }",
+            )
+            .id("animated");
+            ShaderView::new(cx, "not a shader").id("invalid");
+        });
+
+        harness.advance_time(Duration::from_millis(500));
+
+        assert_eq!(harness.get::<ShaderView>("#animated").unwrap().time, 0.5);
+        assert!(!harness.get::<ShaderView>("#invalid").unwrap().effect.is_valid());
+    }
+}
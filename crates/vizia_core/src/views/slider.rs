@@ -9,6 +9,8 @@ enum SliderEventInternal {
     SetThumbSize(f32, f32),
     SetRange(Range<f32>),
     SetKeyboardFraction(f32),
+    SetTicks(Vec<f32>),
+    SetTickLabels(Vec<(f32, String)>),
 }
 
 /// Internal data used by the slider.
@@ -26,6 +28,10 @@ pub struct SliderDataInternal {
     pub step: f32,
     /// How much the slider should change in response to keyboard events.
     pub keyboard_fraction: f32,
+    /// The values at which tick marks are shown.
+    pub ticks: Vec<f32>,
+    /// The values and text of the tick labels.
+    pub tick_labels: Vec<(f32, String)>,
 }
 
 /// The slider control can be used to select from a continuous set of values.
@@ -79,7 +85,7 @@ pub struct SliderDataInternal {
 #[derive(Lens)]
 pub struct Slider<L: Lens> {
     lens: L,
-    is_dragging: bool,
+    drag: DragTracker,
     internal: SliderDataInternal,
     on_changing: Option<Box<dyn Fn(&mut EventContext, f32)>>,
 }
@@ -109,7 +115,7 @@ where
     pub fn new(cx: &mut Context, lens: L) -> Handle<Self> {
         Self {
             lens,
-            is_dragging: false,
+            drag: DragTracker::new(0.0),
 
             internal: SliderDataInternal {
                 orientation: Orientation::Horizontal,
@@ -118,6 +124,8 @@ where
                 range: 0.0..1.0,
                 step: 0.01,
                 keyboard_fraction: 0.1,
+                ticks: Vec::new(),
+                tick_labels: Vec::new(),
             },
 
             on_changing: None,
@@ -184,6 +192,34 @@ where
                                     .bottom(Percentage(100.0 * px));
                             }
                         });
+
+                    // Returns the position of a value along the track as a fraction of the slider size,
+                    // aligned with the center of the thumb.
+                    let tick_offset = move |value: f32| {
+                        let normal_val =
+                            ((value - range.start) / (range.end - range.start)).clamp(0.0, 1.0);
+                        if size > 0.0 {
+                            (0.5 * thumb_size + normal_val * (size - thumb_size)) / size
+                        } else {
+                            normal_val
+                        }
+                    };
+
+                    // Tick marks
+                    for value in slider_data.ticks.iter() {
+                        let offset = tick_offset(*value);
+                        let handle = Element::new(cx).class("slider-tick").hoverable(false);
+                        position_tick(handle, orientation, offset);
+                    }
+
+                    // Tick labels
+                    for (value, text) in slider_data.tick_labels.iter() {
+                        let offset = tick_offset(*value);
+                        let handle = Label::new(cx, text.as_str())
+                            .class("slider-tick-label")
+                            .hoverable(false);
+                        position_tick(handle, orientation, offset);
+                    }
                 });
             });
         })
@@ -197,6 +233,41 @@ where
     }
 }
 
+impl<L: Lens<Target = f32>> Slider<L> {
+    // Changes the value to the one at a position in physical window coordinates along the track.
+    fn change_to_position(&self, cx: &mut EventContext, x: f32, y: f32) {
+        let thumb_size = self.internal.thumb_size;
+        let min = self.internal.range.start;
+        let max = self.internal.range.end;
+        let step = self.internal.step;
+
+        let current = cx.current();
+        let width = cx.cache.get_width(current);
+        let height = cx.cache.get_height(current);
+        let posx = cx.cache.get_posx(current);
+        let posy = cx.cache.get_posy(current);
+
+        let mut dx = match self.internal.orientation {
+            Orientation::Horizontal => (x - posx - thumb_size / 2.0) / (width - thumb_size),
+
+            Orientation::Vertical => {
+                (height - (y - posy) - thumb_size / 2.0) / (height - thumb_size)
+            }
+        };
+
+        dx = dx.clamp(0.0, 1.0);
+
+        let mut val = min + dx * (max - min);
+
+        val = step * (val / step).ceil();
+        val = val.clamp(min, max);
+
+        if let Some(callback) = &self.on_changing {
+            (callback)(cx, val);
+        }
+    }
+}
+
 impl<L: Lens<Target = f32>> View for Slider<L> {
     fn element(&self) -> Option<&'static str> {
         Some("slider")
@@ -227,6 +298,26 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
             SliderEventInternal::SetKeyboardFraction(keyboard_fraction) => {
                 self.internal.keyboard_fraction = *keyboard_fraction;
             }
+
+            SliderEventInternal::SetTicks(ticks) => {
+                self.internal.ticks = ticks.clone();
+            }
+
+            SliderEventInternal::SetTickLabels(tick_labels) => {
+                self.internal.tick_labels = tick_labels.clone();
+            }
+        });
+
+        event.map(|window_event, _| {
+            let Some(gesture) = self.drag.handle(cx, window_event) else {
+                return;
+            };
+
+            if gesture.phase != DragPhase::Ended {
+                let x = cx.logical_to_physical(gesture.current.0);
+                let y = cx.logical_to_physical(gesture.current.1);
+                self.change_to_position(cx, x, y);
+            }
         });
 
         event.map(|window_event, _| match window_event {
@@ -246,53 +337,20 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
 
             WindowEvent::MouseDown(button) if *button == MouseButton::Left => {
                 if !cx.is_disabled() {
-                    self.is_dragging = true;
+                    // The mouse is captured straight away, rather than when the drag starts, so that the slider
+                    // always receives the release which restores the pointer events of the window.
                     cx.capture();
                     cx.focus_with_visibility(false);
                     cx.with_current(Entity::root(), |cx| {
                         cx.set_pointer_events(false);
                     });
 
-                    let thumb_size = self.internal.thumb_size;
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-
-                    let current = cx.current();
-                    let width = cx.cache.get_width(current);
-                    let height = cx.cache.get_height(current);
-                    let posx = cx.cache.get_posx(current);
-                    let posy = cx.cache.get_posy(current);
-
-                    let mut dx = match self.internal.orientation {
-                        Orientation::Horizontal => {
-                            (cx.mouse.left.pos_down.0 - posx - thumb_size / 2.0)
-                                / (width - thumb_size)
-                        }
-
-                        Orientation::Vertical => {
-                            (height - (cx.mouse.left.pos_down.1 - posy) - thumb_size / 2.0)
-                                / (height - thumb_size)
-                        }
-                    };
-
-                    dx = dx.clamp(0.0, 1.0);
-
-                    let mut val = min + dx * (max - min);
-
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-
-                    if let Some(callback) = self.on_changing.take() {
-                        (callback)(cx, val);
-
-                        self.on_changing = Some(callback);
-                    }
+                    let (x, y) = cx.mouse.left.pos_down;
+                    self.change_to_position(cx, x, y);
                 }
             }
 
             WindowEvent::MouseUp(button) if *button == MouseButton::Left => {
-                self.is_dragging = false;
                 cx.focus_with_visibility(false);
                 cx.release();
                 cx.with_current(Entity::root(), |cx| {
@@ -300,43 +358,6 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 });
             }
 
-            WindowEvent::MouseMove(x, y) => {
-                if self.is_dragging {
-                    let thumb_size = self.internal.thumb_size;
-
-                    let min = self.internal.range.start;
-                    let max = self.internal.range.end;
-                    let step = self.internal.step;
-
-                    let current = cx.current();
-                    let width = cx.cache.get_width(current);
-                    let height = cx.cache.get_height(current);
-                    let posx = cx.cache.get_posx(current);
-                    let posy = cx.cache.get_posy(current);
-
-                    let mut dx = match self.internal.orientation {
-                        Orientation::Horizontal => {
-                            (*x - posx - thumb_size / 2.0) / (width - thumb_size)
-                        }
-
-                        Orientation::Vertical => {
-                            (height - (*y - posy) - thumb_size / 2.0) / (height - thumb_size)
-                        }
-                    };
-
-                    dx = dx.clamp(0.0, 1.0);
-
-                    let mut val = min + dx * (max - min);
-
-                    val = step * (val / step).ceil();
-                    val = val.clamp(min, max);
-
-                    if let Some(callback) = &self.on_changing {
-                        (callback)(cx, val);
-                    }
-                }
-            }
-
             WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
                 let min = self.internal.range.start;
                 let max = self.internal.range.end;
@@ -492,4 +513,119 @@ impl<L: Lens> Handle<'_, Slider<L>> {
 
         self
     }
+
+    /// Adds tick marks below the track of the slider at the given values.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// # let mut cx = &mut Context::default();
+    /// # #[derive(Lens, Default)]
+    /// # pub struct AppData {
+    /// #     value: f32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # AppData::default().build(cx);
+    /// Slider::new(cx, AppData::value)
+    ///     .ticks(&[0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn ticks(self, values: &[f32]) -> Self {
+        self.cx.emit_to(self.entity, SliderEventInternal::SetTicks(values.to_vec()));
+
+        self
+    }
+
+    /// Adds labels below the track of the slider at the given values.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_derive::*;
+    /// # let mut cx = &mut Context::default();
+    /// # #[derive(Lens, Default)]
+    /// # pub struct AppData {
+    /// #     value: f32,
+    /// # }
+    /// # impl Model for AppData {}
+    /// # AppData::default().build(cx);
+    /// Slider::new(cx, AppData::value)
+    ///     .ticks(&[0.0, 0.5, 1.0])
+    ///     .tick_labels(&[(0.0, String::from("Min")), (1.0, String::from("Max"))]);
+    /// ```
+    pub fn tick_labels(self, labels: &[(f32, String)]) -> Self {
+        self.cx.emit_to(self.entity, SliderEventInternal::SetTickLabels(labels.to_vec()));
+
+        self
+    }
+}
+
+// Positions a tick mark or tick label along the track of a slider, centered on the given offset. The view is placed
+// with its edge on the offset and then translated back by half of its own size.
+fn position_tick<V: View>(handle: Handle<'_, V>, orientation: Orientation, offset: f32) {
+    if orientation == Orientation::Horizontal {
+        handle
+            .left(Percentage(100.0 * offset))
+            .right(Stretch(1.0))
+            .translate((Percentage(-50.0), Pixels(0.0)));
+    } else {
+        handle
+            .class("vertical")
+            .top(Stretch(1.0))
+            .bottom(Percentage(100.0 * offset))
+            .translate((Pixels(0.0), Percentage(50.0)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    #[derive(Lens)]
+    struct AppData {
+        value: f32,
+    }
+
+    enum AppEvent {
+        SetValue(f32),
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|app_event, _| match app_event {
+                AppEvent::SetValue(value) => self.value = *value,
+            });
+        }
+    }
+
+    #[test]
+    fn dragging_changes_value() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { value: 0.5 }.build(cx);
+            Slider::new(cx, AppData::value)
+                .on_changing(|cx, value| cx.emit(AppEvent::SetValue(value)))
+                .width(Pixels(200.0))
+                .height(Pixels(20.0))
+                .id("slider");
+        });
+        let value = |harness: &mut TestHarness| harness.context().data::<AppData>().unwrap().value;
+
+        // Pressing the track moves the thumb to the cursor straight away.
+        let bounds = harness.bounds("#slider");
+        let y = bounds.center().1;
+        harness.hover(bounds.left(), y);
+        harness.emit(WindowEvent::MouseDown(MouseButton::Left));
+        assert_eq!(value(&mut harness), 0.0);
+
+        // The slider keeps following the cursor outside of its bounds until the button is released.
+        harness.hover(bounds.right() + 50.0, y);
+        assert_eq!(value(&mut harness), 1.0);
+        harness.hover(bounds.center().0, y);
+        assert!((value(&mut harness) - 0.5).abs() < 0.02);
+
+        harness.emit(WindowEvent::MouseUp(MouseButton::Left));
+        harness.hover(bounds.right(), y);
+        assert!((value(&mut harness) - 0.5).abs() < 0.02);
+    }
 }
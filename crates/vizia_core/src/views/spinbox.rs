@@ -4,17 +4,30 @@ use crate::icons::{
 };
 use crate::prelude::*;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum SpinboxEvent {
     Increment,
     Decrement,
+    Repeat,
 }
 
 /// A view which represents a value which can be incremented or decremented.
+///
+/// Holding the up or down arrow key repeatedly increments or decrements the value, with the number of steps
+/// per repeat accelerating the longer the key is held.
 #[derive(Lens)]
 pub struct Spinbox {
     orientation: Orientation,
     icons: SpinboxIcons,
 
+    // Timer used to repeat the held increment or decrement, added when a key is first held.
+    repeat_timer: Option<Timer>,
+    held: Option<SpinboxEvent>,
+    held_since: Instant,
+    repeat_delay: Duration,
+    acceleration: Vec<(Duration, u32)>,
+    no_acceleration: bool,
+
     on_decrement: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     on_increment: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
 }
@@ -48,6 +61,12 @@ impl Spinbox {
         Self {
             orientation: Orientation::Horizontal,
             icons: SpinboxIcons::Chevrons,
+            repeat_timer: None,
+            held: None,
+            held_since: crate::util::now(),
+            repeat_delay: Duration::from_millis(500),
+            acceleration: vec![(Duration::from_secs(1), 10), (Duration::from_secs(2), 100)],
+            no_acceleration: false,
             on_decrement: None,
             on_increment: None,
         }
@@ -158,6 +177,32 @@ impl Handle<'_, Spinbox> {
             handle.modify(move |spinbox| spinbox.icons = icons);
         })
     }
+
+    /// Sets how long an arrow key must be held before the [Spinbox] starts repeating. Defaults to 500ms.
+    pub fn repeat_delay(self, delay: Duration) -> Self {
+        self.modify(|spinbox: &mut Spinbox| spinbox.repeat_delay = delay)
+    }
+
+    /// Sets the acceleration thresholds of the [Spinbox] as pairs of held duration and step multiplier.
+    ///
+    /// Defaults to 10 steps per repeat after one second and 100 steps per repeat after two seconds.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Spinbox::custom(cx, |cx| Label::new(cx, "5"))
+    ///     .acceleration(&[(Duration::from_secs(2), 5), (Duration::from_secs(4), 50)]);
+    /// ```
+    pub fn acceleration(self, thresholds: &[(Duration, u32)]) -> Self {
+        let thresholds = thresholds.to_vec();
+        self.modify(|spinbox: &mut Spinbox| spinbox.acceleration = thresholds)
+    }
+
+    /// Disables repeating and acceleration when an arrow key is held, so that each key press changes the value by one step.
+    pub fn no_acceleration(self, flag: bool) -> Self {
+        self.modify(|spinbox: &mut Spinbox| spinbox.no_acceleration = flag)
+    }
 }
 
 impl View for Spinbox {
@@ -178,6 +223,72 @@ impl View for Spinbox {
                     (callback)(cx)
                 }
             }
+
+            SpinboxEvent::Repeat => {
+                let Some(held) = self.held else {
+                    return;
+                };
+
+                let elapsed = crate::util::now().duration_since(self.held_since);
+                if elapsed < self.repeat_delay {
+                    return;
+                }
+
+                let multiplier = self
+                    .acceleration
+                    .iter()
+                    .filter(|(threshold, _)| elapsed >= *threshold)
+                    .map(|(_, multiplier)| *multiplier)
+                    .max()
+                    .unwrap_or(1);
+
+                let callback = match held {
+                    SpinboxEvent::Increment => &self.on_increment,
+                    _ => &self.on_decrement,
+                };
+
+                if let Some(callback) = callback {
+                    for _ in 0..multiplier {
+                        (callback)(cx);
+                    }
+                }
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::KeyDown(code @ (Code::ArrowUp | Code::ArrowDown), _) => {
+                let step = if *code == Code::ArrowUp {
+                    SpinboxEvent::Increment
+                } else {
+                    SpinboxEvent::Decrement
+                };
+
+                if self.no_acceleration {
+                    cx.emit(step);
+                } else if self.held != Some(step) {
+                    // Key repeats from the OS are ignored while the repeat timer is running.
+                    self.held = Some(step);
+                    self.held_since = crate::util::now();
+                    cx.emit(step);
+                    let timer = *self.repeat_timer.get_or_insert_with(|| {
+                        cx.add_timer(Duration::from_millis(50), None, |cx, action| {
+                            if let TimerAction::Tick(_) = action {
+                                cx.emit(SpinboxEvent::Repeat);
+                            }
+                        })
+                    });
+                    cx.start_timer(timer);
+                }
+            }
+
+            WindowEvent::KeyUp(Code::ArrowUp | Code::ArrowDown, _) | WindowEvent::FocusOut => {
+                self.held = None;
+                if let Some(timer) = self.repeat_timer {
+                    cx.stop_timer(timer);
+                }
+            }
+
+            _ => {}
         });
     }
 }
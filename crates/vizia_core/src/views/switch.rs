@@ -81,6 +81,7 @@ use crate::prelude::*;
 /// ```
 pub struct Switch {
     on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
+    loading: bool,
 }
 
 impl Switch {
@@ -105,7 +106,7 @@ impl Switch {
     /// Switch::new(cx, AppData::value);
     /// ```
     pub fn new(cx: &mut Context, checked: impl Lens<Target = bool>) -> Handle<Self> {
-        Self { on_toggle: None }
+        Self { on_toggle: None, loading: false }
             .build(cx, |cx| {
                 Element::new(cx)
                     .class("switch-handle-bg")
@@ -119,6 +120,37 @@ impl Switch {
             .checked(checked)
             .navigable(true)
     }
+
+    /// Creates a new Switch with a loading state, such as while waiting for a change of the checked state to be applied.
+    ///
+    /// This is equivalent to calling [`pending`](Handle::pending) on a new Switch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     value: bool,
+    /// #     loading: bool,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { value: false, loading: true }.build(cx);
+    /// #
+    /// Switch::with_loading(cx, AppData::value, AppData::loading);
+    /// ```
+    pub fn with_loading(
+        cx: &mut Context,
+        checked: impl Lens<Target = bool>,
+        loading: impl Lens<Target = bool>,
+    ) -> Handle<Self> {
+        Self::new(cx, checked).pending(loading)
+    }
 }
 
 impl Handle<'_, Switch> {
@@ -153,6 +185,131 @@ impl Handle<'_, Switch> {
     {
         self.modify(|switch| switch.on_toggle = Some(Box::new(callback)))
     }
+
+    /// Sets a pending state of the Switch, such as while waiting for a change of the checked state to be applied.
+    ///
+    /// While pending, an animated spinner is shown on the handle of the switch and the switch cannot be toggled. A
+    /// pending switch can be selected with the `:loading` CSS pseudo-class selector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     value: bool,
+    /// #     saving: bool,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { value: false, saving: true }.build(cx);
+    /// #
+    /// Switch::new(cx, AppData::value).pending(AppData::saving);
+    /// ```
+    pub fn pending(mut self, pending: impl Lens<Target = bool>) -> Self {
+        let entity = self.entity();
+        self.context().with_current(entity, |cx| {
+            Binding::new(cx, pending, move |cx, pending| {
+                if pending.get(cx) {
+                    // The spinner adds its own timer, which is removed along with it when the switch stops pending.
+                    Element::new(cx)
+                        .class("switch-spinner")
+                        .hoverable(false)
+                        .position_type(PositionType::Absolute)
+                        .on_build(|cx| {
+                            let duration = Duration::from_millis(800);
+                            let timer = cx.add_timer(duration, None, move |cx, action| {
+                                if matches!(action, TimerAction::Start | TimerAction::Tick(_)) {
+                                    cx.play_animation("rotate", duration, Duration::ZERO);
+                                }
+                            });
+                            cx.start_timer(timer);
+                        });
+                }
+            });
+        });
+
+        self.pointer_events(pending.map(|pending| {
+            if *pending {
+                PointerEvents::None
+            } else {
+                PointerEvents::Auto
+            }
+        }))
+        .bind(pending, |mut handle, pending| {
+            let pending = pending.get(&handle);
+            let entity = handle.entity();
+            let cx = handle.context();
+            if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+                pseudo_classes.set(PseudoClassFlags::LOADING, pending);
+            }
+            cx.needs_restyle(entity);
+            cx.style.needs_access_update(entity);
+            handle.modify(|switch| switch.loading = pending);
+        })
+    }
+
+    /// Adds content inside the track of the Switch, before the handle, such as an "ON" label or a check icon. The
+    /// content is shown while the switch is checked.
+    ///
+    /// A Switch with content inside its track has a larger track and can be selected with the `.with-content` CSS
+    /// class selector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     value: bool,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { value: false }.build(cx);
+    /// #
+    /// Switch::new(cx, AppData::value)
+    ///     .leading(|cx| {
+    ///         Label::new(cx, "ON");
+    ///     })
+    ///     .trailing(|cx| {
+    ///         Label::new(cx, "OFF");
+    ///     });
+    /// ```
+    pub fn leading(self, content: impl FnOnce(&mut Context)) -> Self {
+        self.track_content("switch-leading", content)
+    }
+
+    /// Adds content inside the track of the Switch, after the handle, such as an "OFF" label or a cross icon. The
+    /// content is shown while the switch is not checked.
+    ///
+    /// See [`leading`](Self::leading) for an example.
+    pub fn trailing(self, content: impl FnOnce(&mut Context)) -> Self {
+        self.track_content("switch-trailing", content)
+    }
+
+    fn track_content(mut self, class: &str, content: impl FnOnce(&mut Context)) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        // The content is added to the track so that it is drawn underneath the handle.
+        if let Some(track) = cx.tree.get_first_child(entity) {
+            cx.with_current(track, |cx| {
+                HStack::new(cx, content)
+                    .class(class)
+                    .pointer_events(PointerEvents::None)
+                    .position_type(PositionType::Absolute);
+            });
+        }
+
+        self.class("with-content")
+    }
 }
 
 impl View for Switch {
@@ -164,7 +321,11 @@ impl View for Switch {
         event.map(|window_event, meta| match window_event {
             WindowEvent::Press { mouse } => {
                 let over = if *mouse { cx.mouse.left.pressed } else { cx.focused() };
-                if over == cx.current() && meta.target == cx.current() && !cx.is_disabled() {
+                if over == cx.current()
+                    && meta.target == cx.current()
+                    && !cx.is_disabled()
+                    && !self.loading
+                {
                     if let Some(callback) = &self.on_toggle {
                         (callback)(cx);
                     }
@@ -174,4 +335,70 @@ impl View for Switch {
             _ => {}
         });
     }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        if self.loading {
+            node.set_disabled();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, testing::TestHarness};
+
+    #[derive(Lens)]
+    struct AppData {
+        value: bool,
+        pending: bool,
+    }
+
+    enum AppEvent {
+        Toggle,
+        Resolve,
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|app_event, _| match app_event {
+                AppEvent::Toggle => self.value ^= true,
+                AppEvent::Resolve => self.pending = false,
+            });
+        }
+    }
+
+    #[test]
+    fn pending_switch_ignores_clicks() {
+        let mut harness = TestHarness::new(|cx| {
+            AppData { value: false, pending: true }.build(cx);
+            Switch::new(cx, AppData::value)
+                .leading(|cx| {
+                    Label::new(cx, "ON").id("on");
+                })
+                .trailing(|cx| {
+                    Label::new(cx, "OFF").id("off");
+                })
+                .pending(AppData::pending)
+                .on_toggle(|cx| cx.emit(AppEvent::Toggle))
+                .id("switch");
+        });
+
+        assert!(harness.matches("#switch", ".with-content:loading"));
+        assert!(harness.query("#switch .switch-spinner").is_some());
+        let timers = harness.context().timers.iter().flatten().count();
+        assert_eq!(harness.text("#switch .switch-trailing #off"), Some("OFF"));
+
+        harness.click("#switch");
+        assert!(!harness.matches("#switch", ":checked"));
+
+        harness.emit(AppEvent::Resolve);
+        assert!(!harness.matches("#switch", ":loading"));
+        assert!(harness.query("#switch .switch-spinner").is_none());
+        // The timer of the spinner is removed along with it.
+        assert_eq!(harness.context().timers.iter().flatten().count(), timers - 1);
+
+        // Clicking on the content inside the track toggles the switch.
+        harness.click("#on");
+        assert!(harness.matches("#switch", ":checked"));
+    }
 }
@@ -1,15 +1,15 @@
 // use crate::accessibility::IntoNode;
 use crate::prelude::*;
 
+use crate::systems::text_direction;
 use crate::text::{
-    apply_movement, offset_for_delete_backwards, Direction, EditableText, Movement, Selection,
-    VerticalMovement,
+    apply_movement, caret_stop, offset_for_delete_backwards, offset_for_utf16_index, utf16_index,
+    Direction, EditableText, Movement, Selection, VerticalMovement,
 };
 // use crate::views::scrollview::SCROLL_SENSITIVITY;
 use accesskit::{ActionData, ActionRequest};
 use skia_safe::textlayout::{RectHeightStyle, RectWidthStyle};
 use skia_safe::{Paint, PaintStyle, Rect};
-use unicode_segmentation::UnicodeSegmentation;
 
 /// Events for modifying a textbox.
 pub enum TextEvent {
@@ -70,11 +70,27 @@ pub struct Textbox<L: Lens> {
     on_submit: Option<Box<dyn Fn(&mut EventContext, L::Target, bool) + Send + Sync>>,
     on_blur: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     on_cancel: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    on_key_down: Option<Box<dyn Fn(&mut EventContext, Code, Modifiers) -> bool + Send + Sync>>,
+    key_consumed: bool,
     validate: Option<Box<dyn Fn(&L::Target) -> bool>>,
     placeholder: String,
     show_caret: bool,
     caret_timer: Timer,
     selection: Selection,
+    caret_movement: CaretMovement,
+}
+
+/// Determines how the left and right arrow keys move the caret of a [`Textbox`] through text which mixes
+/// left-to-right and right-to-left runs.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CaretMovement {
+    /// The caret moves to the visually adjacent position, so the left arrow key always moves the caret to the left,
+    /// even if it jumps to a logically distant part of the text at the boundary of a run.
+    #[default]
+    Visual,
+    /// The caret moves to the logically adjacent position, so the left arrow key moves backwards through the text in
+    /// a left-to-right paragraph and forwards through the text in a right-to-left paragraph.
+    Logical,
 }
 
 // Determines whether the enter key submits the text or inserts a new line.
@@ -155,11 +171,14 @@ where
             on_submit: None,
             on_blur: None,
             on_cancel: None,
+            on_key_down: None,
+            key_consumed: false,
             validate: None,
             placeholder: String::from(""),
             show_caret: true,
             caret_timer,
             selection: Selection::new(0, 0),
+            caret_movement: CaretMovement::default(),
         }
         .build(cx, move |cx| {
             cx.add_listener(move |textbox: &mut Self, cx, event| {
@@ -171,6 +190,24 @@ where
                         }
                     }
 
+                    // Listeners receive key events before focus navigation and the default handling of the
+                    // textbox, allowing the key down callback to prevent both.
+                    WindowEvent::KeyDown(code, _) if cx.focused() == cx.current() => {
+                        if let Some(callback) = &textbox.on_key_down {
+                            let modifiers = *cx.modifiers;
+                            textbox.key_consumed = (callback)(cx, *code, modifiers);
+                            if textbox.key_consumed {
+                                meta.consume();
+                            }
+                        }
+                    }
+
+                    // Skip the character input produced by a consumed key.
+                    WindowEvent::CharInput(_) if textbox.key_consumed => {
+                        textbox.key_consumed = false;
+                        meta.consume();
+                    }
+
                     _ => {}
                 });
             });
@@ -222,9 +259,14 @@ where
 
                     cx.style.needs_text_update(cx.current);
                 }
-            } else if let Some(text) = cx.style.text.get_mut(cx.current) {
+            } else {
+                let direction = text_direction(cx.style, cx.current).into();
+                let Some(text) = cx.style.text.get_mut(cx.current) else {
+                    return;
+                };
                 if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
-                    let to_delete = apply_movement(movement, self.selection, text, paragraph, true);
+                    let to_delete =
+                        apply_movement(movement, self.selection, text, paragraph, direction, true);
                     self.selection = to_delete;
                     let new_cursor_pos = self.selection.min();
                     text.edit(to_delete.range(), "");
@@ -243,10 +285,11 @@ where
     }
 
     fn move_cursor(&mut self, cx: &mut EventContext, movement: Movement, selection: bool) {
+        let direction = text_direction(cx.style, cx.current).into();
         if let Some(text) = cx.style.text.get_mut(cx.current) {
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 let new_selection =
-                    apply_movement(movement, self.selection, text, paragraph, selection);
+                    apply_movement(movement, self.selection, text, paragraph, direction, selection);
                 self.selection = new_selection;
                 cx.needs_redraw();
             }
@@ -334,15 +377,7 @@ where
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 let gp = paragraph
                     .get_glyph_position_at_coordinate(self.coordinates_global_to_text(cx, x, y));
-                let num_graphemes = text.graphemes(true).count();
-                let pos = (gp.position as usize).min(num_graphemes);
-                let mut cursor = text.len();
-                for (i, (j, _)) in text.grapheme_indices(true).enumerate() {
-                    if pos == i {
-                        cursor = j;
-                        break;
-                    }
-                }
+                let cursor = offset_for_utf16_index(text, gp.position.max(0) as usize);
 
                 if selection {
                     self.selection.active = cursor;
@@ -361,16 +396,7 @@ where
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 let gp = paragraph
                     .get_glyph_position_at_coordinate(self.coordinates_global_to_text(cx, x, y));
-                let num_graphemes = text.graphemes(true).count();
-                let pos = (gp.position as usize).min(num_graphemes);
-
-                let mut cursor = text.len();
-                for (i, (j, _)) in text.grapheme_indices(true).enumerate() {
-                    if pos == i {
-                        cursor = j;
-                        break;
-                    }
-                }
+                let cursor = offset_for_utf16_index(text, gp.position.max(0) as usize);
 
                 self.selection.active = cursor;
 
@@ -412,9 +438,11 @@ where
         if !self.selection.is_caret() {
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 if let Some(text) = cx.style.text.get(cx.current) {
-                    let min = text.current_grapheme_offset(self.selection.min());
-                    let max = text.current_grapheme_offset(self.selection.max());
+                    let min = utf16_index(text, self.selection.min());
+                    let max = utf16_index(text, self.selection.max());
 
+                    // The rectangles of a range which spans runs of different directions are split into a
+                    // rectangle for each run.
                     let cursor_rects = paragraph.get_rects_for_range(
                         min..max,
                         RectHeightStyle::Tight,
@@ -479,15 +507,11 @@ where
             if let Some(text) = cx.style.text.get(cx.current) {
                 let bounds = cx.bounds();
 
-                let current = text.current_grapheme_offset(self.selection.active);
-
-                let rects = paragraph.get_rects_for_range(
-                    current..current + 1,
-                    RectHeightStyle::Tight,
-                    RectWidthStyle::Tight,
-                );
-
-                let cursor_rect = rects.first().unwrap();
+                let direction = text_direction(cx.style, cx.current);
+                let Some(caret) = caret_stop(text, paragraph, self.selection.active, direction)
+                else {
+                    return;
+                };
 
                 let alignment = cx.alignment();
 
@@ -520,11 +544,11 @@ where
                     _ => 0.0,
                 };
 
-                let x = (bounds.x + padding_left + cursor_rect.rect.left).round();
-                let y = (bounds.y + padding_top + cursor_rect.rect.top + top).round();
+                let x = (bounds.x + padding_left + caret.x).round();
+                let y = (bounds.y + padding_top + caret.top + top).round();
 
                 let x2 = x + 1.0;
-                let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
+                let y2 = y + caret.height;
 
                 let mut paint = Paint::default();
                 paint.set_anti_alias(true);
@@ -575,6 +599,50 @@ impl<L: Lens> Handle<'_, Textbox<L>> {
         self.modify(|textbox: &mut Textbox<L>| textbox.on_cancel = Some(Box::new(callback)))
     }
 
+    /// Sets the callback triggered when a key is pressed while the textbox is focused.
+    ///
+    /// The callback provides the code of the pressed key and the active keyboard modifiers. Returning `true` from
+    /// the callback consumes the key, preventing the default handling of the key by the textbox, such as inserting
+    /// a new line, moving the cursor, or moving focus with the tab key.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     text: String,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { text: String::new() }.build(cx);
+    /// #
+    /// Textbox::new_multiline(cx, AppData::text, true).on_key_down(|cx, code, modifiers| {
+    ///     // Submit the form with ctrl+enter instead of inserting a new line.
+    ///     if code == Code::Enter && modifiers.ctrl() {
+    ///         cx.emit(TextEvent::Submit(true));
+    ///         return true;
+    ///     }
+    ///
+    ///     false
+    /// });
+    /// ```
+    pub fn on_key_down<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Code, Modifiers) -> bool + Send + Sync,
+    {
+        self.modify(|textbox: &mut Textbox<L>| textbox.on_key_down = Some(Box::new(callback)))
+    }
+
+    /// Sets how the left and right arrow keys move the caret through text which mixes left-to-right and right-to-left
+    /// runs. Defaults to [`CaretMovement::Visual`].
+    pub fn caret_movement(self, caret_movement: CaretMovement) -> Self {
+        self.modify(|textbox| textbox.caret_movement = caret_movement)
+    }
+
     /// Sets a validation closure which is called when the textbox is edited and sets the validity attribute to the output of the closure.
     ///
     /// If a textbox is modified with the validate modifier then the `on_submit` will not be called if the text is invalid.
@@ -599,6 +667,53 @@ impl<L: Lens> Handle<'_, Textbox<L>> {
     }
 }
 
+/// Returns true if a key chord is used for editing text, either to type a character, to move the cursor, or as one
+/// of the standard editing shortcuts.
+fn is_editing_chord(code: Code, modifiers: Modifiers) -> bool {
+    #[cfg(target_os = "macos")]
+    let primary = Modifiers::SUPER;
+    #[cfg(not(target_os = "macos"))]
+    let primary = Modifiers::CTRL;
+
+    match code {
+        Code::ArrowLeft
+        | Code::ArrowRight
+        | Code::ArrowUp
+        | Code::ArrowDown
+        | Code::Home
+        | Code::End
+        | Code::PageUp
+        | Code::PageDown
+        | Code::Backspace
+        | Code::Delete => true,
+
+        Code::KeyA | Code::KeyC | Code::KeyV | Code::KeyX | Code::KeyY | Code::KeyZ => {
+            modifiers == primary
+                || !modifiers.intersects(Modifiers::CTRL | Modifiers::ALT | Modifiers::SUPER)
+        }
+
+        Code::Tab
+        | Code::Escape
+        | Code::Enter
+        | Code::NumpadEnter
+        | Code::F1
+        | Code::F2
+        | Code::F3
+        | Code::F4
+        | Code::F5
+        | Code::F6
+        | Code::F7
+        | Code::F8
+        | Code::F9
+        | Code::F10
+        | Code::F11
+        | Code::F12 => false,
+
+        // Any other key without a command modifier types a character.
+        _ => !modifiers.intersects(Modifiers::CTRL | Modifiers::ALT | Modifiers::SUPER),
+    }
+}
+
 impl<L> View for Textbox<L>
 where
     L: Lens<Target: Data + ToStringLocalized + std::str::FromStr>,
@@ -869,6 +984,8 @@ where
                     self.reset_caret_timer(cx);
                     let movement = if cx.modifiers.ctrl() {
                         Movement::Word(Direction::Left)
+                    } else if self.caret_movement == CaretMovement::Visual {
+                        Movement::Visual(Direction::Left)
                     } else {
                         Movement::Grapheme(Direction::Left)
                     };
@@ -881,6 +998,8 @@ where
 
                     let movement = if cx.modifiers.ctrl() {
                         Movement::Word(Direction::Right)
+                    } else if self.caret_movement == CaretMovement::Visual {
+                        Movement::Visual(Direction::Right)
                     } else {
                         Movement::Grapheme(Direction::Right)
                     };
@@ -1071,6 +1190,15 @@ where
             _ => {}
         });
 
+        // Stop the key chords used for editing text from reaching any keymaps further up the tree.
+        event.map(|window_event, meta| {
+            if let WindowEvent::KeyDown(code, _) = window_event {
+                if self.edit && is_editing_chord(*code, *cx.modifiers) {
+                    meta.consume();
+                }
+            }
+        });
+
         // Textbox Events
         event.map(|text_event, _| match text_event {
             TextEvent::InsertText(text) => {
@@ -1301,3 +1429,69 @@ where
         // canvas.restore();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestHarness;
+
+    // Builds a textbox with some text, starts editing it, and moves the caret to the start of the text.
+    fn editing(text: &str, caret_movement: CaretMovement) -> TestHarness {
+        let text = text.to_owned();
+        let mut harness = TestHarness::new(move |cx| {
+            Textbox::new(cx, StaticLens::new_leaked(text))
+                .caret_movement(caret_movement)
+                .width(Pixels(300.0))
+                .id("textbox");
+        });
+
+        let textbox = harness.query("#textbox").unwrap();
+        harness.context().emit_to(textbox, TextEvent::StartEdit);
+        harness.update();
+        harness.key(Code::Home);
+        harness
+    }
+
+    fn caret(harness: &TestHarness) -> usize {
+        harness.get::<Textbox<StaticLens<String>>>("#textbox").unwrap().selection.active
+    }
+
+    fn press(harness: &mut TestHarness, code: Code, times: usize) -> Vec<usize> {
+        (0..times)
+            .map(|_| {
+                harness.key(code);
+                caret(harness)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn visual_caret_movement_in_mixed_text() {
+        // The Hebrew run is displayed reversed, so moving right enters it at its visual left, which is its
+        // logical end.
+        let mut harness = editing("abc אבג", CaretMovement::Visual);
+        assert_eq!(caret(&harness), 0);
+        assert_eq!(press(&mut harness, Code::ArrowRight, 7), vec![1, 2, 3, 4, 8, 6, 10]);
+        assert_eq!(press(&mut harness, Code::ArrowLeft, 3), vec![6, 8, 4]);
+
+        // In a right-to-left paragraph the logical start is on the right and the Latin run is on the left.
+        let mut harness = editing("אבג abc", CaretMovement::Visual);
+        assert_eq!(caret(&harness), 0);
+        assert_eq!(press(&mut harness, Code::ArrowLeft, 7), vec![2, 4, 6, 7, 9, 8, 10]);
+        harness.key(Code::Home);
+        assert_eq!(caret(&harness), 0);
+        harness.key(Code::End);
+        assert_eq!(caret(&harness), 10);
+    }
+
+    #[test]
+    fn logical_caret_movement_follows_base_direction() {
+        let mut harness = editing("abc אבג", CaretMovement::Logical);
+        assert_eq!(press(&mut harness, Code::ArrowRight, 6), vec![1, 2, 3, 4, 6, 8]);
+
+        // The left arrow moves forwards through the text of a right-to-left paragraph.
+        let mut harness = editing("אבג abc", CaretMovement::Logical);
+        assert_eq!(press(&mut harness, Code::ArrowLeft, 5), vec![2, 4, 6, 7, 8]);
+        assert_eq!(press(&mut harness, Code::ArrowRight, 2), vec![7, 6]);
+    }
+}
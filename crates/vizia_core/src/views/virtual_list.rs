@@ -7,10 +7,16 @@ use crate::prelude::*;
 pub struct VirtualList {
     scroll_to_cursor: bool,
     on_change: Option<Box<dyn Fn(&mut EventContext, Range<usize>)>>,
+    on_fetch_more: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    fetch_threshold: usize,
+    // The length of the list when more items were last fetched, so that they are only fetched once for each length.
+    fetched_len: Option<usize>,
 }
 
 pub(crate) enum VirtualListEvent {
     SetScrollY(f32),
+    // Sent when the visible range changes, with the end of the range and the number of items.
+    VisibleRangeChanged(usize, usize),
 }
 
 #[derive(Lens)]
@@ -36,6 +42,7 @@ impl VirtualListData {
     fn recalc(&mut self, cx: &mut EventContext) {
         if self.num_items == 0 {
             self.visible_range = 0..0;
+            cx.emit(VirtualListEvent::VisibleRangeChanged(0, 0));
             return;
         }
 
@@ -64,6 +71,7 @@ impl VirtualListData {
         let end_index = 1 + (visible_end / item_height).trunc() as usize;
 
         self.visible_range = start_index..end_index.min(self.num_items);
+        cx.emit(VirtualListEvent::VisibleRangeChanged(self.visible_range.end, self.num_items));
     }
 }
 
@@ -74,6 +82,8 @@ impl Model for VirtualListData {
                 self.scroll_y = *scroll_y;
                 self.recalc(cx);
             }
+
+            VirtualListEvent::VisibleRangeChanged(..) => {}
         });
 
         event.map(|window_event, _| match window_event {
@@ -120,7 +130,14 @@ impl VirtualList {
     ) -> Handle<Self> {
         let vl = cx.current;
         let num_items = list.map(list_len);
-        Self { scroll_to_cursor: true, on_change: None }.build(cx, |cx| {
+        Self {
+            scroll_to_cursor: true,
+            on_change: None,
+            on_fetch_more: None,
+            fetch_threshold: 3,
+            fetched_len: None,
+        }
+        .build(cx, |cx| {
             Binding::new(cx, num_items, move |cx, lens| {
                 let num_items = lens.get(cx);
 
@@ -184,6 +201,23 @@ impl View for VirtualList {
     fn element(&self) -> Option<&'static str> {
         Some("virtual-list")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|virtual_list_event, meta| {
+            if let VirtualListEvent::VisibleRangeChanged(end, num_items) = *virtual_list_event {
+                if let Some(on_fetch_more) = &self.on_fetch_more {
+                    if end + self.fetch_threshold >= num_items
+                        && self.fetched_len != Some(num_items)
+                    {
+                        self.fetched_len = Some(num_items);
+                        on_fetch_more(cx, num_items);
+                    }
+                }
+
+                meta.consume();
+            }
+        });
+    }
 }
 
 impl Handle<'_, VirtualList> {
@@ -193,6 +227,24 @@ impl Handle<'_, VirtualList> {
             virtual_list.scroll_to_cursor = flag;
         })
     }
+
+    /// Sets the callback triggered when the list is scrolled to within the fetch threshold of its end, which can be
+    /// used to load the next page of items from a paginated data source. The callback is given the number of items
+    /// currently in the list, and is called at most once for each length of the list.
+    pub fn on_fetch_more<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|virtual_list: &mut VirtualList| {
+            virtual_list.on_fetch_more = Some(Box::new(callback))
+        })
+    }
+
+    /// Sets the number of items from the end of the list within which scrolling calls the
+    /// [`on_fetch_more`](Self::on_fetch_more) callback. Defaults to 3.
+    pub fn fetch_threshold(self, threshold: usize) -> Self {
+        self.modify(|virtual_list: &mut VirtualList| virtual_list.fetch_threshold = threshold)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,151 @@
+use std::{
+    fmt::Debug,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    context::{ContextProxy, EventContext, InternalEvent},
+    entity::Entity,
+};
+
+pub(crate) type FileDialogCallback =
+    Box<dyn FnOnce(&mut EventContext, Option<Vec<PathBuf>>) + Send>;
+
+/// A filter which limits the files shown in a file dialog to those with the given extensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDialogFilter {
+    /// The name of the filter shown in the dialog, e.g. "Images".
+    pub name: String,
+    /// The file extensions matched by the filter, without the leading dot, e.g. `["png", "jpg"]`.
+    pub extensions: Vec<String>,
+}
+
+/// Options used to configure a native file dialog.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let options = FileDialogOptions::new()
+///     .title("Open Image")
+///     .filter("Images", &["png", "jpg"])
+///     .multiple(true);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileDialogOptions {
+    /// The title of the dialog.
+    pub title: Option<String>,
+    /// The filters used to limit which files are shown in the dialog.
+    pub filters: Vec<FileDialogFilter>,
+    /// The directory the dialog opens in.
+    pub directory: Option<PathBuf>,
+    /// The file name initially shown in a save dialog.
+    pub file_name: Option<String>,
+    /// Whether multiple files can be selected in an open dialog.
+    pub multiple: bool,
+}
+
+impl FileDialogOptions {
+    /// Creates a new set of file dialog options with no filters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the dialog.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Adds a filter with the given name and file extensions to the dialog.
+    pub fn filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push(FileDialogFilter {
+            name: name.into(),
+            extensions: extensions.iter().map(|extension| extension.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Sets the directory the dialog opens in.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the file name initially shown in a save dialog.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Sets whether multiple files can be selected in an open dialog.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+}
+
+/// The kind of a native file dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDialogKind {
+    /// A dialog for choosing one or more existing files.
+    Open,
+    /// A dialog for choosing a location to save a file to.
+    Save,
+}
+
+/// A request to show a native file dialog, sent to the window by
+/// [`open_file_dialog`](crate::context::EventContext::open_file_dialog) and
+/// [`save_file_dialog`](crate::context::EventContext::save_file_dialog).
+///
+/// Once the dialog has been closed the backend must call [`FileDialog::finish`] with the chosen paths, which
+/// calls the callback of the view which requested the dialog.
+#[derive(Clone)]
+pub struct FileDialog {
+    kind: FileDialogKind,
+    options: FileDialogOptions,
+    source: Entity,
+    callback: Arc<Mutex<Option<FileDialogCallback>>>,
+}
+
+impl FileDialog {
+    pub(crate) fn new(
+        kind: FileDialogKind,
+        options: FileDialogOptions,
+        source: Entity,
+        callback: FileDialogCallback,
+    ) -> Self {
+        Self { kind, options, source, callback: Arc::new(Mutex::new(Some(callback))) }
+    }
+
+    /// Returns the kind of the dialog.
+    pub fn kind(&self) -> FileDialogKind {
+        self.kind
+    }
+
+    /// Returns the options used to configure the dialog.
+    pub fn options(&self) -> &FileDialogOptions {
+        &self.options
+    }
+
+    /// Sends the paths chosen in the dialog, or `None` if the dialog was cancelled, back to the view which
+    /// requested the dialog. This can be called from any thread.
+    pub fn finish(&self, proxy: &mut ContextProxy, paths: Option<Vec<PathBuf>>) {
+        if let Some(callback) = self.callback.lock().unwrap().take() {
+            let _ = proxy.emit_to(
+                self.source,
+                InternalEvent::FileDialogClosed { source: self.source, callback, paths },
+            );
+        }
+    }
+}
+
+impl Debug for FileDialog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDialog")
+            .field("kind", &self.kind)
+            .field("options", &self.options)
+            .field("source", &self.source)
+            .finish()
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Display;
+
+/// An error returned when a global shortcut can't be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobalShortcutError {
+    /// Global shortcuts aren't available, for example in a Wayland session, which doesn't allow applications to
+    /// listen for key presses outside of their own windows.
+    Unavailable(String),
+    /// The key chord is already registered as a global shortcut, either by this or by another application.
+    AlreadyRegistered,
+    /// The operating system refused to register the shortcut.
+    Os(String),
+}
+
+impl Display for GlobalShortcutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GlobalShortcutError::Unavailable(reason) => {
+                write!(f, "global shortcuts are unavailable: {}", reason)
+            }
+            GlobalShortcutError::AlreadyRegistered => {
+                write!(f, "the key chord is already registered as a global shortcut")
+            }
+            GlobalShortcutError::Os(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for GlobalShortcutError {}
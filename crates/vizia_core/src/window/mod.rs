@@ -1,4 +1,12 @@
 //! Window management and system events.
 
+#[cfg(feature = "dialogs")]
+mod file_dialog;
+#[cfg(feature = "global-shortcuts")]
+mod global_shortcut;
 mod window_event;
+#[cfg(feature = "dialogs")]
+pub use file_dialog::*;
+#[cfg(feature = "global-shortcuts")]
+pub use global_shortcut::*;
 pub use window_event::*;
@@ -1,17 +1,62 @@
-use std::path::PathBuf;
+use std::{any::Any, fmt::Debug, path::PathBuf, sync::Arc};
 
 use crate::{entity::Entity, environment::ThemeMode, layout::cache::GeoChanged};
 use vizia_input::{Code, Key, MouseButton};
 use vizia_style::CursorIcon;
-use vizia_window::{WindowPosition, WindowSize};
+use vizia_window::{ResizeDirection, WindowLevel, WindowPosition, WindowSize};
+use web_time::Instant;
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 /// Data associated with a drop event.
 pub enum DropData {
     /// Path to a dropped file.
     File(PathBuf),
     ///  Entity ID of a dropped entity.
     Id(Entity),
+    /// Data of any type, which can be dropped onto a view in any window of the application.
+    Message(Arc<dyn Any + Send + Sync>),
+}
+
+impl DropData {
+    /// Creates drop data containing a message of any type.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// #[derive(Debug)]
+    /// struct Track(usize);
+    ///
+    /// Element::new(cx)
+    ///     .on_drag(|cx| cx.set_drop_data(DropData::message(Track(3))));
+    ///
+    /// Element::new(cx).on_drop(|_, data| {
+    ///     if let Some(Track(index)) = data.downcast_ref::<Track>() {
+    ///         println!("Dropped track {}", index);
+    ///     }
+    /// });
+    /// ```
+    pub fn message<T: Any + Send + Sync>(message: T) -> Self {
+        DropData::Message(Arc::new(message))
+    }
+
+    /// Returns a reference to the message if the data is a message of the given type.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        match self {
+            DropData::Message(message) => message.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl Debug for DropData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DropData::File(path) => f.debug_tuple("File").field(path).finish(),
+            DropData::Id(entity) => f.debug_tuple("Id").field(entity).finish(),
+            DropData::Message(_) => f.write_str("Message(..)"),
+        }
+    }
 }
 
 impl From<Entity> for DropData {
@@ -26,14 +71,107 @@ impl From<PathBuf> for DropData {
     }
 }
 
+/// Data provided by a view when dragging it out of the application with a native drag and drop operation.
+///
+/// See [`on_drag_start`](crate::modifiers::ActionModifiers::on_drag_start) for which payloads are supported on
+/// each platform.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DragData {
+    /// A list of paths to files.
+    Files(Vec<PathBuf>),
+    /// Plain text.
+    Text(String),
+    /// Custom data with the given MIME type.
+    Bytes {
+        /// The MIME type of the data.
+        mime_type: String,
+        /// The data.
+        data: Vec<u8>,
+    },
+}
+
+impl From<PathBuf> for DragData {
+    fn from(value: PathBuf) -> Self {
+        DragData::Files(vec![value])
+    }
+}
+
+impl From<Vec<PathBuf>> for DragData {
+    fn from(value: Vec<PathBuf>) -> Self {
+        DragData::Files(value)
+    }
+}
+
+impl From<String> for DragData {
+    fn from(value: String) -> Self {
+        DragData::Text(value)
+    }
+}
+
+impl From<&str> for DragData {
+    fn from(value: &str) -> Self {
+        DragData::Text(value.to_owned())
+    }
+}
+
+/// The result of a native drag and drop operation started by a view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragResult {
+    /// The data was dropped onto a target which accepted it.
+    Dropped,
+    /// The drag was cancelled, rejected by the target, or is not supported by the platform.
+    Cancelled,
+}
+
+/// The phase of a touch point in a [`WindowEvent::Touch`] event, or of a pen contact in a [`WindowEvent::Pen`] event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchPhase {
+    /// A finger touched the screen.
+    Started,
+    /// A finger moved on the screen.
+    Moved,
+    /// A finger was lifted from the screen.
+    Ended,
+    /// The system cancelled tracking of the touch.
+    Cancelled,
+}
+
+/// A gesture recognized from touch or touchpad input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A two-finger pinch, with the change in scale since the previous pinch event and the center of the pinch in
+    /// window coordinates. A scale greater than 1 means the fingers moved apart.
+    Pinch(f32, (f32, f32)),
+}
+
 /// Events generated by the application in response to OS events as well as events that can be used
 /// to set properties of the window.
 #[derive(Debug, Clone)]
 pub enum WindowEvent {
     /// Emitted when a window is closed. Can also be emitted by a view or model to close the window.
     WindowClose,
-    /// Emitted when a file is dragged and then dropped onto the window.
+    /// Emitted when the user requests that a window be closed, such as by pressing the close button of the window.
+    ///
+    /// The window can be kept open by calling [`prevent_close`](crate::context::EventContext::prevent_close)
+    /// while handling this event, for example to ask the user whether to save any changes. The window can then be
+    /// closed later by emitting a [`WindowEvent::WindowClose`] event.
+    CloseRequested,
+    /// Emitted when a file is dragged and then dropped onto the window. The event is then sent directly to the
+    /// innermost view under the cursor which accepts dropped data, as is data dropped during a drag and drop within
+    /// the application.
     Drop(DropData),
+    /// Emitted when a file is dragged over the window.
+    FileHovered(PathBuf),
+    /// Emitted when a file which was dragged over the window leaves the window without being dropped.
+    FileHoverCancelled,
+    /// Emitted directly to a view which accepts dropped data when a drag and drop operation enters the view.
+    DragEnter,
+    /// Emitted directly to a view which accepts dropped data when the cursor moves over the view during a drag and
+    /// drop operation, with the position of the cursor relative to the view.
+    DragOver(f32, f32),
+    /// Emitted directly to a view which accepts dropped data when a drag and drop operation leaves the view without
+    /// the data being dropped.
+    DragLeave,
     /// Emitted when a mouse button is double clicked.
     MouseDoubleClick(MouseButton),
     /// Emitted when a mouse button is triple clicked
@@ -56,6 +194,37 @@ pub enum WindowEvent {
     MouseMove(f32, f32),
     /// Emitted when the mouse scroll wheel is scrolled.
     MouseScroll(f32, f32),
+    /// Emitted when a finger touches, moves on, or is lifted from a touchscreen. The event is sent to the hovered view
+    /// and propagates up the tree.
+    ///
+    /// The first finger to touch the screen also emits mouse events as if it were the left mouse button, so views
+    /// only need to handle touch events when they are aware of multiple touches.
+    Touch {
+        /// A unique identifier for the finger, which stays the same until the finger is lifted.
+        id: u64,
+        /// The phase of the touch.
+        phase: TouchPhase,
+        /// The position of the finger in window coordinates.
+        position: (f32, f32),
+    },
+    /// Emitted to the hovered view when a gesture is recognized.
+    Gesture(Gesture),
+    /// Emitted when a pen or stylus touches, moves on, or is lifted from a tablet or touchscreen. The event is sent
+    /// to the captured view, or otherwise to the hovered view, and propagates up the tree.
+    ///
+    /// Pen contact also emits mouse events as if the pen were the left mouse button. Pen events are only emitted
+    /// when the platform reports the pressure of the contact.
+    Pen {
+        /// The position of the pen in window coordinates.
+        position: (f32, f32),
+        /// The pressure of the pen, normalized between 0 and 1.
+        pressure: f32,
+        /// The angle between the pen and the surface in radians, where π/2 is perpendicular to the surface, if
+        /// reported by the platform.
+        tilt: Option<f32>,
+        /// The phase of the pen contact.
+        phase: TouchPhase,
+    },
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
     /// Emitted when the mouse cursor leaves the bounding box of an entity.
@@ -84,10 +253,17 @@ pub enum WindowEvent {
     SetCursor(CursorIcon),
     /// Grabs the mouse cursor, preventing it from leaving the window.
     GrabCursor(bool),
-    /// Sets the (x,y) position of the mouse cursor in window coordinates.
+    /// Sets the (x,y) position of the mouse cursor in physical pixels relative to the window. See
+    /// [`EventContext::set_cursor_position`](crate::context::EventContext::set_cursor_position).
     SetCursorPosition(u32, u32),
     /// Sets the title of the window.
     SetTitle(String),
+    /// Emitted when the window has been moved, with its new logical position. The event is sent to the window and
+    /// propagates up to the root.
+    Move(u32, u32),
+    /// Emitted when the window has been resized, with its new logical inner size. The event is sent to the window
+    /// and propagates up to the root.
+    Resize(u32, u32),
     /// Sets the size of the window.
     SetSize(WindowSize),
     /// Sets the position of the window.
@@ -102,12 +278,22 @@ pub enum WindowEvent {
     SetMinimized(bool),
     /// Sets whether the window is maximized.
     SetMaximized(bool),
+    /// Sets whether the window is fullscreen (borderless on the current monitor).
+    SetFullscreen(bool),
     /// Sets whether the window is visible.
     SetVisible(bool),
     /// Sets whether the window has decorations.
     SetDecorations(bool),
     /// Sets whether the window remains on top of other windows.
     SetAlwaysOnTop(bool),
+    /// Sets the level of the window relative to other windows.
+    SetWindowLevel(WindowLevel),
+    /// Sets whether the window is hidden from the taskbar.
+    SetSkipTaskbar(bool),
+    /// Sets whether the window is visible on all workspaces.
+    SetVisibleOnAllWorkspaces(bool),
+    /// Sets whether the background behind the window is blurred.
+    SetBlur(bool),
     /// Emitted when mouse events have been captured.
     MouseCaptureEvent,
     /// Emitted when mouse events have been released.
@@ -115,6 +301,9 @@ pub enum WindowEvent {
     // TODO: check if this includes margins + borders.
     /// Emitted when an entity changes position or size.
     GeometryChanged(GeoChanged),
+    /// Emitted directly to a view on the next frame after it has called
+    /// [`request_animation_frame`](crate::context::EventContext::request_animation_frame), with the time of the frame.
+    AnimationFrame(Instant),
     /// Requests a redraw of the window contents.
     Redraw,
     /// Request a restyle.
@@ -135,6 +324,48 @@ pub enum WindowEvent {
     SetEnabled(bool),
     /// Puts the window in a drag state.
     DragWindow,
+    /// Puts the window in a resize state from the given edge or corner.
+    DragResizeWindow(ResizeDirection),
+    /// Starts a native drag and drop operation with the given data. The origin of the event is the source of the drag.
+    StartDrag {
+        /// The data being dragged.
+        data: DragData,
+        /// A PNG encoded image shown under the cursor during the drag.
+        image: Option<Vec<u8>>,
+    },
+    /// Emitted to the source of a native drag and drop operation when the operation has finished.
+    DragEnded(DragResult),
+    /// Shows a native file dialog parented to the window.
+    #[cfg(feature = "dialogs")]
+    FileDialog(crate::window::FileDialog),
+    /// Registers a key chord with the given id as a global shortcut. The origin of the event receives the
+    /// [`GlobalShortcut`](WindowEvent::GlobalShortcut) events of the shortcut.
+    #[cfg(feature = "global-shortcuts")]
+    RegisterGlobalShortcut(KeyChord, u32),
+    /// Unregisters the global shortcut with the given id.
+    #[cfg(feature = "global-shortcuts")]
+    UnregisterGlobalShortcut(u32),
+    /// Unregisters the global shortcuts registered by the given view. Sent to the main window when a view which
+    /// registered global shortcuts is removed.
+    #[cfg(feature = "global-shortcuts")]
+    UnregisterGlobalShortcuts(Entity),
+    /// Emitted to the view which registered a global shortcut when the key chord of the shortcut is pressed, even
+    /// if the application doesn't have focus.
+    #[cfg(feature = "global-shortcuts")]
+    GlobalShortcut(u32),
+    /// Emitted to the view which registered a global shortcut when the shortcut couldn't be registered.
+    #[cfg(feature = "global-shortcuts")]
+    GlobalShortcutFailed(u32, crate::window::GlobalShortcutError),
+    /// Emitted when the window has been maximized.
+    Maximized,
+    /// Emitted when the window has been minimized.
+    Minimized,
+    /// Emitted when the window has been restored from a maximized, minimized, or fullscreen state.
+    Restored,
+    /// Emitted when a monitor is connected or disconnected, or when the arrangement or scale factor of the
+    /// monitors has changed. The new monitors can be retrieved with
+    /// [`monitors`](crate::context::EventContext::monitors).
+    MonitorsChanged,
 
     /// Emitted when the window is destroyed.
     Destroyed,
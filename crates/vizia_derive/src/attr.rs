@@ -16,7 +16,7 @@
 
 use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned};
-use syn::{parenthesized, Error, ExprPath, Lit, LitStr};
+use syn::{parenthesized, Error, ExprPath, Lit, LitStr, Token};
 
 const BASE_DATA_ATTR_PATH: &str = "data";
 const BASE_LENS_ATTR_PATH: &str = "lens";
@@ -153,7 +153,13 @@ impl Field<DataAttr> {
                     }
 
                     if meta.path.is_ident(DATA_EQ_ATTR_PATH) {
-                        data_attr = DataAttr::Eq;
+                        // `#[data(eq = "path")]` compares the field with a custom function.
+                        data_attr = if meta.input.peek(Token![=]) {
+                            let lit: LitStr = meta.value()?.parse()?;
+                            DataAttr::SameFn(parse_lit_into_expr_path(&Lit::Str(lit))?)
+                        } else {
+                            DataAttr::Eq
+                        };
                         return Ok(());
                     }
 
@@ -190,6 +196,37 @@ impl Field<DataAttr> {
     }
 }
 
+/// Parses the `#[data(...)]` attribute of a struct or enum, which replaces the whole of the generated `same`
+/// implementation with a comparison of the two values.
+///
+/// `#[data(eq)]` compares the values with `PartialEq`, and `#[data(eq = "path")]` compares them with the function
+/// at the given path, which has the signature `fn(&T, &T) -> bool`.
+pub fn parse_container_data_attr(attrs: &[syn::Attribute]) -> Result<DataAttr, Error> {
+    let mut data_attr = DataAttr::Empty;
+    for attr in attrs.iter() {
+        if attr.path().is_ident(BASE_DATA_ATTR_PATH) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(DATA_EQ_ATTR_PATH) {
+                    data_attr = if meta.input.peek(Token![=]) {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        DataAttr::SameFn(parse_lit_into_expr_path(&Lit::Str(lit))?)
+                    } else {
+                        DataAttr::Eq
+                    };
+                    return Ok(());
+                }
+
+                Err(Error::new(
+                    meta.input.span(),
+                    "Expected attribute of the form #[data(eq)] or #[data(eq = \"path\")]",
+                ))
+            })?;
+        }
+    }
+
+    Ok(data_attr)
+}
+
 impl Field<LensAttrs> {
     pub fn parse_ast(field: &syn::Field, index: usize) -> Result<Self, Error> {
         let ident = match field.ident.as_ref() {
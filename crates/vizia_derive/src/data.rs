@@ -16,7 +16,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::attr::{DataAttr, Field, FieldKind, Fields};
+use crate::attr::{parse_container_data_attr, DataAttr, Field, FieldKind, Fields};
 
 use quote::{quote, quote_spanned};
 use syn::{spanned::Spanned, Data, DataEnum, DataStruct};
@@ -24,6 +24,15 @@ use syn::{spanned::Spanned, Data, DataEnum, DataStruct};
 pub(crate) fn derive_data_impl(
     input: syn::DeriveInput,
 ) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match parse_container_data_attr(&input.attrs)? {
+        DataAttr::Empty => {}
+        DataAttr::Eq => {
+            return Ok(derive_container_same(&input, quote!(::core::cmp::PartialEq::eq)))
+        }
+        DataAttr::SameFn(f) => return Ok(derive_container_same(&input, quote!(#f))),
+        DataAttr::Ignore => unreachable!(),
+    }
+
     match &input.data {
         Data::Struct(s) => derive_struct(&input, s),
         Data::Enum(e) => derive_enum(&input, e),
@@ -34,6 +43,24 @@ pub(crate) fn derive_data_impl(
     }
 }
 
+// Implements `Data::same` by comparing the whole of the two values with the given function.
+fn derive_container_same(
+    input: &syn::DeriveInput,
+    same_fn: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ident = &input.ident;
+    let impl_generics = generics_bounds(&input.generics);
+    let (_, ty_generics, where_clause) = &input.generics.split_for_impl();
+
+    quote! {
+        impl<#impl_generics> Data for #ident #ty_generics #where_clause {
+            fn same(&self, other: &Self) -> bool {
+                #same_fn(self, other)
+            }
+        }
+    }
+}
+
 fn derive_struct(
     input: &syn::DeriveInput,
     s: &DataStruct,
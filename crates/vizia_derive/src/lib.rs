@@ -23,12 +23,58 @@ mod lens;
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
+/// Implements `Data` for a struct or enum by comparing each of its fields with `Data::same`.
+///
+/// # Field attributes
+///
+/// - `#[data(ignore)]` skips the field when comparing.
+/// - `#[data(eq)]` compares the field with `PartialEq` instead of `Data::same`.
+/// - `#[data(eq = "path")]` compares the field with the function at the given path, with the signature
+///   `fn(&T, &T) -> bool`.
+///
+/// # Container attributes
+///
+/// - `#[data(eq)]` or `#[data(eq = "path")]` on the struct or enum compares whole values in the same way, instead
+///   of comparing each field.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Clone, Data)]
+/// pub struct Curve {
+///     pub name: String,
+///     #[data(eq = "points_eq")]
+///     pub points: Vec<(f32, f32)>,
+/// }
+///
+/// fn points_eq(a: &Vec<(f32, f32)>, b: &Vec<(f32, f32)>) -> bool {
+///     a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3)
+/// }
+/// ```
 #[proc_macro_derive(Data, attributes(data))]
 pub fn derive_data(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
     data::derive_data_impl(input).unwrap_or_else(|err| err.to_compile_error()).into()
 }
 
+/// Generates a lens for each field of a struct, accessed with an associated constant of the same name as the field.
+///
+/// # Field attributes
+///
+/// - `#[lens(ignore)]` skips generating a lens for the field, which allows the struct to contain fields which can't
+///   be bound to, such as a runtime or a callback.
+/// - `#[lens(name("..."))]` sets the name of the generated lens.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Lens)]
+/// pub struct AppData {
+///     pub count: i32,
+///     #[lens(ignore)]
+///     pub runtime: tokio::runtime::Runtime,
+/// }
+///
+/// Label::new(cx, AppData::count);
+/// ```
 #[proc_macro_derive(Lens, attributes(lens))]
 pub fn derive_lens(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
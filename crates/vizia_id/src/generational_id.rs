@@ -38,6 +38,29 @@ pub trait GenerationalId: Copy + PartialEq {
 
     /// Returns the root id usually referring to the first id (e.g. Entity(0)).
     fn root() -> Self;
+
+    /// Returns the index and generation of the generational id packed into a `u64`, which can be serialized and
+    /// turned back into the id with [`from_u64`](GenerationalId::from_u64).
+    fn to_u64(&self) -> u64 {
+        if self.is_null() {
+            u64::MAX
+        } else {
+            self.index() as u64 | (self.generation() as u64) << GENERATIONAL_ID_INDEX_BITS
+        }
+    }
+
+    /// Creates a generational id from an index and generation packed into a `u64` by
+    /// [`to_u64`](GenerationalId::to_u64), returning `None` if the value is not a valid id.
+    fn from_u64(value: u64) -> Option<Self> {
+        if value == u64::MAX {
+            return Some(Self::null());
+        }
+
+        let index = value & GENERATIONAL_ID_INDEX_MASK;
+        let generation = (value >> GENERATIONAL_ID_INDEX_BITS) & GENERATIONAL_ID_GENERATION_MASK;
+        (index < GENERATIONAL_ID_INDEX_MASK && generation < GENERATIONAL_ID_GENERATION_MASK)
+            .then(|| Self::new(index, generation))
+    }
 }
 
 #[macro_export]
@@ -1,5 +1,8 @@
 use crate::GenerationalId;
-use std::{collections::VecDeque, marker::PhantomData};
+use std::{
+    collections::{HashSet, VecDeque},
+    marker::PhantomData,
+};
 
 const MINIMUM_FREE_INDICES: usize = 4096;
 const IDX_MAX: u64 = u64::MAX >> 16;
@@ -7,12 +10,23 @@ const IDX_MAX: u64 = u64::MAX >> 16;
 /// The IdManager is responsible for allocating and destroying generational IDs.
 ///
 /// The IdManager is generic on ID type, requiring only that the ID type implements [GenerationalId].
+///
+/// Besides the IDs allocated by [`create`](IdManager::create), which differ between runs, a manager which has reserved
+/// stable IDs with [`reserve_stable_ids`](IdManager::reserve_stable_ids) can create them with
+/// [`create_stable`](IdManager::create_stable), which are the same every time they are created from the same key and
+/// so can be serialized and restored, for example to save layout state or to refer to an entity from another process.
 pub struct IdManager<I>
 where
     I: GenerationalId,
 {
     generation: Vec<u16>,
     free_list: VecDeque<u64>,
+    // The first index reserved for stable IDs.
+    stable_start: u64,
+    // The number of indices which are reserved for stable IDs and never used by `create`.
+    stable_ids: u64,
+    // The keys of the stable IDs which are alive.
+    stable: HashSet<u64>,
 
     p: PhantomData<I>,
 }
@@ -34,15 +48,49 @@ where
         Self {
             generation: vec![0],
             free_list: VecDeque::with_capacity(MINIMUM_FREE_INDICES),
+            stable_start: 0,
+            stable_ids: 0,
+            stable: HashSet::new(),
 
             p: PhantomData,
         }
     }
 
+    /// Creates a new IdManager which reserves the indices after the root index for `count` stable IDs. See
+    /// [`reserve_stable_ids`](IdManager::reserve_stable_ids).
+    pub fn with_stable_ids(count: u64) -> Self {
+        let mut id_manager = Self::new();
+        id_manager.reserve_stable_ids(count);
+        id_manager
+    }
+
+    /// Reserves the next `count` indices for stable IDs, with keys from `0` to `count - 1`.
+    ///
+    /// IDs created with [`create`](IdManager::create) never use the reserved indices, so stable IDs can't collide
+    /// with them. The reserved indices follow the IDs which have already been created, so stable IDs are only the same
+    /// between runs if the same number of IDs is created before the reservation.
+    ///
+    /// # Panics
+    /// Panics if stable IDs have already been reserved.
+    pub fn reserve_stable_ids(&mut self, count: u64) {
+        assert!(self.stable_ids == 0, "Stable IDs have already been reserved");
+        let start = self.generation.len() as u64;
+        assert!(start + count < IDX_MAX, "ID index exceeds maximum allowed value of {}", IDX_MAX);
+
+        // The reserved indices start at a generation of one so that they are not alive until they are created.
+        self.generation.resize((start + count) as usize, 1);
+        self.stable_start = start;
+        self.stable_ids = count;
+    }
+
+    /// Destroys all IDs. Any stable IDs which were reserved are reserved again directly after the root index.
     pub fn reset(&mut self) {
+        let stable_ids = std::mem::take(&mut self.stable_ids);
         self.generation.clear();
         self.generation.push(0);
         self.free_list.clear();
+        self.stable.clear();
+        self.reserve_stable_ids(stable_ids);
     }
 
     /// Creates a new generational id.
@@ -61,6 +109,39 @@ where
         I::new(index, self.generation[index as usize] as u64)
     }
 
+    /// Creates a stable id for a key, which always has the same index and a generation of zero, so the same key
+    /// produces the same id in every run.
+    ///
+    /// Returns `None` if the key is outside of the range reserved with
+    /// [`reserve_stable_ids`](IdManager::reserve_stable_ids) or the id for the key is already alive.
+    pub fn create_stable(&mut self, key: u64) -> Option<I> {
+        if key >= self.stable_ids || self.stable.contains(&key) {
+            return None;
+        }
+
+        let index = self.stable_start + key;
+        self.generation[index as usize] = 0;
+        self.stable.insert(key);
+        Some(I::new(index, 0))
+    }
+
+    /// Returns the stable id for a key, creating it if it is not alive. Returns `None` if the key is outside of the
+    /// reserved range. See [`create_stable`](IdManager::create_stable).
+    pub fn get_or_create_stable(&mut self, key: u64) -> Option<I> {
+        self.get_stable(key).or_else(|| self.create_stable(key))
+    }
+
+    /// Returns the stable id for a key if it is alive.
+    pub fn get_stable(&self, key: u64) -> Option<I> {
+        self.stable.contains(&key).then(|| I::new(self.stable_start + key, 0))
+    }
+
+    /// Returns true if an id is a stable id created with [`create_stable`](IdManager::create_stable).
+    pub fn is_stable(&self, id: I) -> bool {
+        let index = id.index() as u64;
+        !id.is_null() && index >= self.stable_start && index < self.stable_start + self.stable_ids
+    }
+
     /// Destroys an ID returning false if the ID has already been destroyed.
     ///
     /// Destroyed IDs are reused after MINIMUM_FREE_INDICES are created for a single genration.
@@ -70,7 +151,12 @@ where
             assert!(index < self.generation.len(), "ID is invalid");
             assert!(self.generation[index] != u16::MAX, "ID generation is at maximum");
             self.generation[index] += 1;
-            self.free_list.push_back(index as u64);
+            // The indices of stable IDs are only reused by `create_stable`.
+            if self.is_stable(id) {
+                self.stable.remove(&(index as u64 - self.stable_start));
+            } else {
+                self.free_list.push_back(index as u64);
+            }
             true
         } else {
             false
@@ -175,6 +261,58 @@ mod tests {
         assert_eq!(id3, Entity::new(0, 1));
     }
 
+    /// Test that stable ids are the same for the same key and don't collide with ids from `create`.
+    #[test]
+    fn stable() {
+        let mut id_manager = IdManager::<Entity>::with_stable_ids(8);
+        let dynamic = id_manager.create();
+        assert!(!id_manager.is_stable(dynamic));
+
+        let id = id_manager.create_stable(3).unwrap();
+        assert_eq!(id, Entity::new(4, 0));
+        assert_ne!(id, dynamic);
+        assert!(id_manager.is_alive(id));
+        assert!(id_manager.is_stable(id));
+        assert_eq!(id_manager.create_stable(3), None);
+        assert_eq!(id_manager.get_or_create_stable(3), Some(id));
+        assert_eq!(id_manager.create_stable(8), None);
+        assert!(!id_manager.is_alive(Entity::new(5, 0)));
+
+        for _ in 0..MINIMUM_FREE_INDICES {
+            let created = id_manager.create();
+            assert!(!id_manager.is_stable(created));
+            id_manager.destroy(created);
+        }
+
+        id_manager.destroy(id);
+        assert!(!id_manager.is_alive(id));
+        assert_eq!(id_manager.create_stable(3), Some(id));
+        assert!(id_manager.is_alive(id));
+    }
+
+    /// Test that stable ids are only reserved when asked for, after the ids which already exist.
+    #[test]
+    fn reserve_stable() {
+        let mut id_manager = IdManager::<Entity>::new();
+        assert_eq!(id_manager.create_stable(0), None);
+
+        let dynamic = id_manager.create();
+        id_manager.reserve_stable_ids(2);
+        assert_eq!(id_manager.create_stable(0), Some(Entity::new(2, 0)));
+        assert_eq!(id_manager.create_stable(2), None);
+        assert!(!id_manager.is_stable(dynamic));
+        assert_eq!(id_manager.create(), Entity::new(4, 0));
+    }
+
+    /// Test converting ids to and from `u64`.
+    #[test]
+    fn to_from_u64() {
+        let id = Entity::new(42, 7);
+        assert_eq!(Entity::from_u64(id.to_u64()), Some(id));
+        assert_eq!(Entity::from_u64(Entity::null().to_u64()), Some(Entity::null()));
+        assert_eq!(Entity::from_u64(GENERATIONAL_ID_INDEX_MASK), None);
+    }
+
     /// Test the is_alive() method.
     #[test]
     fn alive() {
@@ -1,5 +1,7 @@
 use crate::{Code, Modifiers};
+use std::fmt;
 use std::hash::Hash;
+use std::str::FromStr;
 
 /// A key chord used inside of a `Keymap`.
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -24,3 +26,109 @@ impl KeyChord {
         Self { modifiers, code }
     }
 }
+
+/// Formats the key chord as it would be shown in a menu, such as `Ctrl+Shift+S`.
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::SUPER, "Super"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+
+        let code = self.code.to_string();
+        let key = match self.code {
+            Code::ArrowUp => "Up",
+            Code::ArrowDown => "Down",
+            Code::ArrowLeft => "Left",
+            Code::ArrowRight => "Right",
+            _ => code.strip_prefix("Key").or_else(|| code.strip_prefix("Digit")).unwrap_or(&code),
+        };
+
+        f.write_str(key)
+    }
+}
+
+/// An error returned when parsing a [`KeyChord`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyChordError(String);
+
+impl fmt::Display for ParseKeyChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid key chord `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseKeyChordError {}
+
+/// Parses a key chord in the format it is displayed in, such as `Ctrl+Shift+S`.
+///
+/// Modifier names are case insensitive, and the key is either one of the shortened names used when displaying a key
+/// chord, such as `S`, `1`, or `Up`, or the name of a [`Code`], such as `KeyS` or `F5`.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_input::*;
+/// #
+/// let chord: KeyChord = "Ctrl+Shift+S".parse().unwrap();
+/// assert_eq!(chord, KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyS));
+/// assert_eq!(chord.to_string().parse(), Ok(chord));
+/// ```
+impl FromStr for KeyChord {
+    type Err = ParseKeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseKeyChordError(s.to_owned());
+        let mut parts = s.split('+').map(str::trim);
+        let key = parts.next_back().filter(|key| !key.is_empty()).ok_or_else(error)?;
+
+        let mut modifiers = Modifiers::empty();
+        for name in parts {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CTRL,
+                "alt" | "option" => Modifiers::ALT,
+                "shift" => Modifiers::SHIFT,
+                "super" | "meta" | "cmd" => Modifiers::SUPER,
+                _ => return Err(error()),
+            };
+        }
+
+        let code = match key {
+            "Up" => Code::ArrowUp,
+            "Down" => Code::ArrowDown,
+            "Left" => Code::ArrowLeft,
+            "Right" => Code::ArrowRight,
+            _ if key.len() == 1 && key.as_bytes()[0].is_ascii_alphabetic() => {
+                format!("Key{}", key.to_ascii_uppercase()).parse().map_err(|_| error())?
+            }
+            _ if key.len() == 1 && key.as_bytes()[0].is_ascii_digit() => {
+                format!("Digit{}", key).parse().map_err(|_| error())?
+            }
+            _ => key.parse().map_err(|_| error())?,
+        };
+
+        Ok(Self { modifiers, code })
+    }
+}
+
+// Key chords are serialized as strings such as `Ctrl+Shift+S`, so they can be edited by hand in a config file.
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyChord {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeyChord {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
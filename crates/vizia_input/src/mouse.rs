@@ -1,4 +1,11 @@
 use vizia_id::GenerationalId;
+use web_time::{Duration, Instant};
+
+/// The number of recent cursor positions kept by a [`MouseState`] to estimate the velocity of the cursor.
+pub const MOUSE_HISTORY_LEN: usize = 8;
+
+// Positions recorded longer than this before the current time are ignored when estimating the velocity.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
 
 /// A mouse button.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -79,6 +86,10 @@ where
     pub right: MouseButtonData<I>,
     /// The state of the middle mouse button.
     pub middle: MouseButtonData<I>,
+    // A ring buffer of the most recent cursor positions and the times they were recorded.
+    history: [Option<(f32, f32, Instant)>; MOUSE_HISTORY_LEN],
+    // The index of the slot of the ring buffer which is written next.
+    history_index: usize,
 }
 
 impl<I> Default for MouseState<I>
@@ -94,6 +105,8 @@ where
             left: MouseButtonData::default(),
             right: MouseButtonData::default(),
             middle: MouseButtonData::default(),
+            history: [None; MOUSE_HISTORY_LEN],
+            history_index: 0,
         }
     }
 }
@@ -102,6 +115,76 @@ impl<I> MouseState<I>
 where
     I: GenerationalId,
 {
+    /// Moves the mouse cursor to a new position, recording the position and the time it was reached in the history
+    /// used to estimate the velocity of the cursor.
+    pub fn move_cursor(&mut self, x: f32, y: f32, time: Instant) {
+        self.previous_cursor_x = self.cursor_x;
+        self.previous_cursor_y = self.cursor_y;
+        self.cursor_x = x;
+        self.cursor_y = y;
+
+        self.history[self.history_index] = Some((x, y, time));
+        self.history_index = (self.history_index + 1) % MOUSE_HISTORY_LEN;
+    }
+
+    /// Returns the most recent cursor positions and the times they were recorded, from oldest to newest.
+    pub fn history(&self) -> impl Iterator<Item = (f32, f32, Instant)> + '_ {
+        let (newer, older) = self.history.split_at(self.history_index);
+        older.iter().chain(newer).flatten().copied()
+    }
+
+    /// Returns the velocity of the mouse cursor in pixels per second at the given time, estimated from the positions
+    /// of the cursor recorded shortly before with more weight given to the most recent movements.
+    ///
+    /// Positions recorded more than 100ms before `now` are ignored, so the velocity drops to zero once the cursor has
+    /// stopped moving. `now` should come from the same clock as the times passed to
+    /// [`move_cursor`](MouseState::move_cursor).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Dismiss a view which is swiped to the right quickly enough.
+    /// WindowEvent::MouseUp(MouseButton::Left) => {
+    ///     if cx.mouse().velocity(Instant::now()).0 > 1000.0 {
+    ///         cx.emit(AppEvent::Dismiss);
+    ///     }
+    /// }
+    /// ```
+    pub fn velocity(&self, now: Instant) -> (f32, f32) {
+        weighted_average(self.velocities(now).into_iter().map(|(velocity, _)| velocity))
+    }
+
+    /// Returns the acceleration of the mouse cursor in pixels per second squared at the given time, which is the rate
+    /// at which its [`velocity`](MouseState::velocity) is changing.
+    pub fn acceleration(&self, now: Instant) -> (f32, f32) {
+        weighted_average(self.velocities(now).windows(2).filter_map(|pair| {
+            let (((x0, y0), t0), ((x1, y1), t1)) = (pair[0], pair[1]);
+            let dt = t1.checked_duration_since(t0)?.as_secs_f32();
+            (dt > 0.0).then_some(((x1 - x0) / dt, (y1 - y0) / dt))
+        }))
+    }
+
+    // Returns the velocity of each movement between the positions recorded within the velocity window before `now`,
+    // from oldest to newest, together with the time halfway through the movement.
+    fn velocities(&self, now: Instant) -> Vec<((f32, f32), Instant)> {
+        let recent = self
+            .history()
+            .filter(|(_, _, time)| {
+                now.checked_duration_since(*time).is_some_and(|age| age <= VELOCITY_WINDOW)
+            })
+            .collect::<Vec<_>>();
+
+        recent
+            .windows(2)
+            .filter_map(|pair| {
+                let ((x0, y0, t0), (x1, y1, t1)) = (pair[0], pair[1]);
+                let elapsed = t1.checked_duration_since(t0)?;
+                let dt = elapsed.as_secs_f32();
+                (dt > 0.0).then_some((((x1 - x0) / dt, (y1 - y0) / dt), t0 + elapsed / 2))
+            })
+            .collect()
+    }
+
     /// Returns the delta of the mouse cursor position of the current and previous frame.
     pub fn delta(&self) -> (f32, f32) {
         (self.cursor_x - self.previous_cursor_x, self.cursor_y - self.previous_cursor_y)
@@ -126,3 +209,64 @@ where
         }
     }
 }
+
+// Returns the average of some values, weighting each value by its position so that later values count for more.
+fn weighted_average(values: impl Iterator<Item = (f32, f32)>) -> (f32, f32) {
+    let (mut x, mut y, mut total) = (0.0, 0.0, 0.0);
+    for (index, (value_x, value_y)) in values.enumerate() {
+        let weight = (index + 1) as f32;
+        x += value_x * weight;
+        y += value_y * weight;
+        total += weight;
+    }
+
+    if total > 0.0 {
+        (x / total, y / total)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_id::{
+        impl_generational_id, GENERATIONAL_ID_GENERATION_MASK, GENERATIONAL_ID_INDEX_BITS,
+        GENERATIONAL_ID_INDEX_MASK,
+    };
+
+    #[derive(Copy, Clone, PartialEq)]
+    struct Entity(u64);
+
+    impl_generational_id!(Entity);
+
+    #[test]
+    fn estimates_velocity_from_history() {
+        let mut mouse = MouseState::<Entity>::default();
+        let start = Instant::now();
+        assert_eq!(mouse.velocity(start), (0.0, 0.0));
+
+        // Moving 10 pixels to the right every 10ms is a steady 1000 pixels per second.
+        for step in 0..12 {
+            let time = start + Duration::from_millis(10 * step);
+            mouse.move_cursor(10.0 * step as f32, 0.0, time);
+        }
+
+        assert_eq!(mouse.history().count(), MOUSE_HISTORY_LEN);
+        assert_eq!(mouse.history().last().map(|(x, _, _)| x), Some(110.0));
+        let now = start + Duration::from_millis(110);
+        let (vx, vy) = mouse.velocity(now);
+        assert!((vx - 1000.0).abs() < 1.0 && vy == 0.0);
+        assert!(mouse.acceleration(now).0.abs() < 1.0);
+
+        // Speeding up gives a positive acceleration.
+        let now = start + Duration::from_millis(120);
+        mouse.move_cursor(140.0, 0.0, now);
+        assert!(mouse.velocity(now).0 > 1000.0);
+        assert!(mouse.acceleration(now).0 > 0.0);
+
+        // Once the cursor has rested for longer than the velocity window, it has no velocity.
+        let now = start + Duration::from_millis(300);
+        assert_eq!(mouse.velocity(now), (0.0, 0.0));
+    }
+}
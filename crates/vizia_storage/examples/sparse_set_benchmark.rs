@@ -0,0 +1,48 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use vizia_id::{
+    impl_generational_id, GenerationalId, GENERATIONAL_ID_GENERATION_MASK,
+    GENERATIONAL_ID_INDEX_BITS, GENERATIONAL_ID_INDEX_MASK,
+};
+use vizia_storage::SparseSet;
+
+// Compares summing the values of a half-populated sparse set by looking up every key through the sparse array with
+// iterating the dense array directly:
+//
+//     cargo run --release -p vizia_storage --example sparse_set_benchmark
+const KEYS: u64 = 100_000;
+const RUNS: usize = 101;
+
+#[derive(Copy, Clone, PartialEq)]
+struct Entity(u64);
+
+impl_generational_id!(Entity);
+
+fn median(f: impl Fn() -> f32) -> Duration {
+    let mut times = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    times.sort();
+    times[RUNS / 2]
+}
+
+fn main() {
+    let mut set = SparseSet::new();
+    for index in (0..KEYS).step_by(2) {
+        set.insert(Entity::new(index, 0), index as f32);
+    }
+
+    let lookup = median(|| (0..KEYS).filter_map(|index| set.get(Entity::new(index, 0))).sum());
+    let iter_dense = median(|| set.iter_dense::<Entity>().map(|(_, value)| value).sum());
+    let values_slice = median(|| set.values_slice().iter().sum());
+
+    println!("Summed {} of {} keys (median of {} runs):", set.values_slice().len(), KEYS, RUNS);
+    println!("  sparse lookup: {:?}", lookup);
+    println!("  iter_dense:    {:?}", iter_dense);
+    println!("  values_slice:  {:?}", values_slice);
+}
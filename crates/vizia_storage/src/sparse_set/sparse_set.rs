@@ -4,19 +4,22 @@ use std::{
 };
 
 use crate::SparseSetIndex;
-use vizia_id::GenerationalId;
+use vizia_id::{GenerationalId, GENERATIONAL_ID_INDEX_MASK};
 
 pub type SparseSet<V> = SparseSetGeneric<usize, V>;
-use super::entry::Entry;
 
 /// A generic sparse set data structure.
+///
+/// The values are stored contiguously in a dense array, alongside a parallel array of the keys they belong to, so
+/// that they can be iterated or processed in bulk without going through the sparse array.
 #[derive(Debug, Clone)]
 pub struct SparseSetGeneric<I, V>
 where
     I: SparseSetIndex,
 {
     pub sparse: Vec<I>,
-    pub dense: Vec<Entry<I, V>>,
+    keys: Vec<u64>,
+    values: Vec<V>,
 }
 
 impl<I, V> Default for SparseSetGeneric<I, V>
@@ -24,7 +27,7 @@ where
     I: SparseSetIndex,
 {
     fn default() -> Self {
-        Self { sparse: Vec::new(), dense: Vec::new() }
+        Self { sparse: Vec::new(), keys: Vec::new(), values: Vec::new() }
     }
 }
 
@@ -39,18 +42,19 @@ where
 
     pub fn clear(&mut self) {
         self.sparse.clear();
-        self.dense.clear();
+        self.keys.clear();
+        self.values.clear();
     }
 
     pub fn is_empty(&self) -> bool {
-        self.dense.is_empty()
+        self.values.is_empty()
     }
 
     /// Returns the index of the data associated with the key if it exists
     pub fn dense_idx<K: GenerationalId>(&self, key: K) -> Option<I> {
         if let Some(dense_index) = self.sparse.get(key.index()) {
-            if let Some(entry) = self.dense.get(dense_index.index()) {
-                if entry.key.index() == key.index() {
+            if let Some(stored_key) = self.keys.get(dense_index.index()) {
+                if key_index(*stored_key) == key.index() {
                     return Some(*dense_index);
                 }
             }
@@ -65,12 +69,12 @@ where
 
     /// Returns a reference to the data for a given key if it exists
     pub fn get<K: GenerationalId>(&self, key: K) -> Option<&V> {
-        self.dense_idx(key).map(|dense_idx| &self.dense[dense_idx.index()].value)
+        self.dense_idx(key).map(|dense_idx| &self.values[dense_idx.index()])
     }
 
     /// Returns a mutable reference to the data for a given key if it exists
     pub fn get_mut<K: GenerationalId>(&mut self, key: K) -> Option<&mut V> {
-        self.dense_idx(key).map(move |dense_idx| &mut self.dense[dense_idx.index()].value)
+        self.dense_idx(key).map(move |dense_idx| &mut self.values[dense_idx.index()])
     }
 
     /// Inserts data for a given key into the sparse set.
@@ -81,8 +85,9 @@ where
             panic!("Key is null");
         }
 
-        if let Some(stored_value) = self.get_mut(key) {
-            *stored_value = value;
+        if let Some(dense_idx) = self.dense_idx(key) {
+            self.keys[dense_idx.index()] = key.to_u64();
+            self.values[dense_idx.index()] = value;
             return;
         }
 
@@ -92,8 +97,9 @@ where
             self.sparse.resize(sparse_idx + 1, I::null());
         }
 
-        self.sparse[sparse_idx] = I::new(self.dense.len());
-        self.dense.push(Entry { key: I::new(sparse_idx), value });
+        self.sparse[sparse_idx] = I::new(self.values.len());
+        self.keys.push(key.to_u64());
+        self.values.push(value);
     }
 
     /// Removes the data for a given key from the sparse set
@@ -101,10 +107,11 @@ where
         if self.contains(key) {
             let sparse_idx = key.index();
             let dense_idx = self.sparse[sparse_idx];
-            let r = self.dense.swap_remove(dense_idx.index()).value;
-            if dense_idx.index() < self.dense.len() {
-                let swapped_entry = &self.dense[dense_idx.index()];
-                self.sparse[swapped_entry.key.index()] = dense_idx;
+            self.keys.swap_remove(dense_idx.index());
+            let r = self.values.swap_remove(dense_idx.index());
+            if dense_idx.index() < self.keys.len() {
+                let swapped_key = self.keys[dense_idx.index()];
+                self.sparse[key_index(swapped_key)] = dense_idx;
             }
 
             self.sparse[sparse_idx] = I::null();
@@ -114,63 +121,101 @@ where
             None
         }
     }
+
+    /// Returns the values of the populated entries as a contiguous slice, in the order of the dense array.
+    ///
+    /// The position of a value in the slice is its dense index, as returned by [`dense_idx`](Self::dense_idx).
+    pub fn values_slice(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Returns the values of the populated entries as a contiguous mutable slice, in the order of the dense array.
+    pub fn values_slice_mut(&mut self) -> &mut [V] {
+        &mut self.values
+    }
+
+    /// Returns an iterator over the populated entries of the sparse set, yielding the key of each entry with a
+    /// reference to its value.
+    ///
+    /// The entries are visited in the order of the dense array, which is contiguous, rather than in key order.
+    pub fn iter_dense<K: GenerationalId>(&self) -> impl Iterator<Item = (K, &V)> + '_ {
+        self.keys.iter().zip(self.values.iter()).map(|(key, value)| (to_key(*key), value))
+    }
+
+    /// Calls a closure with the key and the value of each populated entry of the sparse set, in the order of the
+    /// dense array.
+    pub fn for_each_populated<K: GenerationalId>(&self, mut f: impl FnMut(K, &V)) {
+        for (key, value) in self.keys.iter().zip(self.values.iter()) {
+            f(to_key(*key), value);
+        }
+    }
+}
+
+/// Returns the index part of a key stored in the dense array.
+fn key_index(key: u64) -> usize {
+    (key & GENERATIONAL_ID_INDEX_MASK) as usize
 }
 
-/// Deref to a slice.
+/// Turns a key stored in the dense array back into a generational id.
+fn to_key<K: GenerationalId>(key: u64) -> K {
+    K::from_u64(key).expect("Sparse set contains an invalid key")
+}
+
+/// Deref to a slice of the values.
 impl<I, T> Deref for SparseSetGeneric<I, T>
 where
     I: SparseSetIndex,
 {
-    type Target = [Entry<I, T>];
+    type Target = [T];
 
     fn deref(&self) -> &Self::Target {
-        &self.dense[..]
+        &self.values[..]
     }
 }
 
-/// Deref to a mutable slice.
+/// Deref to a mutable slice of the values.
 impl<I, T> DerefMut for SparseSetGeneric<I, T>
 where
     I: SparseSetIndex,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.dense[..]
+        &mut self.values[..]
     }
 }
 
-/// Move into an interator, consuming the SparseSetGeneric.
+/// Move into an interator over the values, consuming the SparseSetGeneric.
 impl<I, T> IntoIterator for SparseSetGeneric<I, T>
 where
     I: SparseSetIndex,
 {
-    type Item = Entry<I, T>;
+    type Item = T;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.dense.into_iter()
+        self.values.into_iter()
     }
 }
 
-/// An interator over the elements of the SparseSetGeneric.
+/// An interator over the values of the SparseSetGeneric.
 impl<'a, I, T> IntoIterator for &'a SparseSetGeneric<I, T>
 where
     I: SparseSetIndex,
 {
-    type Item = &'a Entry<I, T>;
-    type IntoIter = slice::Iter<'a, Entry<I, T>>;
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-/// An interator over mutable elements of the SparseSetGeneric.
+/// An interator over mutable values of the SparseSetGeneric.
 impl<'a, I, T> IntoIterator for &'a mut SparseSetGeneric<I, T>
 where
     I: SparseSetIndex,
 {
-    type Item = &'a mut Entry<I, T>;
-    type IntoIter = slice::IterMut<'a, Entry<I, T>>;
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
@@ -196,7 +241,7 @@ mod tests {
         let sparse_set = SparseSetGeneric::<usize, usize>::new();
 
         assert!(sparse_set.sparse.is_empty());
-        assert!(sparse_set.dense.is_empty());
+        assert!(sparse_set.values.is_empty());
         assert!(sparse_set.is_empty());
     }
 
@@ -207,8 +252,8 @@ mod tests {
 
         sparse_set.insert(Entity::new(0, 0), 42);
         assert_eq!(sparse_set.sparse, [0]);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
     }
 
     /// Test adding multiple items with different ids
@@ -218,10 +263,10 @@ mod tests {
 
         sparse_set.insert(Entity::new(0, 0), 42);
         sparse_set.insert(Entity::new(1, 0), 69);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
-        assert_eq!(sparse_set.dense[1].key, 1);
-        assert_eq!(sparse_set.dense[1].value, 69);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
+        assert_eq!(sparse_set.keys[1], 1);
+        assert_eq!(sparse_set.values[1], 69);
     }
 
     /// Test adding multiple items with the same id (i.e. update the value)
@@ -230,11 +275,11 @@ mod tests {
         let mut sparse_set = SparseSetGeneric::<usize, usize>::new();
 
         sparse_set.insert(Entity::new(0, 0), 42);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
         sparse_set.insert(Entity::new(0, 0), 69);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 69);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 69);
     }
 
     /// Test inserting data with a null id
@@ -253,8 +298,8 @@ mod tests {
 
         sparse_set.insert(Entity::new(0, 0), 42);
         assert_eq!(sparse_set.sparse, [0]);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
         assert_eq!(sparse_set.remove(Entity::new(0, 0)), Some(42));
     }
 
@@ -266,10 +311,10 @@ mod tests {
         sparse_set.insert(Entity::new(0, 0), 42);
         sparse_set.insert(Entity::new(1, 0), 69);
         assert_eq!(sparse_set.sparse, [0, 1]);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
-        assert_eq!(sparse_set.dense[1].key, 1);
-        assert_eq!(sparse_set.dense[1].value, 69);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
+        assert_eq!(sparse_set.keys[1], 1);
+        assert_eq!(sparse_set.values[1], 69);
         assert_eq!(sparse_set.remove(Entity::new(0, 0)), Some(42));
     }
 
@@ -281,10 +326,10 @@ mod tests {
         sparse_set.insert(Entity::new(0, 0), 42);
         sparse_set.insert(Entity::new(1, 0), 69);
         assert_eq!(sparse_set.sparse, [0, 1]);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
-        assert_eq!(sparse_set.dense[1].key, 1);
-        assert_eq!(sparse_set.dense[1].value, 69);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
+        assert_eq!(sparse_set.keys[1], 1);
+        assert_eq!(sparse_set.values[1], 69);
         assert_eq!(sparse_set.remove(Entity::new(1, 0)), Some(69));
     }
 
@@ -297,12 +342,12 @@ mod tests {
         sparse_set.insert(Entity::new(1, 0), 69);
         sparse_set.insert(Entity::new(2, 0), 33);
         assert_eq!(sparse_set.sparse, [0, 1, 2]);
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
-        assert_eq!(sparse_set.dense[1].key, 1);
-        assert_eq!(sparse_set.dense[1].value, 69);
-        assert_eq!(sparse_set.dense[2].key, 2);
-        assert_eq!(sparse_set.dense[2].value, 33);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
+        assert_eq!(sparse_set.keys[1], 1);
+        assert_eq!(sparse_set.values[1], 69);
+        assert_eq!(sparse_set.keys[2], 2);
+        assert_eq!(sparse_set.values[2], 33);
         assert_eq!(sparse_set.remove(Entity::new(1, 0)), Some(69));
     }
 
@@ -333,12 +378,44 @@ mod tests {
                 1
             ]
         );
-        assert_eq!(sparse_set.dense[0].key, 0);
-        assert_eq!(sparse_set.dense[0].value, 42);
-        assert_eq!(sparse_set.dense[1].key, 12);
-        assert_eq!(sparse_set.dense[1].value, 69);
-        assert_eq!(sparse_set.dense[2].key, 5);
-        assert_eq!(sparse_set.dense[2].value, 33);
+        assert_eq!(sparse_set.keys[0], 0);
+        assert_eq!(sparse_set.values[0], 42);
+        assert_eq!(sparse_set.keys[1], 12);
+        assert_eq!(sparse_set.values[1], 69);
+        assert_eq!(sparse_set.keys[2], 5);
+        assert_eq!(sparse_set.values[2], 33);
         assert_eq!(sparse_set.remove(Entity::new(12, 0)), Some(69));
     }
+
+    /// Test iterating over the populated entries after a removal
+    #[test]
+    fn iter_dense() {
+        let mut sparse_set = SparseSetGeneric::<usize, usize>::new();
+
+        sparse_set.insert(Entity::new(0, 0), 42);
+        sparse_set.insert(Entity::new(12, 0), 69);
+        sparse_set.insert(Entity::new(5, 0), 33);
+        sparse_set.remove(Entity::new(0, 0));
+
+        assert_eq!(
+            sparse_set.iter_dense().collect::<Vec<_>>(),
+            [(Entity::new(5, 0), &33), (Entity::new(12, 0), &69)]
+        );
+        assert_eq!(sparse_set.values_slice(), [33, 69]);
+
+        let mut populated = Vec::new();
+        sparse_set.for_each_populated(|entity: Entity, value| populated.push((entity, *value)));
+        assert_eq!(populated, [(Entity::new(5, 0), 33), (Entity::new(12, 0), 69)]);
+    }
+
+    /// Test that iterating yields the generation the value was last inserted with
+    #[test]
+    fn iter_dense_generation() {
+        let mut sparse_set = SparseSetGeneric::<usize, usize>::new();
+
+        sparse_set.insert(Entity::new(3, 0), 42);
+        sparse_set.insert(Entity::new(3, 1), 69);
+
+        assert_eq!(sparse_set.iter_dense().collect::<Vec<_>>(), [(Entity::new(3, 1), &69)]);
+    }
 }
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, OnceLock, RwLock};
+
+use precomputed_hash::PrecomputedHash;
+
+/// An interned string, such as the name of an element, class, or id, which can be compared and hashed as an integer.
+///
+/// Strings are added to a global table the first time they are interned and are removed from it once the last atom for
+/// them is dropped, so names which are created dynamically, such as ids containing an index, do not accumulate.
+///
+/// # Example
+/// ```
+/// # use vizia_style::Atom;
+/// let atom = Atom::new("primary");
+/// assert_eq!(atom, Atom::new("primary"));
+/// assert_eq!(atom.as_str(), "primary");
+/// ```
+#[derive(Clone)]
+pub struct Atom {
+    index: u32,
+    name: Arc<str>,
+}
+
+#[derive(Default)]
+struct Interner {
+    // The index of each interned string. The table holds one reference to each string.
+    atoms: HashMap<Arc<str>, u32>,
+    // Indices of strings which have been removed, which are reused for new strings.
+    free: Vec<u32>,
+}
+
+impl Interner {
+    fn get(&self, name: &str) -> Option<Atom> {
+        self.atoms
+            .get_key_value(name)
+            .map(|(name, index)| Atom { index: *index, name: name.clone() })
+    }
+}
+
+fn interner() -> &'static RwLock<Interner> {
+    static INTERNER: OnceLock<RwLock<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(Default::default)
+}
+
+impl Atom {
+    /// Returns the atom for a string, adding the string to the table if it has not been interned before.
+    pub fn new(name: &str) -> Self {
+        if let Some(atom) = interner().read().unwrap().get(name) {
+            return atom;
+        }
+
+        let mut interner = interner().write().unwrap();
+        // The string may have been interned by another thread since the table was read.
+        if let Some(atom) = interner.get(name) {
+            return atom;
+        }
+
+        let index = interner.free.pop().unwrap_or(interner.atoms.len() as u32);
+        let name: Arc<str> = name.into();
+        interner.atoms.insert(name.clone(), index);
+        Atom { index, name }
+    }
+
+    /// Returns the interned string.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for Atom {
+    fn drop(&mut self) {
+        // The string is removed from the table when it is only referenced by the table and this atom.
+        if Arc::strong_count(&self.name) == 2 {
+            let Ok(mut interner) = interner().write() else { return };
+            // The string may have been interned again by another thread since the count was read. Once the table is
+            // locked, only this atom can create new references to the string.
+            if Arc::strong_count(&self.name) == 2 {
+                interner.atoms.remove(&*self.name);
+                interner.free.push(self.index);
+            }
+        }
+    }
+}
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for Atom {}
+
+impl Hash for Atom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl PartialOrd for Atom {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Atom {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl Default for Atom {
+    fn default() -> Self {
+        Atom::new("")
+    }
+}
+
+impl From<&str> for Atom {
+    fn from(name: &str) -> Self {
+        Atom::new(name)
+    }
+}
+
+impl From<&String> for Atom {
+    fn from(name: &String) -> Self {
+        Atom::new(name)
+    }
+}
+
+impl From<String> for Atom {
+    fn from(name: String) -> Self {
+        Atom::new(&name)
+    }
+}
+
+impl PartialEq<str> for Atom {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Atom {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Debug for Atom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Atom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PrecomputedHash for Atom {
+    fn precomputed_hash(&self) -> u32 {
+        // Spreads the bits of the index, as the ancestor bloom filter of the selector matching uses bits from
+        // different parts of the hash as separate keys.
+        fxhash::hash32(&self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interns_strings_once() {
+        let foo = Atom::new("foo");
+        let bar = Atom::from(String::from("bar"));
+
+        assert_ne!(foo, bar);
+        assert_eq!(foo, Atom::from("foo"));
+        assert_eq!(bar, "bar");
+        assert_eq!(foo.to_string(), "foo");
+    }
+
+    #[test]
+    fn frees_unused_strings() {
+        let name = "frees-unused-strings";
+        let atom = Atom::new(name);
+        let copy = atom.clone();
+
+        drop(atom);
+        assert!(interner().read().unwrap().atoms.contains_key(name));
+
+        drop(copy);
+        assert!(!interner().read().unwrap().atoms.contains_key(name));
+    }
+}
@@ -37,6 +37,9 @@ pub use stylesheet::*;
 mod selector;
 pub use selector::*;
 
+mod atom;
+pub use atom::Atom;
+
 pub use selectors;
 
 pub use precomputed_hash;
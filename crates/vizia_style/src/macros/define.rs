@@ -48,7 +48,7 @@ macro_rules! define_property {
         $vis:vis enum $name:ident<'i> {
             $(
                 $(#[$meta: meta])*
-                $str: literal: $variant: ident($inner_ty: ty),
+                $str: literal $(| $alias: literal)*: $variant: ident($inner_ty: ty),
             )+
         }
     ) => {
@@ -70,7 +70,7 @@ macro_rules! define_property {
                 let name_ref = name.as_ref();
                 match name_ref {
                     $(
-                        $str => {
+                        $str $(| $alias)* => {
                             if let Ok(val) = <$inner_ty>::parse(input) {
                                 return Ok($name::$variant(val));
                             }
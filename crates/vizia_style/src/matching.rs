@@ -113,7 +113,7 @@ mod test {
 
         fn has_local_name(&self, local_name: &SelectorIdent) -> bool {
             if let Some(element) = self.store.element.get(&self.entity) {
-                return element == &local_name.0;
+                return element.as_str() == local_name.0.as_str();
             }
 
             false
@@ -163,7 +163,7 @@ mod test {
             case_sensitivity: selectors::attr::CaseSensitivity,
         ) -> bool {
             if let Some(classes) = self.store.classes.get(&self.entity) {
-                return classes.contains(&name.0);
+                return classes.contains(name.0.as_str());
             }
 
             false
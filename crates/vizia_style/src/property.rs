@@ -1,11 +1,12 @@
 use crate::{
     define_property, Alignment, Angle, BackgroundImage, BackgroundSize, BlendMode, Border,
     BorderStyle, BorderWidth, ClipPath, Color, CornerRadius, CornerShape, CursorIcon,
-    CustomParseError, CustomProperty, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWidth, LayoutType, Length, LengthOrPercentage, LineClamp,
-    Opacity, Outline, Overflow, Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow,
-    TextAlign, TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, TextStroke,
-    TextStrokeStyle, Transform, Transition, Translate, Units, UnparsedProperty, Visibility,
+    CustomParseError, CustomProperty, Direction, Display, FilterFunction, FontFamily, FontSize,
+    FontSlant, FontVariation, FontWeight, FontWidth, LayoutType, Length, LengthOrPercentage,
+    LineClamp, LineHeight, Opacity, Outline, Overflow, Parse, PointerEvents, Position,
+    PositionType, Rect, Scale, Shadow, TextAlign, TextDecoration, TextDecorationLine,
+    TextDecorationStyle, TextOverflow, TextStroke, TextStrokeStyle, Transform, Transition,
+    Translate, Units, UnparsedProperty, Visibility,
 };
 use cssparser::Parser;
 
@@ -20,7 +21,7 @@ define_property! {
         "clip-path": ClipPath(ClipPath),
         "opacity": Opacity(Opacity),
         "z-index": ZIndex(i32),
-        "blend-mode": BlendMode(BlendMode),
+        "mix-blend-mode" | "blend-mode": BlendMode(BlendMode),
 
         // Positioning
         "layout-type": LayoutType(LayoutType),
@@ -155,6 +156,7 @@ define_property! {
         "caret-color": CaretColor(Color),
         "text-wrap": TextWrap(bool),
         "text-align": TextAlign(TextAlign),
+        "direction": Direction(Direction),
         "text-overflow": TextOverflow(TextOverflow),
         "line-clamp": LineClamp(LineClamp),
         "text-decoration": TextDecoration(TextDecoration),
@@ -162,6 +164,9 @@ define_property! {
         "text-stroke": TextStroke(TextStroke),
         "text-stroke-width": TextStrokeWidth(Length),
         "text-stroke-style": TextStrokeStyle(TextStrokeStyle),
+        "letter-spacing": LetterSpacing(Length),
+        "word-spacing": WordSpacing(Length),
+        "line-height": LineHeight(LineHeight),
         "underline-style": UnderlineStyle(TextDecorationStyle),
         "underline-thickness": UnderlineThickness(LengthOrPercentage),
         "underline-color": UnderlineColor(Color),
@@ -175,8 +180,9 @@ define_property! {
         // Shadow
         "shadow": Shadow(Vec<Shadow>),
 
-        // Backdrop Filter
-        "backdrop-filter": BackdropFilter(Filter),
+        // Filters
+        "backdrop-filter": BackdropFilter(Vec<FilterFunction>),
+        "filter": Filter(Vec<FilterFunction>),
 
         // Animations
         "transition": Transition(Vec<Transition>),
@@ -199,6 +205,7 @@ mod tests {
     use cssparser::{CowRcStr, ParserInput};
 
     use super::*;
+    use crate::LengthValue;
 
     #[test]
     fn parse_property() {
@@ -207,4 +214,46 @@ mod tests {
         let _parsed_property =
             Property::parse_value(CowRcStr::from("background-color"), &mut parser);
     }
+
+    #[test]
+    fn parse_blend_mode() {
+        for name in ["mix-blend-mode", "blend-mode"] {
+            let mut parser_input = ParserInput::new("multiply");
+            let mut parser = Parser::new(&mut parser_input);
+            let parsed_property = Property::parse_value(CowRcStr::from(name), &mut parser);
+            assert_eq!(parsed_property.ok(), Some(Property::BlendMode(BlendMode::Multiply)));
+        }
+    }
+
+    #[test]
+    fn parse_text_spacing() {
+        let mut parser_input = ParserInput::new("0.1em");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("letter-spacing"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::LetterSpacing(Length::Value(LengthValue::Em(0.1))))
+        );
+
+        let mut parser_input = ParserInput::new("4px");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("word-spacing"), &mut parser);
+        assert_eq!(parsed_property.ok(), Some(Property::WordSpacing(Length::px(4.0))));
+    }
+
+    #[test]
+    fn parse_line_height() {
+        let mut parser_input = ParserInput::new("1.5");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("line-height"), &mut parser);
+        assert_eq!(parsed_property.ok(), Some(Property::LineHeight(LineHeight::Number(1.5))));
+
+        let mut parser_input = ParserInput::new("24px");
+        let mut parser = Parser::new(&mut parser_input);
+        let parsed_property = Property::parse_value(CowRcStr::from("line-height"), &mut parser);
+        assert_eq!(
+            parsed_property.ok(),
+            Some(Property::LineHeight(LineHeight::Length(Length::px(24.0))))
+        );
+    }
 }
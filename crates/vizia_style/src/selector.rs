@@ -2,9 +2,9 @@ use std::hash::Hash;
 
 use cssparser::*;
 use precomputed_hash::PrecomputedHash;
-use selectors::SelectorImpl;
+use selectors::{parser::ParseRelative, SelectorImpl, SelectorList};
 
-use crate::{CustomParseError, Direction, Parse, ParserOptions, PseudoClass, PseudoElement};
+use crate::{Atom, CustomParseError, Direction, Parse, ParserOptions, PseudoClass, PseudoElement};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Selectors;
@@ -33,12 +33,14 @@ impl ToCss for SelectorString {
     }
 }
 
+/// An identifier in a selector, such as an element name, class, or id, which is interned so that it can be matched
+/// without comparing strings.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
-pub struct SelectorIdent(pub String);
+pub struct SelectorIdent(pub Atom);
 
 impl std::convert::From<&str> for SelectorIdent {
     fn from(s: &str) -> SelectorIdent {
-        SelectorIdent(s.to_string())
+        SelectorIdent(Atom::new(s))
     }
 }
 
@@ -47,19 +49,19 @@ impl ToCss for SelectorIdent {
     where
         W: std::fmt::Write,
     {
-        dest.write_str(&self.0)
+        dest.write_str(self.0.as_str())
     }
 }
 
 impl<'a> std::convert::From<CowRcStr<'a>> for SelectorIdent {
     fn from(s: CowRcStr<'a>) -> SelectorIdent {
-        SelectorIdent(s.to_string())
+        SelectorIdent(Atom::new(&s))
     }
 }
 
 impl PrecomputedHash for SelectorIdent {
     fn precomputed_hash(&self) -> u32 {
-        fxhash::hash32(self.0.as_str())
+        self.0.precomputed_hash()
     }
 }
 
@@ -78,6 +80,18 @@ impl SelectorImpl for Selectors {
     type ExtraMatchingData<'a> = ();
 }
 
+impl Selectors {
+    /// Parses a comma separated list of selectors, such as `"button.primary, #submit"`, returning `None` if the
+    /// input is not a valid selector list.
+    pub fn parse_list(input: &str) -> Option<SelectorList<Selectors>> {
+        let mut parser_input = ParserInput::new(input);
+        let mut parser = Parser::new(&mut parser_input);
+        let options = ParserOptions::default();
+        SelectorList::parse(&SelectorParser { options: &options }, &mut parser, ParseRelative::No)
+            .ok()
+    }
+}
+
 pub struct SelectorParser<'a, 'i> {
     pub options: &'a ParserOptions<'i>,
 }
@@ -86,6 +100,10 @@ impl<'i> selectors::parser::Parser<'i> for SelectorParser<'_, 'i> {
     type Impl = Selectors;
     type Error = CustomParseError<'i>;
 
+    fn parse_is_and_where(&self) -> bool {
+        true
+    }
+
     fn parse_non_ts_pseudo_class(
         &self,
         _: SourceLocation,
@@ -114,6 +132,8 @@ impl<'i> selectors::parser::Parser<'i> for SelectorParser<'_, 'i> {
             "optional" => Optional,
             "user-valid" => UserValid,
             "user-invalid" => UserInvalid,
+            "loading" => Loading,
+            "drag-over" => DragOver,
 
             _ => Custom(name.to_string())
 
@@ -1,12 +1,14 @@
 use crate::{macros::define_enum, Parse};
 
 define_enum! {
+    /// Determines the base direction of text, which sets the order of mixed left-to-right and right-to-left runs and
+    /// the side which text starts from.
     #[derive(Default)]
     pub enum Direction {
-        /// The entity will be rendered and acted on by the layout system.
+        /// Text is written from left to right, as in English.
         #[default]
         "ltr": Ltr,
-        /// The entity will not be rendered and acted on by the layout system.
+        /// Text is written from right to left, as in Arabic and Hebrew.
         "rtl": Rtl,
     }
 }
@@ -20,3 +22,12 @@ impl From<bool> for Direction {
         }
     }
 }
+
+impl From<Direction> for skia_safe::textlayout::TextDirection {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Ltr => skia_safe::textlayout::TextDirection::LTR,
+            Direction::Rtl => skia_safe::textlayout::TextDirection::RTL,
+        }
+    }
+}
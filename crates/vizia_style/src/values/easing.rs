@@ -1,6 +1,35 @@
-use crate::{CustomParseError, Parse};
+use crate::{impl_parse, CustomParseError, Parse};
 use cssparser::*;
 
+/// Determines where the jumps of a `steps()` easing function occur.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The first jump happens at the start of the animation.
+    Start,
+    /// The last jump happens at the end of the animation.
+    #[default]
+    End,
+    /// Jumps happen at both the start and the end of the animation.
+    Both,
+    /// There is no jump at the start or the end of the animation.
+    None,
+}
+
+impl_parse! {
+    StepPosition,
+
+    tokens {
+        ident {
+            "jump-start" => StepPosition::Start,
+            "jump-end" => StepPosition::End,
+            "jump-both" => StepPosition::Both,
+            "jump-none" => StepPosition::None,
+            "start" => StepPosition::Start,
+            "end" => StepPosition::End,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum EasingFunction {
     #[default]
@@ -10,7 +39,7 @@ pub enum EasingFunction {
     EaseOut,
     EaseInOut,
     CubicBezier(f32, f32, f32, f32),
-    // TODO: Steps
+    Steps(u32, StepPosition),
 }
 
 impl<'i> Parse<'i> for EasingFunction {
@@ -23,8 +52,8 @@ impl<'i> Parse<'i> for EasingFunction {
               "ease-in" => EasingFunction::EaseIn,
               "ease-out" => EasingFunction::EaseOut,
               "ease-in-out" => EasingFunction::EaseInOut,
-            //   "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::Start },
-            //   "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::End },
+              "step-start" => EasingFunction::Steps(1, StepPosition::Start),
+              "step-end" => EasingFunction::Steps(1, StepPosition::End),
               _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
             };
             return Ok(keyword);
@@ -44,16 +73,54 @@ impl<'i> Parse<'i> for EasingFunction {
                 let y2 = input.try_parse(|input| input.expect_number())?;
                 Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
               },
-            //   "steps" => {
-            //     let count = CSSInteger::parse(input)?;
-            //     let position = input.try_parse(|input| {
-            //       input.expect_comma()?;
-            //       StepPosition::parse(input)
-            //     }).unwrap_or_default();
-            //     Ok(EasingFunction::Steps { count, position })
-            //   },
+              "steps" => {
+                let count_location = input.current_source_location();
+                let count = input.expect_integer()?;
+                let position = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  StepPosition::parse(input)
+                }).unwrap_or_default();
+                // A `jump-none` easing needs at least two steps so that both end points are reached.
+                let min_count = if position == StepPosition::None { 2 } else { 1 };
+                if count < min_count {
+                  return Err(count_location.new_custom_error(CustomParseError::InvalidValue));
+                }
+                Ok(EasingFunction::Steps(count as u32, position))
+              },
               _ => Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
             }
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        EasingFunction, parse_easing_function,
+
+        custom {
+            success {
+                "linear" => EasingFunction::Linear,
+                "ease-in-out" => EasingFunction::EaseInOut,
+                "cubic-bezier(0.1, 0.2, 0.3, 0.4)" => EasingFunction::CubicBezier(0.1, 0.2, 0.3, 0.4),
+                "step-start" => EasingFunction::Steps(1, StepPosition::Start),
+                "step-end" => EasingFunction::Steps(1, StepPosition::End),
+                "steps(4)" => EasingFunction::Steps(4, StepPosition::End),
+                "steps(4, end)" => EasingFunction::Steps(4, StepPosition::End),
+                "steps(4, jump-start)" => EasingFunction::Steps(4, StepPosition::Start),
+                "steps(3, jump-both)" => EasingFunction::Steps(3, StepPosition::Both),
+                "steps(2, jump-none)" => EasingFunction::Steps(2, StepPosition::None),
+            }
+
+            failure {
+                "test",
+                "steps(0)",
+                "steps(1, jump-none)",
+                "steps(1.5)",
+            }
+        }
+    }
+}
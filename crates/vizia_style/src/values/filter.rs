@@ -0,0 +1,161 @@
+use crate::{Angle, Color, CustomParseError, Length, Parse, PercentageOrNumber, Shadow};
+use cssparser::*;
+
+/// A filter function of the `filter` and `backdrop-filter` properties.
+///
+/// Functions which take an amount accept a number or a percentage, where `1.0` or `100%` leaves the content unchanged
+/// for `brightness()`, `contrast()`, `opacity()`, and `saturate()`, and applies the full effect for `grayscale()`,
+/// `invert()`, and `sepia()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterFunction {
+    /// Blurs the content with a gaussian blur of the given radius.
+    Blur(Length),
+    /// Scales the brightness of the content.
+    Brightness(f32),
+    /// Scales the contrast of the content.
+    Contrast(f32),
+    /// Draws a shadow of the content, offset and blurred, below it.
+    DropShadow(Shadow),
+    /// Converts the content to grayscale.
+    Grayscale(f32),
+    /// Rotates the hue of the content.
+    HueRotate(Angle),
+    /// Inverts the colors of the content.
+    Invert(f32),
+    /// Scales the opacity of the content.
+    Opacity(f32),
+    /// Scales the saturation of the content.
+    Saturate(f32),
+    /// Converts the content to sepia.
+    Sepia(f32),
+}
+
+/// An alias of [`FilterFunction`].
+pub type Filter = FilterFunction;
+
+impl Default for FilterFunction {
+    fn default() -> Self {
+        FilterFunction::Blur(Length::default())
+    }
+}
+
+impl<'i> Parse<'i> for FilterFunction {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let function = input.expect_function()?.clone();
+
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+            match_ignore_ascii_case! { &function,
+                "blur" => {
+                    Ok(FilterFunction::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
+                },
+
+                "brightness" => Ok(FilterFunction::Brightness(parse_amount(input))),
+                "contrast" => Ok(FilterFunction::Contrast(parse_amount(input))),
+                "grayscale" => Ok(FilterFunction::Grayscale(parse_amount(input))),
+                "invert" => Ok(FilterFunction::Invert(parse_amount(input))),
+                "opacity" => Ok(FilterFunction::Opacity(parse_amount(input))),
+                "saturate" => Ok(FilterFunction::Saturate(parse_amount(input))),
+                "sepia" => Ok(FilterFunction::Sepia(parse_amount(input))),
+
+                "hue-rotate" => {
+                    Ok(FilterFunction::HueRotate(input.try_parse(Angle::parse).unwrap_or_default()))
+                },
+
+                "drop-shadow" => {
+                    // The color of a drop shadow can come before or after its offsets and blur radius.
+                    let color = input.try_parse(Color::parse).ok();
+                    let mut shadow = Shadow::parse(input)?;
+                    if shadow.color.is_none() {
+                        shadow.color = color;
+                    }
+
+                    Ok(FilterFunction::DropShadow(shadow))
+                },
+
+                _ => {
+                    Err(location.new_unexpected_token_error(Token::Ident(function)))
+                }
+            }
+        })
+    }
+}
+
+// Parses the amount of a filter function, which defaults to 1 when it is omitted.
+fn parse_amount(input: &mut Parser) -> f32 {
+    input.try_parse(PercentageOrNumber::parse).map_or(1.0, |amount| amount.to_factor())
+}
+
+impl<'i> Parse<'i> for Vec<FilterFunction> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let mut results = vec![FilterFunction::parse(input)?];
+        loop {
+            if input.is_exhausted() {
+                return Ok(results);
+            }
+
+            input.skip_whitespace();
+            let location = input.current_source_location();
+
+            if let Ok(filter) = input.try_parse(FilterFunction::parse) {
+                results.push(filter);
+            } else {
+                return Err(cssparser::ParseError {
+                    kind: cssparser::ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+                    location,
+                });
+            }
+        }
+    }
+}
+
+impl From<FilterFunction> for Vec<FilterFunction> {
+    fn from(value: FilterFunction) -> Self {
+        vec![value]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Vec<FilterFunction>, parse_filter,
+
+        custom {
+            success {
+                "none" => vec![],
+                "blur(16px) saturate(180%)" => vec![
+                    FilterFunction::Blur(Length::px(16.0)),
+                    FilterFunction::Saturate(1.8),
+                ],
+                "brightness(0.5) hue-rotate(90deg) invert()" => vec![
+                    FilterFunction::Brightness(0.5),
+                    FilterFunction::HueRotate(Angle::Deg(90.0)),
+                    FilterFunction::Invert(1.0),
+                ],
+                "drop-shadow(red 2px 4px 6px)" => vec![
+                    FilterFunction::DropShadow(Shadow::new(
+                        Length::px(2.0),
+                        Length::px(4.0),
+                        Some(Length::px(6.0)),
+                        None,
+                        Some(Color::rgb(255, 0, 0)),
+                        false,
+                    )),
+                ],
+            }
+
+            failure {
+                "test",
+                "blur(16px) 123",
+                "sharpen(2)",
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use cssparser::*;
+
+use crate::{impl_parse, Length, Parse};
+
+/// The height of a line of text.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum LineHeight {
+    /// The line height is determined by the metrics of the font.
+    #[default]
+    Normal,
+    /// The line height is a multiple of the font size.
+    Number(f32),
+    /// The line height is a fixed length, where `em` units are relative to the font size.
+    Length(Length),
+}
+
+impl_parse! {
+    LineHeight,
+
+    custom {
+        |input| {
+            if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+                return Ok(LineHeight::Normal);
+            }
+
+            if let Ok(number) = input.try_parse(f32::parse) {
+                return Ok(LineHeight::Number(number));
+            }
+
+            Ok(LineHeight::Length(Length::parse(input)?))
+        }
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(number: f32) -> Self {
+        LineHeight::Number(number)
+    }
+}
+
+impl From<f64> for LineHeight {
+    fn from(number: f64) -> Self {
+        LineHeight::Number(number as f32)
+    }
+}
+
+impl From<Length> for LineHeight {
+    fn from(length: Length) -> Self {
+        LineHeight::Length(length)
+    }
+}
+
+impl From<&str> for LineHeight {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        LineHeight::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LineHeight, parse_line_height,
+
+        custom {
+            success {
+                "normal" => LineHeight::Normal,
+                "1.5" => LineHeight::Number(1.5),
+                "2" => LineHeight::Number(2.0),
+                "24px" => LineHeight::Length(Length::px(24.0)),
+            }
+
+            failure {
+                "test",
+                "auto",
+            }
+        }
+    }
+}
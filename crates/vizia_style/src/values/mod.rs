@@ -1,7 +1,6 @@
 pub mod alignment;
 pub mod alpha;
 pub mod angle;
-pub mod backdrop_filter;
 pub mod background_size;
 pub mod basic;
 pub mod blend_mode;
@@ -16,6 +15,7 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod filter;
 pub mod font_family;
 pub mod font_size;
 pub mod font_size_keyword;
@@ -32,6 +32,7 @@ pub mod layout_type;
 pub mod length;
 pub mod length_or_percentage;
 pub mod length_percentage_auto;
+pub mod line_height;
 pub mod matrix;
 pub mod number_or_percentage;
 pub mod opacity;
@@ -60,7 +61,6 @@ pub mod visibility;
 pub use alignment::*;
 pub use alpha::*;
 pub use angle::*;
-pub use backdrop_filter::*;
 pub use background_size::*;
 pub use basic::*;
 pub use blend_mode::*;
@@ -75,6 +75,7 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use filter::*;
 pub use font_family::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
@@ -91,6 +92,7 @@ pub use layout_type::*;
 pub use length::*;
 pub use length_or_percentage::*;
 pub use length_percentage_auto::*;
+pub use line_height::*;
 pub use matrix::*;
 pub use number_or_percentage::*;
 pub use opacity::*;
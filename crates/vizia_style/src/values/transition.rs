@@ -1,5 +1,5 @@
 use crate::{duration::Duration, CustomParseError, EasingFunction, Ident, Parse};
-use cssparser::{ParseError, ParseErrorKind, Parser};
+use cssparser::{match_ignore_ascii_case, ParseError, ParseErrorKind, Parser, Token};
 
 /// Defines a transition that allows to change property values smoothly, over a given duration.
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -12,6 +12,9 @@ pub struct Transition {
     pub delay: Option<Duration>,
 
     pub timing_function: Option<EasingFunction>,
+    /// Whether discrete properties such as `display` and `visibility` are transitioned, set with the
+    /// `allow-discrete` keyword.
+    pub allow_discrete: bool,
 }
 
 impl Transition {
@@ -22,7 +25,7 @@ impl Transition {
         delay: Option<Duration>,
         timing_function: Option<EasingFunction>,
     ) -> Self {
-        Self { property, duration, delay, timing_function }
+        Self { property, duration, delay, timing_function, allow_discrete: false }
     }
 }
 
@@ -34,9 +37,20 @@ impl<'i> Parse<'i> for Transition {
         let duration = Duration::parse(input)?;
         let delay = input.try_parse(Duration::parse).ok();
         let timing_function = input.try_parse(EasingFunction::parse).ok();
+        let allow_discrete = input
+            .try_parse(|input| -> Result<bool, ParseError<'i, CustomParseError<'i>>> {
+                let location = input.current_source_location();
+                let ident = input.expect_ident()?;
+                match_ignore_ascii_case! { ident,
+                    "allow-discrete" => Ok(true),
+                    "normal" => Ok(false),
+                    _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+                }
+            })
+            .unwrap_or_default();
 
         if input.is_exhausted() {
-            Ok(Self { property, duration, delay, timing_function })
+            Ok(Self { property, duration, delay, timing_function, allow_discrete })
         } else {
             Err(ParseError {
                 kind: ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
@@ -65,11 +79,14 @@ mod tests {
                 "width 2s" => Transition::new(String::from("width"), Duration::from_secs(2), None, None),
                 "height 2s 1s" => Transition::new(String::from("height"), Duration::from_secs(2), Some(Duration::from_secs(1)), None),
                 "color 200ms linear" => Transition::new(String::from("color"), Duration::from_millis(200), None, Some(EasingFunction::Linear)),
+                "display 1s allow-discrete" => Transition { allow_discrete: true, ..Transition::new(String::from("display"), Duration::from_secs(1), None, None) },
+                "display 1s ease normal" => Transition::new(String::from("display"), Duration::from_secs(1), None, Some(EasingFunction::Ease)),
             }
 
             failure {
                 "height 2s 1s 1s",
                 "1s 2s height",
+                "display 1s discrete",
             }
         }
     }
@@ -54,6 +54,93 @@ impl From<WindowPosition> for (u32, u32) {
     }
 }
 
+/// The geometry of a window, which can be saved when the application exits and restored the next time it runs.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct WindowGeometry {
+    /// The logical position of the window.
+    pub position: WindowPosition,
+    /// The logical inner size of the window.
+    pub size: WindowSize,
+    /// Whether the window is maximized.
+    pub maximized: bool,
+    /// The name of the monitor the window is on.
+    pub monitor: Option<String>,
+}
+
+/// A monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The name of the monitor, if available.
+    pub name: Option<String>,
+    /// The physical position of the top-left corner of the monitor in screen coordinates.
+    ///
+    /// The position can be negative for monitors placed to the left of or above the primary monitor.
+    pub position: (i32, i32),
+    /// The logical size of the monitor.
+    pub size: WindowSize,
+    /// The scale factor of the monitor.
+    pub scale_factor: f64,
+    /// Whether the monitor is the primary monitor.
+    pub is_primary: bool,
+}
+
+impl Monitor {
+    /// Returns whether the given physical point in screen coordinates lies within the monitor.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let width = (self.size.width as f64 * self.scale_factor).round() as i32;
+        let height = (self.size.height as f64 * self.scale_factor).round() as i32;
+
+        x >= self.position.0
+            && y >= self.position.1
+            && x < self.position.0 + width
+            && y < self.position.1 + height
+    }
+}
+
+/// The placement of a window within the area of a monitor.
+///
+/// The window is placed within the full area of the monitor rather than its work area, since the area taken up by
+/// taskbars and docks is not available from the windowing backends.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorAnchor {
+    /// The window is centered on the monitor.
+    #[default]
+    Center,
+    /// The window is placed in the top-left corner of the monitor.
+    TopLeft,
+    /// The window is placed in the top-right corner of the monitor.
+    TopRight,
+    /// The window is placed in the bottom-left corner of the monitor.
+    BottomLeft,
+    /// The window is placed in the bottom-right corner of the monitor.
+    BottomRight,
+}
+
+/// The level of a window relative to other windows.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowLevel {
+    /// The window is always below other windows.
+    AlwaysOnBottom,
+    /// The window is ordered with other windows as normal.
+    #[default]
+    Normal,
+    /// The window is always above other windows.
+    AlwaysOnTop,
+}
+
+/// The edge or corner of a window from which it is resized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResizeDirection {
+    East,
+    North,
+    NorthEast,
+    NorthWest,
+    South,
+    SouthEast,
+    SouthWest,
+    West,
+}
+
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct WindowButtons: u32 {
@@ -73,13 +160,29 @@ pub struct WindowDescription {
     /// A scale factor applied on top of any DPI scaling, defaults to 1.0.
     pub user_scale_factor: f64,
     pub position: Option<WindowPosition>,
+    /// The name of the monitor the position refers to. If the monitor is unavailable when the window is
+    /// created, the window is moved onto the primary monitor.
+    pub monitor: Option<String>,
+    /// Places the window within the area of the monitor named by `monitor`, or the primary monitor if no
+    /// monitor is named. The `position`, if any, is then treated as a margin from the anchored edges of the monitor.
+    pub monitor_anchor: Option<MonitorAnchor>,
     pub resizable: bool,
     pub minimized: bool,
     pub maximized: bool,
     pub visible: bool,
     pub transparent: bool,
+    /// Whether the background behind a transparent window is blurred. Only supported on Windows, macOS and
+    /// Wayland compositors which implement the KDE blur protocol.
+    pub blur: bool,
     pub decorations: bool,
+    /// Whether the window is always above other windows. When `true` this takes precedence over `window_level`.
+    #[deprecated(note = "use `window_level` instead")]
     pub always_on_top: bool,
+    pub window_level: WindowLevel,
+    /// Whether the window is hidden from the taskbar. Only supported on Windows.
+    pub skip_taskbar: bool,
+    /// Whether the window is visible on all workspaces. Only supported on macOS and X11.
+    pub visible_on_all_workspaces: bool,
     pub vsync: bool,
     pub enabled_window_buttons: WindowButtons,
 
@@ -91,6 +194,7 @@ pub struct WindowDescription {
 
 impl Default for WindowDescription {
     fn default() -> Self {
+        #[allow(deprecated)]
         Self {
             title: "Vizia Application".to_string(),
             inner_size: WindowSize::new(800, 600),
@@ -98,13 +202,19 @@ impl Default for WindowDescription {
             max_inner_size: None,
             user_scale_factor: 1.0,
             position: None,
+            monitor: None,
+            monitor_anchor: None,
             resizable: true,
             minimized: true,
             maximized: false,
             visible: true,
             transparent: false,
+            blur: false,
             decorations: true,
             always_on_top: false,
+            window_level: WindowLevel::Normal,
+            skip_taskbar: false,
+            visible_on_all_workspaces: false,
             vsync: true,
             enabled_window_buttons: WindowButtons::all(),
 
@@ -159,7 +269,49 @@ impl WindowDescription {
     }
 
     pub fn with_always_on_top(mut self, flag: bool) -> Self {
-        self.always_on_top = flag;
+        self.set_window_level(if flag { WindowLevel::AlwaysOnTop } else { WindowLevel::Normal });
+
+        self
+    }
+
+    pub fn with_window_level(mut self, level: WindowLevel) -> Self {
+        self.set_window_level(level);
+
+        self
+    }
+
+    /// Sets the level of the window and clears the deprecated `always_on_top` flag so that it no longer overrides it.
+    #[allow(deprecated)]
+    pub fn set_window_level(&mut self, level: WindowLevel) {
+        self.always_on_top = false;
+        self.window_level = level;
+    }
+
+    /// Returns the level the window should be created with, mapping the deprecated `always_on_top` flag onto
+    /// [`WindowLevel::AlwaysOnTop`].
+    #[allow(deprecated)]
+    pub fn effective_window_level(&self) -> WindowLevel {
+        if self.always_on_top {
+            return WindowLevel::AlwaysOnTop;
+        }
+
+        self.window_level
+    }
+
+    pub fn with_skip_taskbar(mut self, flag: bool) -> Self {
+        self.skip_taskbar = flag;
+
+        self
+    }
+
+    pub fn with_transparent(mut self, flag: bool) -> Self {
+        self.transparent = flag;
+
+        self
+    }
+
+    pub fn with_blur(mut self, flag: bool) -> Self {
+        self.blur = flag;
 
         self
     }
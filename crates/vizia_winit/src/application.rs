@@ -1,8 +1,13 @@
+#[cfg(feature = "global-shortcuts")]
+use crate::global_shortcut::GLOBAL_SHORTCUTS;
 #[cfg(target_os = "windows")]
 use crate::window::set_cloak;
 use crate::{
-    convert::{winit_key_code_to_code, winit_key_to_key},
-    window::{WinState, Window},
+    convert::{window_level_to_window_level, winit_key_code_to_code, winit_key_to_key},
+    window::{
+        cursor_position, set_blur, set_skip_taskbar, set_visible_on_all_workspaces,
+        supports_positioning, CloseRequestedCallback, WinState, Window,
+    },
     window_modifiers::WindowModifiers,
 };
 #[cfg(feature = "accesskit")]
@@ -25,7 +30,7 @@ use winit::{
     event::ElementState,
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy},
     keyboard::{NativeKeyCode, PhysicalKey},
-    window::{CursorIcon, CustomCursor, WindowAttributes, WindowId, WindowLevel},
+    window::{CursorIcon, CustomCursor, WindowAttributes, WindowId},
 };
 
 // #[cfg(all(
@@ -42,11 +47,19 @@ use winit::{
 // use raw_window_handle::{HasRawDisplayHandle, RawDisplayHandle};
 use vizia_window::WindowPosition;
 
+// The time without input after which the application is considered idle.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// The interval at which the monitors are read again to detect changes to the monitor configuration.
+const MONITOR_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Debug)]
 pub enum UserEvent {
     Event(Event),
     #[cfg(feature = "accesskit")]
     AccessKitEvent(accesskit_winit::Event),
+    #[cfg(feature = "global-shortcuts")]
+    GlobalHotKeyEvent(global_hotkey::GlobalHotKeyEvent),
 }
 
 #[cfg(feature = "accesskit")]
@@ -98,11 +111,22 @@ pub struct Application {
     event_manager: EventManager,
     pub(crate) event_loop: Option<EventLoop<UserEvent>>,
     on_idle: IdleCallback,
+    on_close_requested: CloseRequestedCallback,
     window_description: WindowDescription,
     control_flow: ControlFlow,
     event_loop_proxy: EventLoopProxy<UserEvent>,
     windows: HashMap<WindowId, WinState>,
     window_ids: HashMap<Entity, WindowId>,
+    // The touch which also acts as the left mouse button.
+    primary_touch: Option<u64>,
+    max_fps: Option<u32>,
+    idle_fps: Option<u32>,
+    // The time at which a window was last drawn, used to pace frames when the framerate is capped.
+    last_frame: Instant,
+    // The time at which input was last received, used to determine whether the application is idle.
+    last_input: Instant,
+    // The time at which the monitors were last read.
+    last_monitor_poll: Instant,
     #[cfg(feature = "accesskit")]
     accesskit_adapter: Option<accesskit_winit::Adapter>,
     #[cfg(feature = "accesskit")]
@@ -135,22 +159,49 @@ impl Application {
         let event_proxy_obj = event_loop.create_proxy();
         cx.set_event_proxy(Box::new(WinitEventProxy(event_proxy_obj)));
 
+        // Show the inspector of a window in a separate window.
+        #[cfg(feature = "inspector")]
+        cx.set_inspector_window(|cx, window| {
+            Window::new(cx, move |cx| {
+                Inspector::new(cx, window);
+            })
+            .title("Inspector")
+            .inner_size((360, 640));
+        });
+
         cx.renegotiate_language();
         cx.0.remove_user_themes();
         (content)(cx.context());
 
         let proxy = event_loop.create_proxy();
 
+        // Wake the event loop when a global shortcut is pressed, as the events are received on another thread on
+        // some platforms.
+        #[cfg(feature = "global-shortcuts")]
+        {
+            let proxy = std::sync::Mutex::new(event_loop.create_proxy());
+            global_hotkey::GlobalHotKeyEvent::set_event_handler(Some(move |event| {
+                let _ = proxy.lock().unwrap().send_event(UserEvent::GlobalHotKeyEvent(event));
+            }));
+        }
+
         Self {
             cx,
             event_manager: EventManager::new(),
             event_loop: Some(event_loop),
             on_idle: None,
+            on_close_requested: None,
             window_description: WindowDescription::new(),
             control_flow: ControlFlow::Wait,
             event_loop_proxy: proxy,
             windows: HashMap::new(),
             window_ids: HashMap::new(),
+            primary_touch: None,
+            max_fps: None,
+            idle_fps: None,
+            last_frame: Instant::now(),
+            last_input: Instant::now(),
+            last_monitor_poll: Instant::now(),
             #[cfg(feature = "accesskit")]
             accesskit_adapter: None,
             #[cfg(feature = "accesskit")]
@@ -163,20 +214,80 @@ impl Application {
         event_loop: &ActiveEventLoop,
         window_entity: Entity,
         window_description: &WindowDescription,
-        #[allow(unused_variables)] owner: Option<Arc<winit::window::Window>>,
+        owner: Option<Arc<winit::window::Window>>,
     ) -> Result<Arc<winit::window::Window>, Box<dyn Error>> {
         #[allow(unused_mut)]
         let mut window_attributes = apply_window_description(window_description);
 
+        // A window placed on a monitor which is no longer available is moved onto the primary monitor, and that
+        // position is kept. Otherwise a window anchored to a monitor is placed within it, and the position of a
+        // child window is relative to the window which owns it.
+        if let Some((position, size)) = clamp_to_monitor(event_loop, window_description) {
+            window_attributes = window_attributes.with_position(position).with_inner_size(size);
+        } else if let Some(position) = anchor_to_monitor(event_loop, window_description) {
+            window_attributes = window_attributes.with_position(position);
+        } else if let (Some(owner), Some(position)) = (&owner, window_description.position) {
+            if let Ok(owner_position) = owner.outer_position() {
+                let owner_position: LogicalPosition<f64> =
+                    owner_position.to_logical(owner.scale_factor());
+                window_attributes = window_attributes.with_position(LogicalPosition::new(
+                    owner_position.x + position.x as f64,
+                    owner_position.y + position.y as f64,
+                ));
+            }
+        }
+
+        // Windows cannot be positioned by the application on Wayland. Child windows would need to be created as
+        // xdg-popups to be placed relative to their parent, which winit does not expose, so the placement is left
+        // to the compositor while the size of the window is still applied.
+        if !supports_positioning(event_loop) {
+            window_attributes.position = None;
+        }
+
         let window_state = WinState::new(event_loop, window_entity, window_attributes, owner)?;
         let window = window_state.window.clone();
 
+        if window_description.skip_taskbar {
+            set_skip_taskbar(&window, true);
+        }
+
+        if window_description.visible_on_all_workspaces {
+            set_visible_on_all_workspaces(&window, true);
+        }
+
+        if window_description.blur {
+            set_blur(&window, true);
+        }
+
         let window_id = window_state.window.id();
         self.windows.insert(window_id, window_state);
         self.window_ids.insert(window_entity, window_id);
         Ok(window)
     }
 
+    // Returns the time between frames on the monitor of the first window, defaulting to 60 frames per second, or the
+    // time between frames given by the framerate cap if that is longer.
+    fn frame_interval(&self) -> Duration {
+        let refresh_rate = self
+            .windows
+            .values()
+            .next()
+            .and_then(|window| window.window().current_monitor())
+            .and_then(|monitor| monitor.refresh_rate_millihertz())
+            .unwrap_or(60_000);
+
+        let interval = Duration::from_secs_f64(1000.0 / refresh_rate as f64);
+        self.frame_cap().map_or(interval, |cap| cap.max(interval))
+    }
+
+    // Returns the minimum time between frames, which is given by the idle framerate when no window is focused or no
+    // input has been received for a while, and by the maximum framerate otherwise.
+    fn frame_cap(&self) -> Option<Duration> {
+        let idle = !self.cx.0.window_has_focus || self.last_input.elapsed() >= IDLE_TIMEOUT;
+        let fps = if idle { self.idle_fps.or(self.max_fps) } else { self.max_fps };
+        fps.filter(|fps| *fps > 0).map(|fps| Duration::from_secs_f64(1.0 / fps as f64))
+    }
+
     /// Sets the default built-in theming to be ignored.
     pub fn ignore_default_theme(mut self) -> Self {
         self.cx.context().ignore_default_theme = true;
@@ -189,6 +300,40 @@ impl Application {
         self
     }
 
+    /// Limits the rate at which windows are redrawn to a maximum number of frames per second.
+    ///
+    /// Redraws which are requested sooner are delayed until the next frame is due, so that any number of changes
+    /// within a frame are drawn together.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .with_max_fps(60)
+    /// .run();
+    /// ```
+    pub fn with_max_fps(mut self, fps: u32) -> Self {
+        self.max_fps = Some(fps);
+
+        self
+    }
+
+    /// Limits the rate at which windows are redrawn while the application is idle, which is when no window is
+    /// focused or no input has been received for a few seconds.
+    ///
+    /// This reduces the CPU and GPU usage of an application with animations running in the background. An application
+    /// with nothing to redraw does not redraw at all, whether or not it is idle.
+    pub fn with_idle_fps(mut self, fps: u32) -> Self {
+        self.idle_fps = Some(fps);
+
+        self
+    }
+
     /// Takes a closure which will be called at the end of every loop of the application.
     ///
     /// The callback provides a place to run 'idle' processing and happens at the end of each loop but before drawing.
@@ -215,6 +360,35 @@ impl Application {
         self
     }
 
+    /// Catches panics in views while they handle events or are drawn, calling the handler instead of exiting.
+    ///
+    /// The view which panicked, or the closest view marked with
+    /// [`panic_boundary`](vizia_core::view::Handle::panic_boundary) containing it, is disabled and its children
+    /// replaced with the panic message. The default panic hook still prints the panic.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// #
+    /// Application::new(|cx| {
+    ///     // Build application here
+    /// })
+    /// .with_panic_handler(|_cx, panic| {
+    ///     log::error!("{}", panic.message);
+    /// })
+    /// .run();
+    /// ```
+    pub fn with_panic_handler(
+        mut self,
+        handler: impl Fn(&mut EventContext, &ViewPanic) + 'static,
+    ) -> Self {
+        self.cx.set_panic_handler(handler);
+
+        self
+    }
+
     /// Returns a `ContextProxy` which can be used to send events from another thread.
     pub fn get_proxy(&self) -> ContextProxy {
         self.cx.0.get_proxy()
@@ -266,11 +440,22 @@ impl ApplicationHandler<UserEvent> for Application {
                     accesskit_winit::WindowEvent::AccessibilityDeactivated => todo!(),
                 }
             }
+
+            #[cfg(feature = "global-shortcuts")]
+            UserEvent::GlobalHotKeyEvent(hotkey_event) => {
+                if let Some(event) =
+                    GLOBAL_SHORTCUTS.with_borrow(|shortcuts| shortcuts.event(hotkey_event))
+                {
+                    self.cx.send_event(event);
+                }
+            }
         }
     }
 
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.windows.is_empty() {
+            self.cx.set_monitors(available_monitors(event_loop));
+
             // Create the main window
             let main_window: Arc<winit::window::Window> = self
                 .create_window(event_loop, Entity::root(), &self.window_description.clone(), None)
@@ -285,6 +470,7 @@ impl ApplicationHandler<UserEvent> for Application {
                 window: Some(main_window.clone()),
                 on_close: None,
                 on_create: None,
+                on_close_requested: self.on_close_requested.take(),
                 should_close: false,
                 custom_cursors: custom_cursors.clone(),
             });
@@ -364,7 +550,7 @@ impl ApplicationHandler<UserEvent> for Application {
 
     fn window_event(
         &mut self,
-        _event_loop: &ActiveEventLoop,
+        event_loop: &ActiveEventLoop,
         window_id: WindowId,
         event: winit::event::WindowEvent,
     ) {
@@ -373,6 +559,18 @@ impl ApplicationHandler<UserEvent> for Application {
             None => return,
         };
 
+        if matches!(
+            event,
+            winit::event::WindowEvent::CursorMoved { .. }
+                | winit::event::WindowEvent::MouseInput { .. }
+                | winit::event::WindowEvent::MouseWheel { .. }
+                | winit::event::WindowEvent::KeyboardInput { .. }
+                | winit::event::WindowEvent::Touch(_)
+                | winit::event::WindowEvent::Focused(true)
+        ) {
+            self.last_input = Instant::now();
+        }
+
         match event {
             winit::event::WindowEvent::Resized(size) => {
                 window.resize(size);
@@ -380,6 +578,23 @@ impl ApplicationHandler<UserEvent> for Application {
                 self.cx.needs_refresh(window.entity);
                 window.window().request_redraw();
 
+                // Disconnecting a monitor can resize and move the windows on it to another monitor, so the
+                // monitors are read again when a window changes monitor.
+                if self.cx.set_window_monitor(
+                    window.entity,
+                    window.window().current_monitor().and_then(|monitor| monitor.name()),
+                ) {
+                    self.cx.set_monitors(available_monitors(event_loop));
+                }
+
+                // Maximizing, minimizing, and toggling fullscreen all resize the window.
+                self.cx.set_window_state(
+                    window.entity,
+                    window.window().is_minimized().unwrap_or(false),
+                    window.window().is_maximized(),
+                    window.window().fullscreen().is_some(),
+                );
+
                 #[cfg(target_os = "windows")]
                 {
                     self.event_manager.flush_events(self.cx.context(), |_| {});
@@ -416,19 +631,55 @@ impl ApplicationHandler<UserEvent> for Application {
             }
 
             winit::event::WindowEvent::Moved(position) => {
+                // The event reports the outer position of the window, while the cursor position is relative to the
+                // client area, so the inner position is stored where the platform supports it.
+                let position = window.window().inner_position().unwrap_or(position);
                 self.cx.set_window_position(window.entity, position.x as f32, position.y as f32);
+                if self.cx.set_window_monitor(
+                    window.entity,
+                    window.window().current_monitor().and_then(|monitor| monitor.name()),
+                ) {
+                    self.cx.set_monitors(available_monitors(event_loop));
+                }
             }
 
-            winit::event::WindowEvent::CloseRequested | winit::event::WindowEvent::Destroyed => {
+            winit::event::WindowEvent::CloseRequested => {
+                let window_entity = window.entity;
+                self.cx.emit_close_requested(window_entity);
+                self.event_manager.flush_events(self.cx.context(), |_| {});
+
+                if !self.cx.close_prevented(window_entity) {
+                    self.cx.emit_window_event(window_entity, WindowEvent::WindowClose);
+                }
+            }
+
+            winit::event::WindowEvent::Destroyed => {
                 let window_entity = window.entity;
                 self.cx.emit_window_event(window_entity, WindowEvent::WindowClose);
             }
             winit::event::WindowEvent::DroppedFile(path) => {
+                // Cursor events are not received on Windows while a file is dragged over the window.
+                if let Some(position) = cursor_position(window.window()) {
+                    let (x, y) = (position.x as f32, position.y as f32);
+                    self.cx.emit_window_event(window.entity, WindowEvent::MouseMove(x, y));
+                }
+
                 self.cx.emit_window_event(window.entity, WindowEvent::Drop(DropData::File(path)));
             }
 
-            winit::event::WindowEvent::HoveredFile(_) => {}
-            winit::event::WindowEvent::HoveredFileCancelled => {}
+            winit::event::WindowEvent::HoveredFile(path) => {
+                // Cursor events are not received on Windows while a file is dragged over the window.
+                if let Some(position) = cursor_position(window.window()) {
+                    let (x, y) = (position.x as f32, position.y as f32);
+                    self.cx.emit_window_event(window.entity, WindowEvent::MouseMove(x, y));
+                }
+
+                self.cx.emit_window_event(window.entity, WindowEvent::FileHovered(path));
+            }
+
+            winit::event::WindowEvent::HoveredFileCancelled => {
+                self.cx.emit_window_event(window.entity, WindowEvent::FileHoverCancelled);
+            }
             winit::event::WindowEvent::Focused(is_focused) => {
                 self.cx.emit_window_event(window.entity, WindowEvent::WindowFocused(is_focused));
 
@@ -469,7 +720,6 @@ impl ApplicationHandler<UserEvent> for Application {
                 };
 
                 self.cx.emit_window_event(window.entity, event);
-                window.window().request_redraw();
             }
             winit::event::WindowEvent::ModifiersChanged(modifiers) => {
                 self.cx.modifiers().set(Modifiers::SHIFT, modifiers.state().shift_key());
@@ -479,8 +729,6 @@ impl ApplicationHandler<UserEvent> for Application {
                 self.cx.modifiers().set(Modifiers::CTRL, modifiers.state().control_key());
 
                 self.cx.modifiers().set(Modifiers::SUPER, modifiers.state().super_key());
-
-                window.window().request_redraw();
             }
             winit::event::WindowEvent::Ime(_) => {}
             winit::event::WindowEvent::CursorMoved { device_id: _, position } => {
@@ -488,15 +736,12 @@ impl ApplicationHandler<UserEvent> for Application {
                     window.entity,
                     WindowEvent::MouseMove(position.x as f32, position.y as f32),
                 );
-                window.window().request_redraw();
             }
             winit::event::WindowEvent::CursorEntered { device_id: _ } => {
                 self.cx.emit_window_event(window.entity, WindowEvent::MouseEnter);
-                window.window().request_redraw();
             }
             winit::event::WindowEvent::CursorLeft { device_id: _ } => {
                 self.cx.emit_window_event(window.entity, WindowEvent::MouseLeave);
-                window.window().request_redraw();
             }
             winit::event::WindowEvent::MouseWheel { device_id: _, delta, phase: _ } => {
                 let out_event = match delta {
@@ -512,7 +757,79 @@ impl ApplicationHandler<UserEvent> for Application {
                 };
 
                 self.cx.emit_window_event(window.entity, out_event);
-                window.window().request_redraw();
+            }
+            winit::event::WindowEvent::Touch(touch) => {
+                let (x, y) = (touch.location.x as f32, touch.location.y as f32);
+                let phase = match touch.phase {
+                    winit::event::TouchPhase::Started => TouchPhase::Started,
+                    winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                    winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                    winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                };
+
+                // Touches which report a force come from a pen. A pen and the first finger to touch the screen also
+                // act as the left mouse button so that views which only handle mouse events can still be used.
+                let is_pen = touch.force.is_some();
+                let is_primary = is_pen
+                    || match phase {
+                        TouchPhase::Started => {
+                            *self.primary_touch.get_or_insert(touch.id) == touch.id
+                        }
+                        _ => self.primary_touch == Some(touch.id),
+                    };
+
+                if is_primary {
+                    self.cx.emit_window_event(window.entity, WindowEvent::MouseMove(x, y));
+
+                    match phase {
+                        TouchPhase::Started => {
+                            self.cx.emit_window_event(
+                                window.entity,
+                                WindowEvent::MouseDown(MouseButton::Left),
+                            );
+                        }
+                        TouchPhase::Ended | TouchPhase::Cancelled => {
+                            self.cx.emit_window_event(
+                                window.entity,
+                                WindowEvent::MouseUp(MouseButton::Left),
+                            );
+
+                            if self.primary_touch == Some(touch.id) {
+                                self.primary_touch = None;
+                            }
+                        }
+                        TouchPhase::Moved => {}
+                    }
+                }
+
+                let event = match touch.force {
+                    Some(force) => {
+                        let tilt = match force {
+                            winit::event::Force::Calibrated { altitude_angle, .. } => {
+                                altitude_angle.map(|angle| angle as f32)
+                            }
+                            winit::event::Force::Normalized(_) => None,
+                        };
+
+                        WindowEvent::Pen {
+                            position: (x, y),
+                            pressure: force.normalized() as f32,
+                            tilt,
+                            phase,
+                        }
+                    }
+
+                    None => WindowEvent::Touch { id: touch.id, phase, position: (x, y) },
+                };
+
+                self.cx.emit_window_event(window.entity, event);
+            }
+            winit::event::WindowEvent::PinchGesture { device_id: _, delta, phase: _ } => {
+                let center = (self.cx.0.mouse.cursor_x, self.cx.0.mouse.cursor_y);
+                self.cx.emit_window_event(
+                    window.entity,
+                    WindowEvent::Gesture(Gesture::Pinch(1.0 + delta as f32, center)),
+                );
             }
             winit::event::WindowEvent::MouseInput { device_id: _, state, button } => {
                 let button = match button {
@@ -529,8 +846,20 @@ impl ApplicationHandler<UserEvent> for Application {
                     winit::event::ElementState::Released => WindowEvent::MouseUp(button),
                 };
 
-                self.cx.emit_window_event(window.entity, event);
-                window.window().request_redraw();
+                // Data dragged within the application can be dropped onto another window, which doesn't receive
+                // the cursor events of the drag, so the button is released over the window under the cursor.
+                let mut window_entity = window.entity;
+                if matches!(event, WindowEvent::MouseUp(MouseButton::Left)) && self.cx.is_dragging()
+                {
+                    if let Some((entity, x, y)) =
+                        window_under_cursor(&self.windows, window_id, self.cx.cursor_position())
+                    {
+                        self.cx.emit_window_event(entity, WindowEvent::MouseMove(x, y));
+                        window_entity = entity;
+                    }
+                }
+
+                self.cx.emit_window_event(window_entity, event);
             }
 
             winit::event::WindowEvent::ScaleFactorChanged {
@@ -539,6 +868,7 @@ impl ApplicationHandler<UserEvent> for Application {
             } => {
                 self.cx.set_scale_factor(scale_factor);
                 self.cx.needs_refresh(window.entity);
+                self.cx.set_monitors(available_monitors(event_loop));
             }
             winit::event::WindowEvent::ThemeChanged(theme) => {
                 let theme = match theme {
@@ -549,19 +879,18 @@ impl ApplicationHandler<UserEvent> for Application {
             }
             winit::event::WindowEvent::Occluded(_) => {}
             winit::event::WindowEvent::RedrawRequested => {
-                for window in self.windows.values_mut() {
-                    window.make_current();
-                    //self.cx.needs_refresh(window.entity);
-                    if self.cx.draw(window.entity, &mut window.surface, &mut window.dirty_surface) {
-                        window.swap_buffers();
-                    }
+                // Only the window which requested the redraw is drawn, so an unchanged window is never redrawn.
+                window.make_current();
+                if self.cx.draw(window.entity, &mut window.surface, &mut window.dirty_surface) {
+                    window.swap_buffers(self.cx.damage(window.entity));
+                    self.last_frame = Instant::now();
+                }
 
-                    // Un-cloak
-                    #[cfg(target_os = "windows")]
-                    if window.is_initially_cloaked {
-                        window.is_initially_cloaked = false;
-                        set_cloak(window.window(), false);
-                    }
+                // Un-cloak
+                #[cfg(target_os = "windows")]
+                if window.is_initially_cloaked {
+                    window.is_initially_cloaked = false;
+                    set_cloak(window.window(), false);
                 }
             }
 
@@ -577,13 +906,40 @@ impl ApplicationHandler<UserEvent> for Application {
 
         event_loop.set_control_flow(self.control_flow);
 
+        // Winit does not report changes to the monitor configuration, such as a monitor being connected or its
+        // resolution or arrangement changing, so the monitors are read again periodically.
+        if self.last_monitor_poll.elapsed() >= MONITOR_POLL_INTERVAL {
+            self.last_monitor_poll = Instant::now();
+            self.cx.set_monitors(available_monitors(event_loop));
+        }
+
+        let frame_interval = self.frame_interval();
+        self.cx.process_animation_frames(frame_interval);
+
+        // With a framerate cap, redraws which are requested before the next frame is due are delayed until then.
+        let next_frame = self
+            .frame_cap()
+            .map(|cap| self.last_frame + cap)
+            .filter(|next_frame| *next_frame > Instant::now());
+        let mut redraw_delayed = false;
+
         self.event_manager.flush_events(self.cx.context(), |_| {});
 
+        // Idle work only runs when there are no events waiting to be handled, before the views it builds are styled
+        // and laid out.
+        if !self.cx.has_queued_events() {
+            self.cx.process_idle_tasks();
+        }
+
         self.cx.process_style_updates();
 
         if self.cx.process_animations() {
-            for window in self.windows.values() {
-                window.window().request_redraw();
+            if next_frame.is_some() {
+                redraw_delayed = true;
+            } else {
+                for window in self.windows.values() {
+                    window.window().request_redraw();
+                }
             }
         }
 
@@ -610,21 +966,36 @@ impl ApplicationHandler<UserEvent> for Application {
             (idle_callback)(self.cx.context());
         }
 
-        if self.cx.has_queued_events() {
+        if self.cx.has_queued_events() || self.cx.has_idle_tasks() {
             self.event_loop_proxy
                 .send_event(UserEvent::Event(Event::new(())))
                 .expect("Failed to send event");
         }
 
-        if self.cx.0.windows.iter().any(|(_, window_state)| !window_state.redraw_list.is_empty()) {
-            for window in self.windows.values() {
-                window.window().request_redraw();
+        for window in self.windows.values() {
+            if let Some(window_state) = self.cx.0.windows.get(&window.entity) {
+                if !window_state.redraw_list.is_empty() || window_state.dirty_rect.is_some() {
+                    if next_frame.is_some() {
+                        redraw_delayed = true;
+                    } else {
+                        window.window().request_redraw();
+                    }
+                }
             }
         }
 
+        // With no timers due, no animation frames requested, and no windows to redraw, the event loop sleeps until
+        // the next OS event or the next time the monitors are read.
         if self.control_flow != ControlFlow::Poll {
-            if let Some(timer_time) = self.cx.get_next_timer_time() {
-                event_loop.set_control_flow(ControlFlow::WaitUntil(timer_time));
+            let timer_time = self.cx.get_next_timer_time();
+            let frame_time = self.cx.next_animation_frame_time(frame_interval);
+            let redraw_time = next_frame.filter(|_| redraw_delayed);
+            let monitor_time = Some(self.last_monitor_poll + MONITOR_POLL_INTERVAL);
+            let wake_time =
+                [timer_time, frame_time, redraw_time, monitor_time].into_iter().flatten().min();
+
+            if let Some(wake_time) = wake_time {
+                event_loop.set_control_flow(ControlFlow::WaitUntil(wake_time));
             } else {
                 event_loop.set_control_flow(ControlFlow::Wait);
             }
@@ -699,7 +1070,11 @@ impl ApplicationHandler<UserEvent> for Application {
         self.cx.emit_scheduled_events();
     }
 
-    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {}
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        // Unregister any global shortcuts while the event loop is still running.
+        #[cfg(feature = "global-shortcuts")]
+        drop(GLOBAL_SHORTCUTS.take());
+    }
 }
 
 impl WindowModifiers for Application {
@@ -803,8 +1178,71 @@ impl WindowModifiers for Application {
         self
     }
 
-    fn always_on_top(mut self, flag: bool) -> Self {
-        self.window_description.always_on_top = flag;
+    fn always_on_top(mut self, flag: impl Res<bool>) -> Self {
+        self.window_description.set_window_level(if flag.get(&self.cx.0) {
+            WindowLevel::AlwaysOnTop
+        } else {
+            WindowLevel::Normal
+        });
+
+        flag.set_or_bind(&mut self.cx.0, Entity::root(), |cx, flag| {
+            cx.emit(WindowEvent::SetAlwaysOnTop(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn window_level<L: Into<WindowLevel>>(mut self, level: impl Res<L>) -> Self {
+        self.window_description.set_window_level(level.get(&self.cx.0).into());
+
+        level.set_or_bind(&mut self.cx.0, Entity::root(), |cx, level| {
+            cx.emit(WindowEvent::SetWindowLevel(level.get(cx).into()));
+        });
+
+        self
+    }
+
+    fn skip_taskbar(mut self, flag: impl Res<bool>) -> Self {
+        self.window_description.skip_taskbar = flag.get(&self.cx.0);
+
+        flag.set_or_bind(&mut self.cx.0, Entity::root(), |cx, flag| {
+            cx.emit(WindowEvent::SetSkipTaskbar(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn blur(mut self, flag: impl Res<bool>) -> Self {
+        self.window_description.blur = flag.get(&self.cx.0);
+
+        flag.set_or_bind(&mut self.cx.0, Entity::root(), |cx, flag| {
+            cx.emit(WindowEvent::SetBlur(flag.get(cx)));
+        });
+
+        self
+    }
+
+    fn theme_mode(mut self, theme_mode: ThemeMode) -> Self {
+        self.cx.0.set_window_theme_mode(Entity::root(), Some(theme_mode));
+
+        self
+    }
+
+    fn stylesheet(mut self, style: impl IntoCssStr) -> Self {
+        if let Err(error) = self.cx.0.add_window_stylesheet(Entity::root(), style) {
+            log::warn!("{}", error);
+        }
+
+        self
+    }
+
+    fn visible_on_all_workspaces(mut self, flag: impl Res<bool>) -> Self {
+        self.window_description.visible_on_all_workspaces = flag.get(&self.cx.0);
+
+        flag.set_or_bind(&mut self.cx.0, Entity::root(), |cx, flag| {
+            cx.emit(WindowEvent::SetVisibleOnAllWorkspaces(flag.get(cx)));
+        });
+
         self
     }
 
@@ -830,11 +1268,63 @@ impl WindowModifiers for Application {
         self
     }
 
+    fn on_close_requested(
+        mut self,
+        callback: impl Fn(&mut EventContext) -> bool + 'static,
+    ) -> Self {
+        self.on_close_requested = Some(Box::new(callback));
+        self
+    }
+
     fn enabled_window_buttons(mut self, window_buttons: WindowButtons) -> Self {
         self.window_description.enabled_window_buttons = window_buttons;
 
         self
     }
+
+    fn geometry(mut self, geometry: WindowGeometry) -> Self {
+        self.window_description.position = Some(geometry.position);
+        self.window_description.inner_size = geometry.size;
+        self.window_description.maximized = geometry.maximized;
+        self.window_description.monitor = geometry.monitor;
+
+        self
+    }
+
+    fn anchor_to_monitor(mut self, monitor: Option<&Monitor>, anchor: MonitorAnchor) -> Self {
+        self.window_description.monitor = monitor.and_then(|monitor| monitor.name.clone());
+        self.window_description.monitor_anchor = Some(anchor);
+
+        self
+    }
+}
+
+// Returns another window under the cursor, given the position of the cursor relative to the source window, along
+// with the position of the cursor relative to that window.
+fn window_under_cursor(
+    windows: &HashMap<WindowId, WinState>,
+    source: WindowId,
+    (x, y): (f32, f32),
+) -> Option<(Entity, f32, f32)> {
+    let contains = |window: &winit::window::Window, x: f32, y: f32| {
+        let size = window.inner_size();
+        x >= 0.0 && y >= 0.0 && x < size.width as f32 && y < size.height as f32
+    };
+
+    let source = windows.get(&source)?.window();
+    if contains(source, x, y) {
+        return None;
+    }
+
+    // Window positions are not available on all platforms, such as Wayland.
+    let origin = source.inner_position().ok()?;
+    let (screen_x, screen_y) = (origin.x as f32 + x, origin.y as f32 + y);
+
+    windows.values().filter(|window| window.window().id() != source.id()).find_map(|window| {
+        let position = window.window().inner_position().ok()?;
+        let (x, y) = (screen_x - position.x as f32, screen_y - position.y as f32);
+        contains(window.window(), x, y).then_some((window.entity, x, y))
+    })
 }
 
 fn apply_window_description(description: &WindowDescription) -> WindowAttributes {
@@ -864,11 +1354,7 @@ fn apply_window_description(description: &WindowDescription) -> WindowAttributes
         .with_maximized(description.maximized)
         // Accesskit requires that the window start invisible until accesskit is initialized.
         //.with_visible(false)
-        .with_window_level(if description.always_on_top {
-            WindowLevel::AlwaysOnTop
-        } else {
-            WindowLevel::Normal
-        })
+        .with_window_level(window_level_to_window_level(description.effective_window_level()))
         .with_transparent(description.transparent)
         .with_decorations(description.decorations)
         .with_window_icon(description.icon.as_ref().map(|icon| {
@@ -885,6 +1371,101 @@ fn apply_window_description(description: &WindowDescription) -> WindowAttributes
         )
 }
 
+// Returns a position and size which fit the window onto the primary monitor if the monitor the window
+// description refers to is no longer available.
+fn clamp_to_monitor(
+    event_loop: &ActiveEventLoop,
+    description: &WindowDescription,
+) -> Option<(LogicalPosition<f64>, LogicalSize<f64>)> {
+    let position = description.position?;
+    let name = description.monitor.as_ref()?;
+
+    if event_loop.available_monitors().any(|monitor| monitor.name().as_ref() == Some(name)) {
+        return None;
+    }
+
+    let monitor =
+        event_loop.primary_monitor().or_else(|| event_loop.available_monitors().next())?;
+    let scale_factor = monitor.scale_factor();
+    let origin: LogicalPosition<f64> = monitor.position().to_logical(scale_factor);
+    let bounds: LogicalSize<f64> = monitor.size().to_logical(scale_factor);
+
+    let width = (description.inner_size.width as f64).min(bounds.width);
+    let height = (description.inner_size.height as f64).min(bounds.height);
+    let x = (position.x as f64).clamp(origin.x, origin.x + bounds.width - width);
+    let y = (position.y as f64).clamp(origin.y, origin.y + bounds.height - height);
+
+    Some((LogicalPosition::new(x, y), LogicalSize::new(width, height)))
+}
+
+// Returns the position of a window placed within the area of the monitor named by the window description,
+// or the primary monitor if the named monitor is unavailable.
+//
+// Winit does not expose the work area of a monitor, so the window is placed within the full area of the monitor and
+// may overlap taskbars and docks.
+fn anchor_to_monitor(
+    event_loop: &ActiveEventLoop,
+    description: &WindowDescription,
+) -> Option<LogicalPosition<f64>> {
+    let anchor = description.monitor_anchor?;
+
+    let monitor = description
+        .monitor
+        .as_ref()
+        .and_then(|name| {
+            event_loop.available_monitors().find(|monitor| monitor.name().as_ref() == Some(name))
+        })
+        .or_else(|| event_loop.primary_monitor())
+        .or_else(|| event_loop.available_monitors().next())?;
+    let scale_factor = monitor.scale_factor();
+    let origin: LogicalPosition<f64> = monitor.position().to_logical(scale_factor);
+    let bounds: LogicalSize<f64> = monitor.size().to_logical(scale_factor);
+
+    let width = description.inner_size.width as f64;
+    let height = description.inner_size.height as f64;
+    let (margin_x, margin_y) =
+        description.position.map(|margin| (margin.x as f64, margin.y as f64)).unwrap_or_default();
+
+    let left = origin.x + margin_x;
+    let right = origin.x + bounds.width - width - margin_x;
+    let top = origin.y + margin_y;
+    let bottom = origin.y + bounds.height - height - margin_y;
+
+    let (x, y) = match anchor {
+        MonitorAnchor::Center => {
+            (origin.x + (bounds.width - width) / 2.0, origin.y + (bounds.height - height) / 2.0)
+        }
+        MonitorAnchor::TopLeft => (left, top),
+        MonitorAnchor::TopRight => (right, top),
+        MonitorAnchor::BottomLeft => (left, bottom),
+        MonitorAnchor::BottomRight => (right, bottom),
+    };
+
+    Some(LogicalPosition::new(x, y))
+}
+
+// Returns the monitors which are currently connected to the system.
+fn available_monitors(event_loop: &ActiveEventLoop) -> Vec<Monitor> {
+    let primary_monitor = event_loop.primary_monitor();
+
+    event_loop
+        .available_monitors()
+        .map(|monitor| {
+            let scale_factor = monitor.scale_factor();
+            let size: LogicalSize<u32> = monitor.size().to_logical(scale_factor);
+            let position = monitor.position();
+
+            Monitor {
+                name: monitor.name(),
+                position: (position.x, position.y),
+                size: WindowSize::new(size.width, size.height),
+                scale_factor,
+                is_primary: primary_monitor.as_ref() == Some(&monitor),
+            }
+        })
+        .collect()
+}
+
 #[allow(unused_variables)]
 pub fn load_default_cursors(event_loop: &ActiveEventLoop) -> HashMap<CursorIcon, CustomCursor> {
     #[allow(unused_mut)]
@@ -1,9 +1,13 @@
 use vizia_core::prelude::CursorIcon as ViziaCursorIcon;
+use vizia_core::prelude::ResizeDirection as ViziaResizeDirection;
+use vizia_core::prelude::WindowLevel as ViziaWindowLevel;
 use vizia_input::Code as ViziaCode;
 use vizia_input::Key as ViziaKey;
 use winit::keyboard::KeyCode;
 use winit::keyboard::NamedKey;
 use winit::window::CursorIcon as WinitCursorIcon;
+use winit::window::ResizeDirection as WinitResizeDirection;
+use winit::window::WindowLevel as WinitWindowLevel;
 
 pub fn cursor_icon_to_cursor_icon(cursor_icon: ViziaCursorIcon) -> Option<WinitCursorIcon> {
     match cursor_icon {
@@ -46,6 +50,29 @@ pub fn cursor_icon_to_cursor_icon(cursor_icon: ViziaCursorIcon) -> Option<WinitC
     }
 }
 
+pub fn resize_direction_to_resize_direction(
+    direction: ViziaResizeDirection,
+) -> WinitResizeDirection {
+    match direction {
+        ViziaResizeDirection::East => WinitResizeDirection::East,
+        ViziaResizeDirection::North => WinitResizeDirection::North,
+        ViziaResizeDirection::NorthEast => WinitResizeDirection::NorthEast,
+        ViziaResizeDirection::NorthWest => WinitResizeDirection::NorthWest,
+        ViziaResizeDirection::South => WinitResizeDirection::South,
+        ViziaResizeDirection::SouthEast => WinitResizeDirection::SouthEast,
+        ViziaResizeDirection::SouthWest => WinitResizeDirection::SouthWest,
+        ViziaResizeDirection::West => WinitResizeDirection::West,
+    }
+}
+
+pub fn window_level_to_window_level(level: ViziaWindowLevel) -> WinitWindowLevel {
+    match level {
+        ViziaWindowLevel::AlwaysOnBottom => WinitWindowLevel::AlwaysOnBottom,
+        ViziaWindowLevel::Normal => WinitWindowLevel::Normal,
+        ViziaWindowLevel::AlwaysOnTop => WinitWindowLevel::AlwaysOnTop,
+    }
+}
+
 pub fn winit_key_code_to_code(virtual_key_code: KeyCode) -> ViziaCode {
     use winit::keyboard::KeyCode::*;
     match virtual_key_code {
@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+
+use global_hotkey::{
+    hotkey::{HotKey, Modifiers as HotKeyModifiers},
+    GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
+};
+use hashbrown::HashMap;
+use vizia_core::prelude::*;
+
+thread_local! {
+    /// The global shortcuts registered by the application. The manager has to be created and used on the main
+    /// thread, which is where views handle events.
+    pub(crate) static GLOBAL_SHORTCUTS: RefCell<GlobalShortcuts> = RefCell::default();
+}
+
+struct Registration {
+    id: u32,
+    source: Entity,
+    hotkey: HotKey,
+}
+
+/// The global shortcuts registered with the operating system, which are unregistered when dropped.
+#[derive(Default)]
+pub(crate) struct GlobalShortcuts {
+    manager: Option<GlobalHotKeyManager>,
+    // Registered shortcuts by the id of their hotkey.
+    registrations: HashMap<u32, Registration>,
+}
+
+impl GlobalShortcuts {
+    /// Registers a key chord as a global shortcut, replacing any shortcut with the same id.
+    pub fn register(
+        &mut self,
+        chord: KeyChord,
+        id: u32,
+        source: Entity,
+    ) -> Result<(), GlobalShortcutError> {
+        self.unregister(id);
+
+        if self.manager.is_none() {
+            let manager = GlobalHotKeyManager::new()
+                .map_err(|error| GlobalShortcutError::Unavailable(error.to_string()))?;
+            self.manager = Some(manager);
+        }
+
+        let hotkey = HotKey::new(Some(convert_modifiers(chord.modifiers)), chord.code);
+        if self.registrations.contains_key(&hotkey.id()) {
+            return Err(GlobalShortcutError::AlreadyRegistered);
+        }
+
+        self.manager.as_ref().unwrap().register(hotkey).map_err(|error| match error {
+            global_hotkey::Error::AlreadyRegistered(_) => GlobalShortcutError::AlreadyRegistered,
+            error => GlobalShortcutError::Os(error.to_string()),
+        })?;
+
+        self.registrations.insert(hotkey.id(), Registration { id, source, hotkey });
+
+        Ok(())
+    }
+
+    /// Unregisters the global shortcut with the given id.
+    pub fn unregister(&mut self, id: u32) {
+        let Some(manager) = &self.manager else {
+            return;
+        };
+
+        self.registrations.retain(|_, registration| {
+            if registration.id == id {
+                let _ = manager.unregister(registration.hotkey);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Unregisters the global shortcuts registered by the given view.
+    pub fn unregister_source(&mut self, source: Entity) {
+        let Some(manager) = &self.manager else {
+            return;
+        };
+
+        self.registrations.retain(|_, registration| {
+            if registration.source == source {
+                let _ = manager.unregister(registration.hotkey);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Returns the event sent to the view which registered a global shortcut when the shortcut is pressed.
+    pub fn event(&self, event: GlobalHotKeyEvent) -> Option<Event> {
+        if event.state != HotKeyState::Pressed {
+            return None;
+        }
+
+        self.registrations.get(&event.id).map(|registration| {
+            Event::new(WindowEvent::GlobalShortcut(registration.id)).target(registration.source)
+        })
+    }
+}
+
+impl Drop for GlobalShortcuts {
+    fn drop(&mut self) {
+        if let Some(manager) = &self.manager {
+            for registration in self.registrations.values() {
+                let _ = manager.unregister(registration.hotkey);
+            }
+        }
+    }
+}
+
+fn convert_modifiers(modifiers: Modifiers) -> HotKeyModifiers {
+    let mut hotkey_modifiers = HotKeyModifiers::empty();
+    hotkey_modifiers.set(HotKeyModifiers::SHIFT, modifiers.shift());
+    hotkey_modifiers.set(HotKeyModifiers::CONTROL, modifiers.ctrl());
+    hotkey_modifiers.set(HotKeyModifiers::ALT, modifiers.alt());
+    hotkey_modifiers.set(HotKeyModifiers::SUPER, modifiers.logo());
+    hotkey_modifiers
+}
@@ -1,5 +1,7 @@
 pub mod application;
 mod convert;
+#[cfg(feature = "global-shortcuts")]
+mod global_shortcut;
 pub mod window;
 pub mod window_modifiers;
 
@@ -8,12 +10,43 @@ pub trait ModifyWindow {
     fn modify_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T>;
     /// Returns a read-only pointer to the parent window of the current view.
     fn window(&self) -> Option<Arc<winit::window::Window>>;
+    /// Returns the raw window handle of the parent window of the current view, which can be used to create a
+    /// surface for an external renderer.
+    ///
+    /// An external surface should be reconfigured whenever the size or scale factor of the window changes, which is
+    /// signalled by a [`WindowEvent::GeometryChanged`] event, and must be dropped before the window is destroyed, such
+    /// as in the [`on_close`](crate::window_modifiers::WindowModifiers::on_close) callback of the window. Rendering
+    /// into an [`ExternalTextureView`] avoids managing the surface of the window directly.
+    ///
+    /// [`WindowEvent::GeometryChanged`]: vizia_core::prelude::WindowEvent::GeometryChanged
+    /// [`ExternalTextureView`]: vizia_core::prelude::ExternalTextureView
+    fn raw_window_handle(&self) -> Option<RawWindowHandle>;
+    /// Returns the raw display handle of the parent window of the current view, which can be used to create a
+    /// surface for an external renderer.
+    fn raw_display_handle(&self) -> Option<RawDisplayHandle>;
+    /// Sets the title of the parent window of the current view.
+    fn set_window_title(&mut self, title: &str);
+    /// Sets whether the parent window of the current view has decorations, such as a title bar and borders.
+    fn set_decorations(&mut self, flag: bool);
+    /// Sets whether the parent window of the current view is kept above other windows.
+    fn set_always_on_top(&mut self, flag: bool);
+    /// Requests the attention of the user for the parent window of the current view, which flashes its taskbar
+    /// button on Windows and bounces its dock icon on macOS. This has no effect if the window is already focused.
+    fn flash_taskbar(&mut self);
+    /// Sets the icon of the parent window of the current view from RGBA pixel data with the given width and height.
+    /// The icon is left unchanged if the data does not match the size.
+    fn set_window_icon(&mut self, width: u32, height: u32, icon_data: &[u8]);
 }
 
 use std::sync::Arc;
 
 use vizia_core::{context::TreeProps, prelude::EventContext};
 use window::Window;
+pub use winit::raw_window_handle;
+use winit::raw_window_handle::{
+    HasDisplayHandle, HasWindowHandle, RawDisplayHandle, RawWindowHandle,
+};
+use winit::window::{Icon, UserAttentionType, WindowLevel};
 
 impl ModifyWindow for EventContext<'_> {
     fn modify_window<T>(&mut self, f: impl FnOnce(&winit::window::Window) -> T) -> Option<T> {
@@ -27,4 +60,46 @@ impl ModifyWindow for EventContext<'_> {
     fn window(&self) -> Option<Arc<winit::window::Window>> {
         self.get_view_with::<Window>(self.parent_window()).and_then(|window| window.window.clone())
     }
+
+    fn raw_window_handle(&self) -> Option<RawWindowHandle> {
+        let window = self.window()?;
+        let handle = window.window_handle().ok()?.as_raw();
+        Some(handle)
+    }
+
+    fn raw_display_handle(&self) -> Option<RawDisplayHandle> {
+        let window = self.window()?;
+        let handle = window.display_handle().ok()?.as_raw();
+        Some(handle)
+    }
+
+    fn set_window_title(&mut self, title: &str) {
+        self.modify_window(|window| window.set_title(title));
+    }
+
+    fn set_decorations(&mut self, flag: bool) {
+        self.modify_window(|window| window.set_decorations(flag));
+    }
+
+    fn set_always_on_top(&mut self, flag: bool) {
+        self.modify_window(|window| {
+            window.set_window_level(if flag {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            })
+        });
+    }
+
+    fn flash_taskbar(&mut self) {
+        self.modify_window(|window| {
+            window.request_user_attention(Some(UserAttentionType::Informational))
+        });
+    }
+
+    fn set_window_icon(&mut self, width: u32, height: u32, icon_data: &[u8]) {
+        if let Ok(icon) = Icon::from_rgba(icon_data.to_vec(), width, height) {
+            self.modify_window(|window| window.set_window_icon(Some(icon)));
+        }
+    }
 }
@@ -1,17 +1,21 @@
+#[cfg(feature = "global-shortcuts")]
+use crate::global_shortcut::GLOBAL_SHORTCUTS;
 use crate::window_modifiers::WindowModifiers;
 use glutin::context::GlProfile;
 use vizia_core::context::TreeProps;
 #[cfg(target_os = "windows")]
-use winit::platform::windows::WindowExtWindows;
+use winit::platform::windows::{BackdropType, WindowExtWindows};
 #[cfg(target_os = "windows")]
 use winit::{platform::windows::WindowAttributesExtWindows, raw_window_handle::RawWindowHandle};
 
-use crate::convert::cursor_icon_to_cursor_icon;
+use crate::convert::{
+    cursor_icon_to_cursor_icon, resize_direction_to_resize_direction, window_level_to_window_level,
+};
 use hashbrown::HashMap;
 use std::error::Error;
 use std::num::NonZeroU32;
 use std::{ffi::CString, sync::Arc};
-use winit::raw_window_handle::HasWindowHandle;
+use winit::raw_window_handle::{HasDisplayHandle, HasWindowHandle, RawDisplayHandle};
 
 use gl_rs as gl;
 use glutin::config::Config;
@@ -37,7 +41,7 @@ use skia_safe::{
 
 use vizia_core::prelude::*;
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{CursorGrabMode, CursorIcon, CustomCursor, WindowAttributes, WindowLevel};
+use winit::window::{CursorGrabMode, CursorIcon, CustomCursor, Fullscreen, WindowAttributes};
 use winit::{dpi::*, window::WindowId};
 
 pub struct WinState {
@@ -254,8 +258,25 @@ impl WinState {
         );
     }
 
-    pub fn swap_buffers(&mut self) {
+    /// Presents the surface, hinting to the compositor that only the damaged region has changed where this is
+    /// supported, which is currently only with EGL.
+    pub fn swap_buffers(&mut self, damage: Option<BoundingBox>) {
         self.gr_context.flush_and_submit();
+
+        if let Some(damage) = damage {
+            // Damage rects have their origin at the bottom-left of the surface.
+            let height = self.surface.height();
+            let left = damage.left().floor() as i32;
+            let top = damage.top().floor() as i32;
+            let right = damage.right().ceil() as i32;
+            let bottom = damage.bottom().ceil() as i32;
+            let rect =
+                glutin::surface::Rect::new(left, height - bottom, right - left, bottom - top);
+            if self.gl_surface.swap_buffers_with_damage(&self.gl_context, &[rect]).is_ok() {
+                return;
+            }
+        }
+
         self.gl_surface.swap_buffers(&self.gl_context).expect("Failed to swap buffers");
     }
 }
@@ -264,7 +285,10 @@ fn build_window(
     event_loop: &ActiveEventLoop,
     window_attributes: WindowAttributes,
 ) -> (Option<winit::window::Window>, Config) {
-    let template = ConfigTemplateBuilder::new().with_alpha_size(8).with_transparency(true);
+    // A transparent window needs a config with an alpha channel which the compositor blends with the content
+    // behind the window.
+    let transparent = window_attributes.transparent;
+    let template = ConfigTemplateBuilder::new().with_alpha_size(8).with_transparency(transparent);
     let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
 
     display_builder
@@ -273,8 +297,9 @@ fn build_window(
             // be smooth.
             configs
                 .reduce(|accum, config| {
-                    let transparency_check = config.supports_transparency().unwrap_or(false)
-                        & !accum.supports_transparency().unwrap_or(false);
+                    let transparency_check = transparent
+                        && config.supports_transparency().unwrap_or(false)
+                        && !accum.supports_transparency().unwrap_or(false);
 
                     if transparency_check || config.num_samples() < accum.num_samples() {
                         config
@@ -287,6 +312,197 @@ fn build_window(
         .unwrap()
 }
 
+/// Sets whether the window is hidden from the taskbar, which is only supported on Windows.
+#[allow(unused_variables)]
+pub(crate) fn set_skip_taskbar(window: &winit::window::Window, flag: bool) {
+    #[cfg(target_os = "windows")]
+    window.set_skip_taskbar(flag);
+
+    #[cfg(not(target_os = "windows"))]
+    if flag {
+        log::warn!("Hiding a window from the taskbar is not supported on this platform");
+    }
+}
+
+/// Sets whether the window is visible on all workspaces, which is only supported on macOS and X11.
+pub(crate) fn set_visible_on_all_workspaces(window: &winit::window::Window, flag: bool) {
+    let supported = cfg!(target_os = "macos")
+        || matches!(
+            window.window_handle().map(|handle| handle.as_raw()),
+            Ok(winit::raw_window_handle::RawWindowHandle::Xlib(_)
+                | winit::raw_window_handle::RawWindowHandle::Xcb(_))
+        );
+
+    if supported {
+        window.set_visible_on_all_workspaces(flag);
+    } else if flag {
+        log::warn!("Making a window visible on all workspaces is not supported on this platform");
+    }
+}
+
+/// Sets whether the background behind the window is blurred, which uses acrylic on Windows, vibrancy on macOS, and
+/// the KDE blur protocol on Wayland.
+pub(crate) fn set_blur(window: &winit::window::Window, flag: bool) {
+    #[cfg(target_os = "windows")]
+    window.set_system_backdrop(if flag {
+        BackdropType::TransientWindow
+    } else {
+        BackdropType::Auto
+    });
+
+    #[cfg(not(target_os = "windows"))]
+    if cfg!(target_os = "macos") || is_wayland(window) {
+        window.set_blur(flag);
+    } else if flag {
+        log::warn!("Blurring the background of a window is not supported on this platform");
+    }
+}
+
+/// Starts a native drag and drop operation from the window, reporting the result to the source entity once the
+/// drag has finished. Only lists of files can be dragged, and only on Windows and macOS.
+pub(crate) fn start_drag(
+    window: &winit::window::Window,
+    mut proxy: ContextProxy,
+    source: Entity,
+    data: DragData,
+    image: Option<Vec<u8>>,
+) {
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    if let DragData::Files(paths) = data {
+        let result_proxy = proxy.clone();
+        let on_drop = move |result: drag::DragResult, _: drag::CursorPosition| {
+            let result = match result {
+                drag::DragResult::Dropped => DragResult::Dropped,
+                drag::DragResult::Cancel => DragResult::Cancelled,
+            };
+            let _ = result_proxy.clone().emit_to(source, WindowEvent::DragEnded(result));
+        };
+
+        let image = drag::Image::Raw(image.unwrap_or_default());
+        match drag::start_drag(
+            window,
+            drag::DragItem::Files(paths),
+            image,
+            on_drop,
+            drag::Options::default(),
+        ) {
+            Ok(()) => return,
+            Err(err) => log::warn!("Failed to start drag: {}", err),
+        }
+    } else {
+        log::warn!("Only files can be dragged out of a window on this platform");
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        let _ = (window, data, image);
+        log::warn!("Dragging data out of a window is not supported on this platform");
+    }
+
+    let _ = proxy.emit_to(source, WindowEvent::DragEnded(DragResult::Cancelled));
+}
+
+/// Shows a native file dialog parented to the window. The dialog is created on the UI thread, which is required on
+/// some platforms, and then awaited on a separate thread so that neither the UI nor the event loop is blocked.
+#[cfg(feature = "dialogs")]
+pub(crate) fn show_file_dialog(
+    window: &winit::window::Window,
+    mut proxy: ContextProxy,
+    dialog: FileDialog,
+) {
+    use std::{future::Future, path::PathBuf, pin::Pin};
+
+    let options = dialog.options();
+    let mut builder = rfd::AsyncFileDialog::new().set_parent(window);
+
+    if let Some(title) = &options.title {
+        builder = builder.set_title(title);
+    }
+
+    for filter in &options.filters {
+        builder = builder.add_filter(&filter.name, filter.extensions.as_slice());
+    }
+
+    if let Some(directory) = &options.directory {
+        builder = builder.set_directory(directory);
+    }
+
+    if let Some(file_name) = &options.file_name {
+        builder = builder.set_file_name(file_name);
+    }
+
+    let future: Pin<Box<dyn Future<Output = Option<Vec<PathBuf>>> + Send>> = match dialog.kind() {
+        FileDialogKind::Open if options.multiple => {
+            let files = builder.pick_files();
+            Box::pin(async move {
+                files
+                    .await
+                    .map(|files| files.iter().map(|file| file.path().to_path_buf()).collect())
+            })
+        }
+
+        FileDialogKind::Open => {
+            let file = builder.pick_file();
+            Box::pin(async move { file.await.map(|file| vec![file.path().to_path_buf()]) })
+        }
+
+        FileDialogKind::Save => {
+            let file = builder.save_file();
+            Box::pin(async move { file.await.map(|file| vec![file.path().to_path_buf()]) })
+        }
+    };
+
+    std::thread::spawn(move || {
+        let paths = pollster::block_on(future);
+        dialog.finish(&mut proxy, paths);
+    });
+}
+
+/// Returns the position of the cursor relative to the window, which is only queried on Windows where no cursor events
+/// are received while a file is dragged over the window.
+pub(crate) fn cursor_position(window: &winit::window::Window) -> Option<PhysicalPosition<f64>> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows_sys::Win32::{
+            Foundation::{HWND, POINT},
+            Graphics::Gdi::ScreenToClient,
+            UI::WindowsAndMessaging::GetCursorPos,
+        };
+
+        let RawWindowHandle::Win32(handle) = window.window_handle().ok()?.as_raw() else {
+            return None;
+        };
+
+        let mut point = POINT { x: 0, y: 0 };
+        let success = unsafe {
+            GetCursorPos(&mut point) != 0
+                && ScreenToClient(handle.hwnd.get() as HWND, &mut point) != 0
+        };
+
+        success.then(|| PhysicalPosition::new(point.x as f64, point.y as f64))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = window;
+        None
+    }
+}
+
+/// Returns whether a window or event loop uses the Wayland display server.
+pub(crate) fn is_wayland(display: &impl HasDisplayHandle) -> bool {
+    matches!(
+        display.display_handle().map(|handle| handle.as_raw()),
+        Ok(RawDisplayHandle::Wayland(_))
+    )
+}
+
+/// Returns whether windows can be positioned by the application, which is not the case on Wayland where the
+/// compositor is responsible for the placement of windows.
+pub(crate) fn supports_positioning(display: &impl HasDisplayHandle) -> bool {
+    !is_wayland(display)
+}
+
 /// Cloaks the window such that it is not visible to the user, but will still be composited.
 ///
 /// <https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/ne-dwmapi-dwmwindowattribute>
@@ -352,11 +568,13 @@ pub fn create_surface(
 }
 
 type WindowCallback = Option<Box<dyn Fn(&mut EventContext)>>;
+pub(crate) type CloseRequestedCallback = Option<Box<dyn Fn(&mut EventContext) -> bool>>;
 
 pub struct Window {
     pub window: Option<Arc<winit::window::Window>>,
     pub on_close: WindowCallback,
     pub on_create: WindowCallback,
+    pub(crate) on_close_requested: CloseRequestedCallback,
     pub should_close: bool,
     pub(crate) custom_cursors: Arc<HashMap<CursorIcon, CustomCursor>>,
 }
@@ -371,6 +589,7 @@ impl Window {
             window: None,
             on_close: None,
             on_create: None,
+            on_close_requested: None,
             should_close: false,
             custom_cursors: Default::default(),
         }
@@ -392,6 +611,7 @@ impl Window {
             window: None,
             on_close: None,
             on_create: None,
+            on_close_requested: None,
             should_close: false,
             custom_cursors: Default::default(),
         }
@@ -434,11 +654,12 @@ impl View for Window {
             }
 
             WindowEvent::SetCursorPosition(x, y) => {
-                self.window()
-                    .set_cursor_position(winit::dpi::Position::Physical(PhysicalPosition::new(
-                        *x as i32, *y as i32,
-                    )))
-                    .expect("Failed to set cursor position");
+                // Moving the cursor is not supported on some platforms, such as Wayland and the web.
+                if let Err(err) = self.window().set_cursor_position(winit::dpi::Position::Physical(
+                    PhysicalPosition::new(*x as i32, *y as i32),
+                )) {
+                    log::warn!("Failed to set cursor position: {}", err);
+                }
             }
 
             WindowEvent::SetCursor(cursor) => {
@@ -474,6 +695,11 @@ impl View for Window {
                     .set_max_inner_size(size.map(|size| LogicalSize::new(size.width, size.height)));
             }
 
+            // Windows cannot be positioned by the application on Wayland.
+            WindowEvent::SetPosition(_) if !supports_positioning(self.window()) => {
+                meta.consume();
+            }
+
             WindowEvent::SetPosition(pos) => {
                 let parent_window_position = if cx.current() == Entity::root() {
                     WindowPosition::new(0, 0)
@@ -499,6 +725,25 @@ impl View for Window {
                 self.window().set_maximized(*flag);
             }
 
+            WindowEvent::SetFullscreen(flag) => {
+                let fullscreen =
+                    flag.then(|| Fullscreen::Borderless(self.window().current_monitor()));
+                self.window().set_fullscreen(fullscreen);
+            }
+
+            WindowEvent::MonitorsChanged => {
+                // Move the window onto the primary monitor if the monitor it was on has been disconnected.
+                if let Some(window) =
+                    self.window.as_ref().filter(|window| supports_positioning(window))
+                {
+                    if window.current_monitor().is_none() {
+                        if let Some(monitor) = window.primary_monitor() {
+                            window.set_outer_position(monitor.position());
+                        }
+                    }
+                }
+            }
+
             WindowEvent::SetVisible(flag) => {
                 self.window().set_visible(*flag);
 
@@ -510,7 +755,17 @@ impl View for Window {
             }
 
             WindowEvent::ReloadStyles => {
-                cx.reload_styles().unwrap();
+                if let Err(error) = cx.reload_styles() {
+                    log::warn!("{}", error);
+                }
+            }
+
+            WindowEvent::CloseRequested => {
+                if let Some(callback) = &self.on_close_requested {
+                    if !callback(cx) {
+                        cx.prevent_close();
+                    }
+                }
             }
 
             WindowEvent::WindowClose => {
@@ -550,14 +805,75 @@ impl View for Window {
                 meta.consume();
             }
 
+            WindowEvent::DragResizeWindow(direction) => {
+                // Resizing by dragging is not supported on some platforms, such as macOS.
+                if let Err(err) = self
+                    .window()
+                    .drag_resize_window(resize_direction_to_resize_direction(*direction))
+                {
+                    log::warn!("Failed to drag resize window: {}", err);
+                }
+                meta.consume();
+            }
+
             WindowEvent::SetAlwaysOnTop(flag) => {
                 self.window().set_window_level(if *flag {
-                    WindowLevel::AlwaysOnTop
+                    winit::window::WindowLevel::AlwaysOnTop
                 } else {
-                    WindowLevel::Normal
+                    winit::window::WindowLevel::Normal
                 });
             }
 
+            WindowEvent::SetWindowLevel(level) => {
+                self.window().set_window_level(window_level_to_window_level(*level));
+            }
+
+            WindowEvent::SetSkipTaskbar(flag) => {
+                set_skip_taskbar(self.window(), *flag);
+            }
+
+            WindowEvent::SetVisibleOnAllWorkspaces(flag) => {
+                set_visible_on_all_workspaces(self.window(), *flag);
+            }
+
+            WindowEvent::SetBlur(flag) => {
+                set_blur(self.window(), *flag);
+            }
+
+            WindowEvent::StartDrag { data, image } => {
+                start_drag(self.window(), cx.get_proxy(), meta.origin, data.clone(), image.clone());
+                meta.consume();
+            }
+
+            #[cfg(feature = "dialogs")]
+            WindowEvent::FileDialog(dialog) => {
+                show_file_dialog(self.window(), cx.get_proxy(), dialog.clone());
+                meta.consume();
+            }
+
+            #[cfg(feature = "global-shortcuts")]
+            WindowEvent::RegisterGlobalShortcut(chord, id) => {
+                let result = GLOBAL_SHORTCUTS
+                    .with_borrow_mut(|shortcuts| shortcuts.register(*chord, *id, meta.origin));
+                if let Err(error) = result {
+                    log::warn!("Failed to register global shortcut {:?}: {}", chord, error);
+                    cx.emit_to(meta.origin, WindowEvent::GlobalShortcutFailed(*id, error));
+                }
+                meta.consume();
+            }
+
+            #[cfg(feature = "global-shortcuts")]
+            WindowEvent::UnregisterGlobalShortcut(id) => {
+                GLOBAL_SHORTCUTS.with_borrow_mut(|shortcuts| shortcuts.unregister(*id));
+                meta.consume();
+            }
+
+            #[cfg(feature = "global-shortcuts")]
+            WindowEvent::UnregisterGlobalShortcuts(source) => {
+                GLOBAL_SHORTCUTS.with_borrow_mut(|shortcuts| shortcuts.unregister_source(*source));
+                meta.consume();
+            }
+
             _ => {}
         })
     }
@@ -572,6 +888,10 @@ impl WindowModifiers for Handle<'_, Window> {
         self.modify(|window| window.on_create = Some(Box::new(callback)))
     }
 
+    fn on_close_requested(self, callback: impl Fn(&mut EventContext) -> bool + 'static) -> Self {
+        self.modify(|window| window.on_close_requested = Some(Box::new(callback)))
+    }
+
     fn title<T: ToString>(mut self, title: impl Res<T>) -> Self {
         let entity = self.entity();
         let title = title.get(&self).to_string();
@@ -680,10 +1000,67 @@ impl WindowModifiers for Handle<'_, Window> {
         self
     }
 
-    fn always_on_top(mut self, flag: bool) -> Self {
+    fn always_on_top(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        let level = if flag.get(&self) { WindowLevel::AlwaysOnTop } else { WindowLevel::Normal };
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.set_window_level(level);
+        }
+
+        self
+    }
+
+    fn window_level<L: Into<WindowLevel>>(mut self, level: impl Res<L>) -> Self {
+        let entity = self.entity();
+        let level = level.get(&self).into();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.set_window_level(level);
+        }
+
+        self
+    }
+
+    fn skip_taskbar(mut self, flag: impl Res<bool>) -> Self {
         let entity = self.entity();
+        let flag = flag.get(&self);
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.skip_taskbar = flag;
+        }
+
+        self
+    }
+
+    fn blur(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        let flag = flag.get(&self);
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.blur = flag;
+        }
+
+        self
+    }
+
+    fn theme_mode(mut self, theme_mode: ThemeMode) -> Self {
+        let entity = self.entity();
+        self.context().set_window_theme_mode(entity, Some(theme_mode));
+
+        self
+    }
+
+    fn stylesheet(mut self, style: impl IntoCssStr) -> Self {
+        let entity = self.entity();
+        if let Err(error) = self.context().add_window_stylesheet(entity, style) {
+            log::warn!("{}", error);
+        }
+
+        self
+    }
+
+    fn visible_on_all_workspaces(mut self, flag: impl Res<bool>) -> Self {
+        let entity = self.entity();
+        let flag = flag.get(&self);
         if let Some(win_state) = self.context().windows.get_mut(&entity) {
-            win_state.window_description.always_on_top = flag
+            win_state.window_description.visible_on_all_workspaces = flag;
         }
 
         self
@@ -717,4 +1094,27 @@ impl WindowModifiers for Handle<'_, Window> {
 
         self
     }
+
+    fn geometry(mut self, geometry: WindowGeometry) -> Self {
+        let entity = self.entity();
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.position = Some(geometry.position);
+            win_state.window_description.inner_size = geometry.size;
+            win_state.window_description.maximized = geometry.maximized;
+            win_state.window_description.monitor = geometry.monitor;
+        }
+
+        self
+    }
+
+    fn anchor_to_monitor(mut self, monitor: Option<&Monitor>, anchor: MonitorAnchor) -> Self {
+        let entity = self.entity();
+        let name = monitor.and_then(|monitor| monitor.name.clone());
+        if let Some(win_state) = self.context().windows.get_mut(&entity) {
+            win_state.window_description.monitor = name;
+            win_state.window_description.monitor_anchor = Some(anchor);
+        }
+
+        self
+    }
 }
@@ -1,10 +1,31 @@
 use vizia_core::{binding::Res, context::EventContext};
-use vizia_window::{WindowButtons, WindowPosition, WindowSize};
+use vizia_window::{
+    Monitor, MonitorAnchor, WindowButtons, WindowGeometry, WindowLevel, WindowPosition, WindowSize,
+};
 
 /// Modifiers for setting the properties of a window.
 pub trait WindowModifiers {
     fn on_close(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
     fn on_create(self, callback: impl Fn(&mut EventContext) + 'static) -> Self;
+    /// Sets a callback which is called when the user requests that the window be closed, such as by pressing the
+    /// close button of the window. The window is only closed if the callback returns `true`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// # use vizia_winit::window_modifiers::WindowModifiers;
+    /// # let unsaved_changes = false;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .on_close_requested(move |_| {
+    ///     // Ask the user whether to save any changes before closing.
+    ///     !unsaved_changes
+    /// })
+    /// .run();
+    /// ```
+    fn on_close_requested(self, callback: impl Fn(&mut EventContext) -> bool + 'static) -> Self;
     /// Sets the title of the window to the given value. Accepts a type, or lens to a type, which implements `ToString`.
     ///
     /// # Example
@@ -65,6 +86,9 @@ pub trait WindowModifiers {
     fn max_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self;
     /// Sets the position of the window to the given value. Accepts a value, or lens, which can be converted to a [`Position`].
     ///
+    /// The position of a popup window is relative to the window which owns it. On Wayland the position is ignored, as
+    /// the placement of windows is left to the compositor.
+    ///
     /// # Example
     /// ```no_run
     /// # use vizia_core::prelude::*;
@@ -128,7 +152,10 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn visible(self, flag: impl Res<bool>) -> Self;
-    /// Sets whether the window is transparent. Accepts a boolean value, or lens to a boolean value.
+    /// Sets whether the window is transparent.
+    ///
+    /// The window is drawn with an alpha channel, so any part of the window with a translucent background, such as a
+    /// `:root` with a `background-color` of `transparent`, shows the content behind the window.
     ///
     /// # Example
     /// ```no_run
@@ -141,6 +168,49 @@ pub trait WindowModifiers {
     /// .run();
     /// ```
     fn transparent(self, flag: bool) -> Self;
+    /// Sets whether the background behind a transparent window is blurred. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// This uses acrylic on Windows, vibrancy on macOS, and the KDE blur protocol on Wayland, and logs a warning on
+    /// other platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .transparent(true)
+    /// .blur(true)
+    /// .run();
+    /// ```
+    fn blur(self, flag: impl Res<bool>) -> Self;
+    /// Sets the theme mode of the window, overriding the theme mode of the application for the views within the window.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .theme_mode(ThemeMode::DarkMode)
+    /// .run();
+    /// ```
+    fn theme_mode(self, theme_mode: ThemeMode) -> Self;
+    /// Adds a stylesheet which only applies to the views within the window.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .stylesheet(":root { background-color: #303030; }")
+    /// .run();
+    /// ```
+    fn stylesheet(self, style: impl IntoCssStr) -> Self;
     /// Sets whether the window has decorations. Accepts a boolean value, or lens to a boolean value.
     ///
     /// # Example
@@ -166,7 +236,50 @@ pub trait WindowModifiers {
     /// .always_on_top(true)
     /// .run();
     /// ```
-    fn always_on_top(self, flag: bool) -> Self;
+    fn always_on_top(self, flag: impl Res<bool>) -> Self;
+    /// Sets the level of the window relative to other windows. Accepts a value, or lens, which can be converted to a [`WindowLevel`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .window_level(WindowLevel::AlwaysOnBottom)
+    /// .run();
+    /// ```
+    fn window_level<L: Into<WindowLevel>>(self, level: impl Res<L>) -> Self;
+    /// Sets whether the window is hidden from the taskbar. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// This is only supported on Windows and logs a warning on other platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .skip_taskbar(true)
+    /// .run();
+    /// ```
+    fn skip_taskbar(self, flag: impl Res<bool>) -> Self;
+    /// Sets whether the window is visible on all workspaces. Accepts a boolean value, or lens to a boolean value.
+    ///
+    /// This is only supported on macOS and X11 and logs a warning on other platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .visible_on_all_workspaces(true)
+    /// .run();
+    /// ```
+    fn visible_on_all_workspaces(self, flag: impl Res<bool>) -> Self;
     /// Sets whether the window has vsync enabled.
     ///
     /// # Example
@@ -199,4 +312,45 @@ pub trait WindowModifiers {
     fn icon(self, width: u32, height: u32, image: Vec<u8>) -> Self;
 
     fn enabled_window_buttons(self, window_buttons: WindowButtons) -> Self;
+
+    /// Sets the initial position, size, and maximized state of the window from a previously saved [`WindowGeometry`].
+    ///
+    /// If the monitor the geometry was saved on is no longer available, the window is moved onto the primary monitor.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// let geometry = WindowGeometry {
+    ///     position: WindowPosition::new(100, 100),
+    ///     size: WindowSize::new(800, 600),
+    ///     maximized: false,
+    ///     monitor: None,
+    /// };
+    ///
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .geometry(geometry)
+    /// .run();
+    /// ```
+    fn geometry(self, geometry: WindowGeometry) -> Self;
+
+    /// Places the window within the area of the given monitor, or the primary monitor if `None`.
+    ///
+    /// If a position has been set for the window, it is used as a margin from the anchored edges of the monitor.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_winit::application::Application;
+    /// Application::new(|cx|{
+    ///     // Content here
+    /// })
+    /// .inner_size((300, 200))
+    /// .position((20, 20))
+    /// .anchor_to_monitor(None, MonitorAnchor::BottomRight)
+    /// .run();
+    /// ```
+    fn anchor_to_monitor(self, monitor: Option<&Monitor>, anchor: MonitorAnchor) -> Self;
 }
@@ -2,8 +2,17 @@ use vizia::prelude::*;
 mod helpers;
 use helpers::*;
 
+const STYLE: &str = r#"
+    .drop-target:drag-over {
+        border-width: 2px;
+        border-color: #3080ff;
+    }
+"#;
+
 fn main() -> Result<(), ApplicationError> {
     Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
         ExamplePage::vertical(cx, |cx| {
             HStack::new(cx, |cx| {
                 Element::new(cx).size(Pixels(50.0)).background_color(Color::red()).on_drag(|ex| {
@@ -28,6 +37,9 @@ fn main() -> Result<(), ApplicationError> {
             Element::new(cx)
                 .size(Pixels(100.0))
                 .background_color(Color::beige())
+                .class("drop-target")
+                .on_drag_enter(|_, data| println!("Drag entered: {:?}", data))
+                .on_drag_leave(|_| println!("Drag left"))
                 .on_drop(|ex, data| {
                     if let DropData::Id(id) = data {
                         let bg = ex.with_current(id, |ex| ex.background_color());
@@ -45,6 +57,13 @@ fn main() -> Result<(), ApplicationError> {
                         ex.emit(WindowEvent::SetCursor(CursorIcon::Default));
                     }
                 });
+
+            // Drag a file out of the window and drop it onto another application, such as a file manager.
+            Label::new(cx, "Drag Cargo.toml out of the window")
+                .padding(Pixels(8.0))
+                .background_color(Color::lightgray())
+                .on_drag_start(|_| std::fs::canonicalize("Cargo.toml").ok().map(DragData::from))
+                .on_drag_end(|_, result| println!("Drag finished: {:?}", result));
         });
     })
     .run()
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use vizia::prelude::*;
+mod helpers;
+use helpers::*;
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    status: String,
+}
+
+pub enum AppEvent {
+    Opened(Vec<PathBuf>),
+    Saved(PathBuf),
+    Cancelled,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Opened(paths) => self.status = format!("Opened: {:?}", paths),
+            AppEvent::Saved(path) => self.status = format!("Saved: {:?}", path),
+            AppEvent::Cancelled => self.status = String::from("Cancelled"),
+        });
+    }
+}
+
+#[cfg(not(feature = "baseview"))]
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData { status: String::from("No file chosen") }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            Button::new(cx, |cx| Label::new(cx, "Open Files")).on_press(|ex| {
+                let options = FileDialogOptions::new()
+                    .title("Open Files")
+                    .filter("Rust", &["rs"])
+                    .filter("Text", &["txt", "md"])
+                    .multiple(true);
+
+                ex.open_file_dialog(options, |ex, paths| match paths {
+                    Some(paths) => ex.emit(AppEvent::Opened(paths)),
+                    None => ex.emit(AppEvent::Cancelled),
+                });
+            });
+
+            Button::new(cx, |cx| Label::new(cx, "Save File")).on_press(|ex| {
+                let options = FileDialogOptions::new().file_name("untitled.txt");
+
+                ex.save_file_dialog(options, |ex, path| match path {
+                    Some(path) => ex.emit(AppEvent::Saved(path)),
+                    None => ex.emit(AppEvent::Cancelled),
+                });
+            });
+
+            Label::new(cx, AppData::status);
+        });
+    })
+    .title("File Dialog")
+    .run()
+}
@@ -11,6 +11,7 @@
 //! `ALT+SHIFT+X`           => `Action::OnAltShiftX`
 //! `CTRL+ALT+SHIFT+Y`      => `Action::OnCtrlAltShiftY`
 //! `CTRL+ALT+SHIFT+LOGO+Z` => `Action::OnCtrlAltShiftLogoZ`
+//! `CTRL+K` then `CTRL+C`  => `Action::OnCtrlKCtrlC`
 
 use log::debug;
 use vizia::prelude::*;
@@ -64,6 +65,11 @@ fn main() -> Result<(), ApplicationError> {
                     debug!("Action OnCtrlAltShiftLogoZ")
                 }),
             ),
+            (
+                KeyChord::new(Modifiers::CTRL, Code::KeyK),
+                KeymapEntry::new(Action::OnCtrlKCtrlC, |_| debug!("Action OnCtrlKCtrlC"))
+                    .then(KeyChord::new(Modifiers::CTRL, Code::KeyC)),
+            ),
         ])
         .build(cx);
     })
@@ -84,4 +90,5 @@ enum Action {
     OnAltShiftX,
     OnCtrlAltShiftY,
     OnCtrlAltShiftLogoZ,
+    OnCtrlKCtrlC,
 }
@@ -62,7 +62,8 @@ fn main() -> Result<(), ApplicationError> {
                 })
                 .title("Set color...")
                 .inner_size((400, 200))
-                .position((500, 100));
+                .position((500, 100))
+                .theme_mode(ThemeMode::DarkMode);
             }
         });
 
@@ -62,6 +62,7 @@ fn main() -> Result<(), ApplicationError> {
                 })
                 .title("Set color...")
                 .inner_size((400, 200))
+                // Relative to the main window. Ignored on Wayland, where the compositor places the popup.
                 .position((500, 100));
             }
         });
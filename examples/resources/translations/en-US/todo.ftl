@@ -0,0 +1,7 @@
+new-todo = What needs to be done?
+items-left =
+    { NUMBER($count) ->
+        [one] { $count } item left
+       *[other] { $count } items left
+    }
+clear-completed = Clear completed
@@ -0,0 +1,7 @@
+new-todo = Que faut-il faire ?
+items-left =
+    { NUMBER($count) ->
+        [one] { $count } élément restant
+       *[other] { $count } éléments restants
+    }
+clear-completed = Effacer les tâches terminées
@@ -48,17 +48,25 @@ pub struct AppData {
 
 impl Model for AppData {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, meta| {
-            // Intercept WindowClose event to show a dialog if not 'saved'.
-            if let WindowEvent::WindowClose = window_event {
+        event.map(|window_event, _| {
+            // Keep the window open and show a dialog if not 'saved'.
+            if let WindowEvent::CloseRequested = window_event {
                 if !self.is_saved {
                     self.show_dialog = true;
-                    meta.consume();
+                    cx.prevent_close();
                 }
             }
         });
 
         event.map(|app_event, _| match app_event {
+            AppEvent::Close => {
+                if self.is_saved {
+                    cx.emit(WindowEvent::WindowClose);
+                } else {
+                    self.show_dialog = true;
+                }
+            }
+
             AppEvent::CloseModal => {
                 self.show_dialog = false;
             }
@@ -77,6 +85,7 @@ impl Model for AppData {
 }
 
 pub enum AppEvent {
+    Close,
     CloseModal,
     Save,
     SaveAndClose,
@@ -89,8 +98,7 @@ fn main() -> Result<(), ApplicationError> {
         AppData { is_saved: false, show_dialog: false }.build(cx);
 
         HStack::new(cx, |cx| {
-            Button::new(cx, |cx| Label::new(cx, "Close"))
-                .on_press(|cx| cx.emit(WindowEvent::WindowClose));
+            Button::new(cx, |cx| Label::new(cx, "Close")).on_press(|cx| cx.emit(AppEvent::Close));
             Button::new(cx, |cx| Label::new(cx, "Save")).on_press(|cx| cx.emit(AppEvent::Save));
         })
         .horizontal_gap(Pixels(10.0))
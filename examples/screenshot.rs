@@ -0,0 +1,24 @@
+use vizia::backend::BackendContext;
+use vizia::prelude::*;
+
+// Renders a view tree to PNG files without opening a window.
+fn main() {
+    let mut cx = BackendContext::new(Context::new());
+    cx.add_headless_window(WindowSize::new(400, 300), 2.0);
+
+    let stack = VStack::new(cx.context(), |cx| {
+        Label::new(cx, "Rendered without a window");
+        Button::new(cx, |cx| Label::new(cx, "Button"));
+    })
+    .alignment(Alignment::Center)
+    .gap(Pixels(10.0))
+    .entity();
+
+    if let Some(png) = cx.render_to_image() {
+        std::fs::write("screenshot.png", png).expect("Failed to save screenshot");
+    }
+
+    if let Some(png) = cx.snapshot(stack) {
+        std::fs::write("screenshot_stack.png", png).expect("Failed to save snapshot");
+    }
+}
@@ -3,7 +3,7 @@ use vizia::prelude::*;
 const STYLE: &str = r#"
 
 .multiply element {
-    blend-mode: multiply;
+    mix-blend-mode: multiply;
 }
 
 "#;
@@ -10,7 +10,7 @@ const STYLE: &str = r#"
         size: 200px;
         left: 300px;
         top: 300px;
-        backdrop-filter: blur(16px);
+        backdrop-filter: blur(16px) saturate(180%);
         position: absolute;
         corner-radius: 32px;
         background-color: rgba(255, 255, 255, 0.4);
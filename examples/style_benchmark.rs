@@ -0,0 +1,53 @@
+use vizia::backend::BackendContext;
+use vizia::prelude::*;
+
+// Measures how long it takes to restyle a large tree of views with ids and classes without opening a window:
+//
+//     cargo run --release --example style_benchmark
+const VIEWS: usize = 10_000;
+const RUNS: usize = 5;
+
+const STYLE: &str = r#"
+    .row {
+        height: 20px;
+    }
+
+    .row.even {
+        background-color: #eeeeee;
+    }
+
+    .list .row:hover {
+        background-color: #dddddd;
+    }
+
+    #item-0 {
+        color: red;
+    }
+"#;
+
+fn main() {
+    let mut times = Vec::new();
+
+    for _ in 0..RUNS {
+        let mut cx = BackendContext::new(Context::new());
+        cx.add_headless_window(WindowSize::new(800, 600), 1.0);
+        cx.context().add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        VStack::new(cx.context(), |cx| {
+            for index in 0..VIEWS {
+                Element::new(cx)
+                    .id(format!("item-{index}"))
+                    .class("row")
+                    .toggle_class("even", index % 2 == 0);
+            }
+        })
+        .class("list");
+
+        let start = Instant::now();
+        cx.process_style_updates();
+        times.push(start.elapsed());
+    }
+
+    times.sort();
+    println!("Restyled {} views in {:?} (median of {} runs)", VIEWS, times[RUNS / 2], RUNS);
+}
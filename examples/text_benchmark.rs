@@ -0,0 +1,42 @@
+use vizia::backend::BackendContext;
+use vizia::prelude::*;
+
+// Measures how long it takes to build and lay out the text of a large number of labels without opening a window.
+// Compare single-threaded and parallel text construction by running with and without the `parallel` feature:
+//
+//     cargo run --release --example text_benchmark
+//     cargo run --release --example text_benchmark --features parallel
+const LABELS: usize = 10_000;
+const RUNS: usize = 5;
+
+fn main() {
+    let mut times = Vec::new();
+
+    for _ in 0..RUNS {
+        let mut cx = BackendContext::new(Context::new());
+        cx.add_headless_window(WindowSize::new(800, 600), 1.0);
+
+        VStack::new(cx.context(), |cx| {
+            for index in 0..LABELS {
+                Label::new(
+                    cx,
+                    format!("Label number {index}, with enough text to wrap onto a second line"),
+                )
+                .width(Pixels(200.0));
+            }
+        });
+
+        let start = Instant::now();
+        cx.process_style_updates();
+        cx.process_visual_updates();
+        times.push(start.elapsed());
+    }
+
+    times.sort();
+    println!(
+        "Built and laid out the text of {} labels in {:?} (median of {} runs)",
+        LABELS,
+        times[RUNS / 2],
+        RUNS
+    );
+}
@@ -0,0 +1,125 @@
+use vizia::prelude::*;
+
+#[derive(Clone, Data, Lens)]
+pub struct Todo {
+    text: String,
+    done: bool,
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    todos: Vec<Todo>,
+    draft: String,
+}
+
+pub enum AppEvent {
+    SetDraft(String),
+    Add,
+    Toggle(usize),
+    ClearCompleted,
+    ToggleLanguage,
+}
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetDraft(text) => self.draft = text.clone(),
+            AppEvent::Add => {
+                let text = std::mem::take(&mut self.draft);
+                if !text.trim().is_empty() {
+                    self.todos.push(Todo { text: text.trim().to_owned(), done: false });
+                }
+            }
+            AppEvent::Toggle(index) => {
+                if let Some(todo) = self.todos.get_mut(*index) {
+                    todo.done ^= true;
+                }
+            }
+            AppEvent::ClearCompleted => self.todos.retain(|todo| !todo.done),
+            AppEvent::ToggleLanguage => {
+                if cx.environment().locale != "fr" {
+                    cx.emit(EnvironmentEvent::SetLocale("fr".parse().unwrap()));
+                } else {
+                    cx.emit(EnvironmentEvent::SetLocale("en-US".parse().unwrap()));
+                }
+            }
+        });
+    }
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_translation(
+            "en-US".parse().unwrap(),
+            include_str!("resources/translations/en-US/todo.ftl").to_owned(),
+        );
+        cx.add_translation(
+            "fr".parse().unwrap(),
+            include_str!("resources/translations/fr/todo.ftl").to_owned(),
+        );
+
+        AppData {
+            todos: vec![
+                Todo { text: "Write the docs".to_owned(), done: false },
+                Todo { text: "Fix the build".to_owned(), done: true },
+            ],
+            draft: String::new(),
+        }
+        .build(cx);
+
+        VStack::new(cx, |cx| {
+            HStack::new(cx, |cx| {
+                Checkbox::new(cx, Environment::locale.map(|locale| *locale == "fr"))
+                    .id("toggle-language")
+                    .on_toggle(|cx| cx.emit(AppEvent::ToggleLanguage));
+                Label::new(cx, "Français").describing("toggle-language");
+            })
+            .alignment(Alignment::Center)
+            .horizontal_gap(Pixels(10.0))
+            .height(Auto);
+
+            Textbox::new(cx, AppData::draft)
+                .placeholder(Localized::new("new-todo"))
+                .width(Stretch(1.0))
+                .on_edit(|cx, text| cx.emit(AppEvent::SetDraft(text)))
+                .on_submit(|cx, _, enter| {
+                    if enter {
+                        cx.emit(AppEvent::Add);
+                    }
+                });
+
+            List::new(cx, AppData::todos, |cx, index, todo| {
+                HStack::new(cx, |cx| {
+                    Checkbox::new(cx, todo.map(|todo| todo.done))
+                        .on_toggle(move |cx| cx.emit(AppEvent::Toggle(index)));
+                    Label::new(cx, todo.map(|todo| todo.text.clone())).hoverable(false);
+                })
+                .alignment(Alignment::Left)
+                .horizontal_gap(Pixels(10.0))
+                .height(Auto);
+            })
+            .height(Auto);
+
+            // The number of remaining items selects the plural form of the message for the current locale.
+            HStack::new(cx, |cx| {
+                Label::new(
+                    cx,
+                    Localized::new("items-left").arg(
+                        "count",
+                        AppData::todos.map(|todos| todos.iter().filter(|todo| !todo.done).count()),
+                    ),
+                )
+                .width(Stretch(1.0));
+
+                Button::new(cx, |cx| Label::new(cx, Localized::new("clear-completed")))
+                    .on_press(|cx| cx.emit(AppEvent::ClearCompleted));
+            })
+            .alignment(Alignment::Center)
+            .height(Auto);
+        })
+        .vertical_gap(Pixels(10.0))
+        .space(Pixels(10.0));
+    })
+    .title("Todo")
+    .run()
+}
@@ -0,0 +1,43 @@
+use vizia::prelude::*;
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+const STYLE: &str = r#"
+    :root, window {
+        background-color: transparent;
+    }
+
+    .panel {
+        size: 1s;
+        padding: 20px;
+        vertical-gap: 10px;
+        corner-radius: 12px;
+        background-color: #20202080;
+        border-width: 1px;
+        border-color: #ffffff30;
+    }
+"#;
+
+#[cfg(not(feature = "baseview"))]
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        VStack::new(cx, |cx| {
+            Label::new(cx, "Drag the panel to move the window.");
+            Button::new(cx, |cx| Label::new(cx, "Close"))
+                .on_press(|cx| cx.emit(WindowEvent::WindowClose));
+        })
+        .class("panel")
+        .on_press_down(|cx| cx.emit(WindowEvent::DragWindow));
+    })
+    .title("Transparent Window")
+    .inner_size((400, 200))
+    .decorations(false)
+    .transparent(true)
+    .blur(true)
+    .run()
+}
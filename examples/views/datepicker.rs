@@ -1,34 +1,53 @@
 mod helpers;
 use chrono::{NaiveDate, Utc};
 use helpers::*;
+use vizia::localization::format_date;
 use vizia::prelude::*;
 
+const LOCALES: [&str; 4] = ["en-US", "de-DE", "fr-FR", "ar-EG"];
+
 #[derive(Clone, Lens)]
 struct AppState {
     date: NaiveDate,
+    locale: usize,
 }
 
 pub enum AppEvent {
     SetDate(NaiveDate),
+    SetLocale(usize),
 }
 
 impl Model for AppState {
-    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|app_event, _| match app_event {
             AppEvent::SetDate(date) => {
                 self.date = *date;
             }
+
+            AppEvent::SetLocale(index) => {
+                self.locale = *index;
+                cx.emit(EnvironmentEvent::SetLocale(LOCALES[*index].parse().unwrap()));
+            }
         });
     }
 }
 
 fn main() -> Result<(), ApplicationError> {
     Application::new(|cx| {
-        AppState { date: Utc::now().date_naive() }.build(cx);
+        AppState { date: Utc::now().date_naive(), locale: 0 }.build(cx);
 
         ExamplePage::new(cx, |cx| {
+            PickList::new(cx, StaticLens::new_slice(&LOCALES), AppState::locale, true)
+                .on_select(|cx, index| cx.emit(AppEvent::SetLocale(index)))
+                .width(Pixels(140.0));
+
             Datepicker::new(cx, AppState::date)
                 .on_select(|cx, date| cx.emit(AppEvent::SetDate(date)));
+
+            Binding::new(cx, Environment::locale, |cx, locale| {
+                let locale = locale.get(cx);
+                Label::new(cx, AppState::date.map(move |date| format_date(&locale, date)));
+            });
         });
     })
     .title("Datepicker")
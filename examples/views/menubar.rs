@@ -166,7 +166,8 @@ fn main() -> Result<(), ApplicationError> {
                         MenuButton::new(cx, |_| debug!("About"), |cx| Label::new(cx, "About"));
                     },
                 );
-            });
+            })
+            .hover_delay(Duration::from_millis(200));
         });
     })
     .title("Menu Bar")
@@ -4,8 +4,8 @@ use vizia::prelude::*;
 
 #[derive(Clone, Lens)]
 struct AppData {
-    rating1: u32,
-    rating2: u32,
+    rating1: f32,
+    rating2: f32,
 }
 
 impl Model for AppData {
@@ -18,18 +18,19 @@ impl Model for AppData {
 }
 
 enum AppEvent {
-    SetRating1(u32),
-    SetRating2(u32),
+    SetRating1(f32),
+    SetRating2(f32),
 }
 
 fn main() -> Result<(), ApplicationError> {
     Application::new(|cx| {
-        AppData { rating1: 3, rating2: 7 }.build(cx);
+        AppData { rating1: 3.0, rating2: 7.5 }.build(cx);
 
         ExamplePage::vertical(cx, |cx| {
             Rating::new(cx, 5, AppData::rating1)
                 .on_change(|ex, rating| ex.emit(AppEvent::SetRating1(rating)));
             Rating::new(cx, 10, AppData::rating2)
+                .half_precision(true)
                 .on_change(|ex, rating| ex.emit(AppEvent::SetRating2(rating)));
         });
     })
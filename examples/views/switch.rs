@@ -6,16 +6,20 @@ use vizia::prelude::*;
 pub struct AppData {
     pub option1: bool,
     pub option2: bool,
+    pub option3: bool,
+    pub loading: bool,
 }
 
 #[derive(Debug)]
 pub enum AppEvent {
     ToggleOption1,
     ToggleOption2,
+    ToggleOption3,
+    Applied,
 }
 
 impl Model for AppData {
-    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|app_event, _| match app_event {
             AppEvent::ToggleOption1 => {
                 self.option1 ^= true;
@@ -24,13 +28,24 @@ impl Model for AppData {
             AppEvent::ToggleOption2 => {
                 self.option2 ^= true;
             }
+
+            // Simulate waiting for the change to be applied.
+            AppEvent::ToggleOption3 => {
+                self.loading = true;
+                cx.schedule_emit(AppEvent::Applied, Instant::now() + Duration::from_secs(2));
+            }
+
+            AppEvent::Applied => {
+                self.option3 ^= true;
+                self.loading = false;
+            }
         });
     }
 }
 
 fn main() -> Result<(), ApplicationError> {
     Application::new(|cx| {
-        AppData { option1: true, option2: false }.build(cx);
+        AppData { option1: true, option2: false, option3: false, loading: false }.build(cx);
 
         ExamplePage::vertical(cx, |cx| {
             Label::new(cx, "Basic Switches");
@@ -54,6 +69,18 @@ fn main() -> Result<(), ApplicationError> {
             .size(Auto)
             .horizontal_gap(Pixels(5.0))
             .alignment(Alignment::Center);
+
+            Label::new(cx, "Loading Switch");
+
+            HStack::new(cx, |cx| {
+                Switch::with_loading(cx, AppData::option3, AppData::loading)
+                    .on_toggle(|cx| cx.emit(AppEvent::ToggleOption3))
+                    .id("Switch_3");
+                Label::new(cx, "Switch 3").describing("Switch_3");
+            })
+            .size(Auto)
+            .horizontal_gap(Pixels(5.0))
+            .alignment(Alignment::Center);
         });
     })
     .title("Switch")
@@ -50,9 +50,7 @@ impl DemoRegion {
 }
 
 impl View for DemoRegion {
-    fn element(&self) -> Option<&'static str> {
-        Some("demo-region")
-    }
+    const ELEMENT: Option<&'static str> = Some("demo-region");
 
     fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
         event.map(|e, _| match e {
@@ -1,8 +1,31 @@
-use vizia::prelude::*;
+use vizia::{icons::ICON_CHECK, prelude::*};
 
 use crate::components::DemoRegion;
 
+const FILTERS: [&str; 4] = ["Vegetarian", "Vegan", "Gluten free", "Spicy"];
+
+#[derive(Lens)]
+pub struct ChipData {
+    filters: [bool; 4],
+}
+
+pub enum ChipEvent {
+    ToggleFilter(usize),
+}
+
+impl Model for ChipData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|chip_event, _| match chip_event {
+            ChipEvent::ToggleFilter(index) => {
+                self.filters[*index] ^= true;
+            }
+        });
+    }
+}
+
 pub fn chip(cx: &mut Context) {
+    ChipData { filters: [false, true, false, false] }.build(cx);
+
     VStack::new(cx, |cx| {
         Markdown::new(
             cx,
@@ -58,6 +81,52 @@ Chip::new(cx, "Clickable & Closable")
     .on_press(|cx| {...})
     .on_close(|cx| {...});"#,
         );
+
+        Markdown::new(cx, "### Chip with leading content");
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                Chip::new(cx, "Jane Doe").leading(|cx| {
+                    Avatar::new(cx, |cx| {
+                        Label::new(cx, "JD");
+                    });
+                });
+                Chip::new(cx, "Verified").variant(ChipVariant::Outline).leading(|cx| {
+                    Svg::new(cx, ICON_CHECK);
+                });
+            },
+            r#"Chip::new(cx, "Jane Doe").leading(|cx| {
+    Avatar::new(cx, |cx| {
+        Label::new(cx, "JD");
+    });
+});
+Chip::new(cx, "Verified")
+    .variant(ChipVariant::Outline)
+    .leading(|cx| {
+        Svg::new(cx, ICON_CHECK);
+    });"#,
+        );
+
+        Markdown::new(cx, "### Filter chips");
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                for (index, filter) in FILTERS.into_iter().enumerate() {
+                    Chip::new(cx, filter)
+                        .variant(ChipVariant::Outline)
+                        .selectable(ChipData::filters.map(move |filters| filters[index]))
+                        .on_toggle(move |cx| cx.emit(ChipEvent::ToggleFilter(index)));
+                }
+            },
+            r#"for (index, filter) in FILTERS.into_iter().enumerate() {
+    Chip::new(cx, filter)
+        .variant(ChipVariant::Outline)
+        .selectable(AppData::filters.map(move |filters| filters[index]))
+        .on_toggle(move |cx| cx.emit(AppEvent::ToggleFilter(index)));
+}"#,
+        );
     })
     .class("panel");
 }
@@ -1,29 +1,41 @@
 use crate::components::DemoRegion;
 use chrono::{NaiveDate, Utc};
+use vizia::localization::format_date;
 use vizia::prelude::*;
 
+const LOCALES: [&str; 3] = ["en-US", "de-DE", "ar-EG"];
+
 #[derive(Clone, Lens)]
 struct DatepickerState {
     date: NaiveDate,
+    locales: Vec<&'static str>,
+    locale: usize,
 }
 
 pub enum DatepickerEvent {
     SetDate(NaiveDate),
+    SetLocale(usize),
 }
 
 impl Model for DatepickerState {
-    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|app_event, _| match app_event {
             DatepickerEvent::SetDate(date) => {
                 self.date = *date;
             }
+
+            DatepickerEvent::SetLocale(index) => {
+                self.locale = *index;
+                cx.emit(EnvironmentEvent::SetLocale(self.locales[*index].parse().unwrap()));
+            }
         });
     }
 }
 
 pub fn datepicker(cx: &mut Context) {
     VStack::new(cx, |cx| {
-        DatepickerState { date: Utc::now().date_naive() }.build(cx);
+        DatepickerState { date: Utc::now().date_naive(), locales: LOCALES.to_vec(), locale: 0 }
+            .build(cx);
 
         Markdown::new(cx, "# Datepicker");
 
@@ -38,6 +50,38 @@ pub fn datepicker(cx: &mut Context) {
                     .on_select(|cx, date| cx.emit(DatepickerEvent::SetDate(date)));
             },
             r#"Datepicker::new(cx, DatepickerState::date)
+    .on_select(|cx, date| cx.emit(DatepickerEvent::SetDate(date)));"#,
+        );
+
+        Markdown::new(cx, "### Localized datepicker
+The names of months and days, the digits, and the first day of the week follow the locale of the environment.
+        ");
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                VStack::new(cx, |cx| {
+                    PickList::new(cx, DatepickerState::locales, DatepickerState::locale, true)
+                        .on_select(|cx, index| cx.emit(DatepickerEvent::SetLocale(index)))
+                        .width(Pixels(150.0));
+
+                    Datepicker::new(cx, DatepickerState::date)
+                        .on_select(|cx, date| cx.emit(DatepickerEvent::SetDate(date)));
+
+                    Binding::new(cx, Environment::locale, |cx, locale| {
+                        let locale = locale.get(cx);
+                        Label::new(
+                            cx,
+                            DatepickerState::date.map(move |date| format_date(&locale, date)),
+                        );
+                    });
+                })
+                .gap(Pixels(8.0))
+                .size(Auto);
+            },
+            r#"cx.emit(EnvironmentEvent::SetLocale("de-DE".parse().unwrap()));
+
+Datepicker::new(cx, DatepickerState::date)
     .on_select(|cx, date| cx.emit(DatepickerEvent::SetDate(date)));"#,
         );
     })
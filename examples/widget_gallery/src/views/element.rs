@@ -2,6 +2,22 @@ use vizia::prelude::*;
 
 use crate::DemoRegion;
 
+// A custom view which is styled through its element name in the stylesheet of the gallery.
+pub struct ColorSwatch;
+
+impl ColorSwatch {
+    pub fn new(cx: &mut Context, name: &'static str) -> Handle<Self> {
+        Self.build(cx, |cx| {
+            Element::new(cx).class("color");
+            Label::new(cx, name);
+        })
+    }
+}
+
+impl View for ColorSwatch {
+    const ELEMENT: Option<&'static str> = Some("color-swatch");
+}
+
 pub fn element(cx: &mut Context) {
     VStack::new(cx, |cx| {
         Markdown::new(cx, "# Element");
@@ -19,6 +35,38 @@ pub fn element(cx: &mut Context) {
     .size(Pixels(100.0))
     .background_color(Color::red());"#,
         );
+
+        Markdown::new(cx, "### Custom element");
+
+        Markdown::new(
+            cx,
+            "The `ELEMENT` constant of a view sets the element name used to select it in css.",
+        );
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                HStack::new(cx, |cx| {
+                    ColorSwatch::new(cx, "Red").class("red");
+                    ColorSwatch::new(cx, "Blue").class("blue");
+                })
+                .size(Auto)
+                .horizontal_gap(Pixels(8.0));
+            },
+            r#"impl View for ColorSwatch {
+    const ELEMENT: Option<&'static str> = Some("color-swatch");
+}
+
+/* In css */
+color-swatch {
+    corner-radius: 4px;
+    border-width: 1px;
+}
+
+color-swatch.red > .color {
+    background-color: red;
+}"#,
+        );
     })
     .class("panel");
 }
@@ -37,6 +37,28 @@ pub fn knob(cx: &mut Context) {
             r#"Knob::new(cx, 0.5, KnobState::value, false)
     .on_changing(|cx, val| cx.emit(KnobEvent::SetValue(val)));"#,
         );
+
+        Markdown::new(cx, "### Stepped knob with value label");
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                Knob::new(cx, 0.5, KnobState::value, false)
+                    .step(0.1)
+                    .default_value(0.5)
+                    .show_value(true)
+                    .format(|val| format!("{:.0}%", val * 100.0))
+                    .on_change(|cx, val| cx.emit(KnobEvent::SetValue(val)))
+                    .size(Pixels(48.0));
+            },
+            r#"Knob::new(cx, 0.5, KnobState::value, false)
+    .step(0.1)
+    .default_value(0.5)
+    .show_value(true)
+    .format(|val| format!("{:.0}%", val * 100.0))
+    .on_change(|cx, val| cx.emit(KnobEvent::SetValue(val)))
+    .size(Pixels(48.0));"#,
+        );
     })
     .class("panel");
 }
@@ -54,6 +54,16 @@ pub fn progressbar(cx: &mut Context) {
             },
             r#"ProgressBar::horizontal(cx, ProgressData::progress).width(Pixels(300.0));"#,
         );
+
+        Markdown::new(cx, "### Indeterminate progress bar");
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                ProgressBar::horizontal(cx, StaticLens::new(&None::<f32>)).width(Pixels(300.0));
+            },
+            r#"ProgressBar::horizontal(cx, StaticLens::new(&None::<f32>)).width(Pixels(300.0));"#,
+        );
     })
     .class("panel");
 }
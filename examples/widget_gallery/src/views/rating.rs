@@ -4,23 +4,26 @@ use crate::DemoRegion;
 
 #[derive(Clone, Lens)]
 struct RatingData {
-    rating: u32,
+    rating: f32,
+    half_rating: f32,
 }
 
 impl Model for RatingData {
     fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
         event.map(|app_event, _| match app_event {
             RatingEvent::SetRating(val) => self.rating = *val,
+            RatingEvent::SetHalfRating(val) => self.half_rating = *val,
         })
     }
 }
 
 enum RatingEvent {
-    SetRating(u32),
+    SetRating(f32),
+    SetHalfRating(f32),
 }
 
 pub fn rating(cx: &mut Context) {
-    RatingData { rating: 3 }.build(cx);
+    RatingData { rating: 3.0, half_rating: 2.5 }.build(cx);
 
     VStack::new(cx, |cx| {
         Markdown::new(cx, "# Rating");
@@ -38,6 +41,20 @@ pub fn rating(cx: &mut Context) {
             r#"Rating::new(cx, 5, RatingData::rating)
     .on_change(|ex, rating| ex.emit(RatingEvent::SetRating(rating)));"#,
         );
+
+        Markdown::new(cx, "### Half precision");
+
+        DemoRegion::new(
+            cx,
+            |cx| {
+                Rating::new(cx, 5, RatingData::half_rating)
+                    .half_precision(true)
+                    .on_change(|ex, rating| ex.emit(RatingEvent::SetHalfRating(rating)));
+            },
+            r#"Rating::new(cx, 5, RatingData::half_rating)
+    .half_precision(true)
+    .on_change(|ex, rating| ex.emit(RatingEvent::SetHalfRating(rating)));"#,
+        );
     })
     .class("panel");
 }
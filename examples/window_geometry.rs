@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use vizia::prelude::*;
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+const GEOMETRY_PATH: &str = "window_geometry.toml";
+
+/// The window geometry as it is stored in the TOML file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGeometry {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    maximized: bool,
+    monitor: Option<String>,
+}
+
+impl SavedGeometry {
+    fn load() -> Option<Self> {
+        let contents = std::fs::read_to_string(GEOMETRY_PATH).ok()?;
+        toml::from_str(&contents).ok()
+    }
+
+    fn save(&self) {
+        match toml::to_string(self) {
+            Ok(contents) => {
+                if let Err(err) = std::fs::write(GEOMETRY_PATH, contents) {
+                    println!("Failed to save window geometry: {}", err);
+                }
+            }
+
+            Err(err) => println!("Failed to serialize window geometry: {}", err),
+        }
+    }
+}
+
+impl From<SavedGeometry> for WindowGeometry {
+    fn from(saved: SavedGeometry) -> Self {
+        WindowGeometry {
+            position: WindowPosition::new(saved.x, saved.y),
+            size: WindowSize::new(saved.width, saved.height),
+            maximized: saved.maximized,
+            monitor: saved.monitor,
+        }
+    }
+}
+
+pub struct AppData {
+    saved: Option<SavedGeometry>,
+}
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| {
+            if let WindowEvent::WindowClose = window_event {
+                let geometry = cx.window_geometry();
+
+                // Keep the previous position and size when maximized so the window restores to them.
+                let saved = match (&self.saved, geometry.maximized) {
+                    (Some(previous), true) => SavedGeometry {
+                        maximized: true,
+                        monitor: geometry.monitor,
+                        ..previous.clone()
+                    },
+
+                    _ => SavedGeometry {
+                        x: geometry.position.x,
+                        y: geometry.position.y,
+                        width: geometry.size.width,
+                        height: geometry.size.height,
+                        maximized: geometry.maximized,
+                        monitor: geometry.monitor,
+                    },
+                };
+
+                saved.save();
+                self.saved = Some(saved);
+            }
+        });
+    }
+}
+
+#[cfg(not(feature = "baseview"))]
+fn main() -> Result<(), ApplicationError> {
+    let saved = SavedGeometry::load();
+    let geometry = saved.clone().map(WindowGeometry::from);
+
+    let app = Application::new(move |cx| {
+        AppData { saved: saved.clone() }.build(cx);
+
+        Label::new(cx, "Move or resize the window, then close it and run the example again.")
+            .space(Stretch(1.0));
+    })
+    .title("Window Geometry");
+
+    match geometry {
+        Some(geometry) => app.geometry(geometry).run(),
+        None => app.inner_size((600, 400)).run(),
+    }
+}